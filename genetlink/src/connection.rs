@@ -43,6 +43,8 @@ pub fn new_connection_with_socket<S>() -> io::Result<(
 where
     S: AsyncSocket,
 {
-    let (conn, handle, messages) = netlink_proto::new_connection_with_socket(NETLINK_GENERIC)?;
+    let (mut conn, handle, messages) =
+        netlink_proto::new_connection_with_socket::<RawGenlMessage, S>(NETLINK_GENERIC)?;
+    conn.enable_ext_ack();
     Ok((conn, GenetlinkHandle::new(handle), messages))
 }
@@ -10,17 +10,15 @@ pub enum GenetlinkError {
     #[error("Failed to decode generic packet")]
     DecodeError(#[from] netlink_packet_utils::DecodeError),
     #[error("Netlink error message: {0}")]
-    NetlinkError(std::io::Error),
+    NetlinkError(netlink_packet_core::error::ErrorMessage),
     #[error("Cannot find specified netlink attribute: {0}")]
     AttributeNotFound(String),
     #[error("Desire netlink message type not received")]
     NoMessageReceived,
 }
 
-// Since `netlink_packet_core::error::ErrorMessage` doesn't impl `Error` trait,
-// it need to convert to `std::io::Error` here
 impl From<netlink_packet_core::error::ErrorMessage> for GenetlinkError {
     fn from(err_msg: netlink_packet_core::error::ErrorMessage) -> Self {
-        Self::NetlinkError(err_msg.to_io())
+        Self::NetlinkError(err_msg)
     }
 }
@@ -125,8 +125,8 @@ mod test {
                 .query_family_id(&handle, name)
                 .await
                 .or_else(|e| {
-                    if let GenetlinkError::NetlinkError(io_err) = &e {
-                        if io_err.kind() == ErrorKind::NotFound {
+                    if let GenetlinkError::NetlinkError(err_msg) = &e {
+                        if err_msg.to_io().kind() == ErrorKind::NotFound {
                             // Ignore non exist entries
                             Ok(0)
                         } else {
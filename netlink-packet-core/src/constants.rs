@@ -37,3 +37,34 @@ pub const NLM_F_NONREC: u16 = 256;
 pub const NLM_F_CAPPED: u16 = 256;
 /// extended ACK TVLs were included
 pub const NLM_F_ACK_TLVS: u16 = 512;
+
+/// Unused extended ACK attribute
+pub const NLMSGERR_ATTR_UNUSED: u16 = 0;
+/// Error message string (string)
+pub const NLMSGERR_ATTR_MSG: u16 = 1;
+/// Offset of the invalid attribute in the original message, counting from the beginning of the
+/// header (u32)
+pub const NLMSGERR_ATTR_OFFS: u16 = 2;
+/// Arbitrary subsystem specific cookie to be used - in the success case - to identify a created
+/// object or operation or similar (binary)
+pub const NLMSGERR_ATTR_COOKIE: u16 = 3;
+/// Policy for a rejected attribute (nested)
+pub const NLMSGERR_ATTR_POLICY: u16 = 4;
+/// Type of a missing required attribute (u32)
+pub const NLMSGERR_ATTR_MISS_TYPE: u16 = 5;
+/// Offset of the nest where an attribute was missing (u32)
+pub const NLMSGERR_ATTR_MISS_NEST: u16 = 6;
+
+pub const NL_POLICY_TYPE_ATTR_UNSPEC: u16 = 0;
+pub const NL_POLICY_TYPE_ATTR_TYPE: u16 = 1;
+pub const NL_POLICY_TYPE_ATTR_MIN_VALUE_S: u16 = 2;
+pub const NL_POLICY_TYPE_ATTR_MAX_VALUE_S: u16 = 3;
+pub const NL_POLICY_TYPE_ATTR_MIN_VALUE_U: u16 = 4;
+pub const NL_POLICY_TYPE_ATTR_MAX_VALUE_U: u16 = 5;
+pub const NL_POLICY_TYPE_ATTR_MIN_LENGTH: u16 = 6;
+pub const NL_POLICY_TYPE_ATTR_MAX_LENGTH: u16 = 7;
+pub const NL_POLICY_TYPE_ATTR_POLICY_IDX: u16 = 8;
+pub const NL_POLICY_TYPE_ATTR_POLICY_MAXTYPE: u16 = 9;
+pub const NL_POLICY_TYPE_ATTR_BITFIELD32_MASK: u16 = 10;
+pub const NL_POLICY_TYPE_ATTR_PAD: u16 = 11;
+pub const NL_POLICY_TYPE_ATTR_MASK: u16 = 12;
@@ -2,9 +2,23 @@
 
 use std::{fmt, io, mem::size_of};
 
+use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 
-use crate::{DecodeError, Emitable, Field, Parseable, Rest};
+use crate::{
+    constants::*,
+    utils::{
+        nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_ALIGNTO},
+        parsers::{parse_string, parse_u32, parse_u64},
+    },
+    DecodeError,
+    Emitable,
+    Field,
+    Parseable,
+    ParseableParametrized,
+    Rest,
+    NETLINK_HEADER_LEN,
+};
 
 const CODE: Field = 0..4;
 const PAYLOAD: Rest = 4..;
@@ -79,18 +93,35 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ErrorBuffer<T> {
 pub struct ErrorMessage {
     pub code: i32,
     pub header: Vec<u8>,
+    /// Extended ACK attributes. The kernel only sends them if the `NETLINK_EXT_ACK` socket option
+    /// is set, in which case the message has the `NLM_F_ACK_TLVS` flag.
+    pub nlas: Vec<ExtAckNla>,
 }
 
 pub type AckMessage = ErrorMessage;
 
+fn align(len: usize) -> usize {
+    (len + NLA_ALIGNTO - 1) & !(NLA_ALIGNTO - 1)
+}
+
 impl Emitable for ErrorMessage {
     fn buffer_len(&self) -> usize {
-        size_of::<i32>() + self.header.len()
+        if self.nlas.is_empty() {
+            size_of::<i32>() + self.header.len()
+        } else {
+            size_of::<i32>() + align(self.header.len()) + self.nlas.as_slice().buffer_len()
+        }
     }
     fn emit(&self, buffer: &mut [u8]) {
         let mut buffer = ErrorBuffer::new(buffer);
         buffer.set_code(self.code);
-        buffer.payload_mut().copy_from_slice(&self.header)
+        let payload = buffer.payload_mut();
+        payload[..self.header.len()].copy_from_slice(&self.header);
+        if !self.nlas.is_empty() {
+            let offset = align(self.header.len());
+            payload[self.header.len()..offset].fill(0);
+            self.nlas.as_slice().emit(&mut payload[offset..]);
+        }
     }
 }
 
@@ -107,6 +138,49 @@ impl<'buffer, T: AsRef<[u8]> + 'buffer> Parseable<ErrorBuffer<&'buffer T>> for E
         Ok(ErrorMessage {
             code: buf.code(),
             header: buf.payload().to_vec(),
+            nlas: vec![],
+        })
+    }
+}
+
+/// Parse an error message, given the flags of the netlink header it was received with. If these
+/// flags contain `NLM_F_ACK_TLVS`, the extended ACK attributes that follow the original message
+/// are parsed into `ErrorMessage::nlas`.
+impl<'buffer, T: AsRef<[u8]> + 'buffer> ParseableParametrized<ErrorBuffer<&'buffer T>, u16>
+    for ErrorMessage
+{
+    fn parse_with_param(
+        buf: &ErrorBuffer<&'buffer T>,
+        flags: u16,
+    ) -> Result<ErrorMessage, DecodeError> {
+        if flags & NLM_F_ACK_TLVS == 0 {
+            return ErrorMessage::parse(buf);
+        }
+
+        // The original message is either truncated to its header
+        // (NLM_F_CAPPED), or included in full, in which case its
+        // length is given by its header.
+        let payload = buf.payload();
+        let header_len = if flags & NLM_F_CAPPED != 0 {
+            NETLINK_HEADER_LEN
+        } else if payload.len() >= NETLINK_HEADER_LEN {
+            NativeEndian::read_u32(&payload[..4]) as usize
+        } else {
+            payload.len()
+        };
+        let header_len = header_len.min(payload.len());
+        let tlvs = &payload[align(header_len).min(payload.len())..];
+
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(tlvs) {
+            let nla = nla.context("invalid extended ACK attribute")?;
+            nlas.push(ExtAckNla::parse(&nla).context("invalid extended ACK attribute")?);
+        }
+
+        Ok(ErrorMessage {
+            code: buf.code(),
+            header: payload[..header_len].to_vec(),
+            nlas,
         })
     }
 }
@@ -120,20 +194,308 @@ impl ErrorMessage {
     pub fn to_io(&self) -> io::Error {
         io::Error::from_raw_os_error(self.code.abs())
     }
+
+    /// The error message string sent by the kernel (`NLMSGERR_ATTR_MSG`), if any
+    pub fn ext_ack_message(&self) -> Option<&str> {
+        self.nlas.iter().find_map(|nla| match nla {
+            ExtAckNla::Msg(msg) => Some(msg.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The offset of the attribute that caused the error (`NLMSGERR_ATTR_OFFS`), counting from
+    /// the beginning of the original message, if any
+    pub fn bad_attribute_offset(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| match nla {
+            ExtAckNla::Offset(offset) => Some(*offset),
+            _ => None,
+        })
+    }
+
+    /// The cookie sent by the kernel (`NLMSGERR_ATTR_COOKIE`), if any
+    pub fn cookie(&self) -> Option<&[u8]> {
+        self.nlas.iter().find_map(|nla| match nla {
+            ExtAckNla::Cookie(cookie) => Some(cookie.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// The policy of the rejected attribute (`NLMSGERR_ATTR_POLICY`), if any
+    pub fn policy(&self) -> Option<&[PolicyTypeNla]> {
+        self.nlas.iter().find_map(|nla| match nla {
+            ExtAckNla::Policy(policy) => Some(policy.as_slice()),
+            _ => None,
+        })
+    }
 }
 
 impl fmt::Display for ErrorMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.to_io(), f)
+        fmt::Display::fmt(&self.to_io(), f)?;
+        if let Some(msg) = self.ext_ack_message() {
+            write!(f, ": {}", msg)?;
+        }
+        if let Some(offset) = self.bad_attribute_offset() {
+            write!(f, " (bad attribute at offset {})", offset)?;
+        }
+        Ok(())
     }
 }
 
+impl std::error::Error for ErrorMessage {}
+
 impl From<ErrorMessage> for io::Error {
     fn from(e: ErrorMessage) -> io::Error {
         e.to_io()
     }
 }
 
+/// Extended ACK attribute (`NLMSGERR_ATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ExtAckNla {
+    Unused(Vec<u8>),
+    /// Human readable error message
+    Msg(String),
+    /// Offset of the invalid attribute in the original message
+    Offset(u32),
+    /// Subsystem specific cookie
+    Cookie(Vec<u8>),
+    /// Policy of the rejected attribute
+    Policy(Vec<PolicyTypeNla>),
+    /// Type of a missing required attribute
+    MissingType(u32),
+    /// Offset of the nest where an attribute was missing
+    MissingNest(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for ExtAckNla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::ExtAckNla::*;
+        match self {
+            Unused(bytes) | Cookie(bytes) => bytes.len(),
+            Msg(msg) => msg.len() + 1,
+            Offset(_)
+                | MissingType(_)
+                | MissingNest(_)
+                => 4,
+            Policy(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ExtAckNla::*;
+        match self {
+            Unused(bytes) | Cookie(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Msg(msg) => {
+                buffer[..msg.len()].copy_from_slice(msg.as_bytes());
+                buffer[msg.len()] = 0;
+            }
+            Offset(value)
+                | MissingType(value)
+                | MissingNest(value)
+                => NativeEndian::write_u32(buffer, *value),
+            Policy(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ExtAckNla::*;
+        match self {
+            Unused(_) => NLMSGERR_ATTR_UNUSED,
+            Msg(_) => NLMSGERR_ATTR_MSG,
+            Offset(_) => NLMSGERR_ATTR_OFFS,
+            Cookie(_) => NLMSGERR_ATTR_COOKIE,
+            Policy(_) => NLMSGERR_ATTR_POLICY,
+            MissingType(_) => NLMSGERR_ATTR_MISS_TYPE,
+            MissingNest(_) => NLMSGERR_ATTR_MISS_NEST,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, ExtAckNla::Policy(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ExtAckNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ExtAckNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NLMSGERR_ATTR_UNUSED => Unused(payload.to_vec()),
+            NLMSGERR_ATTR_MSG => {
+                Msg(parse_string(payload).context("invalid NLMSGERR_ATTR_MSG value")?)
+            }
+            NLMSGERR_ATTR_OFFS => {
+                Offset(parse_u32(payload).context("invalid NLMSGERR_ATTR_OFFS value")?)
+            }
+            NLMSGERR_ATTR_COOKIE => Cookie(payload.to_vec()),
+            NLMSGERR_ATTR_POLICY => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid NLMSGERR_ATTR_POLICY value")?;
+                    nlas.push(
+                        PolicyTypeNla::parse(&nla).context("invalid NLMSGERR_ATTR_POLICY value")?,
+                    );
+                }
+                Policy(nlas)
+            }
+            NLMSGERR_ATTR_MISS_TYPE => {
+                MissingType(parse_u32(payload).context("invalid NLMSGERR_ATTR_MISS_TYPE value")?)
+            }
+            NLMSGERR_ATTR_MISS_NEST => {
+                MissingNest(parse_u32(payload).context("invalid NLMSGERR_ATTR_MISS_NEST value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attribute policy description (`NL_POLICY_TYPE_ATTR_*`), found in
+/// `NLMSGERR_ATTR_POLICY`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PolicyTypeNla {
+    Unspec(Vec<u8>),
+    /// Type of the attribute (`enum netlink_attribute_type`)
+    Type(u32),
+    MinValueSigned(i64),
+    MaxValueSigned(i64),
+    MinValueUnsigned(u64),
+    MaxValueUnsigned(u64),
+    MinLength(u32),
+    MaxLength(u32),
+    PolicyIdx(u32),
+    PolicyMaxType(u32),
+    Bitfield32Mask(u32),
+    Pad(Vec<u8>),
+    Mask(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for PolicyTypeNla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::PolicyTypeNla::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Type(_)
+                | MinLength(_)
+                | MaxLength(_)
+                | PolicyIdx(_)
+                | PolicyMaxType(_)
+                | Bitfield32Mask(_)
+                => 4,
+            MinValueSigned(_)
+                | MaxValueSigned(_)
+                | MinValueUnsigned(_)
+                | MaxValueUnsigned(_)
+                | Mask(_)
+                => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::PolicyTypeNla::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Type(value)
+                | MinLength(value)
+                | MaxLength(value)
+                | PolicyIdx(value)
+                | PolicyMaxType(value)
+                | Bitfield32Mask(value)
+                => NativeEndian::write_u32(buffer, *value),
+            MinValueSigned(value)
+                | MaxValueSigned(value)
+                => NativeEndian::write_i64(buffer, *value),
+            MinValueUnsigned(value)
+                | MaxValueUnsigned(value)
+                | Mask(value)
+                => NativeEndian::write_u64(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::PolicyTypeNla::*;
+        match self {
+            Unspec(_) => NL_POLICY_TYPE_ATTR_UNSPEC,
+            Type(_) => NL_POLICY_TYPE_ATTR_TYPE,
+            MinValueSigned(_) => NL_POLICY_TYPE_ATTR_MIN_VALUE_S,
+            MaxValueSigned(_) => NL_POLICY_TYPE_ATTR_MAX_VALUE_S,
+            MinValueUnsigned(_) => NL_POLICY_TYPE_ATTR_MIN_VALUE_U,
+            MaxValueUnsigned(_) => NL_POLICY_TYPE_ATTR_MAX_VALUE_U,
+            MinLength(_) => NL_POLICY_TYPE_ATTR_MIN_LENGTH,
+            MaxLength(_) => NL_POLICY_TYPE_ATTR_MAX_LENGTH,
+            PolicyIdx(_) => NL_POLICY_TYPE_ATTR_POLICY_IDX,
+            PolicyMaxType(_) => NL_POLICY_TYPE_ATTR_POLICY_MAXTYPE,
+            Bitfield32Mask(_) => NL_POLICY_TYPE_ATTR_BITFIELD32_MASK,
+            Pad(_) => NL_POLICY_TYPE_ATTR_PAD,
+            Mask(_) => NL_POLICY_TYPE_ATTR_MASK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+fn parse_i64(payload: &[u8]) -> Result<i64, DecodeError> {
+    if payload.len() != size_of::<i64>() {
+        return Err(format!("invalid i64: {:?}", payload).into());
+    }
+    Ok(NativeEndian::read_i64(payload))
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for PolicyTypeNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::PolicyTypeNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NL_POLICY_TYPE_ATTR_UNSPEC => Unspec(payload.to_vec()),
+            NL_POLICY_TYPE_ATTR_TYPE => {
+                Type(parse_u32(payload).context("invalid NL_POLICY_TYPE_ATTR_TYPE value")?)
+            }
+            NL_POLICY_TYPE_ATTR_MIN_VALUE_S => MinValueSigned(
+                parse_i64(payload).context("invalid NL_POLICY_TYPE_ATTR_MIN_VALUE_S value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_MAX_VALUE_S => MaxValueSigned(
+                parse_i64(payload).context("invalid NL_POLICY_TYPE_ATTR_MAX_VALUE_S value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_MIN_VALUE_U => MinValueUnsigned(
+                parse_u64(payload).context("invalid NL_POLICY_TYPE_ATTR_MIN_VALUE_U value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_MAX_VALUE_U => MaxValueUnsigned(
+                parse_u64(payload).context("invalid NL_POLICY_TYPE_ATTR_MAX_VALUE_U value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_MIN_LENGTH => MinLength(
+                parse_u32(payload).context("invalid NL_POLICY_TYPE_ATTR_MIN_LENGTH value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_MAX_LENGTH => MaxLength(
+                parse_u32(payload).context("invalid NL_POLICY_TYPE_ATTR_MAX_LENGTH value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_POLICY_IDX => PolicyIdx(
+                parse_u32(payload).context("invalid NL_POLICY_TYPE_ATTR_POLICY_IDX value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_POLICY_MAXTYPE => PolicyMaxType(
+                parse_u32(payload).context("invalid NL_POLICY_TYPE_ATTR_POLICY_MAXTYPE value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_BITFIELD32_MASK => Bitfield32Mask(
+                parse_u32(payload).context("invalid NL_POLICY_TYPE_ATTR_BITFIELD32_MASK value")?,
+            ),
+            NL_POLICY_TYPE_ATTR_PAD => Pad(payload.to_vec()),
+            NL_POLICY_TYPE_ATTR_MASK => {
+                Mask(parse_u64(payload).context("invalid NL_POLICY_TYPE_ATTR_MASK value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +506,7 @@ mod tests {
         let err_msg = ErrorMessage {
             code: -95,
             header: vec![],
+            nlas: vec![],
         };
 
         let to_io: io::Error = err_msg.to_io();
@@ -151,4 +514,66 @@ mod tests {
         assert_eq!(err_msg.to_string(), io_err.to_string());
         assert_eq!(to_io.raw_os_error(), io_err.raw_os_error());
     }
+
+    #[rustfmt::skip]
+    static EXT_ACK_ERROR: [u8; 40] = [
+        0xea, 0xff, 0xff, 0xff, // code = -22 (EINVAL)
+        // original message header (capped)
+        0x28, 0x00, 0x00, 0x00, // length = 40
+        0x10, 0x00, // type = 16 (RTM_NEWLINK)
+        0x05, 0x04, // flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE
+        0x01, 0x00, 0x00, 0x00, // sequence number = 1
+        0x00, 0x00, 0x00, 0x00, // port number = 0
+
+        0x0c, 0x00, // length = 12
+        0x01, 0x00, // type = NLMSGERR_ATTR_MSG
+        0x6e, 0x6f, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x00, // V = "no mode"
+
+        0x08, 0x00, // length = 8
+        0x02, 0x00, // type = NLMSGERR_ATTR_OFFS
+        0x24, 0x00, 0x00, 0x00, // V = 36
+    ];
+
+    fn ext_ack_error() -> ErrorMessage {
+        ErrorMessage {
+            code: -22,
+            header: EXT_ACK_ERROR[4..20].to_vec(),
+            nlas: vec![ExtAckNla::Msg("no mode".into()), ExtAckNla::Offset(36)],
+        }
+    }
+
+    #[test]
+    fn parse_ext_ack() {
+        let buf = ErrorBuffer::new_checked(&EXT_ACK_ERROR).unwrap();
+        let parsed = ErrorMessage::parse_with_param(&buf, NLM_F_CAPPED | NLM_F_ACK_TLVS).unwrap();
+        assert_eq!(parsed, ext_ack_error());
+        assert_eq!(parsed.ext_ack_message(), Some("no mode"));
+        assert_eq!(parsed.bad_attribute_offset(), Some(36));
+        assert_eq!(parsed.cookie(), None);
+        assert_eq!(
+            parsed.to_string(),
+            format!(
+                "{}: no mode (bad attribute at offset 36)",
+                io::Error::from_raw_os_error(22)
+            )
+        );
+    }
+
+    #[test]
+    fn parse_without_ext_ack_flag() {
+        let buf = ErrorBuffer::new_checked(&EXT_ACK_ERROR).unwrap();
+        let parsed = ErrorMessage::parse_with_param(&buf, NLM_F_CAPPED).unwrap();
+        assert_eq!(parsed.header, EXT_ACK_ERROR[4..].to_vec());
+        assert!(parsed.nlas.is_empty());
+    }
+
+    #[test]
+    fn emit_ext_ack() {
+        let msg = ext_ack_error();
+        assert_eq!(msg.buffer_len(), EXT_ACK_ERROR.len());
+
+        let mut buf = vec![0xff; EXT_ACK_ERROR.len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &EXT_ACK_ERROR[..]);
+    }
 }
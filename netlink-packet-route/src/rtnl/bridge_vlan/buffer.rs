@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const BRIDGE_VLAN_HEADER_LEN: usize = 8;
+
+buffer!(BridgeVlanMessageBuffer(BRIDGE_VLAN_HEADER_LEN) {
+    family: (u8, 0),
+    reserved1: (u8, 1),
+    reserved2: (u16, 2..4),
+    ifindex: (u32, 4..BRIDGE_VLAN_HEADER_LEN),
+    payload: (slice, BRIDGE_VLAN_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> BridgeVlanMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+use super::{buffer::BridgeVlanMessageBuffer, BRIDGE_VLAN_HEADER_LEN};
+use crate::{
+    utils::{Emitable, Parseable},
+    DecodeError,
+};
+
+// see `struct br_vlan_msg` in https://github.com/torvalds/linux/blob/master/include/uapi/linux/if_bridge.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BridgeVlanHeader {
+    /// Address family, always `AF_BRIDGE`
+    pub family: u8,
+    /// Index of the bridge or of the bridge port. `0` to dump the VLANs of
+    /// all the ports.
+    pub ifindex: u32,
+}
+
+impl Emitable for BridgeVlanHeader {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = BridgeVlanMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_reserved1(0);
+        packet.set_reserved2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<BridgeVlanMessageBuffer<&'a T>> for BridgeVlanHeader {
+    fn parse(buf: &BridgeVlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(BridgeVlanHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
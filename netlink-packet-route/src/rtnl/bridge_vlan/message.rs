@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+
+use super::{buffer::BridgeVlanMessageBuffer, header::BridgeVlanHeader, nlas::Nla};
+use crate::{
+    utils::{Emitable, Parseable},
+    DecodeError,
+};
+use anyhow::Context;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BridgeVlanMessage {
+    pub header: BridgeVlanHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl Emitable for BridgeVlanMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<BridgeVlanMessageBuffer<&'a T>> for BridgeVlanMessage {
+    fn parse(buf: &BridgeVlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header =
+            BridgeVlanHeader::parse(buf).context("failed to parse bridge vlan message header")?;
+        let nlas = Vec::<Nla>::parse(buf).context("failed to parse bridge vlan message NLAs")?;
+        Ok(BridgeVlanMessage { header, nlas })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<BridgeVlanMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &BridgeVlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::{
+            bridge_vlan::{Nla, VlanEntry, VlanStats},
+            link::BridgeVlanInfo,
+        },
+        traits::{Emitable, ParseableParametrized},
+        BridgeVlanHeader,
+        BridgeVlanMessage,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_BRIDGE,
+        BRIDGE_VLANDB_DUMPF_STATS,
+        BRIDGE_VLAN_INFO_PVID,
+        BRIDGE_VLAN_INFO_UNTAGGED,
+        BR_STATE_FORWARDING,
+        RTM_GETVLAN,
+        RTM_NEWVLAN,
+    };
+
+    // Entries of a bridge port dumped with `BRIDGE_VLANDB_DUMPF_STATS`, for
+    // the VLANs added with `bridge vlan add dev veth0 vid 1 pvid untagged`
+    // and `bridge vlan add dev veth0 vid 10`. The kernel does not merge the
+    // VLANs into ranges when dumping their statistics.
+    #[rustfmt::skip]
+    static PORT_VLANS: [u8; 168] = [
+        0x07, // family = AF_BRIDGE
+        0x00, 0x00, 0x00, // reserved
+        0x05, 0x00, 0x00, 0x00, // ifindex = 5
+        // NLA: BRIDGE_VLANDB_ENTRY (nested)
+        0x50, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, // BRIDGE_VLANDB_ENTRY_INFO
+        0x06, 0x00, 0x01, 0x00, // flags = PVID | UNTAGGED, vid = 1
+        0x05, 0x00, 0x03, 0x00, // BRIDGE_VLANDB_ENTRY_STATE
+        0x03, 0x00, 0x00, 0x00, // BR_STATE_FORWARDING + padding
+        0x05, 0x00, 0x06, 0x00, // BRIDGE_VLANDB_ENTRY_MCAST_ROUTER
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+        0x34, 0x00, 0x05, 0x80, // BRIDGE_VLANDB_ENTRY_STATS (nested)
+        0x0c, 0x00, 0x01, 0x00, // BRIDGE_VLANDB_STATS_RX_BYTES
+        0xa4, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 420
+        0x0c, 0x00, 0x02, 0x00, // BRIDGE_VLANDB_STATS_RX_PACKETS
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 5
+        0x0c, 0x00, 0x03, 0x00, // BRIDGE_VLANDB_STATS_TX_BYTES
+        0x54, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 84
+        0x0c, 0x00, 0x04, 0x00, // BRIDGE_VLANDB_STATS_TX_PACKETS
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 1
+        // NLA: BRIDGE_VLANDB_ENTRY (nested)
+        0x50, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, // BRIDGE_VLANDB_ENTRY_INFO
+        0x00, 0x00, 0x0a, 0x00, // flags = 0, vid = 10
+        0x05, 0x00, 0x03, 0x00, // BRIDGE_VLANDB_ENTRY_STATE
+        0x03, 0x00, 0x00, 0x00, // BR_STATE_FORWARDING + padding
+        0x05, 0x00, 0x06, 0x00, // BRIDGE_VLANDB_ENTRY_MCAST_ROUTER
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+        0x34, 0x00, 0x05, 0x80, // BRIDGE_VLANDB_ENTRY_STATS (nested)
+        0x0c, 0x00, 0x01, 0x00, // BRIDGE_VLANDB_STATS_RX_BYTES
+        0x62, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 98
+        0x0c, 0x00, 0x02, 0x00, // BRIDGE_VLANDB_STATS_RX_PACKETS
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 1
+        0x0c, 0x00, 0x03, 0x00, // BRIDGE_VLANDB_STATS_TX_BYTES
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0
+        0x0c, 0x00, 0x04, 0x00, // BRIDGE_VLANDB_STATS_TX_PACKETS
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 0
+    ];
+
+    fn port_vlans() -> BridgeVlanMessage {
+        BridgeVlanMessage {
+            header: BridgeVlanHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 5,
+            },
+            nlas: vec![
+                Nla::Entry(vec![
+                    VlanEntry::Info(BridgeVlanInfo {
+                        flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+                        vid: 1,
+                    }),
+                    VlanEntry::State(BR_STATE_FORWARDING),
+                    VlanEntry::McastRouter(1),
+                    VlanEntry::Stats(vec![
+                        VlanStats::RxBytes(420),
+                        VlanStats::RxPackets(5),
+                        VlanStats::TxBytes(84),
+                        VlanStats::TxPackets(1),
+                    ]),
+                ]),
+                Nla::Entry(vec![
+                    VlanEntry::Info(BridgeVlanInfo { flags: 0, vid: 10 }),
+                    VlanEntry::State(BR_STATE_FORWARDING),
+                    VlanEntry::McastRouter(1),
+                    VlanEntry::Stats(vec![
+                        VlanStats::RxBytes(98),
+                        VlanStats::RxPackets(1),
+                        VlanStats::TxBytes(0),
+                        VlanStats::TxPackets(0),
+                    ]),
+                ]),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_port_vlans() {
+        let actual =
+            RtnlMessage::parse_with_param(&RtnlMessageBuffer::new(&&PORT_VLANS[..]), RTM_NEWVLAN)
+                .unwrap();
+        assert_eq!(actual, RtnlMessage::NewBridgeVlan(port_vlans()));
+    }
+
+    #[test]
+    fn emit_port_vlans() {
+        let msg = port_vlans();
+        assert_eq!(msg.buffer_len(), PORT_VLANS.len());
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &PORT_VLANS[..]);
+    }
+
+    // `BRIDGE_VLANDB_DUMP_FLAGS` has the same type as `BRIDGE_VLANDB_ENTRY`
+    #[rustfmt::skip]
+    #[test]
+    fn dump_flags_read_and_emit() {
+        let data = [
+            0x07, 0x00, 0x00, 0x00, // family = AF_BRIDGE
+            0x00, 0x00, 0x00, 0x00, // ifindex = 0
+            0x08, 0x00, 0x01, 0x00, // BRIDGE_VLANDB_DUMP_FLAGS
+            0x01, 0x00, 0x00, 0x00, // BRIDGE_VLANDB_DUMPF_STATS
+        ];
+        let msg = BridgeVlanMessage {
+            header: BridgeVlanHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 0,
+            },
+            nlas: vec![Nla::DumpFlags(BRIDGE_VLANDB_DUMPF_STATS)],
+        };
+        let actual =
+            RtnlMessage::parse_with_param(&RtnlMessageBuffer::new(&&data[..]), RTM_GETVLAN)
+                .unwrap();
+        assert_eq!(actual, RtnlMessage::GetBridgeVlan(msg.clone()));
+
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &data[..]);
+    }
+}
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, link::BridgeVlanInfo, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attributes of a VLAN, or of a range of VLANs, of a bridge port
+/// (`BRIDGE_VLANDB_ENTRY_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanEntry {
+    /// First VLAN of the entry, and its `BRIDGE_VLAN_INFO_*` flags
+    Info(BridgeVlanInfo),
+    /// Last VLAN of the range starting at [`VlanEntry::Info`]
+    Range(u16),
+    /// STP state of the VLAN: one of the `BR_STATE_*` constants
+    State(u8),
+    TunnelInfo(Vec<VlanTunnelInfo>),
+    Stats(Vec<VlanStats>),
+    McastRouter(u8),
+    McastNGroups(u32),
+    McastMaxGroups(u32),
+    NeighSuppress(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for VlanEntry {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::VlanEntry::*;
+        match self {
+            Info(_) => 4,
+            Range(_) => 2,
+            State(_)
+                | McastRouter(_)
+                | NeighSuppress(_)
+                => 1,
+            McastNGroups(_)
+                | McastMaxGroups(_)
+                => 4,
+            TunnelInfo(ref nlas) => nlas.as_slice().buffer_len(),
+            Stats(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanEntry::*;
+        match self {
+            Info(ref info) => buffer[..4].copy_from_slice(<[u8; 4]>::from(info).as_slice()),
+            Range(value) => NativeEndian::write_u16(buffer, *value),
+            State(value)
+                | McastRouter(value)
+                | NeighSuppress(value)
+                => buffer[0] = *value,
+            McastNGroups(value)
+                | McastMaxGroups(value)
+                => NativeEndian::write_u32(buffer, *value),
+            TunnelInfo(ref nlas) => nlas.as_slice().emit(buffer),
+            Stats(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanEntry::*;
+        match self {
+            Info(_) => BRIDGE_VLANDB_ENTRY_INFO,
+            Range(_) => BRIDGE_VLANDB_ENTRY_RANGE,
+            State(_) => BRIDGE_VLANDB_ENTRY_STATE,
+            TunnelInfo(_) => BRIDGE_VLANDB_ENTRY_TUNNEL_INFO,
+            Stats(_) => BRIDGE_VLANDB_ENTRY_STATS,
+            McastRouter(_) => BRIDGE_VLANDB_ENTRY_MCAST_ROUTER,
+            McastNGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS,
+            McastMaxGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS,
+            NeighSuppress(_) => BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, VlanEntry::TunnelInfo(_) | VlanEntry::Stats(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanEntry::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY_INFO => Info(
+                BridgeVlanInfo::try_from(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_INFO value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_RANGE => {
+                Range(parse_u16(payload).context("invalid BRIDGE_VLANDB_ENTRY_RANGE value")?)
+            }
+            BRIDGE_VLANDB_ENTRY_STATE => {
+                State(parse_u8(payload).context("invalid BRIDGE_VLANDB_ENTRY_STATE value")?)
+            }
+            BRIDGE_VLANDB_ENTRY_TUNNEL_INFO => {
+                let err = "invalid BRIDGE_VLANDB_ENTRY_TUNNEL_INFO value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(VlanTunnelInfo::parse(&nla).context(err)?);
+                }
+                TunnelInfo(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_STATS => {
+                let err = "invalid BRIDGE_VLANDB_ENTRY_STATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(VlanStats::parse(&nla).context(err)?);
+                }
+                Stats(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_ROUTER => McastRouter(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_ENTRY_MCAST_ROUTER value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS => McastNGroups(
+                parse_u32(payload).context("invalid BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS => McastMaxGroups(
+                parse_u32(payload).context("invalid BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS => NeighSuppress(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Tunnel mapping of a VLAN entry (`BRIDGE_VLANDB_TINFO_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanTunnelInfo {
+    /// Tunnel id, such as a VXLAN VNI
+    Id(u32),
+    /// `RTM_SETLINK` or `RTM_DELLINK`, to add or remove the mapping
+    Cmd(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for VlanTunnelInfo {
+    fn value_len(&self) -> usize {
+        use self::VlanTunnelInfo::*;
+        match self {
+            Id(_) | Cmd(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanTunnelInfo::*;
+        match self {
+            Id(value) | Cmd(value) => NativeEndian::write_u32(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanTunnelInfo::*;
+        match self {
+            Id(_) => BRIDGE_VLANDB_TINFO_ID,
+            Cmd(_) => BRIDGE_VLANDB_TINFO_CMD,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanTunnelInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanTunnelInfo::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_TINFO_ID => {
+                Id(parse_u32(payload).context("invalid BRIDGE_VLANDB_TINFO_ID value")?)
+            }
+            BRIDGE_VLANDB_TINFO_CMD => {
+                Cmd(parse_u32(payload).context("invalid BRIDGE_VLANDB_TINFO_CMD value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Traffic statistics of a VLAN entry (`BRIDGE_VLANDB_STATS_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanStats {
+    RxBytes(u64),
+    RxPackets(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for VlanStats {
+    fn value_len(&self) -> usize {
+        use self::VlanStats::*;
+        match self {
+            RxBytes(_) | RxPackets(_) | TxBytes(_) | TxPackets(_) => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanStats::*;
+        match self {
+            RxBytes(value) | RxPackets(value) | TxBytes(value) | TxPackets(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanStats::*;
+        match self {
+            RxBytes(_) => BRIDGE_VLANDB_STATS_RX_BYTES,
+            RxPackets(_) => BRIDGE_VLANDB_STATS_RX_PACKETS,
+            TxBytes(_) => BRIDGE_VLANDB_STATS_TX_BYTES,
+            TxPackets(_) => BRIDGE_VLANDB_STATS_TX_PACKETS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanStats::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_STATS_RX_BYTES => {
+                RxBytes(parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_RX_BYTES value")?)
+            }
+            BRIDGE_VLANDB_STATS_RX_PACKETS => RxPackets(
+                parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_RX_PACKETS value")?,
+            ),
+            BRIDGE_VLANDB_STATS_TX_BYTES => {
+                TxBytes(parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_TX_BYTES value")?)
+            }
+            BRIDGE_VLANDB_STATS_TX_PACKETS => TxPackets(
+                parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_TX_PACKETS value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u16, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Options of the VLANs of the bridge itself (`BRIDGE_VLANDB_GOPTS_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GlobalOption {
+    Id(u16),
+    /// Last VLAN of the range starting at [`GlobalOption::Id`]
+    Range(u16),
+    McastSnooping(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for GlobalOption {
+    fn value_len(&self) -> usize {
+        use self::GlobalOption::*;
+        match self {
+            Id(_) | Range(_) => 2,
+            McastSnooping(_) => 1,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::GlobalOption::*;
+        match self {
+            Id(value) | Range(value) => NativeEndian::write_u16(buffer, *value),
+            McastSnooping(value) => buffer[0] = *value,
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::GlobalOption::*;
+        match self {
+            Id(_) => BRIDGE_VLANDB_GOPTS_ID,
+            Range(_) => BRIDGE_VLANDB_GOPTS_RANGE,
+            McastSnooping(_) => BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for GlobalOption {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::GlobalOption::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_GOPTS_ID => {
+                Id(parse_u16(payload).context("invalid BRIDGE_VLANDB_GOPTS_ID value")?)
+            }
+            BRIDGE_VLANDB_GOPTS_RANGE => {
+                Range(parse_u16(payload).context("invalid BRIDGE_VLANDB_GOPTS_RANGE value")?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING => McastSnooping(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
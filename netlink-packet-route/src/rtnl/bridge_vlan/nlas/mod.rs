@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+
+mod entry;
+pub use self::entry::*;
+
+mod global;
+pub use self::global::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Netlink attributes for `RTM_NEWVLAN`, `RTM_DELVLAN` and `RTM_GETVLAN`
+/// messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// A VLAN, or a range of VLANs, of the port
+    Entry(Vec<VlanEntry>),
+    /// Dump flags of `RTM_GETVLAN` requests: a combination of the
+    /// `BRIDGE_VLANDB_DUMPF_*` constants. It shares its type with
+    /// [`Nla::Entry`], and is told apart by the absence of the nested flag.
+    DumpFlags(u32),
+    /// Options of a VLAN, or of a range of VLANs, of the bridge itself
+    GlobalOptions(Vec<GlobalOption>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(ref bytes) => bytes.len(),
+            Entry(ref nlas) => nlas.as_slice().buffer_len(),
+            DumpFlags(_) => 4,
+            GlobalOptions(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Entry(ref nlas) => nlas.as_slice().emit(buffer),
+            DumpFlags(value) => NativeEndian::write_u32(buffer, *value),
+            GlobalOptions(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => BRIDGE_VLANDB_UNSPEC,
+            Entry(_) => BRIDGE_VLANDB_ENTRY,
+            DumpFlags(_) => BRIDGE_VLANDB_DUMP_FLAGS,
+            GlobalOptions(_) => BRIDGE_VLANDB_GLOBAL_OPTIONS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Nla::Entry(_) | Nla::GlobalOptions(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_UNSPEC => Unspec(payload.to_vec()),
+            BRIDGE_VLANDB_DUMP_FLAGS if !buf.nested_flag() => {
+                DumpFlags(parse_u32(payload).context("invalid BRIDGE_VLANDB_DUMP_FLAGS value")?)
+            }
+            BRIDGE_VLANDB_ENTRY => {
+                let err = "invalid BRIDGE_VLANDB_ENTRY value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(VlanEntry::parse(&nla).context(err)?);
+                }
+                Entry(nlas)
+            }
+            BRIDGE_VLANDB_GLOBAL_OPTIONS => {
+                let err = "invalid BRIDGE_VLANDB_GLOBAL_OPTIONS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(GlobalOption::parse(&nla).context(err)?);
+                }
+                GlobalOptions(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
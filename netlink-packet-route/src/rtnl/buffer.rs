@@ -6,6 +6,8 @@ use crate::{
     AddressHeader,
     AddressMessage,
     AddressMessageBuffer,
+    BridgeVlanMessage,
+    BridgeVlanMessageBuffer,
     DecodeError,
     LinkMessage,
     LinkMessageBuffer,
@@ -13,6 +15,8 @@ use crate::{
     NeighbourMessageBuffer,
     NeighbourTableMessage,
     NeighbourTableMessageBuffer,
+    NexthopMessage,
+    NexthopMessageBuffer,
     NsidMessage,
     NsidMessageBuffer,
     RouteHeader,
@@ -21,6 +25,10 @@ use crate::{
     RtnlMessage,
     RuleMessage,
     RuleMessageBuffer,
+    StatsMessage,
+    StatsMessageBuffer,
+    TcActionMessage,
+    TcActionMessageBuffer,
     TcMessage,
     TcMessageBuffer,
 };
@@ -177,6 +185,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // TC action messages
+            RTM_NEWACTION | RTM_DELACTION | RTM_GETACTION => {
+                let err = "invalid tc action message";
+                let msg = TcActionMessage::parse(&TcActionMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWACTION => NewTrafficAction(msg),
+                    RTM_DELACTION => DelTrafficAction(msg),
+                    RTM_GETACTION => GetTrafficAction(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             // ND ID Messages
             RTM_NEWNSID | RTM_GETNSID | RTM_DELNSID => {
                 let err = "invalid nsid message";
@@ -189,6 +209,41 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // Nexthop messages
+            RTM_NEWNEXTHOP | RTM_GETNEXTHOP | RTM_DELNEXTHOP => {
+                let err = "invalid nexthop message";
+                let msg = NexthopMessage::parse(&NexthopMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOP => NewNexthop(msg),
+                    RTM_GETNEXTHOP => GetNexthop(msg),
+                    RTM_DELNEXTHOP => DelNexthop(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Link statistics messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse(&StatsMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => NewStats(msg),
+                    RTM_GETSTATS => GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge VLAN messages
+            RTM_NEWVLAN | RTM_DELVLAN | RTM_GETVLAN => {
+                let err = "invalid bridge vlan message";
+                let msg = BridgeVlanMessage::parse(&BridgeVlanMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWVLAN => NewBridgeVlan(msg),
+                    RTM_DELVLAN => DelBridgeVlan(msg),
+                    RTM_GETVLAN => GetBridgeVlan(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             _ => return Err(format!("Unknown message type: {}", message_type).into()),
         };
         Ok(message)
@@ -58,8 +58,14 @@ pub const RTM_NEWCACHEREPORT: u16 = 96;
 pub const RTM_NEWCHAIN: u16 = 100;
 pub const RTM_DELCHAIN: u16 = 101;
 pub const RTM_GETCHAIN: u16 = 102;
+pub const RTM_NEWNEXTHOP: u16 = 104;
+pub const RTM_DELNEXTHOP: u16 = 105;
+pub const RTM_GETNEXTHOP: u16 = 106;
 pub const RTM_NEWLINKPROP: u16 = 108;
 pub const RTM_DELLINKPROP: u16 = 109;
+pub const RTM_NEWVLAN: u16 = 112;
+pub const RTM_DELVLAN: u16 = 113;
+pub const RTM_GETVLAN: u16 = 114;
 
 /// Unknown route
 pub const RTN_UNSPEC: u8 = 0;
@@ -269,6 +275,7 @@ pub const AF_PHONET: u16 = libc::AF_PHONET as u16;
 pub const AF_IEEE802154: u16 = libc::AF_IEEE802154 as u16;
 pub const AF_CAIF: u16 = libc::AF_CAIF as u16;
 pub const AF_ALG: u16 = libc::AF_ALG as u16;
+pub const AF_MPLS: u16 = 28;
 
 pub const NETNSA_NONE: u16 = 0;
 pub const NETNSA_NSID: u16 = 1;
@@ -304,6 +311,7 @@ pub const NTF_MASTER: u8 = 4;
 pub const NTF_PROXY: u8 = 8;
 pub const NTF_EXT_LEARNED: u8 = 16;
 pub const NTF_OFFLOADED: u8 = 32;
+pub const NTF_STICKY: u8 = 64;
 pub const NTF_ROUTER: u8 = 128;
 
 pub const TCA_UNSPEC: u16 = 0;
@@ -329,6 +337,7 @@ pub const TCA_STATS_APP: u16 = 4;
 pub const TCA_STATS_RATE_EST64: u16 = 5;
 pub const TCA_STATS_PAD: u16 = 6;
 pub const TCA_STATS_BASIC_HW: u16 = 7;
+pub const TCA_STATS_PKT64: u16 = 8;
 
 pub const NDTA_UNSPEC: u16 = 0;
 pub const NDTA_NAME: u16 = 1;
@@ -368,6 +377,39 @@ pub const RTA_EXPIRES: u16 = 23;
 pub const RTA_PAD: u16 = 24;
 pub const RTA_UID: u16 = 25;
 pub const RTA_TTL_PROPAGATE: u16 = 26;
+pub const RTA_IP_PROTO: u16 = 27;
+pub const RTA_SPORT: u16 = 28;
+pub const RTA_DPORT: u16 = 29;
+pub const RTA_NH_ID: u16 = 30;
+
+pub const NHA_UNSPEC: u16 = 0;
+pub const NHA_ID: u16 = 1;
+pub const NHA_GROUP: u16 = 2;
+pub const NHA_GROUP_TYPE: u16 = 3;
+pub const NHA_BLACKHOLE: u16 = 4;
+pub const NHA_OIF: u16 = 5;
+pub const NHA_GATEWAY: u16 = 6;
+pub const NHA_ENCAP_TYPE: u16 = 7;
+pub const NHA_ENCAP: u16 = 8;
+pub const NHA_GROUPS: u16 = 9;
+pub const NHA_MASTER: u16 = 10;
+pub const NHA_FDB: u16 = 11;
+pub const NHA_RES_GROUP: u16 = 12;
+pub const NHA_RES_BUCKET: u16 = 13;
+
+pub const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+pub const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+pub const NHA_RES_GROUP_PAD: u16 = 0;
+pub const NHA_RES_GROUP_BUCKETS: u16 = 1;
+pub const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+pub const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+pub const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+pub const NHA_RES_BUCKET_PAD: u16 = 0;
+pub const NHA_RES_BUCKET_INDEX: u16 = 1;
+pub const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+pub const NHA_RES_BUCKET_NH_ID: u16 = 3;
 
 pub const RTAX_UNSPEC: u16 = 0;
 pub const RTAX_LOCK: u16 = 1;
@@ -399,12 +441,67 @@ pub const IFLA_BRIDGE_FLAGS: u16 = 0;
 pub const BRIDGE_FLAGS_MASTER: u16 = 1; /* Bridge command to/from master */
 pub const BRIDGE_FLAGS_SELF: u16 = 2; /* Bridge command to/from lowerdev */
 
+pub const IFLA_BRIDGE_MODE: u16 = 1;
 pub const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
+pub const IFLA_BRIDGE_MRP: u16 = 4;
+pub const IFLA_BRIDGE_CFM: u16 = 5;
+pub const IFLA_BRIDGE_MST: u16 = 6;
 pub const BRIDGE_VLAN_INFO_MASTER: u16 = 1;
-pub const BRIDGE_VLAN_INFO_PVID: u16 = 4;
-pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 8;
-pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 16;
-pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 32;
+pub const BRIDGE_VLAN_INFO_PVID: u16 = 2;
+pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 4;
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 8;
+pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 16;
+pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = 32;
+pub const BRIDGE_VLAN_INFO_ONLY_OPTS: u16 = 64;
+
+pub const IFLA_BRIDGE_VLAN_TUNNEL_UNSPEC: u16 = 0;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_ID: u16 = 1;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_VID: u16 = 2;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_FLAGS: u16 = 3;
+
+// Bridge port STP states
+pub const BR_STATE_DISABLED: u8 = 0;
+pub const BR_STATE_LISTENING: u8 = 1;
+pub const BR_STATE_LEARNING: u8 = 2;
+pub const BR_STATE_FORWARDING: u8 = 3;
+pub const BR_STATE_BLOCKING: u8 = 4;
+
+// Bridge VLAN database (`RTM_NEWVLAN`, `RTM_DELVLAN` and `RTM_GETVLAN`)
+pub const BRIDGE_VLANDB_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_ENTRY: u16 = 1;
+pub const BRIDGE_VLANDB_GLOBAL_OPTIONS: u16 = 2;
+pub const BRIDGE_VLANDB_DUMP_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_DUMP_FLAGS: u16 = 1;
+pub const BRIDGE_VLANDB_DUMPF_STATS: u32 = 1;
+pub const BRIDGE_VLANDB_DUMPF_GLOBAL: u32 = 2;
+
+pub const BRIDGE_VLANDB_ENTRY_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_ENTRY_INFO: u16 = 1;
+pub const BRIDGE_VLANDB_ENTRY_RANGE: u16 = 2;
+pub const BRIDGE_VLANDB_ENTRY_STATE: u16 = 3;
+pub const BRIDGE_VLANDB_ENTRY_TUNNEL_INFO: u16 = 4;
+pub const BRIDGE_VLANDB_ENTRY_STATS: u16 = 5;
+pub const BRIDGE_VLANDB_ENTRY_MCAST_ROUTER: u16 = 6;
+pub const BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS: u16 = 7;
+pub const BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS: u16 = 8;
+pub const BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS: u16 = 9;
+
+pub const BRIDGE_VLANDB_TINFO_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_TINFO_ID: u16 = 1;
+pub const BRIDGE_VLANDB_TINFO_CMD: u16 = 2;
+
+pub const BRIDGE_VLANDB_STATS_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_STATS_RX_BYTES: u16 = 1;
+pub const BRIDGE_VLANDB_STATS_RX_PACKETS: u16 = 2;
+pub const BRIDGE_VLANDB_STATS_TX_BYTES: u16 = 3;
+pub const BRIDGE_VLANDB_STATS_TX_PACKETS: u16 = 4;
+pub const BRIDGE_VLANDB_STATS_PAD: u16 = 5;
+
+pub const BRIDGE_VLANDB_GOPTS_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_GOPTS_ID: u16 = 1;
+pub const BRIDGE_VLANDB_GOPTS_RANGE: u16 = 2;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING: u16 = 3;
 
 pub const IFLA_BR_UNSPEC: u16 = 0;
 pub const IFLA_BR_FORWARD_DELAY: u16 = 1;
@@ -689,6 +786,14 @@ pub const NDA_IFINDEX: u16 = 8;
 pub const NDA_MASTER: u16 = 9;
 pub const NDA_LINK_NETNSID: u16 = 10;
 pub const NDA_SRC_VNI: u16 = 11;
+pub const NDA_PROTOCOL: u16 = 12;
+pub const NDA_NH_ID: u16 = 13;
+pub const NDA_FDB_EXT_ATTRS: u16 = 14;
+pub const NDA_FLAGS_EXT: u16 = 15;
+
+// Extended neighbour cache entry flags (`NDA_FLAGS_EXT`)
+pub const NTF_EXT_MANAGED: u32 = 1;
+pub const NTF_EXT_LOCKED: u32 = 2;
 
 /// see `https://github.com/torvalds/linux/blob/master/include/uapi/linux/fib_rules.h`
 
@@ -825,43 +930,6 @@ pub const RTEXT_FILTER_SKIP_STATS: u32 = 8;
 // pub const IN6_ADDR_GEN_MODE_STABLE_PRIVACY: int = 2;
 // pub const IN6_ADDR_GEN_MODE_RANDOM: int = 3;
 //
-// pub const BRIDGE_MODE_UNSPEC: int = 0;
-// pub const BRIDGE_MODE_HAIRPIN: int = 1;
-//
-// pub const IFLA_BRPORT_UNSPEC: int = 0;
-// pub const IFLA_BRPORT_STATE: int = 1;
-// pub const IFLA_BRPORT_PRIORITY: int = 2;
-// pub const IFLA_BRPORT_COST: int = 3;
-// pub const IFLA_BRPORT_MODE: int = 4;
-// pub const IFLA_BRPORT_GUARD: int = 5;
-// pub const IFLA_BRPORT_PROTECT: int = 6;
-// pub const IFLA_BRPORT_FAST_LEAVE: int = 7;
-// pub const IFLA_BRPORT_LEARNING: int = 8;
-// pub const IFLA_BRPORT_UNICAST_FLOOD: int = 9;
-// pub const IFLA_BRPORT_PROXYARP: int = 10;
-// pub const IFLA_BRPORT_LEARNING_SYNC: int = 11;
-// pub const IFLA_BRPORT_PROXYARP_WIFI: int = 12;
-// pub const IFLA_BRPORT_ROOT_ID: int = 13;
-// pub const IFLA_BRPORT_BRIDGE_ID: int = 14;
-// pub const IFLA_BRPORT_DESIGNATED_PORT: int = 15;
-// pub const IFLA_BRPORT_DESIGNATED_COST: int = 16;
-// pub const IFLA_BRPORT_ID: int = 17;
-// pub const IFLA_BRPORT_NO: int = 18;
-// pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: int = 19;
-// pub const IFLA_BRPORT_CONFIG_PENDING: int = 20;
-// pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: int = 21;
-// pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: int = 22;
-// pub const IFLA_BRPORT_HOLD_TIMER: int = 23;
-// pub const IFLA_BRPORT_FLUSH: int = 24;
-// pub const IFLA_BRPORT_MULTICAST_ROUTER: int = 25;
-// pub const IFLA_BRPORT_PAD: int = 26;
-// pub const IFLA_BRPORT_MCAST_FLOOD: int = 27;
-// pub const IFLA_BRPORT_MCAST_TO_UCAST: int = 28;
-// pub const IFLA_BRPORT_VLAN_TUNNEL: int = 29;
-// pub const IFLA_BRPORT_BCAST_FLOOD: int = 30;
-// pub const IFLA_BRPORT_GROUP_FWD_MASK: int = 31;
-// pub const IFLA_BRPORT_NEIGH_SUPPRESS: int = 32;
-//
 // pub const IFLA_VLAN_QOS_UNSPEC: int = 0;
 // pub const IFLA_VLAN_QOS_MAPPING: int = 1;
 //
@@ -953,6 +1021,94 @@ pub const IFLA_VXLAN_GPE: u16 = 27;
 pub const IFLA_VXLAN_TTL_INHERIT: u16 = 28;
 pub const IFLA_VXLAN_DF: u16 = 29;
 pub const __IFLA_VXLAN_MAX: u16 = 30;
+
+// FROM https://elixir.bootlin.com/linux/v5.9.8/source/include/uapi/linux/if_tunnel.h#L20
+pub const IFLA_IPTUN_UNSPEC: u16 = 0;
+pub const IFLA_IPTUN_LINK: u16 = 1;
+pub const IFLA_IPTUN_LOCAL: u16 = 2;
+pub const IFLA_IPTUN_REMOTE: u16 = 3;
+pub const IFLA_IPTUN_TTL: u16 = 4;
+pub const IFLA_IPTUN_TOS: u16 = 5;
+pub const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+pub const IFLA_IPTUN_FLOWINFO: u16 = 7;
+pub const IFLA_IPTUN_FLAGS: u16 = 8;
+pub const IFLA_IPTUN_PROTO: u16 = 9;
+pub const IFLA_IPTUN_PMTUDISC: u16 = 10;
+pub const IFLA_IPTUN_6RD_PREFIX: u16 = 11;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIX: u16 = 12;
+pub const IFLA_IPTUN_6RD_PREFIXLEN: u16 = 13;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: u16 = 14;
+pub const IFLA_IPTUN_ENCAP_TYPE: u16 = 15;
+pub const IFLA_IPTUN_ENCAP_FLAGS: u16 = 16;
+pub const IFLA_IPTUN_ENCAP_SPORT: u16 = 17;
+pub const IFLA_IPTUN_ENCAP_DPORT: u16 = 18;
+pub const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+pub const IFLA_IPTUN_FWMARK: u16 = 20;
+
+pub const TUNNEL_ENCAP_NONE: u16 = 0;
+pub const TUNNEL_ENCAP_FOU: u16 = 1;
+pub const TUNNEL_ENCAP_GUE: u16 = 2;
+pub const TUNNEL_ENCAP_MPLS: u16 = 3;
+
+pub const TUNNEL_ENCAP_FLAG_CSUM: u16 = 1;
+pub const TUNNEL_ENCAP_FLAG_CSUM6: u16 = 2;
+pub const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = 4;
+
+pub const SIT_ISATAP: u16 = 1;
+
+pub const IP6_TNL_F_IGN_ENCAP_LIMIT: u32 = 0x1;
+pub const IP6_TNL_F_USE_ORIG_TCLASS: u32 = 0x2;
+pub const IP6_TNL_F_USE_ORIG_FLOWLABEL: u32 = 0x4;
+pub const IP6_TNL_F_MIP6_DEV: u32 = 0x8;
+pub const IP6_TNL_F_RCV_DSCP_COPY: u32 = 0x10;
+pub const IP6_TNL_F_USE_ORIG_FWMARK: u32 = 0x20;
+pub const IP6_TNL_F_ALLOW_LOCAL_REMOTE: u32 = 0x40;
+
+// GRE flags, in host byte order. They are sent in network byte order
+// in the IFLA_GRE_IFLAGS and IFLA_GRE_OFLAGS attributes.
+pub const GRE_CSUM: u16 = 0x8000;
+pub const GRE_ROUTING: u16 = 0x4000;
+pub const GRE_KEY: u16 = 0x2000;
+pub const GRE_SEQ: u16 = 0x1000;
+pub const GRE_STRICT: u16 = 0x0800;
+pub const GRE_REC: u16 = 0x0700;
+pub const GRE_ACK: u16 = 0x0080;
+pub const GRE_FLAGS: u16 = 0x0078;
+pub const GRE_VERSION: u16 = 0x0007;
+
+pub const IFLA_GRE_UNSPEC: u16 = 0;
+pub const IFLA_GRE_LINK: u16 = 1;
+pub const IFLA_GRE_IFLAGS: u16 = 2;
+pub const IFLA_GRE_OFLAGS: u16 = 3;
+pub const IFLA_GRE_IKEY: u16 = 4;
+pub const IFLA_GRE_OKEY: u16 = 5;
+pub const IFLA_GRE_LOCAL: u16 = 6;
+pub const IFLA_GRE_REMOTE: u16 = 7;
+pub const IFLA_GRE_TTL: u16 = 8;
+pub const IFLA_GRE_TOS: u16 = 9;
+pub const IFLA_GRE_PMTUDISC: u16 = 10;
+pub const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub const IFLA_GRE_FLOWINFO: u16 = 12;
+pub const IFLA_GRE_FLAGS: u16 = 13;
+pub const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+pub const IFLA_GRE_ENCAP_FLAGS: u16 = 15;
+pub const IFLA_GRE_ENCAP_SPORT: u16 = 16;
+pub const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+pub const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+pub const IFLA_GRE_IGNORE_DF: u16 = 19;
+pub const IFLA_GRE_FWMARK: u16 = 20;
+pub const IFLA_GRE_ERSPAN_INDEX: u16 = 21;
+pub const IFLA_GRE_ERSPAN_VER: u16 = 22;
+pub const IFLA_GRE_ERSPAN_DIR: u16 = 23;
+pub const IFLA_GRE_ERSPAN_HWID: u16 = 24;
+
+pub const IFLA_VTI_UNSPEC: u16 = 0;
+pub const IFLA_VTI_LINK: u16 = 1;
+pub const IFLA_VTI_IKEY: u16 = 2;
+pub const IFLA_VTI_OKEY: u16 = 3;
+pub const IFLA_VTI_LOCAL: u16 = 4;
+pub const IFLA_VTI_REMOTE: u16 = 5;
+pub const IFLA_VTI_FWMARK: u16 = 6;
 //
 // pub const IFLA_GENEVE_UNSPEC: int = 0;
 // pub const IFLA_GENEVE_ID: int = 1;
@@ -1019,16 +1175,61 @@ pub const IFLA_BOND_AD_INFO_ACTOR_KEY: u16 = 3;
 pub const IFLA_BOND_AD_INFO_PARTNER_KEY: u16 = 4;
 pub const IFLA_BOND_AD_INFO_PARTNER_MAC: u16 = 5;
 
-// pub const IFLA_BOND_SLAVE_UNSPEC: int = 0;
-// pub const IFLA_BOND_SLAVE_STATE: int = 1;
-// pub const IFLA_BOND_SLAVE_MII_STATUS: int = 2;
-// pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: int = 3;
-// pub const IFLA_BOND_SLAVE_PERM_HWADDR: int = 4;
-// pub const IFLA_BOND_SLAVE_QUEUE_ID: int = 5;
-// pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: int = 6;
-// pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: int = 7;
-// pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: int = 8;
-//
+pub const BRIDGE_MODE_UNSPEC: u16 = 0;
+pub const BRIDGE_MODE_HAIRPIN: u16 = 1;
+
+pub const IFLA_BRPORT_UNSPEC: u16 = 0;
+pub const IFLA_BRPORT_STATE: u16 = 1;
+pub const IFLA_BRPORT_PRIORITY: u16 = 2;
+pub const IFLA_BRPORT_COST: u16 = 3;
+pub const IFLA_BRPORT_MODE: u16 = 4;
+pub const IFLA_BRPORT_GUARD: u16 = 5;
+pub const IFLA_BRPORT_PROTECT: u16 = 6;
+pub const IFLA_BRPORT_FAST_LEAVE: u16 = 7;
+pub const IFLA_BRPORT_LEARNING: u16 = 8;
+pub const IFLA_BRPORT_UNICAST_FLOOD: u16 = 9;
+pub const IFLA_BRPORT_PROXYARP: u16 = 10;
+pub const IFLA_BRPORT_LEARNING_SYNC: u16 = 11;
+pub const IFLA_BRPORT_PROXYARP_WIFI: u16 = 12;
+pub const IFLA_BRPORT_ROOT_ID: u16 = 13;
+pub const IFLA_BRPORT_BRIDGE_ID: u16 = 14;
+pub const IFLA_BRPORT_DESIGNATED_PORT: u16 = 15;
+pub const IFLA_BRPORT_DESIGNATED_COST: u16 = 16;
+pub const IFLA_BRPORT_ID: u16 = 17;
+pub const IFLA_BRPORT_NO: u16 = 18;
+pub const IFLA_BRPORT_TOPOLOGY_CHANGE_ACK: u16 = 19;
+pub const IFLA_BRPORT_CONFIG_PENDING: u16 = 20;
+pub const IFLA_BRPORT_MESSAGE_AGE_TIMER: u16 = 21;
+pub const IFLA_BRPORT_FORWARD_DELAY_TIMER: u16 = 22;
+pub const IFLA_BRPORT_HOLD_TIMER: u16 = 23;
+pub const IFLA_BRPORT_FLUSH: u16 = 24;
+pub const IFLA_BRPORT_MULTICAST_ROUTER: u16 = 25;
+pub const IFLA_BRPORT_PAD: u16 = 26;
+pub const IFLA_BRPORT_MCAST_FLOOD: u16 = 27;
+pub const IFLA_BRPORT_MCAST_TO_UCAST: u16 = 28;
+pub const IFLA_BRPORT_VLAN_TUNNEL: u16 = 29;
+pub const IFLA_BRPORT_BCAST_FLOOD: u16 = 30;
+pub const IFLA_BRPORT_GROUP_FWD_MASK: u16 = 31;
+pub const IFLA_BRPORT_NEIGH_SUPPRESS: u16 = 32;
+pub const IFLA_BRPORT_ISOLATED: u16 = 33;
+pub const IFLA_BRPORT_BACKUP_PORT: u16 = 34;
+pub const IFLA_BRPORT_MRP_RING_OPEN: u16 = 35;
+pub const IFLA_BRPORT_MRP_IN_OPEN: u16 = 36;
+pub const IFLA_BRPORT_MCAST_EHT_HOSTS_LIMIT: u16 = 37;
+pub const IFLA_BRPORT_MCAST_EHT_HOSTS_CNT: u16 = 38;
+pub const IFLA_BRPORT_LOCKED: u16 = 39;
+pub const IFLA_BRPORT_MAB: u16 = 40;
+
+pub const IFLA_BOND_SLAVE_UNSPEC: u16 = 0;
+pub const IFLA_BOND_SLAVE_STATE: u16 = 1;
+pub const IFLA_BOND_SLAVE_MII_STATUS: u16 = 2;
+pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: u16 = 3;
+pub const IFLA_BOND_SLAVE_PERM_HWADDR: u16 = 4;
+pub const IFLA_BOND_SLAVE_QUEUE_ID: u16 = 5;
+pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: u16 = 6;
+pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
+pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
+
 // pub const IFLA_VF_INFO_UNSPEC: int = 0;
 // pub const IFLA_VF_INFO: int = 1;
 //
@@ -1084,6 +1285,11 @@ pub const RTNLGRP_NSID: u32 = 28;
 pub const RTNLGRP_MPLS_NETCONF: u32 = 29;
 pub const RTNLGRP_IPV4_MROUTE_R: u32 = 30;
 pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
+pub const RTNLGRP_NEXTHOP: u32 = 32;
+pub const RTNLGRP_BRVLAN: u32 = 33;
+pub const RTNLGRP_MCTP_IFADDR: u32 = 34;
+pub const RTNLGRP_TUNNEL: u32 = 35;
+pub const RTNLGRP_STATS: u32 = 36;
 //
 // pub const IFLA_VF_LINK_STATE_AUTO: int = 0;
 // pub const IFLA_VF_LINK_STATE_ENABLE: int = 1;
@@ -1146,19 +1352,22 @@ pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
 // pub const IFLA_HSR_SEQ_NR: int = 5;
 // pub const IFLA_HSR_VERSION: int = 6;
 //
-// pub const IFLA_STATS_UNSPEC: int = 0;
-// pub const IFLA_STATS_LINK_64: int = 1;
-// pub const IFLA_STATS_LINK_XSTATS: int = 2;
-// pub const IFLA_STATS_LINK_XSTATS_SLAVE: int = 3;
-// pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: int = 4;
-// pub const IFLA_STATS_AF_SPEC: int = 5;
-//
-// pub const LINK_XSTATS_TYPE_UNSPEC: int = 0;
-// pub const LINK_XSTATS_TYPE_BRIDGE: int = 1;
-//
-// pub const IFLA_OFFLOAD_XSTATS_UNSPEC: int = 0;
-// pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: int = 1;
-//
+pub const IFLA_STATS_UNSPEC: u16 = 0;
+pub const IFLA_STATS_LINK_64: u16 = 1;
+pub const IFLA_STATS_LINK_XSTATS: u16 = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+pub const IFLA_STATS_AF_SPEC: u16 = 5;
+
+pub const LINK_XSTATS_TYPE_UNSPEC: u16 = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+pub const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+pub const IFLA_OFFLOAD_XSTATS_UNSPEC: u16 = 0;
+pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+pub const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+pub const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
 // pub const XDP_ATTACHED_NONE: int = 0;
 // pub const XDP_ATTACHED_DRV: int = 1;
 // pub const XDP_ATTACHED_SKB: int = 2;
@@ -1214,11 +1423,118 @@ pub const LWTUNNEL_ENCAP_SEG6: u16 = 5;
 pub const LWTUNNEL_ENCAP_BPF: u16 = 6;
 pub const LWTUNNEL_ENCAP_SEG6_LOCAL: u16 = 7;
 pub const LWTUNNEL_ENCAP_RPL: u16 = 8;
+pub const LWTUNNEL_ENCAP_IOAM6: u16 = 9;
+pub const LWTUNNEL_ENCAP_XFRM: u16 = 10;
 
 pub const MPLS_IPTUNNEL_UNSPEC: u16 = 0;
 pub const MPLS_IPTUNNEL_DST: u16 = 1;
 pub const MPLS_IPTUNNEL_TTL: u16 = 2;
 
+pub const MPLS_LS_LABEL_MASK: u32 = 0xffff_f000;
+pub const MPLS_LS_LABEL_SHIFT: u32 = 12;
+pub const MPLS_LS_TC_MASK: u32 = 0x0000_0e00;
+pub const MPLS_LS_TC_SHIFT: u32 = 9;
+pub const MPLS_LS_S_MASK: u32 = 0x0000_0100;
+pub const MPLS_LS_S_SHIFT: u32 = 8;
+pub const MPLS_LS_TTL_MASK: u32 = 0x0000_00ff;
+pub const MPLS_LS_TTL_SHIFT: u32 = 0;
+
+pub const MPLS_LABEL_IPV4NULL: u32 = 0;
+pub const MPLS_LABEL_RTALERT: u32 = 1;
+pub const MPLS_LABEL_IPV6NULL: u32 = 2;
+pub const MPLS_LABEL_IMPLNULL: u32 = 3;
+pub const MPLS_LABEL_ENTROPY: u32 = 7;
+pub const MPLS_LABEL_GAL: u32 = 13;
+pub const MPLS_LABEL_OAMALERT: u32 = 14;
+pub const MPLS_LABEL_EXTENSION: u32 = 15;
+pub const MPLS_LABEL_FIRST_UNRESERVED: u32 = 16;
+
+pub const LWTUNNEL_IP_UNSPEC: u16 = 0;
+pub const LWTUNNEL_IP_ID: u16 = 1;
+pub const LWTUNNEL_IP_DST: u16 = 2;
+pub const LWTUNNEL_IP_SRC: u16 = 3;
+pub const LWTUNNEL_IP_TTL: u16 = 4;
+pub const LWTUNNEL_IP_TOS: u16 = 5;
+pub const LWTUNNEL_IP_FLAGS: u16 = 6;
+pub const LWTUNNEL_IP_PAD: u16 = 7;
+pub const LWTUNNEL_IP_OPTS: u16 = 8;
+
+pub const LWTUNNEL_IP6_UNSPEC: u16 = 0;
+pub const LWTUNNEL_IP6_ID: u16 = 1;
+pub const LWTUNNEL_IP6_DST: u16 = 2;
+pub const LWTUNNEL_IP6_SRC: u16 = 3;
+pub const LWTUNNEL_IP6_HOPLIMIT: u16 = 4;
+pub const LWTUNNEL_IP6_TC: u16 = 5;
+pub const LWTUNNEL_IP6_FLAGS: u16 = 6;
+pub const LWTUNNEL_IP6_PAD: u16 = 7;
+pub const LWTUNNEL_IP6_OPTS: u16 = 8;
+
+pub const LWT_BPF_PROG_UNSPEC: u16 = 0;
+pub const LWT_BPF_PROG_FD: u16 = 1;
+pub const LWT_BPF_PROG_NAME: u16 = 2;
+
+pub const LWT_BPF_UNSPEC: u16 = 0;
+pub const LWT_BPF_IN: u16 = 1;
+pub const LWT_BPF_OUT: u16 = 2;
+pub const LWT_BPF_XMIT: u16 = 3;
+pub const LWT_BPF_XMIT_HEADROOM: u16 = 4;
+pub const LWT_BPF_MAX_HEADROOM: u32 = 256;
+
+pub const SEG6_IPTUNNEL_UNSPEC: u16 = 0;
+pub const SEG6_IPTUNNEL_SRH: u16 = 1;
+
+pub const SEG6_IPTUN_MODE_INLINE: u32 = 0;
+pub const SEG6_IPTUN_MODE_ENCAP: u32 = 1;
+pub const SEG6_IPTUN_MODE_L2ENCAP: u32 = 2;
+pub const SEG6_IPTUN_MODE_ENCAP_RED: u32 = 3;
+pub const SEG6_IPTUN_MODE_L2ENCAP_RED: u32 = 4;
+
+pub const SR6_FLAG1_PROTECTED: u8 = 1 << 6;
+pub const SR6_FLAG1_OAM: u8 = 1 << 5;
+pub const SR6_FLAG1_ALERT: u8 = 1 << 4;
+pub const SR6_FLAG1_HMAC: u8 = 1 << 3;
+
+pub const SEG6_LOCAL_UNSPEC: u16 = 0;
+pub const SEG6_LOCAL_ACTION: u16 = 1;
+pub const SEG6_LOCAL_SRH: u16 = 2;
+pub const SEG6_LOCAL_TABLE: u16 = 3;
+pub const SEG6_LOCAL_NH4: u16 = 4;
+pub const SEG6_LOCAL_NH6: u16 = 5;
+pub const SEG6_LOCAL_IIF: u16 = 6;
+pub const SEG6_LOCAL_OIF: u16 = 7;
+pub const SEG6_LOCAL_BPF: u16 = 8;
+pub const SEG6_LOCAL_VRFTABLE: u16 = 9;
+pub const SEG6_LOCAL_COUNTERS: u16 = 10;
+pub const SEG6_LOCAL_FLAVORS: u16 = 11;
+
+pub const SEG6_LOCAL_ACTION_UNSPEC: u32 = 0;
+pub const SEG6_LOCAL_ACTION_END: u32 = 1;
+pub const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+pub const SEG6_LOCAL_ACTION_END_T: u32 = 3;
+pub const SEG6_LOCAL_ACTION_END_DX2: u32 = 4;
+pub const SEG6_LOCAL_ACTION_END_DX6: u32 = 5;
+pub const SEG6_LOCAL_ACTION_END_DX4: u32 = 6;
+pub const SEG6_LOCAL_ACTION_END_DT6: u32 = 7;
+pub const SEG6_LOCAL_ACTION_END_DT4: u32 = 8;
+pub const SEG6_LOCAL_ACTION_END_B6: u32 = 9;
+pub const SEG6_LOCAL_ACTION_END_B6_ENCAP: u32 = 10;
+pub const SEG6_LOCAL_ACTION_END_BM: u32 = 11;
+pub const SEG6_LOCAL_ACTION_END_S: u32 = 12;
+pub const SEG6_LOCAL_ACTION_END_AS: u32 = 13;
+pub const SEG6_LOCAL_ACTION_END_AM: u32 = 14;
+pub const SEG6_LOCAL_ACTION_END_BPF: u32 = 15;
+pub const SEG6_LOCAL_ACTION_END_DT46: u32 = 16;
+
+pub const SEG6_LOCAL_BPF_PROG_UNSPEC: u16 = 0;
+pub const SEG6_LOCAL_BPF_PROG: u16 = 1;
+pub const SEG6_LOCAL_BPF_PROG_NAME: u16 = 2;
+
+pub const SEG6_LOCAL_CNT_UNSPEC: u16 = 0;
+pub const SEG6_LOCAL_CNT_PAD: u16 = 1;
+pub const SEG6_LOCAL_CNT_PACKETS: u16 = 2;
+pub const SEG6_LOCAL_CNT_BYTES: u16 = 3;
+pub const SEG6_LOCAL_CNT_ERRORS: u16 = 4;
+
 // Available MACVTAP MODES
 pub const MACVTAP_MODE_PRIVATE: u32 = 1;
 pub const MACVTAP_MODE_VEPA: u32 = 2;
@@ -6,9 +6,9 @@ use anyhow::Context;
 
 use crate::{
     constants::*,
-    nlas::{self, DefaultNla, NlaBuffer},
-    parsers::parse_u16,
-    traits::Parseable,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -16,8 +16,14 @@ use byteorder::{ByteOrder, NativeEndian};
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum AfSpecBridge {
+    /// Whether the request is for the bridge (`BRIDGE_FLAGS_MASTER`) or for
+    /// the port driver (`BRIDGE_FLAGS_SELF`)
     Flags(u16),
+    /// Hairpin mode of the port driver, `BRIDGE_MODE_VEB` or `BRIDGE_MODE_VEPA`
+    Mode(u16),
     VlanInfo(BridgeVlanInfo),
+    /// Mapping of a VLAN, or of a range of VLANs, to tunnel ids
+    VlanTunnelInfo(Vec<BridgeVlanTunnelInfo>),
     Other(DefaultNla),
 }
 
@@ -26,7 +32,8 @@ impl nlas::Nla for AfSpecBridge {
         use self::AfSpecBridge::*;
         match *self {
             VlanInfo(_) => 4,
-            Flags(_) => 2,
+            Flags(_) | Mode(_) => 2,
+            VlanTunnelInfo(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref nla) => nla.value_len(),
         }
     }
@@ -34,7 +41,8 @@ impl nlas::Nla for AfSpecBridge {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::AfSpecBridge::*;
         match *self {
-            Flags(value) => NativeEndian::write_u16(buffer, value),
+            Flags(value) | Mode(value) => NativeEndian::write_u16(buffer, value),
+            VlanTunnelInfo(ref nlas) => nlas.as_slice().emit(buffer),
             VlanInfo(ref info) => {
                 (&mut buffer[..4]).copy_from_slice(<[u8; 4]>::from(info).as_slice())
             }
@@ -46,7 +54,9 @@ impl nlas::Nla for AfSpecBridge {
         use self::AfSpecBridge::*;
         match *self {
             Flags(_) => IFLA_BRIDGE_FLAGS,
+            Mode(_) => IFLA_BRIDGE_MODE,
             VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
+            VlanTunnelInfo(_) => IFLA_BRIDGE_VLAN_TUNNEL_INFO,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -64,6 +74,16 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
             IFLA_BRIDGE_FLAGS => {
                 Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_FLAGS value")?)
             }
+            IFLA_BRIDGE_MODE => Mode(parse_u16(payload).context("invalid IFLA_BRIDGE_MODE value")?),
+            IFLA_BRIDGE_VLAN_TUNNEL_INFO => {
+                let err = "invalid IFLA_BRIDGE_VLAN_TUNNEL_INFO value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(BridgeVlanTunnelInfo::parse(&nla).context(err)?);
+                }
+                VlanTunnelInfo(nlas)
+            }
             kind => Other(DefaultNla::parse(buf).context(format!("Unknown NLA type {}", kind))?),
         })
     }
@@ -102,3 +122,64 @@ impl TryFrom<&[u8]> for BridgeVlanInfo {
         }
     }
 }
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum BridgeVlanTunnelInfo {
+    /// Tunnel id, such as a VXLAN VNI
+    Id(u32),
+    Vid(u16),
+    /// `BRIDGE_VLAN_INFO_RANGE_BEGIN` or `BRIDGE_VLAN_INFO_RANGE_END` when
+    /// mapping a range of VLANs
+    Flags(u16),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for BridgeVlanTunnelInfo {
+    fn value_len(&self) -> usize {
+        use self::BridgeVlanTunnelInfo::*;
+        match *self {
+            Id(_) => 4,
+            Vid(_) | Flags(_) => 2,
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BridgeVlanTunnelInfo::*;
+        match *self {
+            Id(value) => NativeEndian::write_u32(buffer, value),
+            Vid(value) | Flags(value) => NativeEndian::write_u16(buffer, value),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BridgeVlanTunnelInfo::*;
+        match *self {
+            Id(_) => IFLA_BRIDGE_VLAN_TUNNEL_ID,
+            Vid(_) => IFLA_BRIDGE_VLAN_TUNNEL_VID,
+            Flags(_) => IFLA_BRIDGE_VLAN_TUNNEL_FLAGS,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeVlanTunnelInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BridgeVlanTunnelInfo::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_VLAN_TUNNEL_ID => {
+                Id(parse_u32(payload).context("invalid IFLA_BRIDGE_VLAN_TUNNEL_ID value")?)
+            }
+            IFLA_BRIDGE_VLAN_TUNNEL_VID => {
+                Vid(parse_u16(payload).context("invalid IFLA_BRIDGE_VLAN_TUNNEL_VID value")?)
+            }
+            IFLA_BRIDGE_VLAN_TUNNEL_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_VLAN_TUNNEL_FLAGS value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("Unknown NLA type {}", kind))?),
+        })
+    }
+}
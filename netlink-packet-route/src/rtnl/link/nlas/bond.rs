@@ -2,7 +2,7 @@
 
 use crate::{
     constants::*,
-    nlas::{Nla, NlaBuffer, NlasIterator},
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
     parsers::{parse_ip, parse_mac, parse_u16, parse_u32, parse_u8},
     traits::{Emitable, Parseable},
     DecodeError,
@@ -435,3 +435,106 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBond {
         })
     }
 }
+
+/// Attributes of a bond slave, found in the `IFLA_INFO_SLAVE_DATA` of links
+/// whose `IFLA_INFO_SLAVE_KIND` is `bond`.
+// https://elixir.bootlin.com/linux/v6.1/source/drivers/net/bonding/bond_netlink.c#L32
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBondSlave {
+    State(u8),
+    MiiStatus(u8),
+    LinkFailureCount(u32),
+    PermHwaddr(Vec<u8>),
+    QueueId(u16),
+    AdAggregatorId(u16),
+    AdActorOperPortState(u8),
+    AdPartnerOperPortState(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBondSlave {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBondSlave::*;
+        match self {
+            State(_)
+                | MiiStatus(_)
+                | AdActorOperPortState(_)
+                => 1,
+            QueueId(_)
+                | AdAggregatorId(_)
+                | AdPartnerOperPortState(_)
+                => 2,
+            LinkFailureCount(_) => 4,
+            PermHwaddr(bytes) => bytes.len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBondSlave::*;
+        match self {
+            State(value)
+                | MiiStatus(value)
+                | AdActorOperPortState(value)
+                => buffer[0] = *value,
+            QueueId(value)
+                | AdAggregatorId(value)
+                | AdPartnerOperPortState(value)
+                => NativeEndian::write_u16(buffer, *value),
+            LinkFailureCount(value) => NativeEndian::write_u32(buffer, *value),
+            PermHwaddr(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBondSlave::*;
+        match self {
+            State(_) => IFLA_BOND_SLAVE_STATE,
+            MiiStatus(_) => IFLA_BOND_SLAVE_MII_STATUS,
+            LinkFailureCount(_) => IFLA_BOND_SLAVE_LINK_FAILURE_COUNT,
+            PermHwaddr(_) => IFLA_BOND_SLAVE_PERM_HWADDR,
+            QueueId(_) => IFLA_BOND_SLAVE_QUEUE_ID,
+            AdAggregatorId(_) => IFLA_BOND_SLAVE_AD_AGGREGATOR_ID,
+            AdActorOperPortState(_) => IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE,
+            AdPartnerOperPortState(_) => IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBondSlave {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBondSlave::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_SLAVE_STATE => {
+                State(parse_u8(payload).context("invalid IFLA_BOND_SLAVE_STATE value")?)
+            }
+            IFLA_BOND_SLAVE_MII_STATUS => {
+                MiiStatus(parse_u8(payload).context("invalid IFLA_BOND_SLAVE_MII_STATUS value")?)
+            }
+            IFLA_BOND_SLAVE_LINK_FAILURE_COUNT => LinkFailureCount(
+                parse_u32(payload).context("invalid IFLA_BOND_SLAVE_LINK_FAILURE_COUNT value")?,
+            ),
+            IFLA_BOND_SLAVE_PERM_HWADDR => PermHwaddr(payload.to_vec()),
+            IFLA_BOND_SLAVE_QUEUE_ID => {
+                QueueId(parse_u16(payload).context("invalid IFLA_BOND_SLAVE_QUEUE_ID value")?)
+            }
+            IFLA_BOND_SLAVE_AD_AGGREGATOR_ID => AdAggregatorId(
+                parse_u16(payload).context("invalid IFLA_BOND_SLAVE_AD_AGGREGATOR_ID value")?,
+            ),
+            IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE => AdActorOperPortState(
+                parse_u8(payload)
+                    .context("invalid IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE value")?,
+            ),
+            IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE => AdPartnerOperPortState(
+                parse_u16(payload)
+                    .context("invalid IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_mac, parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+/// Attributes of a bridge port, found in the `IFLA_INFO_SLAVE_DATA` of
+/// links whose `IFLA_INFO_SLAVE_KIND` is `bridge`.
+// https://elixir.bootlin.com/linux/v6.1/source/net/bridge/br_netlink.c#L220
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBridgePort {
+    Unspec(Vec<u8>),
+    State(u8),
+    Priority(u16),
+    Cost(u32),
+    /// Hairpin mode
+    Mode(u8),
+    Guard(u8),
+    Protect(u8),
+    FastLeave(u8),
+    Learning(u8),
+    UnicastFlood(u8),
+    ProxyArp(u8),
+    LearningSync(u8),
+    ProxyArpWifi(u8),
+    RootId((u16, [u8; 6])),
+    BridgeId((u16, [u8; 6])),
+    DesignatedPort(u16),
+    DesignatedCost(u16),
+    Id(u16),
+    No(u16),
+    TopologyChangeAck(u8),
+    ConfigPending(u8),
+    MessageAgeTimer(u64),
+    ForwardDelayTimer(u64),
+    HoldTimer(u64),
+    Flush,
+    MulticastRouter(u8),
+    Pad(Vec<u8>),
+    McastFlood(u8),
+    McastToUcast(u8),
+    VlanTunnel(u8),
+    BcastFlood(u8),
+    GroupFwdMask(u16),
+    NeighSuppress(u8),
+    Isolated(u8),
+    BackupPort(u32),
+    MrpRingOpen(u8),
+    MrpInOpen(u8),
+    McastEhtHostsLimit(u32),
+    McastEhtHostsCnt(u32),
+    Locked(u8),
+    Mab(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBridgePort {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBridgePort::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Flush => 0,
+            State(_)
+                | Mode(_)
+                | Guard(_)
+                | Protect(_)
+                | FastLeave(_)
+                | Learning(_)
+                | UnicastFlood(_)
+                | ProxyArp(_)
+                | LearningSync(_)
+                | ProxyArpWifi(_)
+                | TopologyChangeAck(_)
+                | ConfigPending(_)
+                | MulticastRouter(_)
+                | McastFlood(_)
+                | McastToUcast(_)
+                | VlanTunnel(_)
+                | BcastFlood(_)
+                | NeighSuppress(_)
+                | Isolated(_)
+                | MrpRingOpen(_)
+                | MrpInOpen(_)
+                | Locked(_)
+                | Mab(_)
+                => 1,
+            Priority(_)
+                | DesignatedPort(_)
+                | DesignatedCost(_)
+                | Id(_)
+                | No(_)
+                | GroupFwdMask(_)
+                => 2,
+            Cost(_)
+                | BackupPort(_)
+                | McastEhtHostsLimit(_)
+                | McastEhtHostsCnt(_)
+                => 4,
+            RootId(_) | BridgeId(_) => 8,
+            MessageAgeTimer(_)
+                | ForwardDelayTimer(_)
+                | HoldTimer(_)
+                => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBridgePort::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Flush => {}
+            State(value)
+                | Mode(value)
+                | Guard(value)
+                | Protect(value)
+                | FastLeave(value)
+                | Learning(value)
+                | UnicastFlood(value)
+                | ProxyArp(value)
+                | LearningSync(value)
+                | ProxyArpWifi(value)
+                | TopologyChangeAck(value)
+                | ConfigPending(value)
+                | MulticastRouter(value)
+                | McastFlood(value)
+                | McastToUcast(value)
+                | VlanTunnel(value)
+                | BcastFlood(value)
+                | NeighSuppress(value)
+                | Isolated(value)
+                | MrpRingOpen(value)
+                | MrpInOpen(value)
+                | Locked(value)
+                | Mab(value)
+                => buffer[0] = *value,
+            Priority(value)
+                | DesignatedPort(value)
+                | DesignatedCost(value)
+                | Id(value)
+                | No(value)
+                | GroupFwdMask(value)
+                => NativeEndian::write_u16(buffer, *value),
+            Cost(value)
+                | BackupPort(value)
+                | McastEhtHostsLimit(value)
+                | McastEhtHostsCnt(value)
+                => NativeEndian::write_u32(buffer, *value),
+            RootId((priority, address))
+                | BridgeId((priority, address))
+                => {
+                    NativeEndian::write_u16(buffer, *priority);
+                    buffer[2..].copy_from_slice(&address[..]);
+                }
+            MessageAgeTimer(value)
+                | ForwardDelayTimer(value)
+                | HoldTimer(value)
+                => NativeEndian::write_u64(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBridgePort::*;
+        match self {
+            Unspec(_) => IFLA_BRPORT_UNSPEC,
+            State(_) => IFLA_BRPORT_STATE,
+            Priority(_) => IFLA_BRPORT_PRIORITY,
+            Cost(_) => IFLA_BRPORT_COST,
+            Mode(_) => IFLA_BRPORT_MODE,
+            Guard(_) => IFLA_BRPORT_GUARD,
+            Protect(_) => IFLA_BRPORT_PROTECT,
+            FastLeave(_) => IFLA_BRPORT_FAST_LEAVE,
+            Learning(_) => IFLA_BRPORT_LEARNING,
+            UnicastFlood(_) => IFLA_BRPORT_UNICAST_FLOOD,
+            ProxyArp(_) => IFLA_BRPORT_PROXYARP,
+            LearningSync(_) => IFLA_BRPORT_LEARNING_SYNC,
+            ProxyArpWifi(_) => IFLA_BRPORT_PROXYARP_WIFI,
+            RootId(_) => IFLA_BRPORT_ROOT_ID,
+            BridgeId(_) => IFLA_BRPORT_BRIDGE_ID,
+            DesignatedPort(_) => IFLA_BRPORT_DESIGNATED_PORT,
+            DesignatedCost(_) => IFLA_BRPORT_DESIGNATED_COST,
+            Id(_) => IFLA_BRPORT_ID,
+            No(_) => IFLA_BRPORT_NO,
+            TopologyChangeAck(_) => IFLA_BRPORT_TOPOLOGY_CHANGE_ACK,
+            ConfigPending(_) => IFLA_BRPORT_CONFIG_PENDING,
+            MessageAgeTimer(_) => IFLA_BRPORT_MESSAGE_AGE_TIMER,
+            ForwardDelayTimer(_) => IFLA_BRPORT_FORWARD_DELAY_TIMER,
+            HoldTimer(_) => IFLA_BRPORT_HOLD_TIMER,
+            Flush => IFLA_BRPORT_FLUSH,
+            MulticastRouter(_) => IFLA_BRPORT_MULTICAST_ROUTER,
+            Pad(_) => IFLA_BRPORT_PAD,
+            McastFlood(_) => IFLA_BRPORT_MCAST_FLOOD,
+            McastToUcast(_) => IFLA_BRPORT_MCAST_TO_UCAST,
+            VlanTunnel(_) => IFLA_BRPORT_VLAN_TUNNEL,
+            BcastFlood(_) => IFLA_BRPORT_BCAST_FLOOD,
+            GroupFwdMask(_) => IFLA_BRPORT_GROUP_FWD_MASK,
+            NeighSuppress(_) => IFLA_BRPORT_NEIGH_SUPPRESS,
+            Isolated(_) => IFLA_BRPORT_ISOLATED,
+            BackupPort(_) => IFLA_BRPORT_BACKUP_PORT,
+            MrpRingOpen(_) => IFLA_BRPORT_MRP_RING_OPEN,
+            MrpInOpen(_) => IFLA_BRPORT_MRP_IN_OPEN,
+            McastEhtHostsLimit(_) => IFLA_BRPORT_MCAST_EHT_HOSTS_LIMIT,
+            McastEhtHostsCnt(_) => IFLA_BRPORT_MCAST_EHT_HOSTS_CNT,
+            Locked(_) => IFLA_BRPORT_LOCKED,
+            Mab(_) => IFLA_BRPORT_MAB,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridgePort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBridgePort::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRPORT_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_BRPORT_STATE => {
+                State(parse_u8(payload).context("invalid IFLA_BRPORT_STATE value")?)
+            }
+            IFLA_BRPORT_PRIORITY => {
+                Priority(parse_u16(payload).context("invalid IFLA_BRPORT_PRIORITY value")?)
+            }
+            IFLA_BRPORT_COST => Cost(parse_u32(payload).context("invalid IFLA_BRPORT_COST value")?),
+            IFLA_BRPORT_MODE => Mode(parse_u8(payload).context("invalid IFLA_BRPORT_MODE value")?),
+            IFLA_BRPORT_GUARD => {
+                Guard(parse_u8(payload).context("invalid IFLA_BRPORT_GUARD value")?)
+            }
+            IFLA_BRPORT_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_BRPORT_PROTECT value")?)
+            }
+            IFLA_BRPORT_FAST_LEAVE => {
+                FastLeave(parse_u8(payload).context("invalid IFLA_BRPORT_FAST_LEAVE value")?)
+            }
+            IFLA_BRPORT_LEARNING => {
+                Learning(parse_u8(payload).context("invalid IFLA_BRPORT_LEARNING value")?)
+            }
+            IFLA_BRPORT_UNICAST_FLOOD => {
+                UnicastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_UNICAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_PROXYARP => {
+                ProxyArp(parse_u8(payload).context("invalid IFLA_BRPORT_PROXYARP value")?)
+            }
+            IFLA_BRPORT_LEARNING_SYNC => {
+                LearningSync(parse_u8(payload).context("invalid IFLA_BRPORT_LEARNING_SYNC value")?)
+            }
+            IFLA_BRPORT_PROXYARP_WIFI => {
+                ProxyArpWifi(parse_u8(payload).context("invalid IFLA_BRPORT_PROXYARP_WIFI value")?)
+            }
+            IFLA_BRPORT_ROOT_ID | IFLA_BRPORT_BRIDGE_ID => {
+                if payload.len() != 8 {
+                    return Err("invalid IFLA_BRPORT_ROOT_ID or IFLA_BRPORT_BRIDGE_ID value".into());
+                }
+
+                let priority = NativeEndian::read_u16(&payload[..2]);
+                let address = parse_mac(&payload[2..])
+                    .context("invalid IFLA_BRPORT_ROOT_ID or IFLA_BRPORT_BRIDGE_ID value")?;
+
+                match buf.kind() {
+                    IFLA_BRPORT_ROOT_ID => RootId((priority, address)),
+                    IFLA_BRPORT_BRIDGE_ID => BridgeId((priority, address)),
+                    _ => unreachable!(),
+                }
+            }
+            IFLA_BRPORT_DESIGNATED_PORT => DesignatedPort(
+                parse_u16(payload).context("invalid IFLA_BRPORT_DESIGNATED_PORT value")?,
+            ),
+            IFLA_BRPORT_DESIGNATED_COST => DesignatedCost(
+                parse_u16(payload).context("invalid IFLA_BRPORT_DESIGNATED_COST value")?,
+            ),
+            IFLA_BRPORT_ID => Id(parse_u16(payload).context("invalid IFLA_BRPORT_ID value")?),
+            IFLA_BRPORT_NO => No(parse_u16(payload).context("invalid IFLA_BRPORT_NO value")?),
+            IFLA_BRPORT_TOPOLOGY_CHANGE_ACK => TopologyChangeAck(
+                parse_u8(payload).context("invalid IFLA_BRPORT_TOPOLOGY_CHANGE_ACK value")?,
+            ),
+            IFLA_BRPORT_CONFIG_PENDING => ConfigPending(
+                parse_u8(payload).context("invalid IFLA_BRPORT_CONFIG_PENDING value")?,
+            ),
+            IFLA_BRPORT_MESSAGE_AGE_TIMER => MessageAgeTimer(
+                parse_u64(payload).context("invalid IFLA_BRPORT_MESSAGE_AGE_TIMER value")?,
+            ),
+            IFLA_BRPORT_FORWARD_DELAY_TIMER => ForwardDelayTimer(
+                parse_u64(payload).context("invalid IFLA_BRPORT_FORWARD_DELAY_TIMER value")?,
+            ),
+            IFLA_BRPORT_HOLD_TIMER => {
+                HoldTimer(parse_u64(payload).context("invalid IFLA_BRPORT_HOLD_TIMER value")?)
+            }
+            IFLA_BRPORT_FLUSH => Flush,
+            IFLA_BRPORT_MULTICAST_ROUTER => MulticastRouter(
+                parse_u8(payload).context("invalid IFLA_BRPORT_MULTICAST_ROUTER value")?,
+            ),
+            IFLA_BRPORT_PAD => Pad(payload.to_vec()),
+            IFLA_BRPORT_MCAST_FLOOD => {
+                McastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_MCAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_MCAST_TO_UCAST => {
+                McastToUcast(parse_u8(payload).context("invalid IFLA_BRPORT_MCAST_TO_UCAST value")?)
+            }
+            IFLA_BRPORT_VLAN_TUNNEL => {
+                VlanTunnel(parse_u8(payload).context("invalid IFLA_BRPORT_VLAN_TUNNEL value")?)
+            }
+            IFLA_BRPORT_BCAST_FLOOD => {
+                BcastFlood(parse_u8(payload).context("invalid IFLA_BRPORT_BCAST_FLOOD value")?)
+            }
+            IFLA_BRPORT_GROUP_FWD_MASK => GroupFwdMask(
+                parse_u16(payload).context("invalid IFLA_BRPORT_GROUP_FWD_MASK value")?,
+            ),
+            IFLA_BRPORT_NEIGH_SUPPRESS => NeighSuppress(
+                parse_u8(payload).context("invalid IFLA_BRPORT_NEIGH_SUPPRESS value")?,
+            ),
+            IFLA_BRPORT_ISOLATED => {
+                Isolated(parse_u8(payload).context("invalid IFLA_BRPORT_ISOLATED value")?)
+            }
+            IFLA_BRPORT_BACKUP_PORT => {
+                BackupPort(parse_u32(payload).context("invalid IFLA_BRPORT_BACKUP_PORT value")?)
+            }
+            IFLA_BRPORT_MRP_RING_OPEN => {
+                MrpRingOpen(parse_u8(payload).context("invalid IFLA_BRPORT_MRP_RING_OPEN value")?)
+            }
+            IFLA_BRPORT_MRP_IN_OPEN => {
+                MrpInOpen(parse_u8(payload).context("invalid IFLA_BRPORT_MRP_IN_OPEN value")?)
+            }
+            IFLA_BRPORT_MCAST_EHT_HOSTS_LIMIT => McastEhtHostsLimit(
+                parse_u32(payload).context("invalid IFLA_BRPORT_MCAST_EHT_HOSTS_LIMIT value")?,
+            ),
+            IFLA_BRPORT_MCAST_EHT_HOSTS_CNT => McastEhtHostsCnt(
+                parse_u32(payload).context("invalid IFLA_BRPORT_MCAST_EHT_HOSTS_CNT value")?,
+            ),
+            IFLA_BRPORT_LOCKED => {
+                Locked(parse_u8(payload).context("invalid IFLA_BRPORT_LOCKED value")?)
+            }
+            IFLA_BRPORT_MAB => Mab(parse_u8(payload).context("invalid IFLA_BRPORT_MAB value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -1,6 +1,10 @@
 // SPDX-License-Identifier: MIT
 
-use super::bond::InfoBond;
+use super::{
+    bond::{InfoBond, InfoBondSlave},
+    bridge_port::InfoBridgePort,
+    tunnel::{InfoGreTun, InfoIpTun, InfoVti},
+};
 use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
@@ -44,8 +48,8 @@ pub enum Info {
     Xstats(Vec<u8>),
     Kind(InfoKind),
     Data(InfoData),
-    SlaveKind(Vec<u8>),
-    SlaveData(Vec<u8>),
+    SlaveKind(InfoSlaveKind),
+    SlaveData(InfoSlaveData),
 }
 
 impl Nla for Info {
@@ -55,11 +59,11 @@ impl Nla for Info {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => bytes.len(),
             Kind(ref nla) => nla.value_len(),
             Data(ref nla) => nla.value_len(),
+            SlaveKind(ref nla) => nla.value_len(),
+            SlaveData(ref nla) => nla.value_len(),
         }
     }
 
@@ -69,11 +73,11 @@ impl Nla for Info {
         match self {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
-                | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => buffer.copy_from_slice(bytes),
             Kind(ref nla) => nla.emit_value(buffer),
             Data(ref nla) => nla.emit_value(buffer),
+            SlaveKind(ref nla) => nla.emit_value(buffer),
+            SlaveData(ref nla) => nla.emit_value(buffer),
         }
     }
 
@@ -83,7 +87,7 @@ impl Nla for Info {
             Unspec(_) => IFLA_INFO_UNSPEC,
             Xstats(_) => IFLA_INFO_XSTATS,
             SlaveKind(_) => IFLA_INFO_SLAVE_KIND,
-            SlaveData(_) => IFLA_INFO_DATA,
+            SlaveData(_) => IFLA_INFO_SLAVE_DATA,
             Kind(_) => IFLA_INFO_KIND,
             Data(_) => IFLA_INFO_DATA,
         }
@@ -107,13 +111,52 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
         let mut res = Vec::new();
         let nlas = NlasIterator::new(buf.into_inner());
         let mut link_info_kind: Option<InfoKind> = None;
+        let mut link_info_slave_kind: Option<InfoSlaveKind> = None;
         for nla in nlas {
             let nla = nla?;
             match nla.kind() {
                 IFLA_INFO_UNSPEC => res.push(Info::Unspec(nla.value().to_vec())),
                 IFLA_INFO_XSTATS => res.push(Info::Xstats(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_KIND => res.push(Info::SlaveKind(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_DATA => res.push(Info::SlaveData(nla.value().to_vec())),
+                IFLA_INFO_SLAVE_KIND => {
+                    let parsed = InfoSlaveKind::parse(&nla)?;
+                    res.push(Info::SlaveKind(parsed.clone()));
+                    link_info_slave_kind = Some(parsed);
+                }
+                IFLA_INFO_SLAVE_DATA => {
+                    if let Some(link_info_slave_kind) = link_info_slave_kind {
+                        let payload = nla.value();
+                        let info_slave_data = match link_info_slave_kind {
+                            InfoSlaveKind::Bridge => {
+                                let mut v = Vec::new();
+                                let err = "failed to parse IFLA_INFO_SLAVE_DATA (IFLA_INFO_SLAVE_KIND is 'bridge')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoBridgePort::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoSlaveData::Bridge(v)
+                            }
+                            InfoSlaveKind::Bond => {
+                                let mut v = Vec::new();
+                                let err = "failed to parse IFLA_INFO_SLAVE_DATA (IFLA_INFO_SLAVE_KIND is 'bond')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoBondSlave::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoSlaveData::Bond(v)
+                            }
+                            InfoSlaveKind::Other(_) => InfoSlaveData::Other(payload.to_vec()),
+                        };
+                        res.push(Info::SlaveData(info_slave_data));
+                    } else {
+                        return Err(
+                            "IFLA_INFO_SLAVE_DATA is not preceded by an IFLA_INFO_SLAVE_KIND"
+                                .into(),
+                        );
+                    }
+                    link_info_slave_kind = None;
+                }
                 IFLA_INFO_KIND => {
                     let parsed = InfoKind::parse(&nla)?;
                     res.push(Info::Kind(parsed.clone()));
@@ -211,13 +254,83 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::MacVtap(v)
                             }
-                            InfoKind::GreTap => InfoData::GreTap(payload.to_vec()),
-                            InfoKind::GreTap6 => InfoData::GreTap6(payload.to_vec()),
-                            InfoKind::IpTun => InfoData::IpTun(payload.to_vec()),
-                            InfoKind::SitTun => InfoData::SitTun(payload.to_vec()),
-                            InfoKind::GreTun => InfoData::GreTun(payload.to_vec()),
-                            InfoKind::GreTun6 => InfoData::GreTun6(payload.to_vec()),
-                            InfoKind::Vti => InfoData::Vti(payload.to_vec()),
+                            InfoKind::GreTap => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'gretap')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTap(v)
+                            }
+                            InfoKind::GreTap6 => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ip6gretap')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTap6(v)
+                            }
+                            InfoKind::IpTun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ipip')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoIpTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::IpTun(v)
+                            }
+                            InfoKind::SitTun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'sit')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoIpTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::SitTun(v)
+                            }
+                            InfoKind::GreTun => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'gre')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTun(v)
+                            }
+                            InfoKind::GreTun6 => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ip6gre')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGreTun::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::GreTun6(v)
+                            }
+                            InfoKind::Vti => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'vti')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoVti::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Vti(v)
+                            }
                             InfoKind::Vrf => {
                                 let mut v = Vec::new();
                                 let err =
@@ -271,13 +384,13 @@ pub enum InfoData {
     IpVlan(Vec<InfoIpVlan>),
     MacVlan(Vec<InfoMacVlan>),
     MacVtap(Vec<InfoMacVtap>),
-    GreTap(Vec<u8>),
-    GreTap6(Vec<u8>),
-    IpTun(Vec<u8>),
-    SitTun(Vec<u8>),
-    GreTun(Vec<u8>),
-    GreTun6(Vec<u8>),
-    Vti(Vec<u8>),
+    GreTap(Vec<InfoGreTun>),
+    GreTap6(Vec<InfoGreTun>),
+    IpTun(Vec<InfoIpTun>),
+    SitTun(Vec<InfoIpTun>),
+    GreTun(Vec<InfoGreTun>),
+    GreTun6(Vec<InfoGreTun>),
+    Vti(Vec<InfoVti>),
     Vrf(Vec<InfoVrf>),
     Gtp(Vec<u8>),
     Ipoib(Vec<InfoIpoib>),
@@ -300,17 +413,17 @@ impl Nla for InfoData {
             MacVtap(ref nlas) => nlas.as_slice().buffer_len(),
             Vrf(ref nlas) => nlas.as_slice().buffer_len(),
             Vxlan(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTap(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTap6(ref nlas) => nlas.as_slice().buffer_len(),
+            IpTun(ref nlas) => nlas.as_slice().buffer_len(),
+            SitTun(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTun(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTun6(ref nlas) => nlas.as_slice().buffer_len(),
+            Vti(ref nlas) => nlas.as_slice().buffer_len(),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
                 | Gtp(ref bytes)
                 | Wireguard(ref bytes)
                 | Other(ref bytes)
@@ -332,17 +445,17 @@ impl Nla for InfoData {
             MacVtap(ref nlas) => nlas.as_slice().emit(buffer),
             Vrf(ref nlas) => nlas.as_slice().emit(buffer),
             Vxlan(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTap(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTap6(ref nlas) => nlas.as_slice().emit(buffer),
+            IpTun(ref nlas) => nlas.as_slice().emit(buffer),
+            SitTun(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTun(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTun6(ref nlas) => nlas.as_slice().emit(buffer),
+            Vti(ref nlas) => nlas.as_slice().emit(buffer),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
                 | Gtp(ref bytes)
                 | Wireguard(ref bytes)
                 | Other(ref bytes)
@@ -490,6 +603,95 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
     }
 }
 
+/// Kind of the master device a link is enslaved to, as reported in
+/// `IFLA_INFO_SLAVE_KIND`. It determines how `IFLA_INFO_SLAVE_DATA` is
+/// parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoSlaveKind {
+    Bridge,
+    Bond,
+    Other(String),
+}
+
+impl Nla for InfoSlaveKind {
+    fn value_len(&self) -> usize {
+        use self::InfoSlaveKind::*;
+        let len = match *self {
+            Bridge => BRIDGE.len(),
+            Bond => BOND.len(),
+            Other(ref s) => s.len(),
+        };
+        len + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoSlaveKind::*;
+        let s = match *self {
+            Bridge => BRIDGE,
+            Bond => BOND,
+            Other(ref s) => s.as_str(),
+        };
+        buffer[..s.len()].copy_from_slice(s.as_bytes());
+        buffer[s.len()] = 0;
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_KIND
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoSlaveKind {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<InfoSlaveKind, DecodeError> {
+        use self::InfoSlaveKind::*;
+        if buf.kind() != IFLA_INFO_SLAVE_KIND {
+            return Err(format!(
+                "failed to parse IFLA_INFO_SLAVE_KIND: NLA type is {}",
+                buf.kind()
+            )
+            .into());
+        }
+        let s = parse_string(buf.value()).context("invalid IFLA_INFO_SLAVE_KIND value")?;
+        Ok(match s.as_str() {
+            BRIDGE => Bridge,
+            BOND => Bond,
+            _ => Other(s),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoSlaveData {
+    Bridge(Vec<InfoBridgePort>),
+    Bond(Vec<InfoBondSlave>),
+    Other(Vec<u8>),
+}
+
+impl Nla for InfoSlaveData {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().buffer_len(),
+            Bond(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref bytes) => bytes.len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoSlaveData::*;
+        match self {
+            Bridge(ref nlas) => nlas.as_slice().emit(buffer),
+            Bond(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref bytes) => buffer.copy_from_slice(bytes),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_DATA
+    }
+}
+
 // https://elixir.bootlin.com/linux/v5.9.8/source/drivers/net/vxlan.c#L3332
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InfoVxlan {
@@ -2201,6 +2403,265 @@ mod tests {
         assert_eq!(&vec[..], &MACVLAN_SOURCE_SET[..]);
     }
 
+    #[rustfmt::skip]
+    static GRETAP: [u8; 68] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x67, 0x72, 0x65, 0x74, 0x61, 0x70, 0x00, // V = "gretap\0"
+        0x00, // padding
+
+        0x38, 0x00, // length = 56
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x01, 0x00, // type = 1 = IFLA_GRE_LINK
+            0x03, 0x00, 0x00, 0x00, // V = 3
+
+            0x06, 0x00, // length = 6
+            0x02, 0x00, // type = 2 = IFLA_GRE_IFLAGS
+            0x20, 0x00, // V = GRE_KEY (big-endian)
+            0x00, 0x00, // padding
+
+            0x08, 0x00, // length = 8
+            0x04, 0x00, // type = 4 = IFLA_GRE_IKEY
+            0x00, 0x00, 0x00, 0x0a, // V = 10 (big-endian)
+
+            0x08, 0x00, // length = 8
+            0x06, 0x00, // type = 6 = IFLA_GRE_LOCAL
+            0x0a, 0x00, 0x00, 0x01, // V = 10.0.0.1
+
+            0x08, 0x00, // length = 8
+            0x07, 0x00, // type = 7 = IFLA_GRE_REMOTE
+            0x0a, 0x00, 0x00, 0x02, // V = 10.0.0.2
+
+            0x05, 0x00, // length = 5
+            0x08, 0x00, // type = 8 = IFLA_GRE_TTL
+            0x40, // V = 64
+            0x00, 0x00, 0x00, // padding
+
+            0x04, 0x00, // length = 4
+            0x12, 0x00, // type = 18 = IFLA_GRE_COLLECT_METADATA
+    ];
+
+    lazy_static! {
+        static ref GRETAP_INFO: Vec<InfoGreTun> = vec![
+            InfoGreTun::Link(3),
+            InfoGreTun::IFlags(GRE_KEY),
+            InfoGreTun::IKey(10),
+            InfoGreTun::Local(Ipv4Addr::new(10, 0, 0, 1).into()),
+            InfoGreTun::Remote(Ipv4Addr::new(10, 0, 0, 2).into()),
+            InfoGreTun::Ttl(64),
+            InfoGreTun::CollectMetadata,
+        ];
+    }
+
+    #[test]
+    fn parse_info_gretap() {
+        let nla = NlaBuffer::new_checked(&GRETAP[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::GreTap),
+            Info::Data(InfoData::GreTap(GRETAP_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_gretap() {
+        let nlas = vec![
+            Info::Kind(InfoKind::GreTap),
+            Info::Data(InfoData::GreTap(GRETAP_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 68);
+
+        let mut vec = vec![0xff; 68];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GRETAP[..]);
+    }
+
+    #[rustfmt::skip]
+    static IP6GRE: [u8; 52] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x69, 0x70, 0x36, 0x67, 0x72, 0x65, 0x00, // V = "ip6gre\0"
+        0x00, // padding
+
+        0x28, 0x00, // length = 40
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x14, 0x00, // length = 20
+            0x07, 0x00, // type = 7 = IFLA_GRE_REMOTE
+            0xfd, 0x01, 0x00, 0x00, // V = fd01::2
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x02,
+
+            0x08, 0x00, // length = 8
+            0x0c, 0x00, // type = 12 = IFLA_GRE_FLOWINFO
+            0x00, 0x01, 0x23, 0x45, // V = 0x12345 (big-endian)
+
+            0x08, 0x00, // length = 8
+            0x0d, 0x00, // type = 13 = IFLA_GRE_FLAGS
+            0x01, 0x00, 0x00, 0x00, // V = IP6_TNL_F_IGN_ENCAP_LIMIT
+    ];
+
+    lazy_static! {
+        static ref IP6GRE_INFO: Vec<InfoGreTun> = vec![
+            InfoGreTun::Remote(Ipv6Addr::new(0xfd01, 0, 0, 0, 0, 0, 0, 2).into()),
+            InfoGreTun::FlowInfo(0x12345),
+            InfoGreTun::Flags(IP6_TNL_F_IGN_ENCAP_LIMIT),
+        ];
+    }
+
+    #[test]
+    fn parse_info_ip6gre() {
+        let nla = NlaBuffer::new_checked(&IP6GRE[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::GreTun6),
+            Info::Data(InfoData::GreTun6(IP6GRE_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_ip6gre() {
+        let nlas = vec![
+            Info::Kind(InfoKind::GreTun6),
+            Info::Data(InfoData::GreTun6(IP6GRE_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 52);
+
+        let mut vec = vec![0xff; 52];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &IP6GRE[..]);
+    }
+
+    #[rustfmt::skip]
+    static BRIDGE_SLAVE: [u8; 72] = [
+        0x0b, 0x00, // length = 11
+        0x04, 0x00, // type = 4 = IFLA_INFO_SLAVE_KIND
+        0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x00, // V = "bridge\0"
+        0x00, // padding
+
+        0x3c, 0x00, // length = 60
+        0x05, 0x00, // type = 5 = IFLA_INFO_SLAVE_DATA
+            0x05, 0x00, // length = 5
+            0x01, 0x00, // type = 1 = IFLA_BRPORT_STATE
+            0x03, // V = 3 (BR_STATE_FORWARDING)
+            0x00, 0x00, 0x00, // padding
+
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_BRPORT_COST
+            0x64, 0x00, 0x00, 0x00, // V = 100
+
+            0x05, 0x00, // length = 5
+            0x08, 0x00, // type = 8 = IFLA_BRPORT_LEARNING
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // length = 5
+            0x20, 0x00, // type = 32 = IFLA_BRPORT_NEIGH_SUPPRESS
+            0x00, // V = 0
+            0x00, 0x00, 0x00, // padding
+
+            0x0c, 0x00, // length = 12
+            0x0d, 0x00, // type = 13 = IFLA_BRPORT_ROOT_ID
+            0x80, 0x00, // V (priority) = 128 (0x80)
+            0x52, 0x54, 0x00, 0x12, 0x34, 0x56, // V = 52:54:00:12:34:56
+
+            0x0c, 0x00, // length = 12
+            0x17, 0x00, // type = 23 = IFLA_BRPORT_HOLD_TIMER
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // V = 0
+    ];
+
+    lazy_static! {
+        static ref BRIDGE_SLAVE_INFO: Vec<InfoBridgePort> = vec![
+            InfoBridgePort::State(3),
+            InfoBridgePort::Cost(100),
+            InfoBridgePort::Learning(1),
+            InfoBridgePort::NeighSuppress(0),
+            InfoBridgePort::RootId((128, [0x52, 0x54, 0x00, 0x12, 0x34, 0x56])),
+            InfoBridgePort::HoldTimer(0),
+        ];
+    }
+
+    #[test]
+    fn parse_info_bridge_slave() {
+        let nla = NlaBuffer::new_checked(&BRIDGE_SLAVE[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::SlaveKind(InfoSlaveKind::Bridge),
+            Info::SlaveData(InfoSlaveData::Bridge(BRIDGE_SLAVE_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_bridge_slave() {
+        let nlas = vec![
+            Info::SlaveKind(InfoSlaveKind::Bridge),
+            Info::SlaveData(InfoSlaveData::Bridge(BRIDGE_SLAVE_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 72);
+
+        let mut vec = vec![0xff; 72];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BRIDGE_SLAVE[..]);
+    }
+
+    #[rustfmt::skip]
+    static BOND_SLAVE: [u8; 60] = [
+        0x09, 0x00, // length = 9
+        0x04, 0x00, // type = 4 = IFLA_INFO_SLAVE_KIND
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // V = "bond\0"
+        0x00, 0x00, 0x00, // padding
+
+        0x30, 0x00, // length = 48
+        0x05, 0x00, // type = 5 = IFLA_INFO_SLAVE_DATA
+            0x05, 0x00, // length = 5
+            0x01, 0x00, // type = 1 = IFLA_BOND_SLAVE_STATE
+            0x00, // V = 0 (BOND_STATE_ACTIVE)
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // length = 5
+            0x02, 0x00, // type = 2 = IFLA_BOND_SLAVE_MII_STATUS
+            0x00, // V = 0 (BOND_LINK_UP)
+            0x00, 0x00, 0x00, // padding
+
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_BOND_SLAVE_LINK_FAILURE_COUNT
+            0x01, 0x00, 0x00, 0x00, // V = 1
+
+            0x0a, 0x00, // length = 10
+            0x04, 0x00, // type = 4 = IFLA_BOND_SLAVE_PERM_HWADDR
+            0x52, 0x54, 0x00, 0xab, 0xcd, 0xef, // V = 52:54:00:ab:cd:ef
+            0x00, 0x00, // padding
+
+            0x06, 0x00, // length = 6
+            0x05, 0x00, // type = 5 = IFLA_BOND_SLAVE_QUEUE_ID
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // padding
+    ];
+
+    #[test]
+    fn parse_info_bond_slave() {
+        let nla = NlaBuffer::new_checked(&BOND_SLAVE[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::SlaveKind(InfoSlaveKind::Bond),
+            Info::SlaveData(InfoSlaveData::Bond(vec![
+                InfoBondSlave::State(0),
+                InfoBondSlave::MiiStatus(0),
+                InfoBondSlave::LinkFailureCount(1),
+                InfoBondSlave::PermHwaddr(vec![0x52, 0x54, 0x00, 0xab, 0xcd, 0xef]),
+                InfoBondSlave::QueueId(0),
+            ])),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
     #[test]
     fn parse() {
         let nla = NlaBuffer::new_checked(&BRIDGE[..]).unwrap();
@@ -18,6 +18,12 @@ pub use self::link_infos::*;
 mod bond;
 pub use self::bond::*;
 
+mod bridge_port;
+pub use self::bridge_port::*;
+
+mod tunnel;
+pub use self::tunnel::*;
+
 mod prop_list;
 pub use self::prop_list::*;
 
@@ -143,8 +149,8 @@ pub enum Nla {
     NetnsId(i32),
     // custom
     OperState(State),
-    Stats(Vec<u8>),
-    Stats64(Vec<u8>),
+    Stats(Stats),
+    Stats64(Stats64),
     Map(Vec<u8>),
     // AF_SPEC (the type of af_spec depends on the interface family of the message)
     AfSpecInet(Vec<AfSpecInet>),
@@ -263,8 +269,6 @@ impl nlas::Nla for Nla {
                 | Broadcast(ref bytes)
                 | PermAddress(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | Stats(ref bytes)
-                | Stats64(ref bytes)
                 | Map(ref bytes)
                 | ProtoDownReason(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
@@ -310,6 +314,8 @@ impl nlas::Nla for Nla {
                 => NativeEndian::write_i32(buffer, *value),
 
             OperState(state) => buffer[0] = state.into(),
+            Stats(ref stats) => stats.emit(buffer),
+            Stats64(ref stats) => stats.emit(buffer),
             Info(ref nlas) => nlas.as_slice().emit(buffer),
             PropList(ref nlas) => nlas.as_slice().emit(buffer),
             AfSpecInet(ref nlas) => nlas.as_slice().emit(buffer),
@@ -492,8 +498,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
                     .into(),
             ),
             IFLA_MAP => Map(payload.to_vec()),
-            IFLA_STATS => Stats(payload.to_vec()),
-            IFLA_STATS64 => Stats64(payload.to_vec()),
+            IFLA_STATS => {
+                let err = "invalid IFLA_STATS value";
+                let payload = zero_extend(payload, LINK_STATS_MIN_LEN, LINK_STATS_LEN).context(err)?;
+                Stats(stats::Stats::parse(&StatsBuffer::new(&payload[..])).context(err)?)
+            }
+            IFLA_STATS64 => {
+                let err = "invalid IFLA_STATS64 value";
+                let payload =
+                    zero_extend(payload, LINK_STATS64_MIN_LEN, LINK_STATS64_LEN).context(err)?;
+                Stats64(stats64::Stats64::parse(&Stats64Buffer::new(&payload[..])).context(err)?)
+            }
             IFLA_AF_SPEC => match interface_family as u16 {
                 AF_INET | AF_INET6 | AF_UNSPEC => {
                     let mut nlas = vec![];
@@ -525,3 +540,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
         })
     }
 }
+
+// The link statistics structures grew over time. Older kernels send a
+// shorter payload, which is padded with zeros so that it can be parsed
+// with the current layout. Counters appended by newer kernels are
+// ignored.
+fn zero_extend(payload: &[u8], min_len: usize, len: usize) -> Result<Vec<u8>, DecodeError> {
+    if payload.len() < min_len {
+        return Err(format!("invalid payload: length {} < {}", payload.len(), min_len).into());
+    }
+    let mut buf = payload.to_vec();
+    if buf.len() < len {
+        buf.resize(len, 0);
+    }
+    Ok(buf)
+}
@@ -56,6 +56,9 @@ pub struct Stats {
 }
 
 pub const LINK_STATS_LEN: usize = 96;
+/// Length of `IFLA_STATS` on kernels older than 4.6, which do not
+/// report `rx_nohandler`.
+pub(crate) const LINK_STATS_MIN_LEN: usize = 92;
 
 buffer!(StatsBuffer(LINK_STATS_LEN) {
     rx_packets: (u32, 0..4),
@@ -6,6 +6,9 @@ use crate::{
 };
 
 pub const LINK_STATS64_LEN: usize = 192;
+/// Length of `IFLA_STATS64` on kernels older than 4.6, which do not
+/// report `rx_nohandler`.
+pub(crate) const LINK_STATS64_MIN_LEN: usize = 184;
 buffer!(Stats64Buffer(LINK_STATS64_LEN) {
     rx_packets: (u64, 0..8),
     tx_packets: (u64, 8..16),
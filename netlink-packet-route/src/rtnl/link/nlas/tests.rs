@@ -411,3 +411,80 @@ fn parse_af_inet6() {
     // Normally this is the end of the nla iterator
     assert!(nlas.next().is_none());
 }
+
+#[test]
+fn parse_stats() {
+    // IFLA_STATS header followed by the counters: rx_packets = 1, tx_packets = 2, the rest is 0
+    let mut bytes = vec![0u8; 4 + LINK_STATS_LEN];
+    bytes[..4].copy_from_slice(&[0x64, 0x00, 0x07, 0x00]);
+    bytes[4] = 1;
+    bytes[8] = 2;
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let stats = match parsed {
+        super::Nla::Stats(ref stats) => stats,
+        _ => panic!("expected Nla::Stats, got {:?}", parsed),
+    };
+    assert_eq!(stats.rx_packets, 1);
+    assert_eq!(stats.tx_packets, 2);
+    assert_eq!(stats.rx_bytes, 0);
+    assert_eq!(get_byte_buffer(&parsed), bytes);
+}
+
+#[test]
+fn parse_stats64() {
+    // Newer kernels append counters to `struct rtnl_link_stats64`, which must be ignored
+    let mut bytes = vec![0u8; 4 + LINK_STATS64_LEN + 8];
+    bytes[..4].copy_from_slice(&[0xcc, 0x00, 0x17, 0x00]);
+    bytes[4] = 1;
+    bytes[12] = 2;
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let stats = match parsed {
+        super::Nla::Stats64(ref stats) => stats,
+        _ => panic!("expected Nla::Stats64, got {:?}", parsed),
+    };
+    assert_eq!(stats.rx_packets, 1);
+    assert_eq!(stats.tx_packets, 2);
+    // The trailing counters are dropped when emitting
+    let mut expected = bytes[..4 + LINK_STATS64_LEN].to_vec();
+    expected[0] = 0xc4;
+    assert_eq!(get_byte_buffer(&parsed), expected);
+}
+
+#[test]
+fn parse_short_stats() {
+    // Kernels older than 4.6 do not send rx_nohandler, the last counter
+    let mut bytes = [0u8; 4 + LINK_STATS_LEN - 4];
+    bytes[..4].copy_from_slice(&[0x60, 0x00, 0x07, 0x00]);
+    bytes[4] = 1;
+    bytes[92] = 3; // tx_compressed
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let stats = match parsed {
+        super::Nla::Stats(ref stats) => stats,
+        _ => panic!("expected Nla::Stats, got {:?}", parsed),
+    };
+    assert_eq!(stats.rx_packets, 1);
+    assert_eq!(stats.tx_compressed, 3);
+    assert_eq!(stats.rx_nohandler, 0);
+
+    let mut bytes = [0u8; 4 + LINK_STATS64_LEN - 8];
+    bytes[..4].copy_from_slice(&[0xbc, 0x00, 0x17, 0x00]);
+    bytes[4] = 1;
+    bytes[180] = 3; // tx_compressed
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_UNSPEC).unwrap();
+    let stats = match parsed {
+        super::Nla::Stats64(ref stats) => stats,
+        _ => panic!("expected Nla::Stats64, got {:?}", parsed),
+    };
+    assert_eq!(stats.rx_packets, 1);
+    assert_eq!(stats.tx_compressed, 3);
+    assert_eq!(stats.rx_nohandler, 0);
+
+    // Anything shorter is still invalid
+    let bytes = [0x08, 0x00, 0x17, 0x00, 0x01, 0x00, 0x00, 0x00];
+    let buf = NlaBuffer::new_checked(&bytes[..]).unwrap();
+    assert!(super::Nla::parse_with_param(&buf, AF_UNSPEC).is_err());
+}
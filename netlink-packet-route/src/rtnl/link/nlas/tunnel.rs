@@ -0,0 +1,488 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_ipv6, parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+fn emit_ip(addr: &IpAddr, buffer: &mut [u8]) {
+    match addr {
+        IpAddr::V4(addr) => buffer.copy_from_slice(&addr.octets()),
+        IpAddr::V6(addr) => buffer.copy_from_slice(&addr.octets()),
+    }
+}
+
+fn ip_len(addr: &IpAddr) -> usize {
+    match addr {
+        IpAddr::V4(_) => 4,
+        IpAddr::V6(_) => 16,
+    }
+}
+
+/// Attributes of the `gre`, `gretap`, `ip6gre` and `ip6gretap` link kinds.
+///
+/// The GRE flags (`IFlags`/`OFlags`, see the `GRE_*` constants), keys, flow
+/// info and encapsulation ports are exposed in host byte order, and
+/// converted to network byte order on the wire.
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv4/ip_gre.c#L1393
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoGreTun {
+    Unspec(Vec<u8>),
+    Link(u32),
+    IFlags(u16),
+    OFlags(u16),
+    IKey(u32),
+    OKey(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    Ttl(u8),
+    Tos(u8),
+    PMtuDisc(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    Flags(u32),
+    EncapType(u16),
+    EncapFlags(u16),
+    EncapSport(u16),
+    EncapDport(u16),
+    CollectMetadata,
+    IgnoreDf(u8),
+    FwMark(u32),
+    ErspanIndex(u32),
+    ErspanVer(u8),
+    ErspanDir(u8),
+    ErspanHwId(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoGreTun {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoGreTun::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | PMtuDisc(_)
+                | EncapLimit(_)
+                | IgnoreDf(_)
+                | ErspanVer(_)
+                | ErspanDir(_)
+                => 1,
+            IFlags(_)
+                | OFlags(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSport(_)
+                | EncapDport(_)
+                | ErspanHwId(_)
+                => 2,
+            Link(_)
+                | IKey(_)
+                | OKey(_)
+                | FlowInfo(_)
+                | Flags(_)
+                | FwMark(_)
+                | ErspanIndex(_)
+                => 4,
+            Local(addr) | Remote(addr) => ip_len(addr),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoGreTun::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | PMtuDisc(value)
+                | EncapLimit(value)
+                | IgnoreDf(value)
+                | ErspanVer(value)
+                | ErspanDir(value)
+                => buffer[0] = *value,
+            EncapType(value)
+                | EncapFlags(value)
+                | ErspanHwId(value)
+                => NativeEndian::write_u16(buffer, *value),
+            IFlags(value)
+                | OFlags(value)
+                | EncapSport(value)
+                | EncapDport(value)
+                => BigEndian::write_u16(buffer, *value),
+            Link(value)
+                | Flags(value)
+                | FwMark(value)
+                | ErspanIndex(value)
+                => NativeEndian::write_u32(buffer, *value),
+            IKey(value)
+                | OKey(value)
+                | FlowInfo(value)
+                => BigEndian::write_u32(buffer, *value),
+            Local(addr) | Remote(addr) => emit_ip(addr, buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoGreTun::*;
+        match self {
+            Unspec(_) => IFLA_GRE_UNSPEC,
+            Link(_) => IFLA_GRE_LINK,
+            IFlags(_) => IFLA_GRE_IFLAGS,
+            OFlags(_) => IFLA_GRE_OFLAGS,
+            IKey(_) => IFLA_GRE_IKEY,
+            OKey(_) => IFLA_GRE_OKEY,
+            Local(_) => IFLA_GRE_LOCAL,
+            Remote(_) => IFLA_GRE_REMOTE,
+            Ttl(_) => IFLA_GRE_TTL,
+            Tos(_) => IFLA_GRE_TOS,
+            PMtuDisc(_) => IFLA_GRE_PMTUDISC,
+            EncapLimit(_) => IFLA_GRE_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_GRE_FLOWINFO,
+            Flags(_) => IFLA_GRE_FLAGS,
+            EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+            EncapSport(_) => IFLA_GRE_ENCAP_SPORT,
+            EncapDport(_) => IFLA_GRE_ENCAP_DPORT,
+            CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            FwMark(_) => IFLA_GRE_FWMARK,
+            ErspanIndex(_) => IFLA_GRE_ERSPAN_INDEX,
+            ErspanVer(_) => IFLA_GRE_ERSPAN_VER,
+            ErspanDir(_) => IFLA_GRE_ERSPAN_DIR,
+            ErspanHwId(_) => IFLA_GRE_ERSPAN_HWID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoGreTun::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_GRE_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GRE_LINK => Link(parse_u32(payload).context("invalid IFLA_GRE_LINK value")?),
+            IFLA_GRE_IFLAGS => {
+                IFlags(parse_u16_be(payload).context("invalid IFLA_GRE_IFLAGS value")?)
+            }
+            IFLA_GRE_OFLAGS => {
+                OFlags(parse_u16_be(payload).context("invalid IFLA_GRE_OFLAGS value")?)
+            }
+            IFLA_GRE_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_GRE_IKEY value")?),
+            IFLA_GRE_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_GRE_OKEY value")?),
+            IFLA_GRE_LOCAL => Local(parse_ip(payload).context("invalid IFLA_GRE_LOCAL value")?),
+            IFLA_GRE_REMOTE => Remote(parse_ip(payload).context("invalid IFLA_GRE_REMOTE value")?),
+            IFLA_GRE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GRE_TTL value")?),
+            IFLA_GRE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GRE_TOS value")?),
+            IFLA_GRE_PMTUDISC => {
+                PMtuDisc(parse_u8(payload).context("invalid IFLA_GRE_PMTUDISC value")?)
+            }
+            IFLA_GRE_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_GRE_ENCAP_LIMIT value")?)
+            }
+            IFLA_GRE_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_GRE_FLOWINFO value")?)
+            }
+            IFLA_GRE_FLAGS => Flags(parse_u32(payload).context("invalid IFLA_GRE_FLAGS value")?),
+            IFLA_GRE_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_TYPE value")?)
+            }
+            IFLA_GRE_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_FLAGS value")?)
+            }
+            IFLA_GRE_ENCAP_SPORT => {
+                EncapSport(parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_SPORT value")?)
+            }
+            IFLA_GRE_ENCAP_DPORT => {
+                EncapDport(parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_DPORT value")?)
+            }
+            IFLA_GRE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GRE_IGNORE_DF => {
+                IgnoreDf(parse_u8(payload).context("invalid IFLA_GRE_IGNORE_DF value")?)
+            }
+            IFLA_GRE_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_GRE_FWMARK value")?),
+            IFLA_GRE_ERSPAN_INDEX => {
+                ErspanIndex(parse_u32(payload).context("invalid IFLA_GRE_ERSPAN_INDEX value")?)
+            }
+            IFLA_GRE_ERSPAN_VER => {
+                ErspanVer(parse_u8(payload).context("invalid IFLA_GRE_ERSPAN_VER value")?)
+            }
+            IFLA_GRE_ERSPAN_DIR => {
+                ErspanDir(parse_u8(payload).context("invalid IFLA_GRE_ERSPAN_DIR value")?)
+            }
+            IFLA_GRE_ERSPAN_HWID => {
+                ErspanHwId(parse_u16(payload).context("invalid IFLA_GRE_ERSPAN_HWID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of the `ipip` and `sit` link kinds.
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv6/sit.c#L1742
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoIpTun {
+    Unspec(Vec<u8>),
+    Link(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    Ttl(u8),
+    Tos(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    Flags(u16),
+    Protocol(u8),
+    PMtuDisc(u8),
+    SixRdPrefix(Ipv6Addr),
+    SixRdRelayPrefix(Ipv4Addr),
+    SixRdPrefixLen(u16),
+    SixRdRelayPrefixLen(u16),
+    EncapType(u16),
+    EncapFlags(u16),
+    EncapSport(u16),
+    EncapDport(u16),
+    CollectMetadata,
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIpTun {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoIpTun::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | EncapLimit(_)
+                | Protocol(_)
+                | PMtuDisc(_)
+                => 1,
+            Flags(_)
+                | SixRdPrefixLen(_)
+                | SixRdRelayPrefixLen(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSport(_)
+                | EncapDport(_)
+                => 2,
+            Link(_)
+                | FlowInfo(_)
+                | SixRdRelayPrefix(_)
+                | FwMark(_)
+                => 4,
+            SixRdPrefix(_) => 16,
+            Local(addr) | Remote(addr) => ip_len(addr),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoIpTun::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | EncapLimit(value)
+                | Protocol(value)
+                | PMtuDisc(value)
+                => buffer[0] = *value,
+            Flags(value)
+                | SixRdPrefixLen(value)
+                | SixRdRelayPrefixLen(value)
+                | EncapType(value)
+                | EncapFlags(value)
+                => NativeEndian::write_u16(buffer, *value),
+            EncapSport(value)
+                | EncapDport(value)
+                => BigEndian::write_u16(buffer, *value),
+            Link(value)
+                | FwMark(value)
+                => NativeEndian::write_u32(buffer, *value),
+            FlowInfo(value) => BigEndian::write_u32(buffer, *value),
+            SixRdPrefix(addr) => buffer.copy_from_slice(&addr.octets()),
+            SixRdRelayPrefix(addr) => buffer.copy_from_slice(&addr.octets()),
+            Local(addr) | Remote(addr) => emit_ip(addr, buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoIpTun::*;
+        match self {
+            Unspec(_) => IFLA_IPTUN_UNSPEC,
+            Link(_) => IFLA_IPTUN_LINK,
+            Local(_) => IFLA_IPTUN_LOCAL,
+            Remote(_) => IFLA_IPTUN_REMOTE,
+            Ttl(_) => IFLA_IPTUN_TTL,
+            Tos(_) => IFLA_IPTUN_TOS,
+            EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            Flags(_) => IFLA_IPTUN_FLAGS,
+            Protocol(_) => IFLA_IPTUN_PROTO,
+            PMtuDisc(_) => IFLA_IPTUN_PMTUDISC,
+            SixRdPrefix(_) => IFLA_IPTUN_6RD_PREFIX,
+            SixRdRelayPrefix(_) => IFLA_IPTUN_6RD_RELAY_PREFIX,
+            SixRdPrefixLen(_) => IFLA_IPTUN_6RD_PREFIXLEN,
+            SixRdRelayPrefixLen(_) => IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+            EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            EncapSport(_) => IFLA_IPTUN_ENCAP_SPORT,
+            EncapDport(_) => IFLA_IPTUN_ENCAP_DPORT,
+            CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            FwMark(_) => IFLA_IPTUN_FWMARK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpTun {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoIpTun::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPTUN_LINK => Link(parse_u32(payload).context("invalid IFLA_IPTUN_LINK value")?),
+            IFLA_IPTUN_LOCAL => Local(parse_ip(payload).context("invalid IFLA_IPTUN_LOCAL value")?),
+            IFLA_IPTUN_REMOTE => {
+                Remote(parse_ip(payload).context("invalid IFLA_IPTUN_REMOTE value")?)
+            }
+            IFLA_IPTUN_TTL => Ttl(parse_u8(payload).context("invalid IFLA_IPTUN_TTL value")?),
+            IFLA_IPTUN_TOS => Tos(parse_u8(payload).context("invalid IFLA_IPTUN_TOS value")?),
+            IFLA_IPTUN_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_IPTUN_ENCAP_LIMIT value")?)
+            }
+            IFLA_IPTUN_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_IPTUN_FLOWINFO value")?)
+            }
+            IFLA_IPTUN_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_IPTUN_FLAGS value")?)
+            }
+            IFLA_IPTUN_PROTO => {
+                Protocol(parse_u8(payload).context("invalid IFLA_IPTUN_PROTO value")?)
+            }
+            IFLA_IPTUN_PMTUDISC => {
+                PMtuDisc(parse_u8(payload).context("invalid IFLA_IPTUN_PMTUDISC value")?)
+            }
+            IFLA_IPTUN_6RD_PREFIX => SixRdPrefix(Ipv6Addr::from(
+                parse_ipv6(payload).context("invalid IFLA_IPTUN_6RD_PREFIX value")?,
+            )),
+            IFLA_IPTUN_6RD_RELAY_PREFIX => {
+                if payload.len() != 4 {
+                    return Err("invalid IFLA_IPTUN_6RD_RELAY_PREFIX value".into());
+                }
+                SixRdRelayPrefix(Ipv4Addr::new(
+                    payload[0], payload[1], payload[2], payload[3],
+                ))
+            }
+            IFLA_IPTUN_6RD_PREFIXLEN => SixRdPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_PREFIXLEN value")?,
+            ),
+            IFLA_IPTUN_6RD_RELAY_PREFIXLEN => SixRdRelayPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_RELAY_PREFIXLEN value")?,
+            ),
+            IFLA_IPTUN_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_TYPE value")?)
+            }
+            IFLA_IPTUN_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_FLAGS value")?)
+            }
+            IFLA_IPTUN_ENCAP_SPORT => {
+                EncapSport(parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_SPORT value")?)
+            }
+            IFLA_IPTUN_ENCAP_DPORT => {
+                EncapDport(parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_DPORT value")?)
+            }
+            IFLA_IPTUN_COLLECT_METADATA => CollectMetadata,
+            IFLA_IPTUN_FWMARK => {
+                FwMark(parse_u32(payload).context("invalid IFLA_IPTUN_FWMARK value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of the `vti` link kind. The keys are exposed in host byte
+/// order.
+// https://elixir.bootlin.com/linux/v5.9.8/source/net/ipv4/ip_vti.c#L580
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoVti {
+    Unspec(Vec<u8>),
+    Link(u32),
+    IKey(u32),
+    OKey(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoVti {
+    fn value_len(&self) -> usize {
+        use self::InfoVti::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Link(_) | IKey(_) | OKey(_) | FwMark(_) => 4,
+            Local(addr) | Remote(addr) => ip_len(addr),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoVti::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Link(value) | FwMark(value) => NativeEndian::write_u32(buffer, *value),
+            IKey(value) | OKey(value) => BigEndian::write_u32(buffer, *value),
+            Local(addr) | Remote(addr) => emit_ip(addr, buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoVti::*;
+        match self {
+            Unspec(_) => IFLA_VTI_UNSPEC,
+            Link(_) => IFLA_VTI_LINK,
+            IKey(_) => IFLA_VTI_IKEY,
+            OKey(_) => IFLA_VTI_OKEY,
+            Local(_) => IFLA_VTI_LOCAL,
+            Remote(_) => IFLA_VTI_REMOTE,
+            FwMark(_) => IFLA_VTI_FWMARK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVti {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoVti::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VTI_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VTI_LINK => Link(parse_u32(payload).context("invalid IFLA_VTI_LINK value")?),
+            IFLA_VTI_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_VTI_IKEY value")?),
+            IFLA_VTI_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_VTI_OKEY value")?),
+            IFLA_VTI_LOCAL => Local(parse_ip(payload).context("invalid IFLA_VTI_LOCAL value")?),
+            IFLA_VTI_REMOTE => Remote(parse_ip(payload).context("invalid IFLA_VTI_REMOTE value")?),
+            IFLA_VTI_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_VTI_FWMARK value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
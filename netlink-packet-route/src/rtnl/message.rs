@@ -4,6 +4,7 @@ use crate::{
     constants::*,
     traits::{Emitable, ParseableParametrized},
     AddressMessage,
+    BridgeVlanMessage,
     DecodeError,
     LinkMessage,
     NeighbourMessage,
@@ -12,10 +13,13 @@ use crate::{
     NetlinkHeader,
     NetlinkPayload,
     NetlinkSerializable,
+    NexthopMessage,
     NsidMessage,
     RouteMessage,
     RtnlMessageBuffer,
     RuleMessage,
+    StatsMessage,
+    TcActionMessage,
     TcMessage,
 };
 
@@ -51,12 +55,23 @@ pub enum RtnlMessage {
     NewTrafficChain(TcMessage),
     DelTrafficChain(TcMessage),
     GetTrafficChain(TcMessage),
+    NewTrafficAction(TcActionMessage),
+    DelTrafficAction(TcActionMessage),
+    GetTrafficAction(TcActionMessage),
     NewNsId(NsidMessage),
     DelNsId(NsidMessage),
     GetNsId(NsidMessage),
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    NewBridgeVlan(BridgeVlanMessage),
+    DelBridgeVlan(BridgeVlanMessage),
+    GetBridgeVlan(BridgeVlanMessage),
 }
 
 impl RtnlMessage {
@@ -172,6 +187,18 @@ impl RtnlMessage {
         matches!(self, RtnlMessage::GetTrafficChain(_))
     }
 
+    pub fn is_new_action(&self) -> bool {
+        matches!(self, RtnlMessage::NewTrafficAction(_))
+    }
+
+    pub fn is_del_action(&self) -> bool {
+        matches!(self, RtnlMessage::DelTrafficAction(_))
+    }
+
+    pub fn is_get_action(&self) -> bool {
+        matches!(self, RtnlMessage::GetTrafficAction(_))
+    }
+
     pub fn is_new_nsid(&self) -> bool {
         matches!(self, RtnlMessage::NewNsId(_))
     }
@@ -196,6 +223,38 @@ impl RtnlMessage {
         matches!(self, RtnlMessage::DelRule(_))
     }
 
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RtnlMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RtnlMessage::GetStats(_))
+    }
+
+    pub fn is_new_bridge_vlan(&self) -> bool {
+        matches!(self, RtnlMessage::NewBridgeVlan(_))
+    }
+
+    pub fn is_del_bridge_vlan(&self) -> bool {
+        matches!(self, RtnlMessage::DelBridgeVlan(_))
+    }
+
+    pub fn is_get_bridge_vlan(&self) -> bool {
+        matches!(self, RtnlMessage::GetBridgeVlan(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RtnlMessage::*;
 
@@ -230,12 +289,23 @@ impl RtnlMessage {
             NewTrafficChain(_) => RTM_NEWCHAIN,
             DelTrafficChain(_) => RTM_DELCHAIN,
             GetTrafficChain(_) => RTM_GETCHAIN,
+            NewTrafficAction(_) => RTM_NEWACTION,
+            DelTrafficAction(_) => RTM_DELACTION,
+            GetTrafficAction(_) => RTM_GETACTION,
             GetNsId(_) => RTM_GETNSID,
             NewNsId(_) => RTM_NEWNSID,
             DelNsId(_) => RTM_DELNSID,
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            NewBridgeVlan(_) => RTM_NEWVLAN,
+            DelBridgeVlan(_) => RTM_DELVLAN,
+            GetBridgeVlan(_) => RTM_GETVLAN,
         }
     }
 }
@@ -287,6 +357,11 @@ impl Emitable for RtnlMessage {
             | GetTrafficChain(ref msg)
             => msg.buffer_len(),
 
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.buffer_len(),
+
             | NewNsId(ref msg)
             | DelNsId(ref msg)
             | GetNsId(ref msg)
@@ -295,6 +370,20 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.buffer_len(),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
             => msg.buffer_len()
         }
     }
@@ -345,6 +434,11 @@ impl Emitable for RtnlMessage {
             | GetTrafficChain(ref msg)
             => msg.emit(buffer),
 
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.emit(buffer),
+
             | NewNsId(ref msg)
             | DelNsId(ref msg)
             | GetNsId(ref msg)
@@ -353,6 +447,20 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
+            => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.emit(buffer),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
             => msg.emit(buffer)
         }
     }
@@ -3,6 +3,14 @@
 pub mod address;
 pub use address::{AddressHeader, AddressMessage, AddressMessageBuffer, ADDRESS_HEADER_LEN};
 
+pub mod bridge_vlan;
+pub use bridge_vlan::{
+    BridgeVlanHeader,
+    BridgeVlanMessage,
+    BridgeVlanMessageBuffer,
+    BRIDGE_VLAN_HEADER_LEN,
+};
+
 pub mod link;
 pub use link::{LinkHeader, LinkMessage, LinkMessageBuffer, LINK_HEADER_LEN};
 
@@ -22,6 +30,9 @@ pub use neighbour_table::{
     NEIGHBOUR_TABLE_HEADER_LEN,
 };
 
+pub mod nexthop;
+pub use nexthop::{NexthopHeader, NexthopMessage, NexthopMessageBuffer, NEXTHOP_HEADER_LEN};
+
 pub mod nsid;
 pub use nsid::{NsidHeader, NsidMessage, NsidMessageBuffer, NSID_HEADER_LEN};
 
@@ -31,8 +42,20 @@ pub use route::{RouteFlags, RouteHeader, RouteMessage, RouteMessageBuffer, ROUTE
 pub mod rule;
 pub use rule::{RuleHeader, RuleMessage, RuleMessageBuffer, RULE_HEADER_LEN};
 
+pub mod stats;
+pub use stats::{StatsHeader, StatsMessage, StatsMessageBuffer, STATS_HEADER_LEN};
+
 pub mod tc;
-pub use tc::{TcHeader, TcMessage, TcMessageBuffer, TC_HEADER_LEN};
+pub use tc::{
+    TcActionHeader,
+    TcActionMessage,
+    TcActionMessageBuffer,
+    TcHeader,
+    TcMessage,
+    TcMessageBuffer,
+    TC_ACTION_HEADER_LEN,
+    TC_HEADER_LEN,
+};
 
 pub mod constants;
 pub use self::constants::*;
@@ -46,12 +69,15 @@ pub use self::message::*;
 pub mod nlas {
     pub use super::{
         address::nlas as address,
+        bridge_vlan::nlas as bridge_vlan,
         link::nlas as link,
         neighbour::nlas as neighbour,
         neighbour_table::nlas as neighbour_table,
+        nexthop::nlas as nexthop,
         nsid::nlas as nsid,
         route::nlas as route,
         rule::nlas as rule,
+        stats::nlas as stats,
         tc::nlas as tc,
     };
     pub use crate::utils::nla::*;
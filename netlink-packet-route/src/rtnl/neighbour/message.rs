@@ -53,7 +53,8 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<NeighbourMessageBuffer<&'a T>> for Vec<N
 mod test {
     use crate::{
         constants::*,
-        traits::Emitable,
+        neighbour::Nla,
+        traits::{Emitable, Parseable},
         NeighbourHeader,
         NeighbourMessage,
         NeighbourMessageBuffer,
@@ -119,4 +120,90 @@ mod test {
         assert_eq!(packet.buffer_len(), 12);
         packet.emit(&mut buf[..]);
     }
+
+    // bridge fdb append 00:00:00:00:00:00 dev vxlan0 dst 10.0.0.10 port 4790 vni 200 via eth0
+    #[rustfmt::skip]
+    static VXLAN_FDB_MSG: [u8; 76] = [
+        0x07, 0x00, 0x00, 0x00, // family AF_BRIDGE
+        0x0d, 0x00, 0x00, 0x00, // interface index = 13
+        0xc0, 0x00, // state NUD_NOARP | NUD_PERMANENT
+        0x02, // flags NTF_SELF
+        0x01, // ntype
+        0x0a, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // NDA_LLADDR
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x0a, // NDA_DST 10.0.0.10
+        0x06, 0x00, 0x06, 0x00, 0x12, 0xb6, 0x00, 0x00, // NDA_PORT 4790
+        0x08, 0x00, 0x07, 0x00, 0xc8, 0x00, 0x00, 0x00, // NDA_VNI 200
+        0x08, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, // NDA_IFINDEX 6
+        0x14, 0x00, 0x03, 0x00, // NDA_CACHEINFO
+            0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+            0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn vxlan_fdb_entry_read_and_emit() {
+        let msg =
+            NeighbourMessage::parse(&NeighbourMessageBuffer::new(&&VXLAN_FDB_MSG[..])).unwrap();
+        assert_eq!(
+            msg.header,
+            NeighbourHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 13,
+                state: NUD_NOARP | NUD_PERMANENT,
+                flags: NTF_SELF,
+                ntype: 1,
+            }
+        );
+        assert_eq!(
+            msg.nlas[..5],
+            [
+                Nla::LinkLocalAddress(vec![0; 6]),
+                Nla::Destination(vec![10, 0, 0, 10]),
+                Nla::Port(4790),
+                Nla::Vni(200),
+                Nla::IfIndex(6),
+            ]
+        );
+
+        let mut buf = vec![0; VXLAN_FDB_MSG.len()];
+        assert_eq!(msg.buffer_len(), VXLAN_FDB_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, VXLAN_FDB_MSG);
+    }
+
+    // bridge fdb add 02:00:00:00:00:12 dev eth0 master static sticky
+    #[rustfmt::skip]
+    static BRIDGE_FDB_MSG: [u8; 60] = [
+        0x07, 0x00, 0x00, 0x00, // family AF_BRIDGE
+        0x0f, 0x00, 0x00, 0x00, // interface index = 15
+        0x40, 0x00, // state NUD_NOARP
+        0x40, // flags NTF_STICKY
+        0x00, // ntype
+        0x0a, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, // NDA_LLADDR
+        0x08, 0x00, 0x09, 0x00, 0x0c, 0x00, 0x00, 0x00, // NDA_MASTER 12
+        0x08, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, // NDA_FLAGS_EXT
+        0x14, 0x00, 0x03, 0x00, // NDA_CACHEINFO
+            0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+            0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn bridge_fdb_entry_read_and_emit() {
+        let msg =
+            NeighbourMessage::parse(&NeighbourMessageBuffer::new(&&BRIDGE_FDB_MSG[..])).unwrap();
+        assert_eq!(msg.header.state, NUD_NOARP);
+        assert_eq!(msg.header.flags, NTF_STICKY);
+        assert_eq!(
+            msg.nlas[..3],
+            [
+                Nla::LinkLocalAddress(vec![2, 0, 0, 0, 0, 0x12]),
+                Nla::Master(12),
+                Nla::FlagsExt(0),
+            ]
+        );
+
+        let mut buf = vec![0; BRIDGE_FDB_MSG.len()];
+        assert_eq!(msg.buffer_len(), BRIDGE_FDB_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, BRIDGE_FDB_MSG);
+    }
 }
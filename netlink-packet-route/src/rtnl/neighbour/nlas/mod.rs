@@ -4,12 +4,12 @@ mod cache_info;
 pub use self::cache_info::*;
 
 use anyhow::Context;
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 
 use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_u16, parse_u32},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
     traits::Parseable,
     DecodeError,
 };
@@ -22,12 +22,21 @@ pub enum Nla {
     CacheInfo(Vec<u8>),
     Probes(Vec<u8>),
     Vlan(u16),
-    Port(Vec<u8>),
+    /// UDP destination port of a VXLAN forwarding database entry
+    Port(u16),
     Vni(u32),
     IfIndex(u32),
-    Master(Vec<u8>),
+    /// Index of the master device, such as the bridge of a forwarding
+    /// database entry
+    Master(u32),
     LinkNetNsId(Vec<u8>),
     SourceVni(u32),
+    /// Protocol which installed the entry, one of the `RTPROT_*` constants
+    Protocol(u8),
+    /// Nexthop group of a forwarding database entry
+    NhId(u32),
+    /// Extended flags, a combination of the `NTF_EXT_*` constants
+    FlagsExt(u32),
     Other(DefaultNla),
 }
 
@@ -40,14 +49,17 @@ impl nlas::Nla for Nla {
             | Destination(ref bytes)
             | LinkLocalAddress(ref bytes)
             | Probes(ref bytes)
-            | Port(ref bytes)
-            | Master(ref bytes)
             | CacheInfo(ref bytes)
             | LinkNetNsId(ref bytes) => bytes.len(),
-            Vlan(_) => 2,
+            Protocol(_) => 1,
+            Vlan(_)
+            | Port(_) => 2,
             Vni(_)
             | IfIndex(_)
-            | SourceVni(_) => 4,
+            | Master(_)
+            | SourceVni(_)
+            | NhId(_)
+            | FlagsExt(_) => 4,
             Other(ref attr) => attr.value_len(),
         }
     }
@@ -60,14 +72,17 @@ impl nlas::Nla for Nla {
             | Destination(ref bytes)
             | LinkLocalAddress(ref bytes)
             | Probes(ref bytes)
-            | Port(ref bytes)
-            | Master(ref bytes)
             | CacheInfo(ref bytes)
             | LinkNetNsId(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Protocol(value) => buffer[0] = value,
             Vlan(ref value) => NativeEndian::write_u16(buffer, *value),
+            Port(ref value) => BigEndian::write_u16(buffer, *value),
             Vni(ref value)
             | IfIndex(ref value)
-            | SourceVni(ref value) => NativeEndian::write_u32(buffer, *value),
+            | Master(ref value)
+            | SourceVni(ref value)
+            | NhId(ref value)
+            | FlagsExt(ref value) => NativeEndian::write_u32(buffer, *value),
             Other(ref attr) => attr.emit_value(buffer),
         }
     }
@@ -87,6 +102,9 @@ impl nlas::Nla for Nla {
             Master(_) => NDA_MASTER,
             LinkNetNsId(_) => NDA_LINK_NETNSID,
             SourceVni(_) => NDA_SRC_VNI,
+            Protocol(_) => NDA_PROTOCOL,
+            NhId(_) => NDA_NH_ID,
+            FlagsExt(_) => NDA_FLAGS_EXT,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -104,12 +122,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             NDA_CACHEINFO => CacheInfo(payload.to_vec()),
             NDA_PROBES => Probes(payload.to_vec()),
             NDA_VLAN => Vlan(parse_u16(payload)?),
-            NDA_PORT => Port(payload.to_vec()),
+            NDA_PORT => Port(parse_u16_be(payload).context("invalid NDA_PORT value")?),
             NDA_VNI => Vni(parse_u32(payload)?),
             NDA_IFINDEX => IfIndex(parse_u32(payload)?),
-            NDA_MASTER => Master(payload.to_vec()),
+            NDA_MASTER => Master(parse_u32(payload).context("invalid NDA_MASTER value")?),
             NDA_LINK_NETNSID => LinkNetNsId(payload.to_vec()),
             NDA_SRC_VNI => SourceVni(parse_u32(payload)?),
+            NDA_PROTOCOL => Protocol(parse_u8(payload).context("invalid NDA_PROTOCOL value")?),
+            NDA_NH_ID => NhId(parse_u32(payload).context("invalid NDA_NH_ID value")?),
+            NDA_FLAGS_EXT => FlagsExt(parse_u32(payload).context("invalid NDA_FLAGS_EXT value")?),
             _ => Other(DefaultNla::parse(buf).context("invalid link NLA value (unknown type)")?),
         })
     }
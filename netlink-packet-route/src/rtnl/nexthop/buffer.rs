@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const NEXTHOP_HEADER_LEN: usize = 8;
+
+buffer!(NexthopMessageBuffer(NEXTHOP_HEADER_LEN) {
+    family: (u8, 0),
+    scope: (u8, 1),
+    protocol: (u8, 2),
+    reserved: (u8, 3),
+    flags: (u32, 4..NEXTHOP_HEADER_LEN),
+    payload: (slice, NEXTHOP_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NexthopMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
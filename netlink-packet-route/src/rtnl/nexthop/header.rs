@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use super::{buffer::NexthopMessageBuffer, NEXTHOP_HEADER_LEN};
+use crate::{
+    utils::{Emitable, Parseable},
+    DecodeError,
+};
+
+// see https://github.com/torvalds/linux/blob/master/include/uapi/linux/nexthop.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NexthopHeader {
+    /// Address family: one of the `AF_*` constants. `AF_UNSPEC` for groups and blackhole
+    /// nexthops.
+    pub family: u8,
+    /// Scope of the nexthop: one of the `RT_SCOPE_*` constants. The kernel ignores it on
+    /// requests and always reports `RT_SCOPE_LINK` or `RT_SCOPE_HOST`.
+    pub scope: u8,
+    /// Protocol which installed the nexthop: one of the `RTPROT_*` constants.
+    pub protocol: u8,
+    /// Nexthop flags: a combination of the `RTNH_F_*` constants.
+    pub flags: u32,
+}
+
+impl Emitable for NexthopHeader {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NexthopMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_scope(self.scope);
+        packet.set_protocol(self.protocol);
+        packet.set_reserved(0);
+        packet.set_flags(self.flags);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NexthopMessageBuffer<&'a T>> for NexthopHeader {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(NexthopHeader {
+            family: buf.family(),
+            scope: buf.scope(),
+            protocol: buf.protocol(),
+            flags: buf.flags(),
+        })
+    }
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+
+use super::{buffer::NexthopMessageBuffer, header::NexthopHeader, nlas::Nla};
+use crate::{
+    utils::{Emitable, Parseable},
+    DecodeError,
+};
+use anyhow::Context;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NexthopMessage {
+    pub header: NexthopHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl Emitable for NexthopMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>> for NexthopMessage {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = NexthopHeader::parse(buf).context("failed to parse nexthop message header")?;
+        let nlas = Vec::<Nla>::parse(buf).context("failed to parse nexthop message NLAs")?;
+        Ok(NexthopMessage { header, nlas })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::nexthop::{NexthopGroupEntry, Nla, ResilientGroup},
+        traits::{Emitable, ParseableParametrized},
+        NexthopHeader,
+        NexthopMessage,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        NEXTHOP_GRP_TYPE_RES,
+        RTM_NEWNEXTHOP,
+        RTPROT_STATIC,
+    };
+
+    // Reply to `ip nexthop get id 8`, for a group created with
+    // `ip nexthop add id 8 group 1,3/2 type resilient buckets 8`
+    #[rustfmt::skip]
+    static RESILIENT_GROUP: [u8; 84] = [
+        0x00, // family
+        0x00, // scope
+        0x04, // protocol = RTPROT_STATIC
+        0x00, // reserved
+        0x00, 0x00, 0x00, 0x00, // flags
+        // NLA: NHA_ID
+        0x08, 0x00, 0x01, 0x00,
+        0x08, 0x00, 0x00, 0x00, // 8
+        // NLA: NHA_GROUP_TYPE
+        0x06, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, // NEXTHOP_GRP_TYPE_RES + padding
+        // NLA: NHA_GROUP
+        0x14, 0x00, 0x02, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // id 1, weight 3
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // id 2, weight 1
+        // NLA: NHA_RES_GROUP (nested)
+        0x28, 0x00, 0x0c, 0x80,
+        0x06, 0x00, 0x01, 0x00, // NHA_RES_GROUP_BUCKETS
+        0x08, 0x00, 0x00, 0x00, // 8 + padding
+        0x08, 0x00, 0x02, 0x00, // NHA_RES_GROUP_IDLE_TIMER
+        0xe0, 0x2e, 0x00, 0x00, // 12000
+        0x08, 0x00, 0x03, 0x00, // NHA_RES_GROUP_UNBALANCED_TIMER
+        0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, // NHA_RES_GROUP_UNBALANCED_TIME
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    fn resilient_group() -> NexthopMessage {
+        NexthopMessage {
+            header: NexthopHeader {
+                family: 0,
+                scope: 0,
+                protocol: RTPROT_STATIC,
+                flags: 0,
+            },
+            nlas: vec![
+                Nla::Id(8),
+                Nla::GroupType(NEXTHOP_GRP_TYPE_RES),
+                Nla::Group(vec![
+                    NexthopGroupEntry { id: 1, weight: 2 },
+                    NexthopGroupEntry { id: 2, weight: 0 },
+                ]),
+                Nla::ResGroup(vec![
+                    ResilientGroup::Buckets(8),
+                    ResilientGroup::IdleTimer(12000),
+                    ResilientGroup::UnbalancedTimer(0),
+                    ResilientGroup::UnbalancedTime(0),
+                ]),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_resilient_group() {
+        let actual = RtnlMessage::parse_with_param(
+            &RtnlMessageBuffer::new(&&RESILIENT_GROUP[..]),
+            RTM_NEWNEXTHOP,
+        )
+        .unwrap();
+        assert_eq!(actual, RtnlMessage::NewNexthop(resilient_group()));
+    }
+
+    #[test]
+    fn emit_resilient_group() {
+        let msg = resilient_group();
+        assert_eq!(msg.buffer_len(), RESILIENT_GROUP.len());
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &RESILIENT_GROUP[..]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn emit_blackhole() {
+        let msg = NexthopMessage {
+            header: NexthopHeader {
+                family: 2,
+                ..Default::default()
+            },
+            nlas: vec![Nla::Id(7), Nla::Blackhole],
+        };
+        let expected = [
+            0x02, 0x00, 0x00, 0x00, // family = AF_INET
+            0x00, 0x00, 0x00, 0x00, // flags
+            0x08, 0x00, 0x01, 0x00, // NHA_ID
+            0x07, 0x00, 0x00, 0x00,
+            0x04, 0x00, 0x04, 0x00, // NHA_BLACKHOLE
+        ];
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &expected[..]);
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+pub mod buffer;
+pub mod header;
+pub mod message;
+pub mod nlas;
+
+pub use buffer::*;
+pub use header::*;
+pub use message::*;
+pub use nlas::*;
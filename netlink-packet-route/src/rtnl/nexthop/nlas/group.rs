@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// A member of a nexthop group (`struct nexthop_grp`).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct NexthopGroupEntry {
+    /// Identifier of the member nexthop.
+    pub id: u32,
+    /// Weight of the member, minus one: the kernel reports a weight of `1` as `0`.
+    pub weight: u8,
+}
+
+pub const NEXTHOP_GROUP_ENTRY_LEN: usize = 8;
+
+buffer!(NexthopGroupEntryBuffer(NEXTHOP_GROUP_ENTRY_LEN) {
+    id: (u32, 0..4),
+    weight: (u8, 4),
+    reserved_1: (u8, 5),
+    reserved_2: (u16, 6..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<NexthopGroupEntryBuffer<T>> for NexthopGroupEntry {
+    fn parse(buf: &NexthopGroupEntryBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            id: buf.id(),
+            weight: buf.weight(),
+        })
+    }
+}
+
+impl Emitable for NexthopGroupEntry {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_GROUP_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopGroupEntryBuffer::new(buffer);
+        buffer.set_id(self.id);
+        buffer.set_weight(self.weight);
+        buffer.set_reserved_1(0);
+        buffer.set_reserved_2(0);
+    }
+}
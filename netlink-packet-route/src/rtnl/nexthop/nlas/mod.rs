@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+
+mod group;
+pub use self::group::*;
+
+mod resilient;
+pub use self::resilient::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Netlink attributes for `RTM_NEWNEXTHOP`, `RTM_DELNEXTHOP`,
+/// `RTM_GETNEXTHOP` messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Nexthop identifier. Allocated by the kernel if omitted when creating a nexthop.
+    Id(u32),
+    /// Members of a nexthop group
+    Group(Vec<NexthopGroupEntry>),
+    /// Type of a nexthop group: `NEXTHOP_GRP_TYPE_MPATH` or `NEXTHOP_GRP_TYPE_RES`
+    GroupType(u16),
+    /// Blackhole nexthop. Only valid without `Oif` and `Gateway`.
+    Blackhole,
+    /// Output interface index
+    Oif(u32),
+    /// Gateway address, 4 or 16 bytes depending on the address family
+    Gateway(Vec<u8>),
+    /// Lightweight tunnel encapsulation type: one of the `LWTUNNEL_ENCAP_*` constants
+    EncapType(u16),
+    Encap(Vec<u8>),
+    /// Only dump nexthop groups
+    Groups,
+    /// Only dump nexthops enslaved to the given master device
+    Master(u32),
+    /// FDB nexthop or group, for use by vxlan devices
+    Fdb,
+    /// Parameters of a resilient nexthop group
+    ResGroup(Vec<ResilientGroup>),
+    /// A hash bucket of a resilient nexthop group
+    ResBucket(Vec<ResilientBucket>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(ref bytes)
+                | Gateway(ref bytes)
+                | Encap(ref bytes)
+                => bytes.len(),
+            Group(ref entries) => entries.iter().map(|e| e.buffer_len()).sum(),
+            ResGroup(ref nlas) => nlas.as_slice().buffer_len(),
+            ResBucket(ref nlas) => nlas.as_slice().buffer_len(),
+            Blackhole
+                | Groups
+                | Fdb
+                => 0,
+            GroupType(_)
+                | EncapType(_)
+                => 2,
+            Id(_)
+                | Oif(_)
+                | Master(_)
+                => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(ref bytes)
+                | Gateway(ref bytes)
+                | Encap(ref bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Group(ref entries) => {
+                let mut offset = 0;
+                for entry in entries {
+                    let len = entry.buffer_len();
+                    entry.emit(&mut buffer[offset..offset + len]);
+                    offset += len;
+                }
+            }
+            ResGroup(ref nlas) => nlas.as_slice().emit(buffer),
+            ResBucket(ref nlas) => nlas.as_slice().emit(buffer),
+            Blackhole
+                | Groups
+                | Fdb
+                => {}
+            GroupType(value)
+                | EncapType(value)
+                => NativeEndian::write_u16(buffer, *value),
+            Id(value)
+                | Oif(value)
+                | Master(value)
+                => NativeEndian::write_u32(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => NHA_UNSPEC,
+            Id(_) => NHA_ID,
+            Group(_) => NHA_GROUP,
+            GroupType(_) => NHA_GROUP_TYPE,
+            Blackhole => NHA_BLACKHOLE,
+            Oif(_) => NHA_OIF,
+            Gateway(_) => NHA_GATEWAY,
+            EncapType(_) => NHA_ENCAP_TYPE,
+            Encap(_) => NHA_ENCAP,
+            Groups => NHA_GROUPS,
+            Master(_) => NHA_MASTER,
+            Fdb => NHA_FDB,
+            ResGroup(_) => NHA_RES_GROUP,
+            ResBucket(_) => NHA_RES_BUCKET,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        use self::Nla::*;
+        matches!(self, Encap(_) | ResGroup(_) | ResBucket(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_UNSPEC => Unspec(payload.to_vec()),
+            NHA_ID => Id(parse_u32(payload).context("invalid NHA_ID value")?),
+            NHA_GROUP => {
+                let err = "invalid NHA_GROUP value";
+                let mut entries = vec![];
+                for chunk in payload.chunks(NEXTHOP_GROUP_ENTRY_LEN) {
+                    let buf = NexthopGroupEntryBuffer::new_checked(chunk).context(err)?;
+                    entries.push(NexthopGroupEntry::parse(&buf).context(err)?);
+                }
+                Group(entries)
+            }
+            NHA_GROUP_TYPE => {
+                GroupType(parse_u16(payload).context("invalid NHA_GROUP_TYPE value")?)
+            }
+            NHA_BLACKHOLE => Blackhole,
+            NHA_OIF => Oif(parse_u32(payload).context("invalid NHA_OIF value")?),
+            NHA_GATEWAY => Gateway(payload.to_vec()),
+            NHA_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid NHA_ENCAP_TYPE value")?)
+            }
+            NHA_ENCAP => Encap(payload.to_vec()),
+            NHA_GROUPS => Groups,
+            NHA_MASTER => Master(parse_u32(payload).context("invalid NHA_MASTER value")?),
+            NHA_FDB => Fdb,
+            NHA_RES_GROUP => {
+                let err = "invalid NHA_RES_GROUP value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(ResilientGroup::parse(&nla).context(err)?);
+                }
+                ResGroup(nlas)
+            }
+            NHA_RES_BUCKET => {
+                let err = "invalid NHA_RES_BUCKET value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(ResilientBucket::parse(&nla).context(err)?);
+                }
+                ResBucket(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Parameters of a resilient nexthop group, nested in `NHA_RES_GROUP`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ResilientGroup {
+    Pad(Vec<u8>),
+    /// Number of hash buckets
+    Buckets(u16),
+    /// Idle timer, in clock_t
+    IdleTimer(u32),
+    /// Unbalanced timer, in clock_t
+    UnbalancedTimer(u32),
+    /// Time the group has been unbalanced for, in clock_t. Only reported by the kernel.
+    UnbalancedTime(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for ResilientGroup {
+    fn value_len(&self) -> usize {
+        use self::ResilientGroup::*;
+        match self {
+            Pad(ref bytes) => bytes.len(),
+            Buckets(_) => 2,
+            IdleTimer(_) | UnbalancedTimer(_) => 4,
+            UnbalancedTime(_) => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ResilientGroup::*;
+        match self {
+            Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Buckets(value) => NativeEndian::write_u16(buffer, *value),
+            IdleTimer(value) | UnbalancedTimer(value) => NativeEndian::write_u32(buffer, *value),
+            UnbalancedTime(value) => NativeEndian::write_u64(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ResilientGroup::*;
+        match self {
+            Pad(_) => NHA_RES_GROUP_PAD,
+            Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ResilientGroup {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ResilientGroup::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_PAD => Pad(payload.to_vec()),
+            NHA_RES_GROUP_BUCKETS => {
+                Buckets(parse_u16(payload).context("invalid NHA_RES_GROUP_BUCKETS value")?)
+            }
+            NHA_RES_GROUP_IDLE_TIMER => {
+                IdleTimer(parse_u32(payload).context("invalid NHA_RES_GROUP_IDLE_TIMER value")?)
+            }
+            NHA_RES_GROUP_UNBALANCED_TIMER => UnbalancedTimer(
+                parse_u32(payload).context("invalid NHA_RES_GROUP_UNBALANCED_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIME => UnbalancedTime(
+                parse_u64(payload).context("invalid NHA_RES_GROUP_UNBALANCED_TIME value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// A hash bucket of a resilient nexthop group, nested in `NHA_RES_BUCKET`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ResilientBucket {
+    Pad(Vec<u8>),
+    /// Index of the bucket in the group
+    Index(u16),
+    /// Time the bucket has been idle for, in clock_t
+    IdleTime(u64),
+    /// Identifier of the nexthop the bucket is assigned to
+    NhId(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for ResilientBucket {
+    fn value_len(&self) -> usize {
+        use self::ResilientBucket::*;
+        match self {
+            Pad(ref bytes) => bytes.len(),
+            Index(_) => 2,
+            NhId(_) => 4,
+            IdleTime(_) => 8,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ResilientBucket::*;
+        match self {
+            Pad(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Index(value) => NativeEndian::write_u16(buffer, *value),
+            NhId(value) => NativeEndian::write_u32(buffer, *value),
+            IdleTime(value) => NativeEndian::write_u64(buffer, *value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ResilientBucket::*;
+        match self {
+            Pad(_) => NHA_RES_BUCKET_PAD,
+            Index(_) => NHA_RES_BUCKET_INDEX,
+            IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            NhId(_) => NHA_RES_BUCKET_NH_ID,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ResilientBucket {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ResilientBucket::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_PAD => Pad(payload.to_vec()),
+            NHA_RES_BUCKET_INDEX => {
+                Index(parse_u16(payload).context("invalid NHA_RES_BUCKET_INDEX value")?)
+            }
+            NHA_RES_BUCKET_IDLE_TIME => {
+                IdleTime(parse_u64(payload).context("invalid NHA_RES_BUCKET_IDLE_TIME value")?)
+            }
+            NHA_RES_BUCKET_NH_ID => {
+                NhId(parse_u32(payload).context("invalid NHA_RES_BUCKET_NH_ID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -31,6 +31,14 @@ bitflags! {
         const RTM_F_LOOKUP_TABLE = RTM_F_LOOKUP_TABLE;
         /// Return the full FIB lookup match (see commit `b61798130f1be5bff08712308126c2d7ebe390ef`)
         const RTM_F_FIB_MATCH = RTM_F_FIB_MATCH;
+        /// Next-hop flags of a single path route, see
+        /// [`NextHopFlags`](crate::nlas::route::NextHopFlags)
+        const RTNH_F_DEAD = RTNH_F_DEAD as u32;
+        const RTNH_F_PERVASIVE = RTNH_F_PERVASIVE as u32;
+        const RTNH_F_ONLINK = RTNH_F_ONLINK as u32;
+        const RTNH_F_OFFLOAD = RTNH_F_OFFLOAD as u32;
+        const RTNH_F_LINKDOWN = RTNH_F_LINKDOWN as u32;
+        const RTNH_F_UNRESOLVED = RTNH_F_UNRESOLVED as u32;
     }
 }
 
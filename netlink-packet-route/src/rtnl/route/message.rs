@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
-    nlas::route::Nla,
-    traits::{Emitable, Parseable},
+    nlas::route::{parse_encap_type, NextHopFlags, Nla, RouteNextHop},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
     RouteHeader,
     RouteMessageBuffer,
@@ -40,9 +40,10 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>> for RouteMess
 
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>> for Vec<Nla> {
     fn parse(buf: &RouteMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let encap_type = parse_encap_type(buf.nlas());
         let mut nlas = vec![];
         for nla_buf in buf.nlas() {
-            nlas.push(Nla::parse(&nla_buf?)?);
+            nlas.push(Nla::parse_with_param(&nla_buf?, encap_type)?);
         }
         Ok(nlas)
     }
@@ -85,6 +86,21 @@ impl RouteMessage {
         })
     }
 
+    /// Returns the table of the route, from `RTA_TABLE` if present, since
+    /// the header only holds the table ids below 256.
+    pub fn table(&self) -> u32 {
+        self.nlas
+            .iter()
+            .find_map(|nla| {
+                if let Nla::Table(v) = nla {
+                    Some(*v)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| u32::from(self.header.table))
+    }
+
     /// Returns the source address prefix, if present.
     pub fn source_prefix(&self) -> Option<(IpAddr, u8)> {
         self.nlas.iter().find_map(|nla| {
@@ -121,4 +137,51 @@ impl RouteMessage {
             }
         })
     }
+
+    /// Returns the next-hops of the route: the members of a multipath route,
+    /// or the single next-hop of the other routes. They are sorted by
+    /// interface, gateway and weight, so that the next-hops of two routes can
+    /// be compared directly.
+    pub fn next_hops(&self) -> Vec<RouteNextHop> {
+        let mut next_hops: Vec<RouteNextHop> = match self.nlas.iter().find_map(|nla| {
+            if let Nla::MultiPath(next_hops) = nla {
+                Some(next_hops)
+            } else {
+                None
+            }
+        }) {
+            Some(next_hops) => next_hops.iter().map(RouteNextHop::from).collect(),
+            None => {
+                let interface_id = self.output_interface().unwrap_or(0);
+                let gateway = self.gateway().or_else(|| {
+                    self.nlas.iter().find_map(|nla| {
+                        if let Nla::Via(via) = nla {
+                            via.ip()
+                        } else {
+                            None
+                        }
+                    })
+                });
+                if interface_id == 0 && gateway.is_none() {
+                    return vec![];
+                }
+                vec![RouteNextHop {
+                    interface_id,
+                    gateway,
+                    // the next-hop flags are the low byte of the route flags
+                    flags: NextHopFlags::from_bits_truncate(self.header.flags.bits() as u8),
+                    encap: self.nlas.iter().find_map(|nla| {
+                        if let Nla::Encap(encap) = nla {
+                            Some(encap.clone())
+                        } else {
+                            None
+                        }
+                    }),
+                    ..Default::default()
+                }]
+            }
+        };
+        next_hops.sort_by_key(|nh| (nh.interface_id, nh.gateway, nh.weight));
+        next_hops
+    }
 }
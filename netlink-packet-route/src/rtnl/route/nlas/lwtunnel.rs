@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u16_be, parse_u32, parse_u64, parse_u8},
+    route::nlas::{MplsIpTunnel, Seg6IpTunnel, Seg6Local},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+/// Lightweight tunnel encapsulation of a route (`RTA_ENCAP`). Its type is
+/// given by the `RTA_ENCAP_TYPE` attribute of the route, see
+/// [`Encap::encap_type`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Encap {
+    /// Push an MPLS label stack
+    Mpls(Vec<MplsIpTunnel>),
+    /// Set the metadata of an IPv4 collect-metadata tunnel device
+    Ip(Vec<IpTunnel>),
+    /// Set the metadata of an IPv6 collect-metadata tunnel device
+    Ip6(Vec<IpTunnel>),
+    /// Insert or encapsulate in an IPv6 segment routing header
+    Seg6(Vec<Seg6IpTunnel>),
+    /// Process the packets matching the route as a local segment
+    Seg6Local(Vec<Seg6Local>),
+    /// Run BPF programs on the packets
+    Bpf(Vec<BpfEncap>),
+    /// Encapsulation of another type, with the raw attributes
+    Other(u16, Vec<u8>),
+}
+
+impl Encap {
+    /// The `LWTUNNEL_ENCAP_*` type of the encapsulation, to be sent in
+    /// `RTA_ENCAP_TYPE` along with the encapsulation.
+    pub fn encap_type(&self) -> u16 {
+        use self::Encap::*;
+        match self {
+            Mpls(_) => LWTUNNEL_ENCAP_MPLS,
+            Ip(_) => LWTUNNEL_ENCAP_IP,
+            Ip6(_) => LWTUNNEL_ENCAP_IP6,
+            Seg6(_) => LWTUNNEL_ENCAP_SEG6,
+            Seg6Local(_) => LWTUNNEL_ENCAP_SEG6_LOCAL,
+            Bpf(_) => LWTUNNEL_ENCAP_BPF,
+            Other(kind, _) => *kind,
+        }
+    }
+}
+
+impl Emitable for Encap {
+    fn buffer_len(&self) -> usize {
+        use self::Encap::*;
+        match self {
+            Mpls(nlas) => nlas.as_slice().buffer_len(),
+            Ip(nlas) | Ip6(nlas) => nlas.as_slice().buffer_len(),
+            Seg6(nlas) => nlas.as_slice().buffer_len(),
+            Seg6Local(nlas) => nlas.as_slice().buffer_len(),
+            Bpf(nlas) => nlas.as_slice().buffer_len(),
+            Other(_, bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        use self::Encap::*;
+        match self {
+            Mpls(nlas) => nlas.as_slice().emit(buffer),
+            Ip(nlas) | Ip6(nlas) => nlas.as_slice().emit(buffer),
+            Seg6(nlas) => nlas.as_slice().emit(buffer),
+            Seg6Local(nlas) => nlas.as_slice().emit(buffer),
+            Bpf(nlas) => nlas.as_slice().emit(buffer),
+            Other(_, bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+pub(crate) fn parse_nlas<N>(payload: &[u8]) -> Result<Vec<N>, DecodeError>
+where
+    for<'a> N: Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(payload) {
+        nlas.push(N::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Encap {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: u16) -> Result<Self, DecodeError> {
+        use self::Encap::*;
+        let payload = buf.value();
+        Ok(match kind {
+            LWTUNNEL_ENCAP_MPLS => Mpls(parse_nlas(payload).context("invalid MPLS encap")?),
+            LWTUNNEL_ENCAP_IP => Ip(parse_nlas(payload).context("invalid IP encap")?),
+            LWTUNNEL_ENCAP_IP6 => Ip6(parse_nlas(payload).context("invalid IPv6 encap")?),
+            LWTUNNEL_ENCAP_SEG6 => Seg6(parse_nlas(payload).context("invalid seg6 encap")?),
+            LWTUNNEL_ENCAP_SEG6_LOCAL => {
+                Seg6Local(parse_nlas(payload).context("invalid seg6local encap")?)
+            }
+            LWTUNNEL_ENCAP_BPF => Bpf(parse_nlas(payload).context("invalid BPF encap")?),
+            _ => Other(kind, payload.to_vec()),
+        })
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_IP` or `LWTUNNEL_ENCAP_IP6`. For the latter, `Ttl` is the
+/// hop limit and `Tos` the traffic class.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum IpTunnel {
+    Unspec(Vec<u8>),
+    /// Tunnel key, such as the VNI of VXLAN
+    Id(u64),
+    Destination(Vec<u8>),
+    Source(Vec<u8>),
+    Ttl(u8),
+    Tos(u8),
+    /// `TUNNEL_*` flags
+    Flags(u16),
+    Pad(Vec<u8>),
+    Opts(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for IpTunnel {
+    fn value_len(&self) -> usize {
+        use self::IpTunnel::*;
+        match self {
+            Unspec(bytes) | Destination(bytes) | Source(bytes) | Pad(bytes) | Opts(bytes) => {
+                bytes.len()
+            }
+            Id(_) => 8,
+            Ttl(_) | Tos(_) => 1,
+            Flags(_) => 2,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::IpTunnel::*;
+        match self {
+            Unspec(bytes) | Destination(bytes) | Source(bytes) | Pad(bytes) | Opts(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Id(value) => BigEndian::write_u64(buffer, *value),
+            Ttl(value) | Tos(value) => buffer[0] = *value,
+            Flags(value) => BigEndian::write_u16(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::IpTunnel::*;
+        match self {
+            Unspec(_) => LWTUNNEL_IP_UNSPEC,
+            Id(_) => LWTUNNEL_IP_ID,
+            Destination(_) => LWTUNNEL_IP_DST,
+            Source(_) => LWTUNNEL_IP_SRC,
+            Ttl(_) => LWTUNNEL_IP_TTL,
+            Tos(_) => LWTUNNEL_IP_TOS,
+            Flags(_) => LWTUNNEL_IP_FLAGS,
+            Pad(_) => LWTUNNEL_IP_PAD,
+            Opts(_) => LWTUNNEL_IP_OPTS,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for IpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::IpTunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_UNSPEC => Unspec(payload.to_vec()),
+            // the id is in network byte order
+            LWTUNNEL_IP_ID => Id(u64::from_be(
+                parse_u64(payload).context("invalid LWTUNNEL_IP_ID value")?,
+            )),
+            LWTUNNEL_IP_DST => Destination(payload.to_vec()),
+            LWTUNNEL_IP_SRC => Source(payload.to_vec()),
+            LWTUNNEL_IP_TTL => Ttl(parse_u8(payload).context("invalid LWTUNNEL_IP_TTL value")?),
+            LWTUNNEL_IP_TOS => Tos(parse_u8(payload).context("invalid LWTUNNEL_IP_TOS value")?),
+            LWTUNNEL_IP_FLAGS => {
+                Flags(parse_u16_be(payload).context("invalid LWTUNNEL_IP_FLAGS value")?)
+            }
+            LWTUNNEL_IP_PAD => Pad(payload.to_vec()),
+            LWTUNNEL_IP_OPTS => Opts(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_BPF`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BpfEncap {
+    Unspec(Vec<u8>),
+    /// Program run on the packets received by the route
+    In(Vec<BpfProgram>),
+    /// Program run on the packets sent through the route
+    Out(Vec<BpfProgram>),
+    /// Program run on the packets before they are sent to the device
+    Xmit(Vec<BpfProgram>),
+    /// Headroom reserved for the headers pushed by the `Xmit` program
+    XmitHeadroom(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for BpfEncap {
+    fn value_len(&self) -> usize {
+        use self::BpfEncap::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            In(nlas) | Out(nlas) | Xmit(nlas) => nlas.as_slice().buffer_len(),
+            XmitHeadroom(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BpfEncap::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            In(nlas) | Out(nlas) | Xmit(nlas) => nlas.as_slice().emit(buffer),
+            XmitHeadroom(value) => NativeEndian::write_u32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BpfEncap::*;
+        match self {
+            Unspec(_) => LWT_BPF_UNSPEC,
+            In(_) => LWT_BPF_IN,
+            Out(_) => LWT_BPF_OUT,
+            Xmit(_) => LWT_BPF_XMIT,
+            XmitHeadroom(_) => LWT_BPF_XMIT_HEADROOM,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BpfEncap {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BpfEncap::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_UNSPEC => Unspec(payload.to_vec()),
+            LWT_BPF_IN => In(parse_nlas(payload).context("invalid LWT_BPF_IN value")?),
+            LWT_BPF_OUT => Out(parse_nlas(payload).context("invalid LWT_BPF_OUT value")?),
+            LWT_BPF_XMIT => Xmit(parse_nlas(payload).context("invalid LWT_BPF_XMIT value")?),
+            LWT_BPF_XMIT_HEADROOM => {
+                XmitHeadroom(parse_u32(payload).context("invalid LWT_BPF_XMIT_HEADROOM value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+/// BPF program of a lightweight tunnel, used by [`BpfEncap`] and by the
+/// `End.BPF` action of [`Seg6Local`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BpfProgram {
+    Unspec(Vec<u8>),
+    /// File descriptor of the program, only in requests
+    Fd(u32),
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for BpfProgram {
+    fn value_len(&self) -> usize {
+        use self::BpfProgram::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Fd(_) => 4,
+            Name(name) => name.len() + 1,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BpfProgram::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Fd(value) => NativeEndian::write_u32(buffer, *value),
+            Name(name) => {
+                buffer[..name.len()].copy_from_slice(name.as_bytes());
+                buffer[name.len()] = 0;
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BpfProgram::*;
+        match self {
+            Unspec(_) => LWT_BPF_PROG_UNSPEC,
+            Fd(_) => LWT_BPF_PROG_FD,
+            Name(_) => LWT_BPF_PROG_NAME,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BpfProgram {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BpfProgram::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_PROG_UNSPEC => Unspec(payload.to_vec()),
+            LWT_BPF_PROG_FD => Fd(parse_u32(payload).context("invalid LWT_BPF_PROG_FD value")?),
+            LWT_BPF_PROG_NAME => {
+                Name(parse_string(payload).context("invalid LWT_BPF_PROG_NAME value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
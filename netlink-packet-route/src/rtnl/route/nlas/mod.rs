@@ -6,29 +6,38 @@ pub use self::cache_info::*;
 mod metrics;
 pub use self::metrics::*;
 
+mod lwtunnel;
+pub use self::lwtunnel::*;
+
 mod mfc_stats;
 pub use self::mfc_stats::*;
 
 mod mpls_ip_tunnel;
 pub use self::mpls_ip_tunnel::*;
 
+mod mpls_label;
+pub use self::mpls_label::*;
+
 mod next_hops;
 pub use self::next_hops::*;
 
+mod seg6;
+pub use self::seg6::*;
+
+mod via;
+pub use self::via::*;
+
 use anyhow::Context;
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 
 use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
-#[cfg(feature = "rich_nlas")]
-use crate::traits::Emitable;
-
 /// Netlink attributes for `RTM_NEWROUTE`, `RTM_DELROUTE`,
 /// `RTM_GETROUTE` messages.
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -41,9 +50,6 @@ pub enum Nla {
     MfcStats(Vec<u8>),
     #[cfg(feature = "rich_nlas")]
     MfcStats(MfcStats),
-    #[cfg(not(feature = "rich_nlas"))]
-    MultiPath(Vec<u8>),
-    #[cfg(feature = "rich_nlas")]
     // See: https://codecave.cc/multipath-routing-in-linux-part-1.html
     MultiPath(Vec<NextHop>),
     #[cfg(not(feature = "rich_nlas"))]
@@ -57,10 +63,11 @@ pub enum Nla {
     PrefSource(Vec<u8>),
     Session(Vec<u8>),
     MpAlgo(Vec<u8>),
-    Via(Vec<u8>),
-    NewDestination(Vec<u8>),
+    Via(Via),
+    /// Labels of the `AF_MPLS` routes, replacing the label of the packets
+    NewDestination(Vec<MplsLabel>),
     Pref(Vec<u8>),
-    Encap(Vec<u8>),
+    Encap(Encap),
     Expires(Vec<u8>),
     Pad(Vec<u8>),
     Uid(Vec<u8>),
@@ -73,6 +80,14 @@ pub enum Nla {
     Flow(u32),
     Table(u32),
     Mark(u32),
+    /// Identifier of the nexthop object used by the route
+    NhId(u32),
+    /// IP protocol of a route lookup
+    IpProto(u8),
+    /// Source port of a route lookup
+    SourcePort(u16),
+    /// Destination port of a route lookup
+    DestinationPort(u16),
     Other(DefaultNla),
 }
 
@@ -88,21 +103,21 @@ impl nlas::Nla for Nla {
                 | PrefSource(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
-                | NewDestination(ref bytes)
                 | Pref(ref bytes)
-                | Encap(ref bytes)
                 | Expires(ref bytes)
                 | Pad(ref bytes)
                 | Uid(ref bytes)
                 | TtlPropagate(ref bytes)
                 => bytes.len(),
 
+            Via(ref via) => via.buffer_len(),
+            NewDestination(ref labels) => labels.len() * MPLS_LABEL_LEN,
+            Encap(ref encap) => encap.buffer_len(),
+
             #[cfg(not(feature = "rich_nlas"))]
             CacheInfo(ref bytes)
                 | MfcStats(ref bytes)
                 | Metrics(ref bytes)
-                | MultiPath(ref bytes)
                 => bytes.len(),
 
             #[cfg(feature = "rich_nlas")]
@@ -111,10 +126,10 @@ impl nlas::Nla for Nla {
             MfcStats(ref stats) => stats.buffer_len(),
             #[cfg(feature = "rich_nlas")]
             Metrics(ref metrics) => metrics.buffer_len(),
-            #[cfg(feature = "rich_nlas")]
             MultiPath(ref next_hops) => next_hops.iter().map(|nh| nh.buffer_len()).sum(),
 
-            EncapType(_) => 2,
+            EncapType(_) | SourcePort(_) | DestinationPort(_) => 2,
+            IpProto(_) => 1,
             Iif(_)
                 | Oif(_)
                 | Priority(_)
@@ -122,6 +137,7 @@ impl nlas::Nla for Nla {
                 | Flow(_)
                 | Table(_)
                 | Mark(_)
+                | NhId(_)
                 => 4,
 
             Other(ref attr) => attr.value_len(),
@@ -139,19 +155,19 @@ impl nlas::Nla for Nla {
                 | PrefSource(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
-                | NewDestination(ref bytes)
                 | Pref(ref bytes)
-                | Encap(ref bytes)
                 | Expires(ref bytes)
                 | Pad(ref bytes)
                 | Uid(ref bytes)
                 | TtlPropagate(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
 
+            Via(ref via) => via.emit(buffer),
+            NewDestination(ref labels) => emit_mpls_labels(labels, buffer),
+            Encap(ref encap) => encap.emit(buffer),
+
             #[cfg(not(feature = "rich_nlas"))]
-                MultiPath(ref bytes)
-                | CacheInfo(ref bytes)
+                CacheInfo(ref bytes)
                 | MfcStats(ref bytes)
                 | Metrics(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
@@ -162,7 +178,6 @@ impl nlas::Nla for Nla {
             MfcStats(ref stats) => stats.emit(buffer),
             #[cfg(feature = "rich_nlas")]
             Metrics(ref metrics) => metrics.emit(buffer),
-            #[cfg(feature = "rich_nlas")]
             MultiPath(ref next_hops) => {
                 let mut offset = 0;
                 for nh in next_hops {
@@ -173,6 +188,8 @@ impl nlas::Nla for Nla {
             }
 
             EncapType(value) => NativeEndian::write_u16(buffer, value),
+            SourcePort(value) | DestinationPort(value) => BigEndian::write_u16(buffer, value),
+            IpProto(value) => buffer[0] = value,
             Iif(value)
                 | Oif(value)
                 | Priority(value)
@@ -180,6 +197,7 @@ impl nlas::Nla for Nla {
                 | Flow(value)
                 | Table(value)
                 | Mark(value)
+                | NhId(value)
                 => NativeEndian::write_u32(buffer, value),
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -215,13 +233,36 @@ impl nlas::Nla for Nla {
             Pad(_) => RTA_PAD,
             Uid(_) => RTA_UID,
             TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            NhId(_) => RTA_NH_ID,
+            IpProto(_) => RTA_IP_PROTO,
+            SourcePort(_) => RTA_SPORT,
+            DestinationPort(_) => RTA_DPORT,
             Other(ref attr) => attr.kind(),
         }
     }
 }
 
+/// Returns the `RTA_ENCAP_TYPE` of the given attributes, needed to parse
+/// `RTA_ENCAP` which the kernel puts first.
+pub(crate) fn parse_encap_type<'a>(
+    nlas: impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+) -> u16 {
+    nlas.filter_map(|nla| nla.ok())
+        .find(|nla| nla.kind() == RTA_ENCAP_TYPE)
+        .and_then(|nla| parse_u16(nla.value()).ok())
+        .unwrap_or(LWTUNNEL_ENCAP_NONE)
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Self::parse_with_param(buf, LWTUNNEL_ENCAP_NONE)
+    }
+}
+
+/// Parse a route attribute, `encap_type` being the `RTA_ENCAP_TYPE` of the
+/// route. Without it, `RTA_ENCAP` is parsed as `Encap::Other`.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nla {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, encap_type: u16) -> Result<Self, DecodeError> {
         use self::Nla::*;
 
         let payload = buf.value();
@@ -233,10 +274,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_PREFSRC => PrefSource(payload.to_vec()),
             RTA_SESSION => Session(payload.to_vec()),
             RTA_MP_ALGO => MpAlgo(payload.to_vec()),
-            RTA_VIA => Via(payload.to_vec()),
-            RTA_NEWDST => NewDestination(payload.to_vec()),
+            RTA_VIA => Via(
+                via::Via::parse(&ViaBuffer::new_checked(payload).context("invalid RTA_VIA value")?)
+                    .context("invalid RTA_VIA value")?,
+            ),
+            RTA_NEWDST => {
+                NewDestination(parse_mpls_labels(payload).context("invalid RTA_NEWDST value")?)
+            }
             RTA_PREF => Pref(payload.to_vec()),
-            RTA_ENCAP => Encap(payload.to_vec()),
+            RTA_ENCAP => Encap(
+                lwtunnel::Encap::parse_with_param(buf, encap_type)
+                    .context("invalid RTA_ENCAP value")?,
+            ),
             RTA_EXPIRES => Expires(payload.to_vec()),
             RTA_PAD => Pad(payload.to_vec()),
             RTA_UID => Uid(payload.to_vec()),
@@ -253,6 +302,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_FLOW => Flow(parse_u32(payload).context("invalid RTA_FLOW value")?),
             RTA_TABLE => Table(parse_u32(payload).context("invalid RTA_TABLE value")?),
             RTA_MARK => Mark(parse_u32(payload).context("invalid RTA_MARK value")?),
+            RTA_NH_ID => NhId(parse_u32(payload).context("invalid RTA_NH_ID value")?),
+            RTA_IP_PROTO => IpProto(parse_u8(payload).context("invalid RTA_IP_PROTO value")?),
+            RTA_SPORT => SourcePort(parse_u16_be(payload).context("invalid RTA_SPORT value")?),
+            RTA_DPORT => {
+                DestinationPort(parse_u16_be(payload).context("invalid RTA_DPORT value")?)
+            }
 
             #[cfg(not(feature = "rich_nlas"))]
             RTA_CACHEINFO => CacheInfo(payload.to_vec()),
@@ -282,9 +337,6 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
                 )
                 .context("invalid RTA_METRICS value")?,
             ),
-            #[cfg(not(feature = "rich_nlas"))]
-            RTA_MULTIPATH => MultiPath(payload.to_vec()),
-            #[cfg(feature = "rich_nlas")]
             RTA_MULTIPATH => {
                 let mut next_hops = vec![];
                 let mut buf = payload;
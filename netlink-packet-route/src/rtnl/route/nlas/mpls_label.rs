@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{constants::*, DecodeError};
+
+pub const MPLS_LABEL_LEN: usize = 4;
+
+/// A label stack entry (`struct mpls_label`), as found in `RTA_DST` and
+/// `RTA_NEWDST` of `AF_MPLS` routes and in `MPLS_IPTUNNEL_DST`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MplsLabel {
+    /// Label value, on 20 bits
+    pub label: u32,
+    pub traffic_class: u8,
+    /// Whether this is the last entry of the stack
+    pub bottom_of_stack: bool,
+    pub ttl: u8,
+}
+
+impl MplsLabel {
+    pub fn new(label: u32) -> Self {
+        MplsLabel {
+            label,
+            ..Default::default()
+        }
+    }
+
+    /// Build a label stack from the label values, the first one being the
+    /// outermost. The bottom of stack flag is set on the last entry, as the
+    /// kernel expects.
+    pub fn stack(labels: &[u32]) -> Vec<Self> {
+        let mut stack: Vec<Self> = labels.iter().map(|label| Self::new(*label)).collect();
+        if let Some(last) = stack.last_mut() {
+            last.bottom_of_stack = true;
+        }
+        stack
+    }
+
+    /// Encode a label stack, for instance to set it as the `RTA_DST` of an
+    /// `AF_MPLS` route
+    pub fn encode_stack(labels: &[Self]) -> Vec<u8> {
+        let mut buffer = vec![0; labels.len() * MPLS_LABEL_LEN];
+        emit_mpls_labels(labels, &mut buffer);
+        buffer
+    }
+
+    pub fn from_entry(entry: u32) -> Self {
+        MplsLabel {
+            label: (entry & MPLS_LS_LABEL_MASK) >> MPLS_LS_LABEL_SHIFT,
+            traffic_class: ((entry & MPLS_LS_TC_MASK) >> MPLS_LS_TC_SHIFT) as u8,
+            bottom_of_stack: entry & MPLS_LS_S_MASK != 0,
+            ttl: ((entry & MPLS_LS_TTL_MASK) >> MPLS_LS_TTL_SHIFT) as u8,
+        }
+    }
+
+    pub fn to_entry(self) -> u32 {
+        ((self.label << MPLS_LS_LABEL_SHIFT) & MPLS_LS_LABEL_MASK)
+            | ((u32::from(self.traffic_class) << MPLS_LS_TC_SHIFT) & MPLS_LS_TC_MASK)
+            | (u32::from(self.bottom_of_stack) << MPLS_LS_S_SHIFT)
+            | ((u32::from(self.ttl) << MPLS_LS_TTL_SHIFT) & MPLS_LS_TTL_MASK)
+    }
+}
+
+pub(crate) fn parse_mpls_labels(payload: &[u8]) -> Result<Vec<MplsLabel>, DecodeError> {
+    let entries = payload.chunks_exact(MPLS_LABEL_LEN);
+    if !entries.remainder().is_empty() {
+        return Err(format!("invalid MPLS label stack: {:?}", payload).into());
+    }
+    Ok(entries
+        .map(|entry| MplsLabel::from_entry(BigEndian::read_u32(entry)))
+        .collect())
+}
+
+pub(crate) fn emit_mpls_labels(labels: &[MplsLabel], buffer: &mut [u8]) {
+    for (label, entry) in labels.iter().zip(buffer.chunks_mut(MPLS_LABEL_LEN)) {
+        BigEndian::write_u32(entry, label.to_entry());
+    }
+}
@@ -7,8 +7,8 @@ use crate::{
     constants,
     nlas::{NlaBuffer, NlasIterator},
     parsers::parse_ip,
-    route::nlas::Nla,
-    traits::{Emitable, Parseable},
+    route::nlas::{parse_encap_type, Encap, Nla},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
@@ -96,9 +96,10 @@ impl<'a, T: AsRef<[u8]>> Parseable<NextHopBuffer<&'a T>> for NextHop {
 
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<NextHopBuffer<&'a T>> for Vec<Nla> {
     fn parse(buf: &NextHopBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let encap_type = parse_encap_type(buf.nlas());
         let mut nlas = vec![];
         for nla_buf in buf.nlas() {
-            nlas.push(Nla::parse(&nla_buf?)?);
+            nlas.push(Nla::parse_with_param(&nla_buf?, encap_type)?);
         }
         Ok(nlas)
     }
@@ -131,4 +132,72 @@ impl NextHop {
             }
         })
     }
+
+    /// Gateway of another family than the route (it is encoded as an
+    /// `RTA_VIA` nla)
+    pub fn via(&self) -> Option<IpAddr> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Via(via) = nla {
+                via.ip()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Lightweight tunnel encapsulation of the next-hop
+    pub fn encap(&self) -> Option<&Encap> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Encap(encap) = nla {
+                Some(encap)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Weight of the next-hop, from 1 to 256 (it is encoded as `hops + 1`)
+    pub fn weight(&self) -> u16 {
+        u16::from(self.hops) + 1
+    }
+}
+
+/// Next-hop of a route, as a whole. It is built from either the
+/// `RTA_MULTIPATH` members or the attributes of a single path route, see
+/// [`RouteMessage::next_hops`](crate::RouteMessage::next_hops), so that the
+/// next-hops of routes can be compared regardless of how they were given.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RouteNextHop {
+    /// Index of the output interface, 0 when the route has none
+    pub interface_id: u32,
+    /// Gateway, given with either `RTA_GATEWAY` or `RTA_VIA`
+    pub gateway: Option<IpAddr>,
+    /// Weight of the next-hop in the multipath route, from 1 to 256
+    pub weight: u16,
+    pub flags: NextHopFlags,
+    pub encap: Option<Encap>,
+}
+
+impl Default for RouteNextHop {
+    fn default() -> Self {
+        RouteNextHop {
+            interface_id: 0,
+            gateway: None,
+            weight: 1,
+            flags: NextHopFlags::empty(),
+            encap: None,
+        }
+    }
+}
+
+impl From<&NextHop> for RouteNextHop {
+    fn from(nh: &NextHop) -> Self {
+        RouteNextHop {
+            interface_id: nh.interface_id,
+            gateway: nh.gateway().or_else(|| nh.via()),
+            weight: nh.weight(),
+            flags: nh.flags,
+            encap: nh.encap().cloned(),
+        }
+    }
 }
@@ -0,0 +1,380 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u32, parse_u64},
+    route::nlas::{parse_nlas, BpfProgram},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const IPV6_SR_HDR_LEN: usize = 8;
+pub const SEG6_IPTUNNEL_ENCAP_HEADER_LEN: usize = 4;
+const IPV6_SRCRT_TYPE_4: u8 = 4;
+const SEGMENT_LEN: usize = 16;
+
+/// IPv6 segment routing header (`struct ipv6_sr_hdr`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Ipv6SrHdr {
+    /// `SR6_FLAG1_*` flags
+    pub flags: u8,
+    pub tag: u16,
+    /// Segments, in the order they are visited. They are stored the other way
+    /// around in the header.
+    pub segments: Vec<Ipv6Addr>,
+    /// Raw TLVs following the segments. They are padded to 8 bytes with
+    /// `Pad1` TLVs when emitted.
+    pub tlvs: Vec<u8>,
+}
+
+buffer!(Ipv6SrHdrBuffer(IPV6_SR_HDR_LEN) {
+    next_header: (u8, 0),
+    header_length: (u8, 1),
+    routing_type: (u8, 2),
+    segments_left: (u8, 3),
+    first_segment: (u8, 4),
+    flags: (u8, 5),
+    tag: (slice, 6..8),
+    payload: (slice, IPV6_SR_HDR_LEN..),
+});
+
+impl Emitable for Ipv6SrHdr {
+    fn buffer_len(&self) -> usize {
+        IPV6_SR_HDR_LEN + self.segments.len() * SEGMENT_LEN + self.tlvs.len().div_ceil(8) * 8
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let last = self.segments.len().saturating_sub(1) as u8;
+        let mut packet = Ipv6SrHdrBuffer::new(buffer);
+        packet.set_next_header(0);
+        // the header length is in 8 bytes units, without the first 8 bytes
+        packet.set_header_length((self.buffer_len() / 8 - 1) as u8);
+        packet.set_routing_type(IPV6_SRCRT_TYPE_4);
+        packet.set_segments_left(last);
+        packet.set_first_segment(last);
+        packet.set_flags(self.flags);
+        BigEndian::write_u16(packet.tag_mut(), self.tag);
+        let payload = packet.payload_mut();
+        for (segment, slot) in self
+            .segments
+            .iter()
+            .rev()
+            .zip(payload.chunks_mut(SEGMENT_LEN))
+        {
+            slot.copy_from_slice(&segment.octets());
+        }
+        let offset = self.segments.len() * SEGMENT_LEN;
+        let (tlvs, padding) = payload[offset..].split_at_mut(self.tlvs.len());
+        tlvs.copy_from_slice(self.tlvs.as_slice());
+        // Pad1 TLVs are single zero bytes
+        padding.fill(0);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Ipv6SrHdrBuffer<&'a T>> for Ipv6SrHdr {
+    fn parse(buf: &Ipv6SrHdrBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let len = (buf.header_length() as usize + 1) * 8 - IPV6_SR_HDR_LEN;
+        let segments_len = (buf.first_segment() as usize + 1) * SEGMENT_LEN;
+        let payload = buf.payload();
+        if payload.len() < len || len < segments_len {
+            return Err(format!(
+                "invalid segment routing header: {} bytes of segments and TLVs, expected {}",
+                payload.len(),
+                len
+            )
+            .into());
+        }
+        let mut segments = vec![];
+        for segment in payload[..segments_len].chunks(SEGMENT_LEN).rev() {
+            segments.push(Ipv6Addr::from(parse_ipv6(segment)?));
+        }
+        Ok(Ipv6SrHdr {
+            flags: buf.flags(),
+            tag: BigEndian::read_u16(buf.tag()),
+            segments,
+            tlvs: payload[segments_len..len].to_vec(),
+        })
+    }
+}
+
+/// Segment routing encapsulation (`struct seg6_iptunnel_encap`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Seg6Encap {
+    /// `SEG6_IPTUN_MODE_*` mode
+    pub mode: u32,
+    pub srh: Ipv6SrHdr,
+}
+
+buffer!(Seg6EncapBuffer(SEG6_IPTUNNEL_ENCAP_HEADER_LEN + IPV6_SR_HDR_LEN) {
+    mode: (u32, 0..4),
+    srh: (slice, SEG6_IPTUNNEL_ENCAP_HEADER_LEN..),
+});
+
+impl Emitable for Seg6Encap {
+    fn buffer_len(&self) -> usize {
+        SEG6_IPTUNNEL_ENCAP_HEADER_LEN + self.srh.buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = Seg6EncapBuffer::new(buffer);
+        packet.set_mode(self.mode);
+        self.srh.emit(packet.srh_mut());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Seg6EncapBuffer<&'a T>> for Seg6Encap {
+    fn parse(buf: &Seg6EncapBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Seg6Encap {
+            mode: buf.mode(),
+            srh: Ipv6SrHdr::parse(&Ipv6SrHdrBuffer::new_checked(buf.srh())?)?,
+        })
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6IpTunnel {
+    Unspec(Vec<u8>),
+    Srh(Seg6Encap),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Seg6IpTunnel {
+    fn value_len(&self) -> usize {
+        use self::Seg6IpTunnel::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Srh(encap) => encap.buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Seg6IpTunnel::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Srh(encap) => encap.emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Seg6IpTunnel::*;
+        match self {
+            Unspec(_) => SEG6_IPTUNNEL_UNSPEC,
+            Srh(_) => SEG6_IPTUNNEL_SRH,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6IpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Seg6IpTunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_IPTUNNEL_UNSPEC => Unspec(payload.to_vec()),
+            SEG6_IPTUNNEL_SRH => Srh(Seg6Encap::parse(
+                &Seg6EncapBuffer::new_checked(payload)
+                    .context("invalid SEG6_IPTUNNEL_SRH value")?,
+            )
+            .context("invalid SEG6_IPTUNNEL_SRH value")?),
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6_LOCAL`. The attributes besides `Action` depend on
+/// the action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6Local {
+    Unspec(Vec<u8>),
+    /// `SEG6_LOCAL_ACTION_*` action
+    Action(u32),
+    /// Header of the `End.B6` and `End.B6.Encaps` actions
+    Srh(Ipv6SrHdr),
+    Table(u32),
+    Nh4(Ipv4Addr),
+    Nh6(Ipv6Addr),
+    Iif(u32),
+    Oif(u32),
+    /// Program of the `End.BPF` action
+    Bpf(Vec<BpfProgram>),
+    /// Table of the VRF of the `End.DT4`, `End.DT6` and `End.DT46` actions
+    VrfTable(u32),
+    Counters(Vec<Seg6LocalCounter>),
+    Flavors(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Seg6Local {
+    fn value_len(&self) -> usize {
+        use self::Seg6Local::*;
+        match self {
+            Unspec(bytes) | Flavors(bytes) => bytes.len(),
+            Srh(srh) => srh.buffer_len(),
+            Action(_) | Table(_) | Iif(_) | Oif(_) | VrfTable(_) => 4,
+            Nh4(_) => 4,
+            Nh6(_) => 16,
+            Bpf(nlas) => nlas.as_slice().buffer_len(),
+            Counters(nlas) => nlas.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Seg6Local::*;
+        match self {
+            Unspec(bytes) | Flavors(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Srh(srh) => srh.emit(buffer),
+            Action(value) | Table(value) | Iif(value) | Oif(value) | VrfTable(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Nh4(addr) => buffer.copy_from_slice(&addr.octets()),
+            Nh6(addr) => buffer.copy_from_slice(&addr.octets()),
+            Bpf(nlas) => nlas.as_slice().emit(buffer),
+            Counters(nlas) => nlas.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Seg6Local::*;
+        match self {
+            Unspec(_) => SEG6_LOCAL_UNSPEC,
+            Action(_) => SEG6_LOCAL_ACTION,
+            Srh(_) => SEG6_LOCAL_SRH,
+            Table(_) => SEG6_LOCAL_TABLE,
+            Nh4(_) => SEG6_LOCAL_NH4,
+            Nh6(_) => SEG6_LOCAL_NH6,
+            Iif(_) => SEG6_LOCAL_IIF,
+            Oif(_) => SEG6_LOCAL_OIF,
+            Bpf(_) => SEG6_LOCAL_BPF,
+            VrfTable(_) => SEG6_LOCAL_VRFTABLE,
+            Counters(_) => SEG6_LOCAL_COUNTERS,
+            Flavors(_) => SEG6_LOCAL_FLAVORS,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        use self::Seg6Local::*;
+        matches!(self, Counters(_) | Flavors(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6Local {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Seg6Local::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_UNSPEC => Unspec(payload.to_vec()),
+            SEG6_LOCAL_ACTION => {
+                Action(parse_u32(payload).context("invalid SEG6_LOCAL_ACTION value")?)
+            }
+            SEG6_LOCAL_SRH => Srh(Ipv6SrHdr::parse(
+                &Ipv6SrHdrBuffer::new_checked(payload).context("invalid SEG6_LOCAL_SRH value")?,
+            )
+            .context("invalid SEG6_LOCAL_SRH value")?),
+            SEG6_LOCAL_TABLE => {
+                Table(parse_u32(payload).context("invalid SEG6_LOCAL_TABLE value")?)
+            }
+            SEG6_LOCAL_NH4 => {
+                if payload.len() != 4 {
+                    return Err(format!("invalid SEG6_LOCAL_NH4 value: {:?}", payload).into());
+                }
+                let mut octets = [0; 4];
+                octets.copy_from_slice(payload);
+                Nh4(Ipv4Addr::from(octets))
+            }
+            SEG6_LOCAL_NH6 => Nh6(Ipv6Addr::from(
+                parse_ipv6(payload).context("invalid SEG6_LOCAL_NH6 value")?,
+            )),
+            SEG6_LOCAL_IIF => Iif(parse_u32(payload).context("invalid SEG6_LOCAL_IIF value")?),
+            SEG6_LOCAL_OIF => Oif(parse_u32(payload).context("invalid SEG6_LOCAL_OIF value")?),
+            SEG6_LOCAL_BPF => Bpf(parse_nlas(payload).context("invalid SEG6_LOCAL_BPF value")?),
+            SEG6_LOCAL_VRFTABLE => {
+                VrfTable(parse_u32(payload).context("invalid SEG6_LOCAL_VRFTABLE value")?)
+            }
+            SEG6_LOCAL_COUNTERS => {
+                Counters(parse_nlas(payload).context("invalid SEG6_LOCAL_COUNTERS value")?)
+            }
+            SEG6_LOCAL_FLAVORS => Flavors(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+/// Counters of a seg6local route, requested by sending an empty
+/// `Seg6Local::Counters` when the route is created
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6LocalCounter {
+    Unspec(Vec<u8>),
+    Pad(Vec<u8>),
+    Packets(u64),
+    Bytes(u64),
+    Errors(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Seg6LocalCounter {
+    fn value_len(&self) -> usize {
+        use self::Seg6LocalCounter::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Packets(_) | Bytes(_) | Errors(_) => 8,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Seg6LocalCounter::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Packets(value) | Bytes(value) | Errors(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Seg6LocalCounter::*;
+        match self {
+            Unspec(_) => SEG6_LOCAL_CNT_UNSPEC,
+            Pad(_) => SEG6_LOCAL_CNT_PAD,
+            Packets(_) => SEG6_LOCAL_CNT_PACKETS,
+            Bytes(_) => SEG6_LOCAL_CNT_BYTES,
+            Errors(_) => SEG6_LOCAL_CNT_ERRORS,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6LocalCounter {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Seg6LocalCounter::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_CNT_UNSPEC => Unspec(payload.to_vec()),
+            SEG6_LOCAL_CNT_PAD => Pad(payload.to_vec()),
+            SEG6_LOCAL_CNT_PACKETS => {
+                Packets(parse_u64(payload).context("invalid SEG6_LOCAL_CNT_PACKETS value")?)
+            }
+            SEG6_LOCAL_CNT_BYTES => {
+                Bytes(parse_u64(payload).context("invalid SEG6_LOCAL_CNT_BYTES value")?)
+            }
+            SEG6_LOCAL_CNT_ERRORS => {
+                Errors(parse_u64(payload).context("invalid SEG6_LOCAL_CNT_ERRORS value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use crate::{
+    constants::*,
+    parsers::parse_ip,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const VIA_HEADER_LEN: usize = 2;
+
+/// Gateway of another family than the route (`struct rtvia`), used by the
+/// `AF_MPLS` routes and by IPv4 routes with an IPv6 gateway
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Via {
+    /// Address family of the gateway, `AF_INET`, `AF_INET6` or `AF_PACKET`
+    pub family: u16,
+    pub address: Vec<u8>,
+}
+
+buffer!(ViaBuffer(VIA_HEADER_LEN) {
+    family: (u16, 0..2),
+    address: (slice, VIA_HEADER_LEN..),
+});
+
+impl Via {
+    /// Returns the gateway address, if it is an IP address.
+    pub fn ip(&self) -> Option<IpAddr> {
+        match self.family {
+            AF_INET | AF_INET6 => parse_ip(&self.address).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl From<IpAddr> for Via {
+    fn from(addr: IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(addr) => Via {
+                family: AF_INET,
+                address: addr.octets().to_vec(),
+            },
+            IpAddr::V6(addr) => Via {
+                family: AF_INET6,
+                address: addr.octets().to_vec(),
+            },
+        }
+    }
+}
+
+impl Emitable for Via {
+    fn buffer_len(&self) -> usize {
+        VIA_HEADER_LEN + self.address.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = ViaBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet
+            .address_mut()
+            .copy_from_slice(self.address.as_slice());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<ViaBuffer<&'a T>> for Via {
+    fn parse(buf: &ViaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Via {
+            family: buf.family(),
+            address: buf.address().to_vec(),
+        })
+    }
+}
@@ -123,3 +123,351 @@ mod test_rich_nlas {
         assert_eq!(buf, ROUTE_MSG);
     }
 }
+
+mod test_lwtunnel {
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{
+                Encap,
+                Ipv6SrHdr,
+                MplsIpTunnel,
+                MplsLabel,
+                Nla,
+                Seg6Encap,
+                Seg6IpTunnel,
+                Seg6Local,
+                Via,
+            },
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+    use std::net::{IpAddr, Ipv4Addr};
+
+    // ip -6 route add 2001:db8:98::/64 encap seg6 mode encap \
+    //     segs 2001:db8::1,2001:db8::2 dev nht0
+    #[rustfmt::skip]
+    static SEG6_ROUTE_MSG: [u8; 116] = [
+        0x0a, 0x40, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, // RTA_TABLE 254
+        0x14, 0x00, 0x01, 0x00, // RTA_DST 2001:db8:98::
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x98, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x04, 0x00, 0x00, // RTA_PRIORITY 1024
+        0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, // RTA_OIF 6
+        0x34, 0x00, 0x16, 0x00, // RTA_ENCAP
+            0x30, 0x00, 0x01, 0x00, // SEG6_IPTUNNEL_SRH
+                0x01, 0x00, 0x00, 0x00, // mode encap
+                0x00, // next header
+                0x04, // header length
+                0x04, // routing type
+                0x01, // segments left
+                0x01, // first segment
+                0x00, // flags
+                0x00, 0x00, // tag
+                // segments, the last one first
+                0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+                0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x06, 0x00, 0x15, 0x00, 0x05, 0x00, 0x00, 0x00, // RTA_ENCAP_TYPE seg6
+    ];
+
+    #[test]
+    fn seg6_route_read_and_emit() {
+        let msg =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&SEG6_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        let expected = Nla::Encap(Encap::Seg6(vec![Seg6IpTunnel::Srh(Seg6Encap {
+            mode: SEG6_IPTUN_MODE_ENCAP,
+            srh: Ipv6SrHdr {
+                segments: vec![
+                    "2001:db8::1".parse().unwrap(),
+                    "2001:db8::2".parse().unwrap(),
+                ],
+                ..Default::default()
+            },
+        })]));
+        assert_eq!(msg.nlas[4], expected);
+        assert_eq!(msg.nlas[5], Nla::EncapType(LWTUNNEL_ENCAP_SEG6));
+
+        let mut buf = vec![0; SEG6_ROUTE_MSG.len()];
+        assert_eq!(msg.buffer_len(), SEG6_ROUTE_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, SEG6_ROUTE_MSG);
+    }
+
+    #[test]
+    fn seg6_srh_emit_pads_tlvs() {
+        let srh = Ipv6SrHdr {
+            segments: vec!["2001:db8::1".parse().unwrap()],
+            // HMAC TLV header, truncated
+            tlvs: vec![0x05, 0x06, 0x00],
+            ..Default::default()
+        };
+        assert_eq!(srh.buffer_len(), 8 + 16 + 8);
+        let mut buf = vec![0xff; srh.buffer_len()];
+        srh.emit(&mut buf[..]);
+        // header length in 8 bytes units, without the first 8 bytes
+        assert_eq!(buf[1], 3);
+        assert_eq!(
+            &buf[24..],
+            &[0x05, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    // ip -6 route add 2001:db8:97::1/128 encap seg6local action End.DT6 \
+    //     table 254 dev nht0
+    #[rustfmt::skip]
+    static SEG6_LOCAL_ROUTE_MSG: [u8; 84] = [
+        0x0a, 0x80, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, // RTA_TABLE 254
+        0x14, 0x00, 0x01, 0x00, // RTA_DST 2001:db8:97::1
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x97, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x04, 0x00, 0x00, // RTA_PRIORITY 1024
+        0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, // RTA_OIF 6
+        0x14, 0x00, 0x16, 0x00, // RTA_ENCAP
+            0x08, 0x00, 0x01, 0x00, 0x07, 0x00, 0x00, 0x00, // SEG6_LOCAL_ACTION End.DT6
+            0x08, 0x00, 0x03, 0x00, 0xfe, 0x00, 0x00, 0x00, // SEG6_LOCAL_TABLE 254
+        0x06, 0x00, 0x15, 0x00, 0x07, 0x00, 0x00, 0x00, // RTA_ENCAP_TYPE seg6local
+    ];
+
+    #[test]
+    fn seg6_local_route_read_and_emit() {
+        let msg = RouteMessage::parse(
+            &RouteMessageBuffer::new_checked(&&SEG6_LOCAL_ROUTE_MSG[..]).unwrap(),
+        )
+        .unwrap();
+        let expected = Nla::Encap(Encap::Seg6Local(vec![
+            Seg6Local::Action(SEG6_LOCAL_ACTION_END_DT6),
+            Seg6Local::Table(254),
+        ]));
+        assert_eq!(msg.nlas[4], expected);
+
+        let mut buf = vec![0; SEG6_LOCAL_ROUTE_MSG.len()];
+        assert_eq!(msg.buffer_len(), SEG6_LOCAL_ROUTE_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, SEG6_LOCAL_ROUTE_MSG);
+    }
+
+    // ip route add 10.98.0.0/24 encap mpls 100/200 dev nht0
+    #[rustfmt::skip]
+    static MPLS_ENCAP_ROUTE_MSG: [u8; 52] = [
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x62, 0x00, 0x00, // RTA_DST 10.98.0.0
+        0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, // RTA_OIF 6
+        0x10, 0x00, 0x16, 0x00, // RTA_ENCAP
+            0x0c, 0x00, 0x01, 0x00, // MPLS_IPTUNNEL_DST
+                0x00, 0x06, 0x40, 0x00, // label 100
+                0x00, 0x0c, 0x81, 0x00, // label 200, bottom of stack
+        0x06, 0x00, 0x15, 0x00, 0x01, 0x00, 0x00, 0x00, // RTA_ENCAP_TYPE mpls
+    ];
+
+    #[test]
+    fn mpls_encap_route_read_and_emit() {
+        let msg = RouteMessage::parse(
+            &RouteMessageBuffer::new_checked(&&MPLS_ENCAP_ROUTE_MSG[..]).unwrap(),
+        )
+        .unwrap();
+        let expected = Nla::Encap(Encap::Mpls(vec![MplsIpTunnel::Destination(
+            MplsLabel::stack(&[100, 200]),
+        )]));
+        assert_eq!(msg.nlas[2], expected);
+
+        let mut buf = vec![0; MPLS_ENCAP_ROUTE_MSG.len()];
+        assert_eq!(msg.buffer_len(), MPLS_ENCAP_ROUTE_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, MPLS_ENCAP_ROUTE_MSG);
+    }
+
+    // ip -f mpls route add 300 as 400 via inet 10.99.0.2 dev nht0
+    #[rustfmt::skip]
+    static MPLS_ROUTE_MSG: [u8; 48] = [
+        0x1c, 0x14, 0x00, 0x00, 0xfe, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x12, 0xc1, 0x00, // RTA_DST label 300
+        0x0a, 0x00, 0x12, 0x00, // RTA_VIA
+            0x02, 0x00, // AF_INET
+            0x0a, 0x63, 0x00, 0x02, 0x00, 0x00, // 10.99.0.2 and padding
+        0x08, 0x00, 0x13, 0x00, 0x00, 0x19, 0x01, 0x00, // RTA_NEWDST label 400
+        0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, // RTA_OIF 6
+    ];
+
+    fn mpls_route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_MPLS as u8;
+        msg.header.destination_prefix_length = 20;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_STATIC;
+        msg.header.kind = RTN_UNICAST;
+        msg.nlas = vec![
+            Nla::Destination(MplsLabel::encode_stack(&MplsLabel::stack(&[300]))),
+            Nla::Via(Via::from(IpAddr::V4(Ipv4Addr::new(10, 99, 0, 2)))),
+            Nla::NewDestination(MplsLabel::stack(&[400])),
+            Nla::Oif(6),
+        ];
+        msg
+    }
+
+    #[test]
+    fn mpls_route_read() {
+        let msg =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&MPLS_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(msg, mpls_route_message());
+    }
+
+    #[test]
+    fn mpls_route_emit() {
+        let msg = mpls_route_message();
+        let mut buf = vec![0; MPLS_ROUTE_MSG.len()];
+        assert_eq!(msg.buffer_len(), MPLS_ROUTE_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, MPLS_ROUTE_MSG);
+    }
+}
+
+mod test_multipath {
+    use crate::{
+        rtnl::route::{
+            nlas::{Encap, IpTunnel, NextHopFlags, RouteNextHop},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+    use std::net::{IpAddr, Ipv4Addr};
+
+    // ip route add 10.91.0.0/24 \
+    //     nexthop via 10.0.0.3 dev nht0 weight 2 \
+    //     nexthop via 10.1.0.4 dev nht0 onlink \
+    //     nexthop encap ip id 7 dst 10.0.0.8 via inet6 fe80::1 dev nht0
+    #[rustfmt::skip]
+    static MULTIPATH_ROUTE_MSG: [u8; 160] = [
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, // RTA_TABLE 254
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x5b, 0x00, 0x00, // RTA_DST 10.91.0.0
+        0x84, 0x00, 0x09, 0x00, // RTA_MULTIPATH
+            0x10, 0x00, // length (16)
+            0x00, // flags
+            0x01, // hops (weight 2)
+            0x06, 0x00, 0x00, 0x00, // interface index
+                0x08, 0x00, 0x05, 0x00, 0x0a, 0x00, 0x00, 0x03, // RTA_GATEWAY 10.0.0.3
+
+            0x10, 0x00, // length (16)
+            0x04, // flags (onlink)
+            0x00, // hops (weight 1)
+            0x06, 0x00, 0x00, 0x00, // interface index
+                0x08, 0x00, 0x05, 0x00, 0x0a, 0x01, 0x00, 0x04, // RTA_GATEWAY 10.1.0.4
+
+            0x60, 0x00, // length (96)
+            0x00, // flags
+            0x00, // hops (weight 1)
+            0x06, 0x00, 0x00, 0x00, // interface index
+                0x16, 0x00, 0x12, 0x00, // RTA_VIA
+                    0x0a, 0x00, // AF_INET6
+                    0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+                    0x00, 0x00, // padding
+                0x38, 0x00, 0x16, 0x00, // RTA_ENCAP
+                    0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, // id 7
+                    0x08, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x08, // dst 10.0.0.8
+                    0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // src 0.0.0.0
+                    0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, // tos 0
+                    0x05, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, // ttl 0
+                    0x06, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, // flags 0
+                0x06, 0x00, 0x15, 0x00, 0x02, 0x00, 0x00, 0x00, // RTA_ENCAP_TYPE ip
+    ];
+
+    #[test]
+    fn multipath_route_next_hops() {
+        let msg = RouteMessage::parse(
+            &RouteMessageBuffer::new_checked(&&MULTIPATH_ROUTE_MSG[..]).unwrap(),
+        )
+        .unwrap();
+        let expected = vec![
+            RouteNextHop {
+                interface_id: 6,
+                gateway: Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3))),
+                weight: 2,
+                ..Default::default()
+            },
+            RouteNextHop {
+                interface_id: 6,
+                gateway: Some(IpAddr::V4(Ipv4Addr::new(10, 1, 0, 4))),
+                flags: NextHopFlags::RTNH_F_ONLINK,
+                ..Default::default()
+            },
+            RouteNextHop {
+                interface_id: 6,
+                gateway: Some("fe80::1".parse().unwrap()),
+                encap: Some(Encap::Ip(vec![
+                    IpTunnel::Id(7),
+                    IpTunnel::Destination(vec![10, 0, 0, 8]),
+                    IpTunnel::Source(vec![0, 0, 0, 0]),
+                    IpTunnel::Tos(0),
+                    IpTunnel::Ttl(0),
+                    IpTunnel::Flags(0),
+                ])),
+                ..Default::default()
+            },
+        ];
+        assert_eq!(msg.next_hops(), expected);
+
+        let mut buf = vec![0; MULTIPATH_ROUTE_MSG.len()];
+        assert_eq!(msg.buffer_len(), MULTIPATH_ROUTE_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, MULTIPATH_ROUTE_MSG);
+    }
+}
+
+mod test_lookup {
+    use crate::{
+        rtnl::route::{nlas::Nla, RouteFlags, RouteMessage, RouteMessageBuffer},
+        utils::{Emitable, Parseable},
+    };
+
+    // ip route get fibmatch 10.0.0.5 ipproto tcp sport 1234 dport 80
+    #[rustfmt::skip]
+    static LOOKUP_REQUEST_MSG: [u8; 44] = [
+        0x02, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x05, // RTA_DST 10.0.0.5
+        0x05, 0x00, 0x1b, 0x00, 0x06, 0x00, 0x00, 0x00, // RTA_IP_PROTO tcp
+        0x06, 0x00, 0x1c, 0x00, 0x04, 0xd2, 0x00, 0x00, // RTA_SPORT 1234
+        0x06, 0x00, 0x1d, 0x00, 0x00, 0x50, 0x00, 0x00, // RTA_DPORT 80
+    ];
+
+    fn lookup_request_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = 2;
+        msg.header.destination_prefix_length = 32;
+        msg.header.flags = RouteFlags::RTM_F_FIB_MATCH;
+        msg.nlas = vec![
+            Nla::Destination(vec![10, 0, 0, 5]),
+            Nla::IpProto(6),
+            Nla::SourcePort(1234),
+            Nla::DestinationPort(80),
+        ];
+        msg
+    }
+
+    #[test]
+    fn lookup_request_read() {
+        let msg = RouteMessage::parse(
+            &RouteMessageBuffer::new_checked(&&LOOKUP_REQUEST_MSG[..]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(msg, lookup_request_message());
+    }
+
+    #[test]
+    fn lookup_request_emit() {
+        let msg = lookup_request_message();
+        let mut buf = vec![0; LOOKUP_REQUEST_MSG.len()];
+        assert_eq!(msg.buffer_len(), LOOKUP_REQUEST_MSG.len());
+        msg.emit(&mut buf[..]);
+        assert_eq!(buf, LOOKUP_REQUEST_MSG);
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use super::{buffer::StatsMessageBuffer, STATS_HEADER_LEN};
+use crate::{
+    utils::{Emitable, Parseable},
+    DecodeError,
+};
+
+// see `struct if_stats_msg` in https://github.com/torvalds/linux/blob/master/include/uapi/linux/if_link.h
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsHeader {
+    /// Address family: one of the `AF_*` constants.
+    pub family: u8,
+    /// Index of the link. `0` to dump the statistics of all the links.
+    pub ifindex: u32,
+    /// Statistics to retrieve: for each wanted `IFLA_STATS_*` attribute, the bit
+    /// `1 << (IFLA_STATS_* - 1)` must be set.
+    pub filter_mask: u32,
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_filter_mask(self.filter_mask);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<StatsMessageBuffer<&'a T>> for StatsHeader {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(StatsHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+            filter_mask: buf.filter_mask(),
+        })
+    }
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+
+use super::{buffer::StatsMessageBuffer, header::StatsHeader, nlas::Nla};
+use crate::{
+    utils::{Emitable, Parseable},
+    DecodeError,
+};
+use anyhow::Context;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for StatsMessage {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = StatsHeader::parse(buf).context("failed to parse stats message header")?;
+        let nlas = Vec::<Nla>::parse(buf).context("failed to parse stats message NLAs")?;
+        Ok(StatsMessage { header, nlas })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::{
+            link::Stats64,
+            stats::{Nla, OffloadXStats},
+        },
+        traits::{Emitable, ParseableParametrized},
+        RtnlMessage,
+        RtnlMessageBuffer,
+        StatsHeader,
+        StatsMessage,
+        RTM_NEWSTATS,
+    };
+
+    // Part of the reply to `ip stats show dev lo group offload`
+    #[rustfmt::skip]
+    static OFFLOAD_STATS: [u8; 40] = [
+        0x00, // family
+        0x00, 0x00, 0x00, // padding
+        0x01, 0x00, 0x00, 0x00, // ifindex = 1
+        0x18, 0x00, 0x00, 0x00, // filter mask = LINK_OFFLOAD_XSTATS | AF_SPEC
+        // NLA: IFLA_STATS_LINK_OFFLOAD_XSTATS (nested)
+        0x18, 0x00, 0x04, 0x80,
+        // NLA: IFLA_OFFLOAD_XSTATS_HW_S_INFO (nested)
+        0x14, 0x00, 0x02, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // NLA: IFLA_STATS_AF_SPEC (nested, empty)
+        0x04, 0x00, 0x05, 0x80,
+    ];
+
+    fn offload_stats() -> StatsMessage {
+        StatsMessage {
+            header: StatsHeader {
+                family: 0,
+                ifindex: 1,
+                filter_mask: 0x18,
+            },
+            nlas: vec![
+                Nla::LinkOffloadXStats(vec![OffloadXStats::HwStatsInfo(vec![
+                    0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00,
+                    0x00, 0x00, 0x00,
+                ])]),
+                Nla::AfSpec(vec![]),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_offload_stats() {
+        let actual = RtnlMessage::parse_with_param(
+            &RtnlMessageBuffer::new(&&OFFLOAD_STATS[..]),
+            RTM_NEWSTATS,
+        )
+        .unwrap();
+        assert_eq!(actual, RtnlMessage::NewStats(offload_stats()));
+    }
+
+    #[test]
+    fn emit_offload_stats() {
+        let msg = offload_stats();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &OFFLOAD_STATS[..]);
+    }
+
+    #[test]
+    fn link64_round_trip() {
+        let stats = Stats64 {
+            rx_packets: 8005,
+            tx_packets: 8005,
+            rx_bytes: 66802027,
+            tx_bytes: 66802027,
+            rx_errors: 0,
+            tx_errors: 0,
+            rx_dropped: 0,
+            tx_dropped: 0,
+            multicast: 0,
+            collisions: 0,
+            rx_length_errors: 0,
+            rx_over_errors: 0,
+            rx_crc_errors: 0,
+            rx_frame_errors: 0,
+            rx_fifo_errors: 0,
+            rx_missed_errors: 0,
+            tx_aborted_errors: 0,
+            tx_carrier_errors: 0,
+            tx_fifo_errors: 0,
+            tx_heartbeat_errors: 0,
+            tx_window_errors: 0,
+            rx_compressed: 0,
+            tx_compressed: 0,
+            rx_nohandler: 0,
+        };
+        let msg = StatsMessage {
+            header: StatsHeader {
+                family: 0,
+                ifindex: 1,
+                filter_mask: 1,
+            },
+            nlas: vec![Nla::Link64(stats)],
+        };
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(buf.len(), 12 + 4 + 192);
+        let actual =
+            RtnlMessage::parse_with_param(&RtnlMessageBuffer::new(&buf), RTM_NEWSTATS).unwrap();
+        assert_eq!(actual, RtnlMessage::NewStats(msg));
+    }
+}
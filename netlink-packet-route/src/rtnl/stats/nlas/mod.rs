@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+
+mod offload;
+pub use self::offload::*;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{
+        self,
+        link::{Stats64, Stats64Buffer},
+        DefaultNla,
+        NlaBuffer,
+        NlasIterator,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Netlink attributes for `RTM_NEWSTATS` and `RTM_GETSTATS` messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Link statistics, the same as `IFLA_STATS64` in link messages
+    Link64(Stats64),
+    /// Statistics specific to the link type, one attribute per `LINK_XSTATS_TYPE_*` type
+    LinkXStats(Vec<DefaultNla>),
+    /// Statistics specific to the type of the master of the link, one attribute per
+    /// `LINK_XSTATS_TYPE_*` type
+    LinkXStatsSlave(Vec<DefaultNla>),
+    LinkOffloadXStats(Vec<OffloadXStats>),
+    /// Statistics specific to an address family, one attribute per `AF_*` family
+    AfSpec(Vec<DefaultNla>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(ref bytes) => bytes.len(),
+            Link64(ref stats) => stats.buffer_len(),
+            LinkXStats(ref nlas)
+                | LinkXStatsSlave(ref nlas)
+                | AfSpec(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            LinkOffloadXStats(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Link64(ref stats) => stats.emit(buffer),
+            LinkXStats(ref nlas)
+                | LinkXStatsSlave(ref nlas)
+                | AfSpec(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            LinkOffloadXStats(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => IFLA_STATS_UNSPEC,
+            Link64(_) => IFLA_STATS_LINK_64,
+            LinkXStats(_) => IFLA_STATS_LINK_XSTATS,
+            LinkXStatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            LinkOffloadXStats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        use self::Nla::*;
+        matches!(
+            self,
+            LinkXStats(_) | LinkXStatsSlave(_) | LinkOffloadXStats(_) | AfSpec(_)
+        )
+    }
+}
+
+fn parse_default_nlas(payload: &[u8], err: &'static str) -> Result<Vec<DefaultNla>, DecodeError> {
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(payload) {
+        let nla = nla.context(err)?;
+        nlas.push(DefaultNla::parse(&nla).context(err)?);
+    }
+    Ok(nlas)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_STATS_LINK_64 => {
+                let err = "invalid IFLA_STATS_LINK_64 value";
+                Link64(
+                    Stats64::parse(&Stats64Buffer::new_checked(payload).context(err)?)
+                        .context(err)?,
+                )
+            }
+            IFLA_STATS_LINK_XSTATS => LinkXStats(parse_default_nlas(
+                payload,
+                "invalid IFLA_STATS_LINK_XSTATS value",
+            )?),
+            IFLA_STATS_LINK_XSTATS_SLAVE => LinkXStatsSlave(parse_default_nlas(
+                payload,
+                "invalid IFLA_STATS_LINK_XSTATS_SLAVE value",
+            )?),
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let err = "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    nlas.push(OffloadXStats::parse(&nla).context(err)?);
+                }
+                LinkOffloadXStats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => AfSpec(parse_default_nlas(
+                payload,
+                "invalid IFLA_STATS_AF_SPEC value",
+            )?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
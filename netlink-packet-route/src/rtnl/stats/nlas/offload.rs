@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{
+        self,
+        link::{Stats64, Stats64Buffer},
+        DefaultNla,
+        NlaBuffer,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Statistics of offloaded traffic, nested in `IFLA_STATS_LINK_OFFLOAD_XSTATS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OffloadXStats {
+    /// Statistics of the traffic that hit the CPU instead of being offloaded
+    CpuHit(Stats64),
+    /// Nested information about the hardware statistics suites of the link
+    HwStatsInfo(Vec<u8>),
+    /// Layer 3 hardware statistics (`struct rtnl_hw_stats64`)
+    L3Stats(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for OffloadXStats {
+    fn value_len(&self) -> usize {
+        use self::OffloadXStats::*;
+        match self {
+            CpuHit(ref stats) => stats.buffer_len(),
+            HwStatsInfo(ref bytes) | L3Stats(ref bytes) => bytes.len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::OffloadXStats::*;
+        match self {
+            CpuHit(ref stats) => stats.emit(buffer),
+            HwStatsInfo(ref bytes) | L3Stats(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::OffloadXStats::*;
+        match self {
+            CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            HwStatsInfo(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO,
+            L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, OffloadXStats::HwStatsInfo(_))
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for OffloadXStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::OffloadXStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                let err = "invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value";
+                CpuHit(
+                    Stats64::parse(&Stats64Buffer::new_checked(payload).context(err)?)
+                        .context(err)?,
+                )
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => HwStatsInfo(payload.to_vec()),
+            IFLA_OFFLOAD_XSTATS_L3_STATS => L3Stats(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
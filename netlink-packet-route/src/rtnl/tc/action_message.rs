@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    tc::{constants::*, Action},
+    traits::{Emitable, Parseable},
+    DecodeError,
+    TcActionMessageBuffer,
+    TC_ACTION_HEADER_LEN,
+};
+
+/// Message of the `RTM_{NEW,DEL,GET}ACTION` requests, which manage the
+/// actions independently of the filters. An action created this way can be
+/// shared by several filters, that refer to it by its kind and index.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcActionMessage {
+    pub header: TcActionHeader,
+    pub nlas: Vec<TcActionMessageNla>,
+}
+
+impl TcActionMessage {
+    pub fn into_parts(self) -> (TcActionHeader, Vec<TcActionMessageNla>) {
+        (self.header, self.nlas)
+    }
+
+    pub fn from_parts(header: TcActionHeader, nlas: Vec<TcActionMessageNla>) -> Self {
+        TcActionMessage { header, nlas }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcActionHeader {
+    pub family: u8,
+}
+
+impl Emitable for TcActionHeader {
+    fn buffer_len(&self) -> usize {
+        TC_ACTION_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcActionMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl Emitable for TcActionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcActionMessageBuffer<T>> for TcActionHeader {
+    fn parse(buf: &TcActionMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family(),
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>> for TcActionMessage {
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: TcActionHeader::parse(buf)
+                .context("failed to parse tc action message header")?,
+            nlas: Vec::<TcActionMessageNla>::parse(buf)
+                .context("failed to parse tc action message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>> for Vec<TcActionMessageNla> {
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            let buf = nla_buf.context("invalid tc action nla")?;
+            nlas.push(TcActionMessageNla::parse(&buf)?);
+        }
+        Ok(nlas)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TcActionMessageNla {
+    Unspec(Vec<u8>),
+    /// The actions, whose `tab` is their position in the list, starting
+    /// at 1
+    Actions(Vec<Action>),
+    /// `TCA_ACT_FLAG_*` flags of a dump request
+    Flags(NlaBitfield32),
+    /// Number of actions in a dump reply
+    Count(u32),
+    /// Only dump the actions used in the last given milliseconds
+    TimeDelta(u32),
+    ExtWarnMsg(String),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for TcActionMessageNla {
+    fn value_len(&self) -> usize {
+        use self::TcActionMessageNla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Actions(actions) => actions.as_slice().buffer_len(),
+            Flags(flags) => flags.buffer_len(),
+            Count(_) | TimeDelta(_) => 4,
+            ExtWarnMsg(msg) => msg.len() + 1,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::TcActionMessageNla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Actions(actions) => actions.as_slice().emit(buffer),
+            Flags(flags) => flags.emit(buffer),
+            Count(value) | TimeDelta(value) => NativeEndian::write_u32(buffer, *value),
+            ExtWarnMsg(msg) => {
+                buffer[..msg.len()].copy_from_slice(msg.as_bytes());
+                buffer[msg.len()] = 0;
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::TcActionMessageNla::*;
+        match self {
+            Unspec(_) => TCA_ROOT_UNSPEC,
+            Actions(_) => TCA_ROOT_TAB,
+            Flags(_) => TCA_ROOT_FLAGS,
+            Count(_) => TCA_ROOT_COUNT,
+            TimeDelta(_) => TCA_ROOT_TIME_DELTA,
+            ExtWarnMsg(_) => TCA_ROOT_EXT_WARN_MSG,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcActionMessageNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::TcActionMessageNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ROOT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_ROOT_TAB => {
+                let mut actions = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_ROOT_TAB")?;
+                    actions.push(Action::parse(&nla).context("failed to parse TCA_ROOT_TAB")?);
+                }
+                Actions(actions)
+            }
+            TCA_ROOT_FLAGS => Flags(
+                NlaBitfield32::parse(
+                    &NlaBitfield32Buffer::new_checked(payload).context("invalid TCA_ROOT_FLAGS")?,
+                )
+                .context("failed to parse TCA_ROOT_FLAGS")?,
+            ),
+            TCA_ROOT_COUNT => Count(parse_u32(payload).context("failed to parse TCA_ROOT_COUNT")?),
+            TCA_ROOT_TIME_DELTA => {
+                TimeDelta(parse_u32(payload).context("failed to parse TCA_ROOT_TIME_DELTA")?)
+            }
+            TCA_ROOT_EXT_WARN_MSG => {
+                ExtWarnMsg(parse_string(payload).context("failed to parse TCA_ROOT_EXT_WARN_MSG")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("failed to parse tc action nla")?),
+        })
+    }
+}
+
+pub const NLA_BITFIELD32_LEN: usize = 8;
+
+/// Flags, along with the mask of the flags they set (`struct nla_bitfield32`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct NlaBitfield32 {
+    pub value: u32,
+    pub selector: u32,
+}
+
+buffer!(NlaBitfield32Buffer(NLA_BITFIELD32_LEN) {
+    value: (u32, 0..4),
+    selector: (u32, 4..NLA_BITFIELD32_LEN),
+});
+
+impl Emitable for NlaBitfield32 {
+    fn buffer_len(&self) -> usize {
+        NLA_BITFIELD32_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NlaBitfield32Buffer::new(buffer);
+        packet.set_value(self.value);
+        packet.set_selector(self.selector);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NlaBitfield32Buffer<T>> for NlaBitfield32 {
+    fn parse(buf: &NlaBitfield32Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            value: buf.value(),
+            selector: buf.selector(),
+        })
+    }
+}
@@ -23,3 +23,18 @@ impl<'a, T: AsRef<[u8]> + ?Sized> TcMessageBuffer<&'a T> {
         NlasIterator::new(self.payload())
     }
 }
+
+pub const TC_ACTION_HEADER_LEN: usize = 4;
+
+buffer!(TcActionMessageBuffer(TC_ACTION_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..TC_ACTION_HEADER_LEN),
+    payload: (slice, TC_ACTION_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TcActionMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
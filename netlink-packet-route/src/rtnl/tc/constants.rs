@@ -43,6 +43,151 @@ pub const TC_U32_VAROFFSET: u8 = 4;
 pub const TC_U32_EAT: u8 = 8;
 pub const TC_U32_MAXDEPTH: u8 = 8;
 
+/// Flower filter
+pub const TCA_FLOWER_UNSPEC: u16 = 0;
+pub const TCA_FLOWER_CLASSID: u16 = 1;
+pub const TCA_FLOWER_INDEV: u16 = 2;
+pub const TCA_FLOWER_ACT: u16 = 3;
+pub const TCA_FLOWER_KEY_ETH_DST: u16 = 4;
+pub const TCA_FLOWER_KEY_ETH_DST_MASK: u16 = 5;
+pub const TCA_FLOWER_KEY_ETH_SRC: u16 = 6;
+pub const TCA_FLOWER_KEY_ETH_SRC_MASK: u16 = 7;
+pub const TCA_FLOWER_KEY_ETH_TYPE: u16 = 8;
+pub const TCA_FLOWER_KEY_IP_PROTO: u16 = 9;
+pub const TCA_FLOWER_KEY_IPV4_SRC: u16 = 10;
+pub const TCA_FLOWER_KEY_IPV4_SRC_MASK: u16 = 11;
+pub const TCA_FLOWER_KEY_IPV4_DST: u16 = 12;
+pub const TCA_FLOWER_KEY_IPV4_DST_MASK: u16 = 13;
+pub const TCA_FLOWER_KEY_IPV6_SRC: u16 = 14;
+pub const TCA_FLOWER_KEY_IPV6_SRC_MASK: u16 = 15;
+pub const TCA_FLOWER_KEY_IPV6_DST: u16 = 16;
+pub const TCA_FLOWER_KEY_IPV6_DST_MASK: u16 = 17;
+pub const TCA_FLOWER_KEY_TCP_SRC: u16 = 18;
+pub const TCA_FLOWER_KEY_TCP_DST: u16 = 19;
+pub const TCA_FLOWER_KEY_UDP_SRC: u16 = 20;
+pub const TCA_FLOWER_KEY_UDP_DST: u16 = 21;
+pub const TCA_FLOWER_FLAGS: u16 = 22;
+pub const TCA_FLOWER_KEY_VLAN_ID: u16 = 23;
+pub const TCA_FLOWER_KEY_VLAN_PRIO: u16 = 24;
+pub const TCA_FLOWER_KEY_VLAN_ETH_TYPE: u16 = 25;
+pub const TCA_FLOWER_KEY_ENC_KEY_ID: u16 = 26;
+pub const TCA_FLOWER_KEY_ENC_IPV4_SRC: u16 = 27;
+pub const TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK: u16 = 28;
+pub const TCA_FLOWER_KEY_ENC_IPV4_DST: u16 = 29;
+pub const TCA_FLOWER_KEY_ENC_IPV4_DST_MASK: u16 = 30;
+pub const TCA_FLOWER_KEY_ENC_IPV6_SRC: u16 = 31;
+pub const TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK: u16 = 32;
+pub const TCA_FLOWER_KEY_ENC_IPV6_DST: u16 = 33;
+pub const TCA_FLOWER_KEY_ENC_IPV6_DST_MASK: u16 = 34;
+pub const TCA_FLOWER_KEY_TCP_SRC_MASK: u16 = 35;
+pub const TCA_FLOWER_KEY_TCP_DST_MASK: u16 = 36;
+pub const TCA_FLOWER_KEY_UDP_SRC_MASK: u16 = 37;
+pub const TCA_FLOWER_KEY_UDP_DST_MASK: u16 = 38;
+pub const TCA_FLOWER_KEY_SCTP_SRC_MASK: u16 = 39;
+pub const TCA_FLOWER_KEY_SCTP_DST_MASK: u16 = 40;
+pub const TCA_FLOWER_KEY_SCTP_SRC: u16 = 41;
+pub const TCA_FLOWER_KEY_SCTP_DST: u16 = 42;
+pub const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT: u16 = 43;
+pub const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK: u16 = 44;
+pub const TCA_FLOWER_KEY_ENC_UDP_DST_PORT: u16 = 45;
+pub const TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK: u16 = 46;
+pub const TCA_FLOWER_KEY_FLAGS: u16 = 47;
+pub const TCA_FLOWER_KEY_FLAGS_MASK: u16 = 48;
+pub const TCA_FLOWER_KEY_ICMPV4_CODE: u16 = 49;
+pub const TCA_FLOWER_KEY_ICMPV4_CODE_MASK: u16 = 50;
+pub const TCA_FLOWER_KEY_ICMPV4_TYPE: u16 = 51;
+pub const TCA_FLOWER_KEY_ICMPV4_TYPE_MASK: u16 = 52;
+pub const TCA_FLOWER_KEY_ICMPV6_CODE: u16 = 53;
+pub const TCA_FLOWER_KEY_ICMPV6_CODE_MASK: u16 = 54;
+pub const TCA_FLOWER_KEY_ICMPV6_TYPE: u16 = 55;
+pub const TCA_FLOWER_KEY_ICMPV6_TYPE_MASK: u16 = 56;
+pub const TCA_FLOWER_KEY_ARP_SIP: u16 = 57;
+pub const TCA_FLOWER_KEY_ARP_SIP_MASK: u16 = 58;
+pub const TCA_FLOWER_KEY_ARP_TIP: u16 = 59;
+pub const TCA_FLOWER_KEY_ARP_TIP_MASK: u16 = 60;
+pub const TCA_FLOWER_KEY_ARP_OP: u16 = 61;
+pub const TCA_FLOWER_KEY_ARP_OP_MASK: u16 = 62;
+pub const TCA_FLOWER_KEY_ARP_SHA: u16 = 63;
+pub const TCA_FLOWER_KEY_ARP_SHA_MASK: u16 = 64;
+pub const TCA_FLOWER_KEY_ARP_THA: u16 = 65;
+pub const TCA_FLOWER_KEY_ARP_THA_MASK: u16 = 66;
+pub const TCA_FLOWER_KEY_MPLS_TTL: u16 = 67;
+pub const TCA_FLOWER_KEY_MPLS_BOS: u16 = 68;
+pub const TCA_FLOWER_KEY_MPLS_TC: u16 = 69;
+pub const TCA_FLOWER_KEY_MPLS_LABEL: u16 = 70;
+pub const TCA_FLOWER_KEY_TCP_FLAGS: u16 = 71;
+pub const TCA_FLOWER_KEY_TCP_FLAGS_MASK: u16 = 72;
+pub const TCA_FLOWER_KEY_IP_TOS: u16 = 73;
+pub const TCA_FLOWER_KEY_IP_TOS_MASK: u16 = 74;
+pub const TCA_FLOWER_KEY_IP_TTL: u16 = 75;
+pub const TCA_FLOWER_KEY_IP_TTL_MASK: u16 = 76;
+pub const TCA_FLOWER_KEY_CVLAN_ID: u16 = 77;
+pub const TCA_FLOWER_KEY_CVLAN_PRIO: u16 = 78;
+pub const TCA_FLOWER_KEY_CVLAN_ETH_TYPE: u16 = 79;
+pub const TCA_FLOWER_KEY_ENC_IP_TOS: u16 = 80;
+pub const TCA_FLOWER_KEY_ENC_IP_TOS_MASK: u16 = 81;
+pub const TCA_FLOWER_KEY_ENC_IP_TTL: u16 = 82;
+pub const TCA_FLOWER_KEY_ENC_IP_TTL_MASK: u16 = 83;
+pub const TCA_FLOWER_KEY_ENC_OPTS: u16 = 84;
+pub const TCA_FLOWER_KEY_ENC_OPTS_MASK: u16 = 85;
+pub const TCA_FLOWER_IN_HW_COUNT: u16 = 86;
+pub const TCA_FLOWER_KEY_PORT_SRC_MIN: u16 = 87;
+pub const TCA_FLOWER_KEY_PORT_SRC_MAX: u16 = 88;
+pub const TCA_FLOWER_KEY_PORT_DST_MIN: u16 = 89;
+pub const TCA_FLOWER_KEY_PORT_DST_MAX: u16 = 90;
+pub const TCA_FLOWER_KEY_CT_STATE: u16 = 91;
+pub const TCA_FLOWER_KEY_CT_STATE_MASK: u16 = 92;
+pub const TCA_FLOWER_KEY_CT_ZONE: u16 = 93;
+pub const TCA_FLOWER_KEY_CT_ZONE_MASK: u16 = 94;
+pub const TCA_FLOWER_KEY_CT_MARK: u16 = 95;
+pub const TCA_FLOWER_KEY_CT_MARK_MASK: u16 = 96;
+pub const TCA_FLOWER_KEY_CT_LABELS: u16 = 97;
+pub const TCA_FLOWER_KEY_CT_LABELS_MASK: u16 = 98;
+pub const TCA_FLOWER_KEY_MPLS_OPTS: u16 = 99;
+pub const TCA_FLOWER_KEY_HASH: u16 = 100;
+pub const TCA_FLOWER_KEY_HASH_MASK: u16 = 101;
+pub const TCA_FLOWER_KEY_NUM_OF_VLANS: u16 = 102;
+pub const TCA_FLOWER_KEY_PPPOE_SID: u16 = 103;
+pub const TCA_FLOWER_KEY_PPP_PROTO: u16 = 104;
+pub const TCA_FLOWER_KEY_L2TPV3_SID: u16 = 105;
+
+/// Flower flow key flags
+pub const TCA_FLOWER_KEY_FLAGS_IS_FRAGMENT: u32 = 1;
+pub const TCA_FLOWER_KEY_FLAGS_FRAG_IS_FIRST: u32 = 2;
+
+/// Flower connection tracking state
+pub const TCA_FLOWER_KEY_CT_FLAGS_NEW: u16 = 1;
+pub const TCA_FLOWER_KEY_CT_FLAGS_ESTABLISHED: u16 = 2;
+pub const TCA_FLOWER_KEY_CT_FLAGS_RELATED: u16 = 4;
+pub const TCA_FLOWER_KEY_CT_FLAGS_TRACKED: u16 = 8;
+pub const TCA_FLOWER_KEY_CT_FLAGS_INVALID: u16 = 16;
+pub const TCA_FLOWER_KEY_CT_FLAGS_REPLY: u16 = 32;
+
+/// Classifier flags
+pub const TCA_CLS_FLAGS_SKIP_HW: u32 = 1;
+pub const TCA_CLS_FLAGS_SKIP_SW: u32 = 2;
+pub const TCA_CLS_FLAGS_IN_HW: u32 = 4;
+pub const TCA_CLS_FLAGS_NOT_IN_HW: u32 = 8;
+pub const TCA_CLS_FLAGS_VERBOSE: u32 = 16;
+
+/// BPF filter
+pub const TCA_BPF_UNSPEC: u16 = 0;
+pub const TCA_BPF_ACT: u16 = 1;
+pub const TCA_BPF_POLICE: u16 = 2;
+pub const TCA_BPF_CLASSID: u16 = 3;
+pub const TCA_BPF_OPS_LEN: u16 = 4;
+pub const TCA_BPF_OPS: u16 = 5;
+pub const TCA_BPF_FD: u16 = 6;
+pub const TCA_BPF_NAME: u16 = 7;
+pub const TCA_BPF_FLAGS: u16 = 8;
+pub const TCA_BPF_FLAGS_GEN: u16 = 9;
+pub const TCA_BPF_TAG: u16 = 10;
+pub const TCA_BPF_ID: u16 = 11;
+pub const TCA_BPF_MAX: u16 = TCA_BPF_ID;
+
+/// BPF filter flags
+pub const TCA_BPF_FLAG_ACT_DIRECT: u32 = 1;
+
 /// Action attributes
 pub const TCA_ACT_UNSPEC: u16 = 0;
 pub const TCA_ACT_KIND: u16 = 1;
@@ -77,10 +222,24 @@ pub const TC_ACT_TRAP: i32 = 8;
 pub const TC_ACT_VALUE_MAX: i32 = TC_ACT_TRAP;
 
 pub const TC_ACT_JUMP: i32 = 0x10000000;
+pub const TC_ACT_GOTO_CHAIN: i32 = 0x20000000;
+pub const TC_ACT_EXT_VAL_MASK: i32 = 0x0fffffff;
 
 pub const TCA_ACT_TAB: u16 = 1; // TCA_ROOT_TAB
 pub const TCAA_MAX: u16 = 1;
 
+/// Action message attributes
+pub const TCA_ROOT_UNSPEC: u16 = 0;
+pub const TCA_ROOT_TAB: u16 = 1;
+pub const TCA_ROOT_FLAGS: u16 = 2;
+pub const TCA_ROOT_COUNT: u16 = 3;
+pub const TCA_ROOT_TIME_DELTA: u16 = 4;
+pub const TCA_ROOT_EXT_WARN_MSG: u16 = 5;
+
+pub const TCA_FLAG_LARGE_DUMP_ON: u32 = 1;
+pub const TCA_ACT_FLAG_LARGE_DUMP_ON: u32 = TCA_FLAG_LARGE_DUMP_ON;
+pub const TCA_ACT_FLAG_TERSE_DUMP: u32 = 2;
+
 /// Mirred action attr
 pub const TCA_MIRRED_UNSPEC: u16 = 0;
 pub const TCA_MIRRED_TM: u16 = 1;
@@ -92,3 +251,242 @@ pub const TCA_EGRESS_REDIR: i32 = 1; /* packet redirect to EGRESS */
 pub const TCA_EGRESS_MIRROR: i32 = 2; /* mirror packet to EGRESS */
 pub const TCA_INGRESS_REDIR: i32 = 3; /* packet redirect to INGRESS */
 pub const TCA_INGRESS_MIRROR: i32 = 4; /* mirror packet to INGRESS */
+
+/// BPF action attr
+pub const TCA_ACT_BPF_UNSPEC: u16 = 0;
+pub const TCA_ACT_BPF_TM: u16 = 1;
+pub const TCA_ACT_BPF_PARMS: u16 = 2;
+pub const TCA_ACT_BPF_OPS_LEN: u16 = 3;
+pub const TCA_ACT_BPF_OPS: u16 = 4;
+pub const TCA_ACT_BPF_FD: u16 = 5;
+pub const TCA_ACT_BPF_NAME: u16 = 6;
+pub const TCA_ACT_BPF_PAD: u16 = 7;
+pub const TCA_ACT_BPF_TAG: u16 = 8;
+pub const TCA_ACT_BPF_ID: u16 = 9;
+pub const TCA_ACT_BPF_MAX: u16 = TCA_ACT_BPF_ID;
+
+/// Rate tables
+pub const TC_RTAB_SIZE: usize = 1024;
+
+pub const TC_LINKLAYER_UNAWARE: u8 = 0;
+pub const TC_LINKLAYER_ETHERNET: u8 = 1;
+pub const TC_LINKLAYER_ATM: u8 = 2;
+
+/// Generic action attr
+pub const TCA_GACT_UNSPEC: u16 = 0;
+pub const TCA_GACT_TM: u16 = 1;
+pub const TCA_GACT_PARMS: u16 = 2;
+pub const TCA_GACT_PROB: u16 = 3;
+pub const TCA_GACT_PAD: u16 = 4;
+pub const TCA_GACT_MAX: u16 = TCA_GACT_PAD;
+
+pub const PGACT_NONE: u16 = 0;
+pub const PGACT_NETRAND: u16 = 1;
+pub const PGACT_DETERM: u16 = 2;
+
+/// Police action attr
+pub const TCA_POLICE_UNSPEC: u16 = 0;
+pub const TCA_POLICE_TBF: u16 = 1;
+pub const TCA_POLICE_RATE: u16 = 2;
+pub const TCA_POLICE_PEAKRATE: u16 = 3;
+pub const TCA_POLICE_AVRATE: u16 = 4;
+pub const TCA_POLICE_RESULT: u16 = 5;
+pub const TCA_POLICE_TM: u16 = 6;
+pub const TCA_POLICE_PAD: u16 = 7;
+pub const TCA_POLICE_RATE64: u16 = 8;
+pub const TCA_POLICE_PEAKRATE64: u16 = 9;
+pub const TCA_POLICE_PKTRATE64: u16 = 10;
+pub const TCA_POLICE_PKTBURST64: u16 = 11;
+pub const TCA_POLICE_MAX: u16 = TCA_POLICE_PKTBURST64;
+
+/// Skbedit action attr
+pub const TCA_SKBEDIT_UNSPEC: u16 = 0;
+pub const TCA_SKBEDIT_TM: u16 = 1;
+pub const TCA_SKBEDIT_PARMS: u16 = 2;
+pub const TCA_SKBEDIT_PRIORITY: u16 = 3;
+pub const TCA_SKBEDIT_QUEUE_MAPPING: u16 = 4;
+pub const TCA_SKBEDIT_MARK: u16 = 5;
+pub const TCA_SKBEDIT_PAD: u16 = 6;
+pub const TCA_SKBEDIT_PTYPE: u16 = 7;
+pub const TCA_SKBEDIT_MASK: u16 = 8;
+pub const TCA_SKBEDIT_FLAGS: u16 = 9;
+pub const TCA_SKBEDIT_QUEUE_MAPPING_MAX: u16 = 10;
+pub const TCA_SKBEDIT_MAX: u16 = TCA_SKBEDIT_QUEUE_MAPPING_MAX;
+
+pub const SKBEDIT_F_PRIORITY: u64 = 0x1;
+pub const SKBEDIT_F_QUEUE_MAPPING: u64 = 0x2;
+pub const SKBEDIT_F_MARK: u64 = 0x4;
+pub const SKBEDIT_F_PTYPE: u64 = 0x8;
+pub const SKBEDIT_F_MASK: u64 = 0x10;
+pub const SKBEDIT_F_INHERITDSFIELD: u64 = 0x20;
+pub const SKBEDIT_F_TXQ_SKBHASH: u64 = 0x40;
+
+/// Vlan action attr
+pub const TCA_VLAN_UNSPEC: u16 = 0;
+pub const TCA_VLAN_TM: u16 = 1;
+pub const TCA_VLAN_PARMS: u16 = 2;
+pub const TCA_VLAN_PUSH_VLAN_ID: u16 = 3;
+pub const TCA_VLAN_PUSH_VLAN_PROTOCOL: u16 = 4;
+pub const TCA_VLAN_PAD: u16 = 5;
+pub const TCA_VLAN_PUSH_VLAN_PRIORITY: u16 = 6;
+pub const TCA_VLAN_PUSH_ETH_DST: u16 = 7;
+pub const TCA_VLAN_PUSH_ETH_SRC: u16 = 8;
+pub const TCA_VLAN_MAX: u16 = TCA_VLAN_PUSH_ETH_SRC;
+
+pub const TCA_VLAN_ACT_POP: i32 = 1;
+pub const TCA_VLAN_ACT_PUSH: i32 = 2;
+pub const TCA_VLAN_ACT_MODIFY: i32 = 3;
+pub const TCA_VLAN_ACT_POP_ETH: i32 = 4;
+pub const TCA_VLAN_ACT_PUSH_ETH: i32 = 5;
+
+/// Tunnel key action attr
+pub const TCA_TUNNEL_KEY_UNSPEC: u16 = 0;
+pub const TCA_TUNNEL_KEY_TM: u16 = 1;
+pub const TCA_TUNNEL_KEY_PARMS: u16 = 2;
+pub const TCA_TUNNEL_KEY_ENC_IPV4_SRC: u16 = 3;
+pub const TCA_TUNNEL_KEY_ENC_IPV4_DST: u16 = 4;
+pub const TCA_TUNNEL_KEY_ENC_IPV6_SRC: u16 = 5;
+pub const TCA_TUNNEL_KEY_ENC_IPV6_DST: u16 = 6;
+pub const TCA_TUNNEL_KEY_ENC_KEY_ID: u16 = 7;
+pub const TCA_TUNNEL_KEY_PAD: u16 = 8;
+pub const TCA_TUNNEL_KEY_ENC_DST_PORT: u16 = 9;
+pub const TCA_TUNNEL_KEY_NO_CSUM: u16 = 10;
+pub const TCA_TUNNEL_KEY_ENC_OPTS: u16 = 11;
+pub const TCA_TUNNEL_KEY_ENC_TOS: u16 = 12;
+pub const TCA_TUNNEL_KEY_ENC_TTL: u16 = 13;
+pub const TCA_TUNNEL_KEY_NO_FRAG: u16 = 14;
+pub const TCA_TUNNEL_KEY_MAX: u16 = TCA_TUNNEL_KEY_NO_FRAG;
+
+pub const TCA_TUNNEL_KEY_ACT_SET: i32 = 1;
+pub const TCA_TUNNEL_KEY_ACT_RELEASE: i32 = 2;
+
+/// Pedit action attr
+pub const TCA_PEDIT_UNSPEC: u16 = 0;
+pub const TCA_PEDIT_TM: u16 = 1;
+pub const TCA_PEDIT_PARMS: u16 = 2;
+pub const TCA_PEDIT_PAD: u16 = 3;
+pub const TCA_PEDIT_PARMS_EX: u16 = 4;
+pub const TCA_PEDIT_KEYS_EX: u16 = 5;
+pub const TCA_PEDIT_KEY_EX: u16 = 6;
+pub const TCA_PEDIT_MAX: u16 = TCA_PEDIT_KEY_EX;
+
+pub const TCA_PEDIT_KEY_EX_HTYPE: u16 = 1;
+pub const TCA_PEDIT_KEY_EX_CMD: u16 = 2;
+
+pub const TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK: u16 = 0;
+pub const TCA_PEDIT_KEY_EX_HDR_TYPE_ETH: u16 = 1;
+pub const TCA_PEDIT_KEY_EX_HDR_TYPE_IP4: u16 = 2;
+pub const TCA_PEDIT_KEY_EX_HDR_TYPE_IP6: u16 = 3;
+pub const TCA_PEDIT_KEY_EX_HDR_TYPE_TCP: u16 = 4;
+pub const TCA_PEDIT_KEY_EX_HDR_TYPE_UDP: u16 = 5;
+
+pub const TCA_PEDIT_KEY_EX_CMD_SET: u16 = 0;
+pub const TCA_PEDIT_KEY_EX_CMD_ADD: u16 = 1;
+
+/// HTB qdisc and class attr
+pub const TCA_HTB_UNSPEC: u16 = 0;
+pub const TCA_HTB_PARMS: u16 = 1;
+pub const TCA_HTB_INIT: u16 = 2;
+pub const TCA_HTB_CTAB: u16 = 3;
+pub const TCA_HTB_RTAB: u16 = 4;
+pub const TCA_HTB_DIRECT_QLEN: u16 = 5;
+pub const TCA_HTB_RATE64: u16 = 6;
+pub const TCA_HTB_CEIL64: u16 = 7;
+pub const TCA_HTB_PAD: u16 = 8;
+pub const TCA_HTB_OFFLOAD: u16 = 9;
+pub const TCA_HTB_MAX: u16 = TCA_HTB_OFFLOAD;
+
+pub const TC_HTB_PROTOVER: u32 = 3;
+pub const TC_HTB_NUMPRIO: u32 = 8;
+pub const TC_HTB_MAXDEPTH: u32 = 8;
+
+/// TBF qdisc attr
+pub const TCA_TBF_UNSPEC: u16 = 0;
+pub const TCA_TBF_PARMS: u16 = 1;
+pub const TCA_TBF_RTAB: u16 = 2;
+pub const TCA_TBF_PTAB: u16 = 3;
+pub const TCA_TBF_RATE64: u16 = 4;
+pub const TCA_TBF_PRATE64: u16 = 5;
+pub const TCA_TBF_BURST: u16 = 6;
+pub const TCA_TBF_PBURST: u16 = 7;
+pub const TCA_TBF_PAD: u16 = 8;
+pub const TCA_TBF_MAX: u16 = TCA_TBF_PAD;
+
+/// FQ_CoDel qdisc attr
+pub const TCA_FQ_CODEL_UNSPEC: u16 = 0;
+pub const TCA_FQ_CODEL_TARGET: u16 = 1;
+pub const TCA_FQ_CODEL_LIMIT: u16 = 2;
+pub const TCA_FQ_CODEL_INTERVAL: u16 = 3;
+pub const TCA_FQ_CODEL_ECN: u16 = 4;
+pub const TCA_FQ_CODEL_FLOWS: u16 = 5;
+pub const TCA_FQ_CODEL_QUANTUM: u16 = 6;
+pub const TCA_FQ_CODEL_CE_THRESHOLD: u16 = 7;
+pub const TCA_FQ_CODEL_DROP_BATCH_SIZE: u16 = 8;
+pub const TCA_FQ_CODEL_MEMORY_LIMIT: u16 = 9;
+pub const TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR: u16 = 10;
+pub const TCA_FQ_CODEL_CE_THRESHOLD_MASK: u16 = 11;
+pub const TCA_FQ_CODEL_MAX: u16 = TCA_FQ_CODEL_CE_THRESHOLD_MASK;
+
+pub const TCA_FQ_CODEL_XSTATS_QDISC: u32 = 0;
+pub const TCA_FQ_CODEL_XSTATS_CLASS: u32 = 1;
+
+/// FQ qdisc attr
+pub const TCA_FQ_UNSPEC: u16 = 0;
+pub const TCA_FQ_PLIMIT: u16 = 1;
+pub const TCA_FQ_FLOW_PLIMIT: u16 = 2;
+pub const TCA_FQ_QUANTUM: u16 = 3;
+pub const TCA_FQ_INITIAL_QUANTUM: u16 = 4;
+pub const TCA_FQ_RATE_ENABLE: u16 = 5;
+pub const TCA_FQ_FLOW_DEFAULT_RATE: u16 = 6;
+pub const TCA_FQ_FLOW_MAX_RATE: u16 = 7;
+pub const TCA_FQ_BUCKETS_LOG: u16 = 8;
+pub const TCA_FQ_FLOW_REFILL_DELAY: u16 = 9;
+pub const TCA_FQ_ORPHAN_MASK: u16 = 10;
+pub const TCA_FQ_LOW_RATE_THRESHOLD: u16 = 11;
+pub const TCA_FQ_CE_THRESHOLD: u16 = 12;
+pub const TCA_FQ_TIMER_SLACK: u16 = 13;
+pub const TCA_FQ_HORIZON: u16 = 14;
+pub const TCA_FQ_HORIZON_DROP: u16 = 15;
+pub const TCA_FQ_MAX: u16 = TCA_FQ_HORIZON_DROP;
+
+/// Netem qdisc attr
+pub const TCA_NETEM_UNSPEC: u16 = 0;
+pub const TCA_NETEM_CORR: u16 = 1;
+pub const TCA_NETEM_DELAY_DIST: u16 = 2;
+pub const TCA_NETEM_REORDER: u16 = 3;
+pub const TCA_NETEM_CORRUPT: u16 = 4;
+pub const TCA_NETEM_LOSS: u16 = 5;
+pub const TCA_NETEM_RATE: u16 = 6;
+pub const TCA_NETEM_ECN: u16 = 7;
+pub const TCA_NETEM_RATE64: u16 = 8;
+pub const TCA_NETEM_PAD: u16 = 9;
+pub const TCA_NETEM_LATENCY64: u16 = 10;
+pub const TCA_NETEM_JITTER64: u16 = 11;
+pub const TCA_NETEM_SLOT: u16 = 12;
+pub const TCA_NETEM_SLOT_DIST: u16 = 13;
+pub const TCA_NETEM_PRNG_SEED: u16 = 14;
+pub const TCA_NETEM_MAX: u16 = TCA_NETEM_PRNG_SEED;
+
+/// Prio qdisc
+pub const TCQ_PRIO_BANDS: usize = 16;
+pub const TC_PRIO_MAX: usize = 15;
+
+/// Mqprio qdisc attr
+pub const TC_QOPT_BITMASK: usize = 15;
+pub const TC_QOPT_MAX_QUEUE: usize = 16;
+
+pub const TCA_MQPRIO_UNSPEC: u16 = 0;
+pub const TCA_MQPRIO_MODE: u16 = 1;
+pub const TCA_MQPRIO_SHAPER: u16 = 2;
+pub const TCA_MQPRIO_MIN_RATE64: u16 = 3;
+pub const TCA_MQPRIO_MAX_RATE64: u16 = 4;
+pub const TCA_MQPRIO_MAX: u16 = TCA_MQPRIO_MAX_RATE64;
+
+pub const TC_MQPRIO_HW_OFFLOAD_NONE: u8 = 0;
+pub const TC_MQPRIO_HW_OFFLOAD_TCS: u8 = 1;
+
+pub const TC_MQPRIO_MODE_DCB: u16 = 0;
+pub const TC_MQPRIO_MODE_CHANNEL: u16 = 1;
+
+pub const TC_MQPRIO_SHAPER_DCB: u16 = 0;
+pub const TC_MQPRIO_SHAPER_BW_RATE: u16 = 1;
@@ -5,11 +5,11 @@ use anyhow::Context;
 use crate::{
     constants::*,
     nlas::{
-        tc::{Nla, Stats, Stats2, StatsBuffer, TcOpt},
+        tc::{parse_qopt, Nla, Stats, Stats2, StatsBuffer, TcOpt, XStats},
         DefaultNla,
         NlasIterator,
     },
-    parsers::{parse_string, parse_u8},
+    parsers::{parse_string, parse_u32, parse_u8},
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
     TcMessageBuffer,
@@ -119,7 +119,8 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcMessageBuffer<&'a T>> for Vec<Nla> {
                     Nla::Kind(kind.clone())
                 }
                 TCA_OPTIONS => {
-                    let mut nlas = vec![];
+                    let (mut nlas, payload) =
+                        parse_qopt(payload, &kind).context("failed to parse TCA_OPTIONS")?;
                     for nla in NlasIterator::new(payload) {
                         let nla = nla.context("invalid TCA_OPTIONS")?;
                         nlas.push(
@@ -133,7 +134,9 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcMessageBuffer<&'a T>> for Vec<Nla> {
                     Stats::parse(&StatsBuffer::new_checked(payload).context("invalid TCA_STATS")?)
                         .context("failed to parse TCA_STATS")?,
                 ),
-                TCA_XSTATS => Nla::XStats(payload.to_vec()),
+                TCA_XSTATS => Nla::XStats(
+                    XStats::parse_with_param(&buf, &kind).context("failed to parse TCA_XSTATS")?,
+                ),
                 TCA_RATE => Nla::Rate(payload.to_vec()),
                 TCA_FCNT => Nla::Fcnt(payload.to_vec()),
                 TCA_STATS2 => {
@@ -149,6 +152,12 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcMessageBuffer<&'a T>> for Vec<Nla> {
                 TCA_HW_OFFLOAD => {
                     Nla::HwOffload(parse_u8(payload).context("failed to parse TCA_HW_OFFLOAD")?)
                 }
+                TCA_INGRESS_BLOCK => Nla::IngressBlock(
+                    parse_u32(payload).context("failed to parse TCA_INGRESS_BLOCK")?,
+                ),
+                TCA_EGRESS_BLOCK => Nla::EgressBlock(
+                    parse_u32(payload).context("failed to parse TCA_EGRESS_BLOCK")?,
+                ),
                 _ => Nla::Other(DefaultNla::parse(&buf).context("failed to parse tc nla")?),
             };
 
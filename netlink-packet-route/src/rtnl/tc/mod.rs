@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: MIT
 
+mod action_message;
 mod buffer;
 pub mod constants;
 mod message;
 pub mod nlas;
 
-pub use self::{buffer::*, message::*, nlas::*};
+pub use self::{action_message::*, buffer::*, message::*, nlas::*};
 
 #[cfg(test)]
 mod test;
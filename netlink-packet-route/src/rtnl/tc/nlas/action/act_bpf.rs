@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+
+/// BPF action
+///
+/// The BPF action runs a classic BPF program (`Ops`) or an eBPF program
+/// loaded with `bpf(2)` (`Fd`) and uses its return code as the action
+/// verdict.
+use std::os::unix::io::RawFd;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_i32, parse_string, parse_u16, parse_u32},
+    tc::{constants::*, TcGen, TcGenBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "bpf";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tm(Vec<u8>),
+    Parms(TcGen),
+    /// Number of classic BPF instructions in `Ops`
+    OpsLen(u16),
+    /// Classic BPF program, as an array of `struct sock_filter`
+    Ops(Vec<u8>),
+    /// File descriptor of the eBPF program to run. Only used in requests.
+    Fd(RawFd),
+    /// Name of the eBPF program
+    Name(String),
+    Pad(Vec<u8>),
+    /// Tag of the eBPF program. Only reported by the kernel.
+    Tag(Vec<u8>),
+    /// ID of the eBPF program. Only reported by the kernel.
+    Id(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Ops(bytes) | Pad(bytes) | Tag(bytes) => bytes.len(),
+            Parms(p) => p.buffer_len(),
+            OpsLen(_) => 2,
+            Fd(_) | Id(_) => 4,
+            Name(s) => s.len() + 1,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Ops(bytes) | Pad(bytes) | Tag(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Parms(p) => p.emit(buffer),
+            OpsLen(i) => NativeEndian::write_u16(buffer, *i),
+            Fd(fd) => NativeEndian::write_i32(buffer, *fd),
+            Id(i) => NativeEndian::write_u32(buffer, *i),
+            Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_ACT_BPF_UNSPEC,
+            Tm(_) => TCA_ACT_BPF_TM,
+            Parms(_) => TCA_ACT_BPF_PARMS,
+            OpsLen(_) => TCA_ACT_BPF_OPS_LEN,
+            Ops(_) => TCA_ACT_BPF_OPS,
+            Fd(_) => TCA_ACT_BPF_FD,
+            Name(_) => TCA_ACT_BPF_NAME,
+            Pad(_) => TCA_ACT_BPF_PAD,
+            Tag(_) => TCA_ACT_BPF_TAG,
+            Id(_) => TCA_ACT_BPF_ID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ACT_BPF_UNSPEC => Unspec(payload.to_vec()),
+            TCA_ACT_BPF_TM => Tm(payload.to_vec()),
+            TCA_ACT_BPF_PARMS => Parms(
+                TcGen::parse(
+                    &TcGenBuffer::new_checked(payload).context("invalid TCA_ACT_BPF_PARMS")?,
+                )
+                .context("failed to parse TCA_ACT_BPF_PARMS")?,
+            ),
+            TCA_ACT_BPF_OPS_LEN => {
+                OpsLen(parse_u16(payload).context("failed to parse TCA_ACT_BPF_OPS_LEN")?)
+            }
+            TCA_ACT_BPF_OPS => Ops(payload.to_vec()),
+            TCA_ACT_BPF_FD => Fd(parse_i32(payload).context("failed to parse TCA_ACT_BPF_FD")?),
+            TCA_ACT_BPF_NAME => {
+                Name(parse_string(payload).context("failed to parse TCA_ACT_BPF_NAME")?)
+            }
+            TCA_ACT_BPF_PAD => Pad(payload.to_vec()),
+            TCA_ACT_BPF_TAG => Tag(payload.to_vec()),
+            TCA_ACT_BPF_ID => Id(parse_u32(payload).context("failed to parse TCA_ACT_BPF_ID")?),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse bpf action nla")?),
+        })
+    }
+}
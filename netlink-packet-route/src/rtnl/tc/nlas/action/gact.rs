@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+/// Generic action
+///
+/// The gact action applies a verdict (`TC_ACT_*`) to the packets, for
+/// instance drop, pass or goto chain (`TC_ACT_GOTO_CHAIN | chain`). The
+/// verdict is set in the `action` field of the parameters. An optional
+/// probability can apply a different verdict to a share of the packets.
+use anyhow::Context;
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    tc::{constants::*, TcGen, TcGenBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "gact";
+pub const TC_GACT_PROB_BUF_LEN: usize = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tm(Vec<u8>),
+    Parms(TcGen),
+    Prob(TcGactProb),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => bytes.len(),
+            Parms(p) => p.buffer_len(),
+            Prob(p) => p.buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Parms(p) => p.emit(buffer),
+            Prob(p) => p.emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_GACT_UNSPEC,
+            Tm(_) => TCA_GACT_TM,
+            Parms(_) => TCA_GACT_PARMS,
+            Prob(_) => TCA_GACT_PROB,
+            Pad(_) => TCA_GACT_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_GACT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_GACT_TM => Tm(payload.to_vec()),
+            TCA_GACT_PARMS => Parms(
+                TcGen::parse(&TcGenBuffer::new_checked(payload).context("invalid TCA_GACT_PARMS")?)
+                    .context("failed to parse TCA_GACT_PARMS")?,
+            ),
+            TCA_GACT_PROB => Prob(
+                TcGactProb::parse(
+                    &TcGactProbBuffer::new_checked(payload).context("invalid TCA_GACT_PROB")?,
+                )
+                .context("failed to parse TCA_GACT_PROB")?,
+            ),
+            TCA_GACT_PAD => Pad(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse gact nla")?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcGactProb {
+    /// `PGACT_*`
+    pub ptype: u16,
+    /// Probability (`PGACT_NETRAND`) or period (`PGACT_DETERM`) of the
+    /// alternative verdict, out of 10000
+    pub pval: u16,
+    /// Alternative verdict
+    pub paction: i32,
+}
+
+buffer!(TcGactProbBuffer(TC_GACT_PROB_BUF_LEN) {
+    ptype: (u16, 0..2),
+    pval: (u16, 2..4),
+    paction: (i32, 4..8),
+});
+
+impl Emitable for TcGactProb {
+    fn buffer_len(&self) -> usize {
+        TC_GACT_PROB_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcGactProbBuffer::new(buffer);
+        packet.set_ptype(self.ptype);
+        packet.set_pval(self.pval);
+        packet.set_paction(self.paction);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcGactProbBuffer<T>> for TcGactProb {
+    fn parse(buf: &TcGactProbBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ptype: buf.ptype(),
+            pval: buf.pval(),
+            paction: buf.paction(),
+        })
+    }
+}
@@ -1,6 +1,13 @@
 // SPDX-License-Identifier: MIT
 
+pub mod act_bpf;
+pub mod gact;
 pub mod mirred;
+pub mod pedit;
+pub mod police;
+pub mod skbedit;
+pub mod tunnel_key;
+pub mod vlan;
 
 use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
@@ -145,6 +152,13 @@ impl nlas::Nla for ActNla {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ActOpt {
     Mirred(mirred::Nla),
+    Bpf(act_bpf::Nla),
+    Gact(gact::Nla),
+    Police(police::Nla),
+    Skbedit(skbedit::Nla),
+    Vlan(vlan::Nla),
+    TunnelKey(tunnel_key::Nla),
+    Pedit(pedit::Nla),
     // Other options
     Other(DefaultNla),
 }
@@ -154,6 +168,13 @@ impl nlas::Nla for ActOpt {
         use self::ActOpt::*;
         match self {
             Mirred(nla) => nla.value_len(),
+            Bpf(nla) => nla.value_len(),
+            Gact(nla) => nla.value_len(),
+            Police(nla) => nla.value_len(),
+            Skbedit(nla) => nla.value_len(),
+            Vlan(nla) => nla.value_len(),
+            TunnelKey(nla) => nla.value_len(),
+            Pedit(nla) => nla.value_len(),
             Other(nla) => nla.value_len(),
         }
     }
@@ -162,6 +183,13 @@ impl nlas::Nla for ActOpt {
         use self::ActOpt::*;
         match self {
             Mirred(nla) => nla.emit_value(buffer),
+            Bpf(nla) => nla.emit_value(buffer),
+            Gact(nla) => nla.emit_value(buffer),
+            Police(nla) => nla.emit_value(buffer),
+            Skbedit(nla) => nla.emit_value(buffer),
+            Vlan(nla) => nla.emit_value(buffer),
+            TunnelKey(nla) => nla.emit_value(buffer),
+            Pedit(nla) => nla.emit_value(buffer),
             Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -170,6 +198,13 @@ impl nlas::Nla for ActOpt {
         use self::ActOpt::*;
         match self {
             Mirred(nla) => nla.kind(),
+            Bpf(nla) => nla.kind(),
+            Gact(nla) => nla.kind(),
+            Police(nla) => nla.kind(),
+            Skbedit(nla) => nla.kind(),
+            Vlan(nla) => nla.kind(),
+            TunnelKey(nla) => nla.kind(),
+            Pedit(nla) => nla.kind(),
             Other(nla) => nla.kind(),
         }
     }
@@ -185,6 +220,23 @@ where
             mirred::KIND => {
                 Self::Mirred(mirred::Nla::parse(buf).context("failed to parse mirred action")?)
             }
+            act_bpf::KIND => {
+                Self::Bpf(act_bpf::Nla::parse(buf).context("failed to parse bpf action")?)
+            }
+            gact::KIND => Self::Gact(gact::Nla::parse(buf).context("failed to parse gact action")?),
+            police::KIND => {
+                Self::Police(police::Nla::parse(buf).context("failed to parse police action")?)
+            }
+            skbedit::KIND => {
+                Self::Skbedit(skbedit::Nla::parse(buf).context("failed to parse skbedit action")?)
+            }
+            vlan::KIND => Self::Vlan(vlan::Nla::parse(buf).context("failed to parse vlan action")?),
+            tunnel_key::KIND => Self::TunnelKey(
+                tunnel_key::Nla::parse(buf).context("failed to parse tunnel_key action")?,
+            ),
+            pedit::KIND => {
+                Self::Pedit(pedit::Nla::parse(buf).context("failed to parse pedit action")?)
+            }
             _ => Self::Other(DefaultNla::parse(buf).context("failed to parse action options")?),
         })
     }
@@ -0,0 +1,321 @@
+// SPDX-License-Identifier: MIT
+
+/// Pedit action
+///
+/// The pedit action edits arbitrary bytes of the packets. Each key
+/// replaces the bits selected by `!mask` of the 32 bits word at `off`
+/// with `val`. With the extended keys (`ParmsEx` and `KeysEx`), the
+/// offsets are relative to a given header (`TCA_PEDIT_KEY_EX_HDR_TYPE_*`)
+/// and the value can also be added to the current one.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::parse_u16,
+    tc::{constants::*, TC_GEN_BUF_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "pedit";
+pub const TC_PEDIT_SEL_BUF_LEN: usize = TC_GEN_BUF_LEN + 4;
+pub const TC_PEDIT_KEY_BUF_LEN: usize = 24;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tm(Vec<u8>),
+    Parms(TcPeditSel),
+    Pad(Vec<u8>),
+    /// Parameters used along with `KeysEx`
+    ParmsEx(TcPeditSel),
+    /// Extended keys, one per key of `ParmsEx`
+    KeysEx(Vec<TcPeditKeyEx>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => bytes.len(),
+            Parms(sel) | ParmsEx(sel) => sel.buffer_len(),
+            KeysEx(keys) => keys.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Parms(sel) | ParmsEx(sel) => sel.emit(buffer),
+            KeysEx(keys) => keys.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Self::KeysEx(_))
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_PEDIT_UNSPEC,
+            Tm(_) => TCA_PEDIT_TM,
+            Parms(_) => TCA_PEDIT_PARMS,
+            Pad(_) => TCA_PEDIT_PAD,
+            ParmsEx(_) => TCA_PEDIT_PARMS_EX,
+            KeysEx(_) => TCA_PEDIT_KEYS_EX,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_PEDIT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_PEDIT_TM => Tm(payload.to_vec()),
+            TCA_PEDIT_PARMS => Parms(
+                TcPeditSel::parse(
+                    &TcPeditSelBuffer::new_checked(payload).context("invalid TCA_PEDIT_PARMS")?,
+                )
+                .context("failed to parse TCA_PEDIT_PARMS")?,
+            ),
+            TCA_PEDIT_PAD => Pad(payload.to_vec()),
+            TCA_PEDIT_PARMS_EX => ParmsEx(
+                TcPeditSel::parse(
+                    &TcPeditSelBuffer::new_checked(payload)
+                        .context("invalid TCA_PEDIT_PARMS_EX")?,
+                )
+                .context("failed to parse TCA_PEDIT_PARMS_EX")?,
+            ),
+            TCA_PEDIT_KEYS_EX => {
+                let mut keys = vec![];
+                for key in NlasIterator::new(payload) {
+                    let key = key.context("invalid TCA_PEDIT_KEYS_EX")?;
+                    keys.push(
+                        TcPeditKeyEx::parse(&key).context("failed to parse TCA_PEDIT_KEYS_EX")?,
+                    );
+                }
+                KeysEx(keys)
+            }
+            _ => Other(DefaultNla::parse(buf).context("failed to parse pedit nla")?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcPeditSel {
+    pub index: u32,
+    pub capab: u32,
+    pub action: i32,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+
+    pub flags: u8,
+    pub keys: Vec<TcPeditKey>,
+}
+
+buffer!(TcPeditSelBuffer(TC_PEDIT_SEL_BUF_LEN) {
+    index: (u32, 0..4),
+    capab: (u32, 4..8),
+    action: (i32, 8..12),
+    refcnt: (i32, 12..16),
+    bindcnt: (i32, 16..20),
+    nkeys: (u8, TC_GEN_BUF_LEN),
+    flags: (u8, TC_GEN_BUF_LEN + 1),
+    //pad: (u16, 22..24),
+    keys: (slice, TC_PEDIT_SEL_BUF_LEN..),
+});
+
+impl Emitable for TcPeditSel {
+    fn buffer_len(&self) -> usize {
+        TC_PEDIT_SEL_BUF_LEN + self.keys.len() * TC_PEDIT_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcPeditSelBuffer::new(buffer);
+        packet.set_index(self.index);
+        packet.set_capab(self.capab);
+        packet.set_action(self.action);
+        packet.set_refcnt(self.refcnt);
+        packet.set_bindcnt(self.bindcnt);
+
+        packet.set_nkeys(self.keys.len() as u8);
+        packet.set_flags(self.flags);
+
+        let key_buf = packet.keys_mut();
+        for (i, k) in self.keys.iter().enumerate() {
+            k.emit(&mut key_buf[(i * TC_PEDIT_KEY_BUF_LEN)..((i + 1) * TC_PEDIT_KEY_BUF_LEN)]);
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcPeditSelBuffer<&T>> for TcPeditSel {
+    fn parse(buf: &TcPeditSelBuffer<&T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        let key_payload = buf.keys();
+        if key_payload.len() < nkeys * TC_PEDIT_KEY_BUF_LEN {
+            return Err(format!(
+                "invalid pedit selector: {} keys announced but only {} bytes of keys",
+                nkeys,
+                key_payload.len()
+            )
+            .into());
+        }
+        let mut keys = Vec::with_capacity(nkeys);
+        for i in 0..nkeys {
+            let keybuf = TcPeditKeyBuffer::new_checked(
+                &key_payload[(i * TC_PEDIT_KEY_BUF_LEN)..(i + 1) * TC_PEDIT_KEY_BUF_LEN],
+            )
+            .context("invalid pedit key")?;
+            keys.push(TcPeditKey::parse(&keybuf).context("failed to parse pedit key")?);
+        }
+
+        Ok(Self {
+            index: buf.index(),
+            capab: buf.capab(),
+            action: buf.action(),
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            flags: buf.flags(),
+            keys,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcPeditKey {
+    /// Bits of the word to keep
+    pub mask: u32,
+    /// Bits to set (or to add with `TCA_PEDIT_KEY_EX_CMD_ADD`), in network
+    /// byte order
+    pub val: u32,
+    /// Offset of the word, relative to the network header or to the header
+    /// of the extended key
+    pub off: i32,
+    pub at: u32,
+    pub offmask: u32,
+    pub shift: u32,
+}
+
+buffer!(TcPeditKeyBuffer(TC_PEDIT_KEY_BUF_LEN) {
+    mask: (u32, 0..4),
+    val: (u32, 4..8),
+    off: (i32, 8..12),
+    at: (u32, 12..16),
+    offmask: (u32, 16..20),
+    shift: (u32, 20..TC_PEDIT_KEY_BUF_LEN),
+});
+
+impl Emitable for TcPeditKey {
+    fn buffer_len(&self) -> usize {
+        TC_PEDIT_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcPeditKeyBuffer::new(buffer);
+        packet.set_mask(self.mask);
+        packet.set_val(self.val);
+        packet.set_off(self.off);
+        packet.set_at(self.at);
+        packet.set_offmask(self.offmask);
+        packet.set_shift(self.shift);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcPeditKeyBuffer<T>> for TcPeditKey {
+    fn parse(buf: &TcPeditKeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: buf.mask(),
+            val: buf.val(),
+            off: buf.off(),
+            at: buf.at(),
+            offmask: buf.offmask(),
+            shift: buf.shift(),
+        })
+    }
+}
+
+/// Extended pedit key (`TCA_PEDIT_KEY_EX`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcPeditKeyEx {
+    /// `TCA_PEDIT_KEY_EX_HDR_TYPE_*`
+    pub htype: u16,
+    /// `TCA_PEDIT_KEY_EX_CMD_*`
+    pub cmd: u16,
+}
+
+// Each attribute is a u16 padded to 4 bytes
+const TC_PEDIT_KEY_EX_LEN: usize = 16;
+
+enum KeyExNla {
+    HType(u16),
+    Cmd(u16),
+}
+
+impl nlas::Nla for KeyExNla {
+    fn value_len(&self) -> usize {
+        2
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::HType(value) | Self::Cmd(value) => NativeEndian::write_u16(buffer, *value),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::HType(_) => TCA_PEDIT_KEY_EX_HTYPE,
+            Self::Cmd(_) => TCA_PEDIT_KEY_EX_CMD,
+        }
+    }
+}
+
+impl nlas::Nla for TcPeditKeyEx {
+    fn value_len(&self) -> usize {
+        TC_PEDIT_KEY_EX_LEN
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        [KeyExNla::HType(self.htype), KeyExNla::Cmd(self.cmd)]
+            .as_slice()
+            .emit(buffer);
+    }
+
+    fn is_nested(&self) -> bool {
+        true
+    }
+
+    fn kind(&self) -> u16 {
+        TCA_PEDIT_KEY_EX
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcPeditKeyEx {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut key = Self::default();
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = nla.context("invalid TCA_PEDIT_KEY_EX")?;
+            match nla.kind() {
+                TCA_PEDIT_KEY_EX_HTYPE => {
+                    key.htype =
+                        parse_u16(nla.value()).context("failed to parse TCA_PEDIT_KEY_EX_HTYPE")?
+                }
+                TCA_PEDIT_KEY_EX_CMD => {
+                    key.cmd =
+                        parse_u16(nla.value()).context("failed to parse TCA_PEDIT_KEY_EX_CMD")?
+                }
+                _ => {}
+            }
+        }
+        Ok(key)
+    }
+}
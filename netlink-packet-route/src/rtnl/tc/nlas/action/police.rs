@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+
+/// Police action
+///
+/// The police action limits the rate of the packets with a token bucket.
+/// Packets exceeding the rate get the `action` verdict of the `Tbf`
+/// parameters, conforming packets get the `Result` verdict (`conform-exceed
+/// EXCEED/CONFORM` in `tc`).
+///
+/// When a byte rate is set, the kernel also requires a `Rate` table of
+/// `TC_RTAB_SIZE` bytes. It is only used to detect the link layer, so a
+/// zeroed table can be sent along with a non zero `cell_log`.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_i32, parse_u32, parse_u64},
+    tc::{constants::*, TcRateSpec, TcRateSpecBuffer, TC_RATESPEC_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "police";
+pub const TC_POLICE_BUF_LEN: usize = 20 + 2 * TC_RATESPEC_LEN + 12;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tbf(TcPolice),
+    /// Rate table of the rate
+    Rate(Vec<u8>),
+    /// Rate table of the peak rate
+    PeakRate(Vec<u8>),
+    /// Average rate in bytes per second
+    AvRate(u32),
+    /// Verdict of the conforming packets
+    Result(i32),
+    Tm(Vec<u8>),
+    Pad(Vec<u8>),
+    Rate64(u64),
+    PeakRate64(u64),
+    PktRate64(u64),
+    PktBurst64(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Rate(bytes) | PeakRate(bytes) | Tm(bytes) | Pad(bytes) => bytes.len(),
+            Tbf(p) => p.buffer_len(),
+            AvRate(_) | Result(_) => 4,
+            Rate64(_) | PeakRate64(_) | PktRate64(_) | PktBurst64(_) => 8,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Rate(bytes) | PeakRate(bytes) | Tm(bytes) | Pad(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Tbf(p) => p.emit(buffer),
+            AvRate(value) => NativeEndian::write_u32(buffer, *value),
+            Result(value) => NativeEndian::write_i32(buffer, *value),
+            Rate64(value) | PeakRate64(value) | PktRate64(value) | PktBurst64(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_POLICE_UNSPEC,
+            Tbf(_) => TCA_POLICE_TBF,
+            Rate(_) => TCA_POLICE_RATE,
+            PeakRate(_) => TCA_POLICE_PEAKRATE,
+            AvRate(_) => TCA_POLICE_AVRATE,
+            Result(_) => TCA_POLICE_RESULT,
+            Tm(_) => TCA_POLICE_TM,
+            Pad(_) => TCA_POLICE_PAD,
+            Rate64(_) => TCA_POLICE_RATE64,
+            PeakRate64(_) => TCA_POLICE_PEAKRATE64,
+            PktRate64(_) => TCA_POLICE_PKTRATE64,
+            PktBurst64(_) => TCA_POLICE_PKTBURST64,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_POLICE_UNSPEC => Unspec(payload.to_vec()),
+            TCA_POLICE_TBF => Tbf(TcPolice::parse(
+                &TcPoliceBuffer::new_checked(payload).context("invalid TCA_POLICE_TBF")?,
+            )
+            .context("failed to parse TCA_POLICE_TBF")?),
+            TCA_POLICE_RATE => Rate(payload.to_vec()),
+            TCA_POLICE_PEAKRATE => PeakRate(payload.to_vec()),
+            TCA_POLICE_AVRATE => {
+                AvRate(parse_u32(payload).context("failed to parse TCA_POLICE_AVRATE")?)
+            }
+            TCA_POLICE_RESULT => {
+                Result(parse_i32(payload).context("failed to parse TCA_POLICE_RESULT")?)
+            }
+            TCA_POLICE_TM => Tm(payload.to_vec()),
+            TCA_POLICE_PAD => Pad(payload.to_vec()),
+            TCA_POLICE_RATE64 => {
+                Rate64(parse_u64(payload).context("failed to parse TCA_POLICE_RATE64")?)
+            }
+            TCA_POLICE_PEAKRATE64 => {
+                PeakRate64(parse_u64(payload).context("failed to parse TCA_POLICE_PEAKRATE64")?)
+            }
+            TCA_POLICE_PKTRATE64 => {
+                PktRate64(parse_u64(payload).context("failed to parse TCA_POLICE_PKTRATE64")?)
+            }
+            TCA_POLICE_PKTBURST64 => {
+                PktBurst64(parse_u64(payload).context("failed to parse TCA_POLICE_PKTBURST64")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("failed to parse police nla")?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcPolice {
+    pub index: u32,
+    /// Verdict of the packets exceeding the rate
+    pub action: i32,
+    pub limit: u32,
+    /// Size of the bucket, in scheduler ticks
+    pub burst: u32,
+    pub mtu: u32,
+    pub rate: TcRateSpec,
+    pub peakrate: TcRateSpec,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+    pub capab: u32,
+}
+
+buffer!(TcPoliceBuffer(TC_POLICE_BUF_LEN) {
+    index: (u32, 0..4),
+    action: (i32, 4..8),
+    limit: (u32, 8..12),
+    burst: (u32, 12..16),
+    mtu: (u32, 16..20),
+    rate: (slice, 20..32),
+    peakrate: (slice, 32..44),
+    refcnt: (i32, 44..48),
+    bindcnt: (i32, 48..52),
+    capab: (u32, 52..TC_POLICE_BUF_LEN),
+});
+
+impl Emitable for TcPolice {
+    fn buffer_len(&self) -> usize {
+        TC_POLICE_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcPoliceBuffer::new(buffer);
+        packet.set_index(self.index);
+        packet.set_action(self.action);
+        packet.set_limit(self.limit);
+        packet.set_burst(self.burst);
+        packet.set_mtu(self.mtu);
+        self.rate.emit(packet.rate_mut());
+        self.peakrate.emit(packet.peakrate_mut());
+        packet.set_refcnt(self.refcnt);
+        packet.set_bindcnt(self.bindcnt);
+        packet.set_capab(self.capab);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcPoliceBuffer<&'a T>> for TcPolice {
+    fn parse(buf: &TcPoliceBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            action: buf.action(),
+            limit: buf.limit(),
+            burst: buf.burst(),
+            mtu: buf.mtu(),
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            peakrate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.peakrate()))?,
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            capab: buf.capab(),
+        })
+    }
+}
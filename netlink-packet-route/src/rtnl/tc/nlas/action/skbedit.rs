@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+/// Skbedit action
+///
+/// The skbedit action modifies the metadata of the packets: priority,
+/// transmit queue, firewall mark or packet type.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    tc::{constants::*, TcGen, TcGenBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "skbedit";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tm(Vec<u8>),
+    Parms(TcGen),
+    Priority(u32),
+    QueueMapping(u16),
+    Mark(u32),
+    Pad(Vec<u8>),
+    /// Packet type (`PACKET_*`)
+    Ptype(u16),
+    /// Mask applied to the firewall mark
+    Mask(u32),
+    /// `SKBEDIT_F_*` flags
+    Flags(u64),
+    QueueMappingMax(u16),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => bytes.len(),
+            Parms(p) => p.buffer_len(),
+            QueueMapping(_) | Ptype(_) | QueueMappingMax(_) => 2,
+            Priority(_) | Mark(_) | Mask(_) => 4,
+            Flags(_) => 8,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Parms(p) => p.emit(buffer),
+            QueueMapping(value) | Ptype(value) | QueueMappingMax(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Priority(value) | Mark(value) | Mask(value) => NativeEndian::write_u32(buffer, *value),
+            Flags(value) => NativeEndian::write_u64(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_SKBEDIT_UNSPEC,
+            Tm(_) => TCA_SKBEDIT_TM,
+            Parms(_) => TCA_SKBEDIT_PARMS,
+            Priority(_) => TCA_SKBEDIT_PRIORITY,
+            QueueMapping(_) => TCA_SKBEDIT_QUEUE_MAPPING,
+            Mark(_) => TCA_SKBEDIT_MARK,
+            Pad(_) => TCA_SKBEDIT_PAD,
+            Ptype(_) => TCA_SKBEDIT_PTYPE,
+            Mask(_) => TCA_SKBEDIT_MASK,
+            Flags(_) => TCA_SKBEDIT_FLAGS,
+            QueueMappingMax(_) => TCA_SKBEDIT_QUEUE_MAPPING_MAX,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_SKBEDIT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_SKBEDIT_TM => Tm(payload.to_vec()),
+            TCA_SKBEDIT_PARMS => Parms(
+                TcGen::parse(
+                    &TcGenBuffer::new_checked(payload).context("invalid TCA_SKBEDIT_PARMS")?,
+                )
+                .context("failed to parse TCA_SKBEDIT_PARMS")?,
+            ),
+            TCA_SKBEDIT_PRIORITY => {
+                Priority(parse_u32(payload).context("failed to parse TCA_SKBEDIT_PRIORITY")?)
+            }
+            TCA_SKBEDIT_QUEUE_MAPPING => QueueMapping(
+                parse_u16(payload).context("failed to parse TCA_SKBEDIT_QUEUE_MAPPING")?,
+            ),
+            TCA_SKBEDIT_MARK => {
+                Mark(parse_u32(payload).context("failed to parse TCA_SKBEDIT_MARK")?)
+            }
+            TCA_SKBEDIT_PAD => Pad(payload.to_vec()),
+            TCA_SKBEDIT_PTYPE => {
+                Ptype(parse_u16(payload).context("failed to parse TCA_SKBEDIT_PTYPE")?)
+            }
+            TCA_SKBEDIT_MASK => {
+                Mask(parse_u32(payload).context("failed to parse TCA_SKBEDIT_MASK")?)
+            }
+            TCA_SKBEDIT_FLAGS => {
+                Flags(parse_u64(payload).context("failed to parse TCA_SKBEDIT_FLAGS")?)
+            }
+            TCA_SKBEDIT_QUEUE_MAPPING_MAX => QueueMappingMax(
+                parse_u16(payload).context("failed to parse TCA_SKBEDIT_QUEUE_MAPPING_MAX")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse skbedit nla")?),
+        })
+    }
+}
@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: MIT
+
+/// Tunnel key action
+///
+/// The tunnel_key action sets the tunnel metadata (addresses, key ID,
+/// destination port, ...) used by the collect_md tunnel devices to
+/// encapsulate the packets, or releases the metadata of decapsulated
+/// packets, depending on the `t_action` (`TCA_TUNNEL_KEY_ACT_*`) of the
+/// parameters.
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u16_be, parse_u32_be, parse_u8},
+    tc::{constants::*, TC_GEN_BUF_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "tunnel_key";
+pub const TC_TUNNEL_KEY_BUF_LEN: usize = TC_GEN_BUF_LEN + 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tm(Vec<u8>),
+    Parms(TcTunnelKey),
+    EncIpv4Src(Ipv4Addr),
+    EncIpv4Dst(Ipv4Addr),
+    EncIpv6Src(Ipv6Addr),
+    EncIpv6Dst(Ipv6Addr),
+    /// Tunnel key ID, for instance the VXLAN VNI
+    EncKeyId(u32),
+    Pad(Vec<u8>),
+    EncDstPort(u16),
+    /// Disable the UDP checksum of the outer header when non zero
+    NoCsum(u8),
+    /// Tunnel options, kept as raw nested attributes
+    EncOpts(Vec<u8>),
+    EncTos(u8),
+    EncTtl(u8),
+    /// Set the DF bit of the outer header
+    NoFrag,
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) | EncOpts(bytes) => bytes.len(),
+            Parms(_) => TC_TUNNEL_KEY_BUF_LEN,
+            EncIpv4Src(_) | EncIpv4Dst(_) | EncKeyId(_) => 4,
+            EncIpv6Src(_) | EncIpv6Dst(_) => 16,
+            EncDstPort(_) => 2,
+            NoCsum(_) | EncTos(_) | EncTtl(_) => 1,
+            NoFrag => 0,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) | EncOpts(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Parms(p) => p.emit(buffer),
+            EncIpv4Src(addr) | EncIpv4Dst(addr) => buffer.copy_from_slice(&addr.octets()),
+            EncIpv6Src(addr) | EncIpv6Dst(addr) => buffer.copy_from_slice(&addr.octets()),
+            EncKeyId(id) => BigEndian::write_u32(buffer, *id),
+            EncDstPort(port) => BigEndian::write_u16(buffer, *port),
+            NoCsum(value) | EncTos(value) | EncTtl(value) => buffer[0] = *value,
+            NoFrag => {}
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Self::EncOpts(_))
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_TUNNEL_KEY_UNSPEC,
+            Tm(_) => TCA_TUNNEL_KEY_TM,
+            Parms(_) => TCA_TUNNEL_KEY_PARMS,
+            EncIpv4Src(_) => TCA_TUNNEL_KEY_ENC_IPV4_SRC,
+            EncIpv4Dst(_) => TCA_TUNNEL_KEY_ENC_IPV4_DST,
+            EncIpv6Src(_) => TCA_TUNNEL_KEY_ENC_IPV6_SRC,
+            EncIpv6Dst(_) => TCA_TUNNEL_KEY_ENC_IPV6_DST,
+            EncKeyId(_) => TCA_TUNNEL_KEY_ENC_KEY_ID,
+            Pad(_) => TCA_TUNNEL_KEY_PAD,
+            EncDstPort(_) => TCA_TUNNEL_KEY_ENC_DST_PORT,
+            NoCsum(_) => TCA_TUNNEL_KEY_NO_CSUM,
+            EncOpts(_) => TCA_TUNNEL_KEY_ENC_OPTS,
+            EncTos(_) => TCA_TUNNEL_KEY_ENC_TOS,
+            EncTtl(_) => TCA_TUNNEL_KEY_ENC_TTL,
+            NoFrag => TCA_TUNNEL_KEY_NO_FRAG,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TUNNEL_KEY_UNSPEC => Unspec(payload.to_vec()),
+            TCA_TUNNEL_KEY_TM => Tm(payload.to_vec()),
+            TCA_TUNNEL_KEY_PARMS => Parms(
+                TcTunnelKey::parse(
+                    &TcTunnelKeyBuffer::new_checked(payload)
+                        .context("invalid TCA_TUNNEL_KEY_PARMS")?,
+                )
+                .context("failed to parse TCA_TUNNEL_KEY_PARMS")?,
+            ),
+            TCA_TUNNEL_KEY_ENC_IPV4_SRC => EncIpv4Src(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_IPV4_SRC")?,
+            )),
+            TCA_TUNNEL_KEY_ENC_IPV4_DST => EncIpv4Dst(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_IPV4_DST")?,
+            )),
+            TCA_TUNNEL_KEY_ENC_IPV6_SRC => EncIpv6Src(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_IPV6_SRC")?,
+            )),
+            TCA_TUNNEL_KEY_ENC_IPV6_DST => EncIpv6Dst(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_IPV6_DST")?,
+            )),
+            TCA_TUNNEL_KEY_ENC_KEY_ID => EncKeyId(
+                parse_u32_be(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_KEY_ID")?,
+            ),
+            TCA_TUNNEL_KEY_PAD => Pad(payload.to_vec()),
+            TCA_TUNNEL_KEY_ENC_DST_PORT => EncDstPort(
+                parse_u16_be(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_DST_PORT")?,
+            ),
+            TCA_TUNNEL_KEY_NO_CSUM => {
+                NoCsum(parse_u8(payload).context("failed to parse TCA_TUNNEL_KEY_NO_CSUM")?)
+            }
+            TCA_TUNNEL_KEY_ENC_OPTS => EncOpts(payload.to_vec()),
+            TCA_TUNNEL_KEY_ENC_TOS => {
+                EncTos(parse_u8(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_TOS")?)
+            }
+            TCA_TUNNEL_KEY_ENC_TTL => {
+                EncTtl(parse_u8(payload).context("failed to parse TCA_TUNNEL_KEY_ENC_TTL")?)
+            }
+            TCA_TUNNEL_KEY_NO_FRAG => NoFrag,
+            _ => Other(DefaultNla::parse(buf).context("failed to parse tunnel_key nla")?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcTunnelKey {
+    pub index: u32,
+    pub capab: u32,
+    pub action: i32,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+
+    /// `TCA_TUNNEL_KEY_ACT_*`
+    pub t_action: i32,
+}
+
+buffer!(TcTunnelKeyBuffer(TC_TUNNEL_KEY_BUF_LEN) {
+    index: (u32, 0..4),
+    capab: (u32, 4..8),
+    action: (i32, 8..12),
+    refcnt: (i32, 12..16),
+    bindcnt: (i32, 16..20),
+    t_action: (i32, TC_GEN_BUF_LEN..TC_TUNNEL_KEY_BUF_LEN),
+});
+
+impl Emitable for TcTunnelKey {
+    fn buffer_len(&self) -> usize {
+        TC_TUNNEL_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcTunnelKeyBuffer::new(buffer);
+        packet.set_index(self.index);
+        packet.set_capab(self.capab);
+        packet.set_action(self.action);
+        packet.set_refcnt(self.refcnt);
+        packet.set_bindcnt(self.bindcnt);
+
+        packet.set_t_action(self.t_action);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcTunnelKeyBuffer<T>> for TcTunnelKey {
+    fn parse(buf: &TcTunnelKeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            capab: buf.capab(),
+            action: buf.action(),
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            t_action: buf.t_action(),
+        })
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+
+/// Vlan action
+///
+/// The vlan action pops, pushes or modifies the outer VLAN tag of the
+/// packets, depending on the `v_action` (`TCA_VLAN_ACT_*`) of the
+/// parameters.
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_mac, parse_u16, parse_u16_be, parse_u8},
+    tc::{constants::*, TC_GEN_BUF_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "vlan";
+pub const TC_VLAN_BUF_LEN: usize = TC_GEN_BUF_LEN + 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tm(Vec<u8>),
+    Parms(TcVlan),
+    PushVlanId(u16),
+    /// VLAN protocol (`ETH_P_8021Q` or `ETH_P_8021AD`), in host byte order
+    PushVlanProtocol(u16),
+    Pad(Vec<u8>),
+    PushVlanPriority(u8),
+    PushEthDst([u8; 6]),
+    PushEthSrc([u8; 6]),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => bytes.len(),
+            Parms(_) => TC_VLAN_BUF_LEN,
+            PushVlanId(_) | PushVlanProtocol(_) => 2,
+            PushVlanPriority(_) => 1,
+            PushEthDst(_) | PushEthSrc(_) => 6,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Parms(p) => p.emit(buffer),
+            PushVlanId(id) => NativeEndian::write_u16(buffer, *id),
+            PushVlanProtocol(proto) => BigEndian::write_u16(buffer, *proto),
+            PushVlanPriority(prio) => buffer[0] = *prio,
+            PushEthDst(mac) | PushEthSrc(mac) => buffer.copy_from_slice(&mac[..]),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_VLAN_UNSPEC,
+            Tm(_) => TCA_VLAN_TM,
+            Parms(_) => TCA_VLAN_PARMS,
+            PushVlanId(_) => TCA_VLAN_PUSH_VLAN_ID,
+            PushVlanProtocol(_) => TCA_VLAN_PUSH_VLAN_PROTOCOL,
+            Pad(_) => TCA_VLAN_PAD,
+            PushVlanPriority(_) => TCA_VLAN_PUSH_VLAN_PRIORITY,
+            PushEthDst(_) => TCA_VLAN_PUSH_ETH_DST,
+            PushEthSrc(_) => TCA_VLAN_PUSH_ETH_SRC,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_VLAN_UNSPEC => Unspec(payload.to_vec()),
+            TCA_VLAN_TM => Tm(payload.to_vec()),
+            TCA_VLAN_PARMS => Parms(
+                TcVlan::parse(
+                    &TcVlanBuffer::new_checked(payload).context("invalid TCA_VLAN_PARMS")?,
+                )
+                .context("failed to parse TCA_VLAN_PARMS")?,
+            ),
+            TCA_VLAN_PUSH_VLAN_ID => {
+                PushVlanId(parse_u16(payload).context("failed to parse TCA_VLAN_PUSH_VLAN_ID")?)
+            }
+            TCA_VLAN_PUSH_VLAN_PROTOCOL => PushVlanProtocol(
+                parse_u16_be(payload).context("failed to parse TCA_VLAN_PUSH_VLAN_PROTOCOL")?,
+            ),
+            TCA_VLAN_PAD => Pad(payload.to_vec()),
+            TCA_VLAN_PUSH_VLAN_PRIORITY => PushVlanPriority(
+                parse_u8(payload).context("failed to parse TCA_VLAN_PUSH_VLAN_PRIORITY")?,
+            ),
+            TCA_VLAN_PUSH_ETH_DST => {
+                PushEthDst(parse_mac(payload).context("failed to parse TCA_VLAN_PUSH_ETH_DST")?)
+            }
+            TCA_VLAN_PUSH_ETH_SRC => {
+                PushEthSrc(parse_mac(payload).context("failed to parse TCA_VLAN_PUSH_ETH_SRC")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("failed to parse vlan nla")?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcVlan {
+    pub index: u32,
+    pub capab: u32,
+    pub action: i32,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+
+    /// `TCA_VLAN_ACT_*`
+    pub v_action: i32,
+}
+
+buffer!(TcVlanBuffer(TC_VLAN_BUF_LEN) {
+    index: (u32, 0..4),
+    capab: (u32, 4..8),
+    action: (i32, 8..12),
+    refcnt: (i32, 12..16),
+    bindcnt: (i32, 16..20),
+    v_action: (i32, TC_GEN_BUF_LEN..TC_VLAN_BUF_LEN),
+});
+
+impl Emitable for TcVlan {
+    fn buffer_len(&self) -> usize {
+        TC_VLAN_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcVlanBuffer::new(buffer);
+        packet.set_index(self.index);
+        packet.set_capab(self.capab);
+        packet.set_action(self.action);
+        packet.set_refcnt(self.refcnt);
+        packet.set_bindcnt(self.bindcnt);
+
+        packet.set_v_action(self.v_action);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcVlanBuffer<T>> for TcVlan {
+    fn parse(buf: &TcVlanBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            capab: buf.capab(),
+            action: buf.action(),
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            v_action: buf.v_action(),
+        })
+    }
+}
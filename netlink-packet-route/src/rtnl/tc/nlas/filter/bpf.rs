@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+
+/// BPF filter
+///
+/// The BPF filter runs a classic BPF program (`Ops`) or an eBPF program
+/// loaded with `bpf(2)` (`Fd`) on each packet. When the program is attached
+/// in direct-action mode, its return code is the tc action verdict and no
+/// separate action is required.
+use std::os::unix::io::RawFd;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_i32, parse_string, parse_u16, parse_u32},
+    tc::{constants::*, Action},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "bpf";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Act(Vec<Action>),
+    Police(Vec<u8>),
+    ClassId(u32),
+    /// Number of classic BPF instructions in `Ops`
+    OpsLen(u16),
+    /// Classic BPF program, as an array of `struct sock_filter`
+    Ops(Vec<u8>),
+    /// File descriptor of the eBPF program to attach. Only used in requests.
+    Fd(RawFd),
+    /// Name of the eBPF program, for instance the ELF section it was loaded
+    /// from
+    Name(String),
+    /// `TCA_BPF_FLAG_*` flags
+    Flags(u32),
+    /// Generic classifier flags (`TCA_CLS_FLAGS_*`)
+    FlagsGen(u32),
+    /// Tag of the attached eBPF program. Only reported by the kernel.
+    Tag(Vec<u8>),
+    /// ID of the attached eBPF program. Only reported by the kernel.
+    Id(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(b) | Police(b) | Ops(b) | Tag(b) => b.len(),
+            ClassId(_) | Fd(_) | Flags(_) | FlagsGen(_) | Id(_) => 4,
+            OpsLen(_) => 2,
+            Name(s) => s.len() + 1,
+            Act(acts) => acts.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(b) | Police(b) | Ops(b) | Tag(b) => buffer.copy_from_slice(b.as_slice()),
+            ClassId(i) | Flags(i) | FlagsGen(i) | Id(i) => NativeEndian::write_u32(buffer, *i),
+            Fd(fd) => NativeEndian::write_i32(buffer, *fd),
+            OpsLen(i) => NativeEndian::write_u16(buffer, *i),
+            Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Act(acts) => acts.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Self::Act(_) | Self::Police(_))
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_BPF_UNSPEC,
+            Act(_) => TCA_BPF_ACT,
+            Police(_) => TCA_BPF_POLICE,
+            ClassId(_) => TCA_BPF_CLASSID,
+            OpsLen(_) => TCA_BPF_OPS_LEN,
+            Ops(_) => TCA_BPF_OPS,
+            Fd(_) => TCA_BPF_FD,
+            Name(_) => TCA_BPF_NAME,
+            Flags(_) => TCA_BPF_FLAGS,
+            FlagsGen(_) => TCA_BPF_FLAGS_GEN,
+            Tag(_) => TCA_BPF_TAG,
+            Id(_) => TCA_BPF_ID,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_BPF_UNSPEC => Unspec(payload.to_vec()),
+            TCA_BPF_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act.context("invalid TCA_BPF_ACT")?;
+                    acts.push(Action::parse(&act).context("failed to parse TCA_BPF_ACT")?);
+                }
+                Act(acts)
+            }
+            TCA_BPF_POLICE => Police(payload.to_vec()),
+            TCA_BPF_CLASSID => {
+                ClassId(parse_u32(payload).context("failed to parse TCA_BPF_CLASSID")?)
+            }
+            TCA_BPF_OPS_LEN => {
+                OpsLen(parse_u16(payload).context("failed to parse TCA_BPF_OPS_LEN")?)
+            }
+            TCA_BPF_OPS => Ops(payload.to_vec()),
+            TCA_BPF_FD => Fd(parse_i32(payload).context("failed to parse TCA_BPF_FD")?),
+            TCA_BPF_NAME => Name(parse_string(payload).context("failed to parse TCA_BPF_NAME")?),
+            TCA_BPF_FLAGS => Flags(parse_u32(payload).context("failed to parse TCA_BPF_FLAGS")?),
+            TCA_BPF_FLAGS_GEN => {
+                FlagsGen(parse_u32(payload).context("failed to parse TCA_BPF_FLAGS_GEN")?)
+            }
+            TCA_BPF_TAG => Tag(payload.to_vec()),
+            TCA_BPF_ID => Id(parse_u32(payload).context("failed to parse TCA_BPF_ID")?),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse bpf nla")?),
+        })
+    }
+}
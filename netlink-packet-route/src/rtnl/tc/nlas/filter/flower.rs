@@ -0,0 +1,834 @@
+// SPDX-License-Identifier: MIT
+
+/// Flower filter
+///
+/// The flower filter matches packets on the fields of their flow key: L2,
+/// L3 and L4 headers, VLAN tags, tunnel metadata and connection tracking
+/// state. It is the classifier used by most hardware offloading drivers.
+///
+/// Multi-byte protocol fields (ports, Ethernet types, tunnel key IDs, ...)
+/// are stored in host byte order and converted from/to network byte order
+/// when parsing and emitting.
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{
+        parse_ipv6,
+        parse_mac,
+        parse_string,
+        parse_u16,
+        parse_u16_be,
+        parse_u32,
+        parse_u32_be,
+        parse_u8,
+    },
+    tc::{constants::*, Action},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "flower";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Class ID the matching packets are classified to
+    ClassId(u32),
+    /// Name of the input interface to match
+    Indev(String),
+    /// Actions applied to the matching packets
+    Act(Vec<Action>),
+    EthDst([u8; 6]),
+    EthDstMask([u8; 6]),
+    EthSrc([u8; 6]),
+    EthSrcMask([u8; 6]),
+    /// Ethernet type (`ETH_P_*`), in host byte order
+    EthType(u16),
+    IpProto(u8),
+    Ipv4Src(Ipv4Addr),
+    Ipv4SrcMask(Ipv4Addr),
+    Ipv4Dst(Ipv4Addr),
+    Ipv4DstMask(Ipv4Addr),
+    Ipv6Src(Ipv6Addr),
+    Ipv6SrcMask(Ipv6Addr),
+    Ipv6Dst(Ipv6Addr),
+    Ipv6DstMask(Ipv6Addr),
+    TcpSrc(u16),
+    TcpDst(u16),
+    UdpSrc(u16),
+    UdpDst(u16),
+    /// Filter flags (`TCA_CLS_FLAGS_*`)
+    Flags(u32),
+    /// VLAN ID of the outer VLAN tag
+    VlanId(u16),
+    VlanPrio(u8),
+    VlanEthType(u16),
+    /// Tunnel key ID, for instance the VXLAN VNI
+    EncKeyId(u32),
+    EncIpv4Src(Ipv4Addr),
+    EncIpv4SrcMask(Ipv4Addr),
+    EncIpv4Dst(Ipv4Addr),
+    EncIpv4DstMask(Ipv4Addr),
+    EncIpv6Src(Ipv6Addr),
+    EncIpv6SrcMask(Ipv6Addr),
+    EncIpv6Dst(Ipv6Addr),
+    EncIpv6DstMask(Ipv6Addr),
+    TcpSrcMask(u16),
+    TcpDstMask(u16),
+    UdpSrcMask(u16),
+    UdpDstMask(u16),
+    SctpSrcMask(u16),
+    SctpDstMask(u16),
+    SctpSrc(u16),
+    SctpDst(u16),
+    EncUdpSrcPort(u16),
+    EncUdpSrcPortMask(u16),
+    EncUdpDstPort(u16),
+    EncUdpDstPortMask(u16),
+    /// Packet flags (`TCA_FLOWER_KEY_FLAGS_*`)
+    KeyFlags(u32),
+    KeyFlagsMask(u32),
+    Icmpv4Code(u8),
+    Icmpv4CodeMask(u8),
+    Icmpv4Type(u8),
+    Icmpv4TypeMask(u8),
+    Icmpv6Code(u8),
+    Icmpv6CodeMask(u8),
+    Icmpv6Type(u8),
+    Icmpv6TypeMask(u8),
+    ArpSip(Ipv4Addr),
+    ArpSipMask(Ipv4Addr),
+    ArpTip(Ipv4Addr),
+    ArpTipMask(Ipv4Addr),
+    ArpOp(u8),
+    ArpOpMask(u8),
+    ArpSha([u8; 6]),
+    ArpShaMask([u8; 6]),
+    ArpTha([u8; 6]),
+    ArpThaMask([u8; 6]),
+    MplsTtl(u8),
+    MplsBos(u8),
+    MplsTc(u8),
+    MplsLabel(u32),
+    TcpFlags(u16),
+    TcpFlagsMask(u16),
+    IpTos(u8),
+    IpTosMask(u8),
+    IpTtl(u8),
+    IpTtlMask(u8),
+    /// VLAN ID of the inner (customer) VLAN tag
+    CvlanId(u16),
+    CvlanPrio(u8),
+    CvlanEthType(u16),
+    EncIpTos(u8),
+    EncIpTosMask(u8),
+    EncIpTtl(u8),
+    EncIpTtlMask(u8),
+    /// Tunnel options, kept as raw nested attributes
+    EncOpts(Vec<u8>),
+    EncOptsMask(Vec<u8>),
+    /// Number of hardware devices the filter is offloaded to
+    InHwCount(u32),
+    PortSrcMin(u16),
+    PortSrcMax(u16),
+    PortDstMin(u16),
+    PortDstMax(u16),
+    /// Connection tracking state (`TCA_FLOWER_KEY_CT_FLAGS_*`)
+    CtState(u16),
+    CtStateMask(u16),
+    CtZone(u16),
+    CtZoneMask(u16),
+    CtMark(u32),
+    CtMarkMask(u32),
+    CtLabels(Vec<u8>),
+    CtLabelsMask(Vec<u8>),
+    /// MPLS label stack entries, kept as raw nested attributes
+    MplsOpts(Vec<u8>),
+    Hash(u32),
+    HashMask(u32),
+    NumOfVlans(u8),
+    PppoeSid(u16),
+    PppProto(u16),
+    L2tpv3Sid(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref x)
+            | EncOpts(ref x)
+            | EncOptsMask(ref x)
+            | CtLabels(ref x)
+            | CtLabelsMask(ref x)
+            | MplsOpts(ref x) => x.len(),
+            ClassId(_)
+            | Ipv4Src(_)
+            | Ipv4SrcMask(_)
+            | Ipv4Dst(_)
+            | Ipv4DstMask(_)
+            | Flags(_)
+            | EncKeyId(_)
+            | EncIpv4Src(_)
+            | EncIpv4SrcMask(_)
+            | EncIpv4Dst(_)
+            | EncIpv4DstMask(_)
+            | KeyFlags(_)
+            | KeyFlagsMask(_)
+            | ArpSip(_)
+            | ArpSipMask(_)
+            | ArpTip(_)
+            | ArpTipMask(_)
+            | MplsLabel(_)
+            | InHwCount(_)
+            | CtMark(_)
+            | CtMarkMask(_)
+            | Hash(_)
+            | HashMask(_)
+            | L2tpv3Sid(_) => 4,
+            EthType(_)
+            | TcpSrc(_)
+            | TcpDst(_)
+            | UdpSrc(_)
+            | UdpDst(_)
+            | VlanId(_)
+            | VlanEthType(_)
+            | TcpSrcMask(_)
+            | TcpDstMask(_)
+            | UdpSrcMask(_)
+            | UdpDstMask(_)
+            | SctpSrcMask(_)
+            | SctpDstMask(_)
+            | SctpSrc(_)
+            | SctpDst(_)
+            | EncUdpSrcPort(_)
+            | EncUdpSrcPortMask(_)
+            | EncUdpDstPort(_)
+            | EncUdpDstPortMask(_)
+            | TcpFlags(_)
+            | TcpFlagsMask(_)
+            | CvlanId(_)
+            | CvlanEthType(_)
+            | PortSrcMin(_)
+            | PortSrcMax(_)
+            | PortDstMin(_)
+            | PortDstMax(_)
+            | CtState(_)
+            | CtStateMask(_)
+            | CtZone(_)
+            | CtZoneMask(_)
+            | PppoeSid(_)
+            | PppProto(_) => 2,
+            IpProto(_)
+            | VlanPrio(_)
+            | Icmpv4Code(_)
+            | Icmpv4CodeMask(_)
+            | Icmpv4Type(_)
+            | Icmpv4TypeMask(_)
+            | Icmpv6Code(_)
+            | Icmpv6CodeMask(_)
+            | Icmpv6Type(_)
+            | Icmpv6TypeMask(_)
+            | ArpOp(_)
+            | ArpOpMask(_)
+            | MplsTtl(_)
+            | MplsBos(_)
+            | MplsTc(_)
+            | IpTos(_)
+            | IpTosMask(_)
+            | IpTtl(_)
+            | IpTtlMask(_)
+            | CvlanPrio(_)
+            | EncIpTos(_)
+            | EncIpTosMask(_)
+            | EncIpTtl(_)
+            | EncIpTtlMask(_)
+            | NumOfVlans(_) => 1,
+            EthDst(_)
+            | EthDstMask(_)
+            | EthSrc(_)
+            | EthSrcMask(_)
+            | ArpSha(_)
+            | ArpShaMask(_)
+            | ArpTha(_)
+            | ArpThaMask(_) => 6,
+            Ipv6Src(_)
+            | Ipv6SrcMask(_)
+            | Ipv6Dst(_)
+            | Ipv6DstMask(_)
+            | EncIpv6Src(_)
+            | EncIpv6SrcMask(_)
+            | EncIpv6Dst(_)
+            | EncIpv6DstMask(_) => 16,
+            Indev(ref s) => s.len() + 1,
+            Act(ref acts) => acts.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref x)
+            | EncOpts(ref x)
+            | EncOptsMask(ref x)
+            | CtLabels(ref x)
+            | CtLabelsMask(ref x)
+            | MplsOpts(ref x) => buffer.copy_from_slice(x.as_slice()),
+            ClassId(x)
+            | Flags(x)
+            | MplsLabel(x)
+            | InHwCount(x)
+            | CtMark(x)
+            | CtMarkMask(x)
+            | Hash(x)
+            | HashMask(x) => NativeEndian::write_u32(buffer, x),
+            EncKeyId(x)
+            | KeyFlags(x)
+            | KeyFlagsMask(x)
+            | L2tpv3Sid(x) => BigEndian::write_u32(buffer, x),
+            VlanId(x)
+            | CvlanId(x)
+            | CtState(x)
+            | CtStateMask(x)
+            | CtZone(x)
+            | CtZoneMask(x) => NativeEndian::write_u16(buffer, x),
+            EthType(x)
+            | TcpSrc(x)
+            | TcpDst(x)
+            | UdpSrc(x)
+            | UdpDst(x)
+            | VlanEthType(x)
+            | TcpSrcMask(x)
+            | TcpDstMask(x)
+            | UdpSrcMask(x)
+            | UdpDstMask(x)
+            | SctpSrcMask(x)
+            | SctpDstMask(x)
+            | SctpSrc(x)
+            | SctpDst(x)
+            | EncUdpSrcPort(x)
+            | EncUdpSrcPortMask(x)
+            | EncUdpDstPort(x)
+            | EncUdpDstPortMask(x)
+            | TcpFlags(x)
+            | TcpFlagsMask(x)
+            | CvlanEthType(x)
+            | PortSrcMin(x)
+            | PortSrcMax(x)
+            | PortDstMin(x)
+            | PortDstMax(x)
+            | PppoeSid(x)
+            | PppProto(x) => BigEndian::write_u16(buffer, x),
+            IpProto(x)
+            | VlanPrio(x)
+            | Icmpv4Code(x)
+            | Icmpv4CodeMask(x)
+            | Icmpv4Type(x)
+            | Icmpv4TypeMask(x)
+            | Icmpv6Code(x)
+            | Icmpv6CodeMask(x)
+            | Icmpv6Type(x)
+            | Icmpv6TypeMask(x)
+            | ArpOp(x)
+            | ArpOpMask(x)
+            | MplsTtl(x)
+            | MplsBos(x)
+            | MplsTc(x)
+            | IpTos(x)
+            | IpTosMask(x)
+            | IpTtl(x)
+            | IpTtlMask(x)
+            | CvlanPrio(x)
+            | EncIpTos(x)
+            | EncIpTosMask(x)
+            | EncIpTtl(x)
+            | EncIpTtlMask(x)
+            | NumOfVlans(x) => buffer[0] = x,
+            EthDst(ref x)
+            | EthDstMask(ref x)
+            | EthSrc(ref x)
+            | EthSrcMask(ref x)
+            | ArpSha(ref x)
+            | ArpShaMask(ref x)
+            | ArpTha(ref x)
+            | ArpThaMask(ref x) => buffer.copy_from_slice(&x[..]),
+            Ipv4Src(ref x)
+            | Ipv4SrcMask(ref x)
+            | Ipv4Dst(ref x)
+            | Ipv4DstMask(ref x)
+            | EncIpv4Src(ref x)
+            | EncIpv4SrcMask(ref x)
+            | EncIpv4Dst(ref x)
+            | EncIpv4DstMask(ref x)
+            | ArpSip(ref x)
+            | ArpSipMask(ref x)
+            | ArpTip(ref x)
+            | ArpTipMask(ref x) => buffer.copy_from_slice(&x.octets()),
+            Ipv6Src(ref x)
+            | Ipv6SrcMask(ref x)
+            | Ipv6Dst(ref x)
+            | Ipv6DstMask(ref x)
+            | EncIpv6Src(ref x)
+            | EncIpv6SrcMask(ref x)
+            | EncIpv6Dst(ref x)
+            | EncIpv6DstMask(ref x) => buffer.copy_from_slice(&x.octets()),
+            Indev(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Act(ref acts) => acts.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        use self::Nla::*;
+        matches!(self, Act(_) | EncOpts(_) | EncOptsMask(_) | MplsOpts(_))
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_FLOWER_UNSPEC,
+            ClassId(_) => TCA_FLOWER_CLASSID,
+            Indev(_) => TCA_FLOWER_INDEV,
+            Act(_) => TCA_FLOWER_ACT,
+            EthDst(_) => TCA_FLOWER_KEY_ETH_DST,
+            EthDstMask(_) => TCA_FLOWER_KEY_ETH_DST_MASK,
+            EthSrc(_) => TCA_FLOWER_KEY_ETH_SRC,
+            EthSrcMask(_) => TCA_FLOWER_KEY_ETH_SRC_MASK,
+            EthType(_) => TCA_FLOWER_KEY_ETH_TYPE,
+            IpProto(_) => TCA_FLOWER_KEY_IP_PROTO,
+            Ipv4Src(_) => TCA_FLOWER_KEY_IPV4_SRC,
+            Ipv4SrcMask(_) => TCA_FLOWER_KEY_IPV4_SRC_MASK,
+            Ipv4Dst(_) => TCA_FLOWER_KEY_IPV4_DST,
+            Ipv4DstMask(_) => TCA_FLOWER_KEY_IPV4_DST_MASK,
+            Ipv6Src(_) => TCA_FLOWER_KEY_IPV6_SRC,
+            Ipv6SrcMask(_) => TCA_FLOWER_KEY_IPV6_SRC_MASK,
+            Ipv6Dst(_) => TCA_FLOWER_KEY_IPV6_DST,
+            Ipv6DstMask(_) => TCA_FLOWER_KEY_IPV6_DST_MASK,
+            TcpSrc(_) => TCA_FLOWER_KEY_TCP_SRC,
+            TcpDst(_) => TCA_FLOWER_KEY_TCP_DST,
+            UdpSrc(_) => TCA_FLOWER_KEY_UDP_SRC,
+            UdpDst(_) => TCA_FLOWER_KEY_UDP_DST,
+            Flags(_) => TCA_FLOWER_FLAGS,
+            VlanId(_) => TCA_FLOWER_KEY_VLAN_ID,
+            VlanPrio(_) => TCA_FLOWER_KEY_VLAN_PRIO,
+            VlanEthType(_) => TCA_FLOWER_KEY_VLAN_ETH_TYPE,
+            EncKeyId(_) => TCA_FLOWER_KEY_ENC_KEY_ID,
+            EncIpv4Src(_) => TCA_FLOWER_KEY_ENC_IPV4_SRC,
+            EncIpv4SrcMask(_) => TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK,
+            EncIpv4Dst(_) => TCA_FLOWER_KEY_ENC_IPV4_DST,
+            EncIpv4DstMask(_) => TCA_FLOWER_KEY_ENC_IPV4_DST_MASK,
+            EncIpv6Src(_) => TCA_FLOWER_KEY_ENC_IPV6_SRC,
+            EncIpv6SrcMask(_) => TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK,
+            EncIpv6Dst(_) => TCA_FLOWER_KEY_ENC_IPV6_DST,
+            EncIpv6DstMask(_) => TCA_FLOWER_KEY_ENC_IPV6_DST_MASK,
+            TcpSrcMask(_) => TCA_FLOWER_KEY_TCP_SRC_MASK,
+            TcpDstMask(_) => TCA_FLOWER_KEY_TCP_DST_MASK,
+            UdpSrcMask(_) => TCA_FLOWER_KEY_UDP_SRC_MASK,
+            UdpDstMask(_) => TCA_FLOWER_KEY_UDP_DST_MASK,
+            SctpSrcMask(_) => TCA_FLOWER_KEY_SCTP_SRC_MASK,
+            SctpDstMask(_) => TCA_FLOWER_KEY_SCTP_DST_MASK,
+            SctpSrc(_) => TCA_FLOWER_KEY_SCTP_SRC,
+            SctpDst(_) => TCA_FLOWER_KEY_SCTP_DST,
+            EncUdpSrcPort(_) => TCA_FLOWER_KEY_ENC_UDP_SRC_PORT,
+            EncUdpSrcPortMask(_) => TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK,
+            EncUdpDstPort(_) => TCA_FLOWER_KEY_ENC_UDP_DST_PORT,
+            EncUdpDstPortMask(_) => TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK,
+            KeyFlags(_) => TCA_FLOWER_KEY_FLAGS,
+            KeyFlagsMask(_) => TCA_FLOWER_KEY_FLAGS_MASK,
+            Icmpv4Code(_) => TCA_FLOWER_KEY_ICMPV4_CODE,
+            Icmpv4CodeMask(_) => TCA_FLOWER_KEY_ICMPV4_CODE_MASK,
+            Icmpv4Type(_) => TCA_FLOWER_KEY_ICMPV4_TYPE,
+            Icmpv4TypeMask(_) => TCA_FLOWER_KEY_ICMPV4_TYPE_MASK,
+            Icmpv6Code(_) => TCA_FLOWER_KEY_ICMPV6_CODE,
+            Icmpv6CodeMask(_) => TCA_FLOWER_KEY_ICMPV6_CODE_MASK,
+            Icmpv6Type(_) => TCA_FLOWER_KEY_ICMPV6_TYPE,
+            Icmpv6TypeMask(_) => TCA_FLOWER_KEY_ICMPV6_TYPE_MASK,
+            ArpSip(_) => TCA_FLOWER_KEY_ARP_SIP,
+            ArpSipMask(_) => TCA_FLOWER_KEY_ARP_SIP_MASK,
+            ArpTip(_) => TCA_FLOWER_KEY_ARP_TIP,
+            ArpTipMask(_) => TCA_FLOWER_KEY_ARP_TIP_MASK,
+            ArpOp(_) => TCA_FLOWER_KEY_ARP_OP,
+            ArpOpMask(_) => TCA_FLOWER_KEY_ARP_OP_MASK,
+            ArpSha(_) => TCA_FLOWER_KEY_ARP_SHA,
+            ArpShaMask(_) => TCA_FLOWER_KEY_ARP_SHA_MASK,
+            ArpTha(_) => TCA_FLOWER_KEY_ARP_THA,
+            ArpThaMask(_) => TCA_FLOWER_KEY_ARP_THA_MASK,
+            MplsTtl(_) => TCA_FLOWER_KEY_MPLS_TTL,
+            MplsBos(_) => TCA_FLOWER_KEY_MPLS_BOS,
+            MplsTc(_) => TCA_FLOWER_KEY_MPLS_TC,
+            MplsLabel(_) => TCA_FLOWER_KEY_MPLS_LABEL,
+            TcpFlags(_) => TCA_FLOWER_KEY_TCP_FLAGS,
+            TcpFlagsMask(_) => TCA_FLOWER_KEY_TCP_FLAGS_MASK,
+            IpTos(_) => TCA_FLOWER_KEY_IP_TOS,
+            IpTosMask(_) => TCA_FLOWER_KEY_IP_TOS_MASK,
+            IpTtl(_) => TCA_FLOWER_KEY_IP_TTL,
+            IpTtlMask(_) => TCA_FLOWER_KEY_IP_TTL_MASK,
+            CvlanId(_) => TCA_FLOWER_KEY_CVLAN_ID,
+            CvlanPrio(_) => TCA_FLOWER_KEY_CVLAN_PRIO,
+            CvlanEthType(_) => TCA_FLOWER_KEY_CVLAN_ETH_TYPE,
+            EncIpTos(_) => TCA_FLOWER_KEY_ENC_IP_TOS,
+            EncIpTosMask(_) => TCA_FLOWER_KEY_ENC_IP_TOS_MASK,
+            EncIpTtl(_) => TCA_FLOWER_KEY_ENC_IP_TTL,
+            EncIpTtlMask(_) => TCA_FLOWER_KEY_ENC_IP_TTL_MASK,
+            EncOpts(_) => TCA_FLOWER_KEY_ENC_OPTS,
+            EncOptsMask(_) => TCA_FLOWER_KEY_ENC_OPTS_MASK,
+            InHwCount(_) => TCA_FLOWER_IN_HW_COUNT,
+            PortSrcMin(_) => TCA_FLOWER_KEY_PORT_SRC_MIN,
+            PortSrcMax(_) => TCA_FLOWER_KEY_PORT_SRC_MAX,
+            PortDstMin(_) => TCA_FLOWER_KEY_PORT_DST_MIN,
+            PortDstMax(_) => TCA_FLOWER_KEY_PORT_DST_MAX,
+            CtState(_) => TCA_FLOWER_KEY_CT_STATE,
+            CtStateMask(_) => TCA_FLOWER_KEY_CT_STATE_MASK,
+            CtZone(_) => TCA_FLOWER_KEY_CT_ZONE,
+            CtZoneMask(_) => TCA_FLOWER_KEY_CT_ZONE_MASK,
+            CtMark(_) => TCA_FLOWER_KEY_CT_MARK,
+            CtMarkMask(_) => TCA_FLOWER_KEY_CT_MARK_MASK,
+            CtLabels(_) => TCA_FLOWER_KEY_CT_LABELS,
+            CtLabelsMask(_) => TCA_FLOWER_KEY_CT_LABELS_MASK,
+            MplsOpts(_) => TCA_FLOWER_KEY_MPLS_OPTS,
+            Hash(_) => TCA_FLOWER_KEY_HASH,
+            HashMask(_) => TCA_FLOWER_KEY_HASH_MASK,
+            NumOfVlans(_) => TCA_FLOWER_KEY_NUM_OF_VLANS,
+            PppoeSid(_) => TCA_FLOWER_KEY_PPPOE_SID,
+            PppProto(_) => TCA_FLOWER_KEY_PPP_PROTO,
+            L2tpv3Sid(_) => TCA_FLOWER_KEY_L2TPV3_SID,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_UNSPEC => Unspec(payload.to_vec()),
+            TCA_FLOWER_CLASSID => {
+                ClassId(parse_u32(payload).context("failed to parse TCA_FLOWER_CLASSID")?)
+            }
+            TCA_FLOWER_INDEV => {
+                Indev(parse_string(payload).context("failed to parse TCA_FLOWER_INDEV")?)
+            }
+            TCA_FLOWER_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act.context("invalid TCA_FLOWER_ACT")?;
+                    acts.push(Action::parse(&act).context("failed to parse TCA_FLOWER_ACT")?);
+                }
+                Act(acts)
+            }
+            TCA_FLOWER_KEY_ETH_DST => {
+                EthDst(parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ETH_DST")?)
+            }
+            TCA_FLOWER_KEY_ETH_DST_MASK => EthDstMask(
+                parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ETH_DST_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ETH_SRC => {
+                EthSrc(parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ETH_SRC")?)
+            }
+            TCA_FLOWER_KEY_ETH_SRC_MASK => EthSrcMask(
+                parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ETH_SRC_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ETH_TYPE => {
+                EthType(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_ETH_TYPE")?)
+            }
+            TCA_FLOWER_KEY_IP_PROTO => {
+                IpProto(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_IP_PROTO")?)
+            }
+            TCA_FLOWER_KEY_IPV4_SRC => Ipv4Src(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_IPV4_SRC")?,
+            )),
+            TCA_FLOWER_KEY_IPV4_SRC_MASK => Ipv4SrcMask(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_IPV4_SRC_MASK")?,
+            )),
+            TCA_FLOWER_KEY_IPV4_DST => Ipv4Dst(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_IPV4_DST")?,
+            )),
+            TCA_FLOWER_KEY_IPV4_DST_MASK => Ipv4DstMask(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_IPV4_DST_MASK")?,
+            )),
+            TCA_FLOWER_KEY_IPV6_SRC => Ipv6Src(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_IPV6_SRC")?,
+            )),
+            TCA_FLOWER_KEY_IPV6_SRC_MASK => Ipv6SrcMask(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_IPV6_SRC_MASK")?,
+            )),
+            TCA_FLOWER_KEY_IPV6_DST => Ipv6Dst(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_IPV6_DST")?,
+            )),
+            TCA_FLOWER_KEY_IPV6_DST_MASK => Ipv6DstMask(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_IPV6_DST_MASK")?,
+            )),
+            TCA_FLOWER_KEY_TCP_SRC => {
+                TcpSrc(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_TCP_SRC")?)
+            }
+            TCA_FLOWER_KEY_TCP_DST => {
+                TcpDst(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_TCP_DST")?)
+            }
+            TCA_FLOWER_KEY_UDP_SRC => {
+                UdpSrc(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_UDP_SRC")?)
+            }
+            TCA_FLOWER_KEY_UDP_DST => {
+                UdpDst(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_UDP_DST")?)
+            }
+            TCA_FLOWER_FLAGS => {
+                Flags(parse_u32(payload).context("failed to parse TCA_FLOWER_FLAGS")?)
+            }
+            TCA_FLOWER_KEY_VLAN_ID => {
+                VlanId(parse_u16(payload).context("failed to parse TCA_FLOWER_KEY_VLAN_ID")?)
+            }
+            TCA_FLOWER_KEY_VLAN_PRIO => {
+                VlanPrio(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_VLAN_PRIO")?)
+            }
+            TCA_FLOWER_KEY_VLAN_ETH_TYPE => VlanEthType(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_VLAN_ETH_TYPE")?,
+            ),
+            TCA_FLOWER_KEY_ENC_KEY_ID => EncKeyId(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ENC_KEY_ID")?,
+            ),
+            TCA_FLOWER_KEY_ENC_IPV4_SRC => EncIpv4Src(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IPV4_SRC")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK => EncIpv4SrcMask(Ipv4Addr::from(
+                parse_u32_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV4_DST => EncIpv4Dst(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IPV4_DST")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV4_DST_MASK => EncIpv4DstMask(Ipv4Addr::from(
+                parse_u32_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ENC_IPV4_DST_MASK")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV6_SRC => EncIpv6Src(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IPV6_SRC")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK => EncIpv6SrcMask(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV6_DST => EncIpv6Dst(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IPV6_DST")?,
+            )),
+            TCA_FLOWER_KEY_ENC_IPV6_DST_MASK => EncIpv6DstMask(Ipv6Addr::from(
+                parse_ipv6(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IPV6_DST_MASK")?,
+            )),
+            TCA_FLOWER_KEY_TCP_SRC_MASK => TcpSrcMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_TCP_SRC_MASK")?,
+            ),
+            TCA_FLOWER_KEY_TCP_DST_MASK => TcpDstMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_TCP_DST_MASK")?,
+            ),
+            TCA_FLOWER_KEY_UDP_SRC_MASK => UdpSrcMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_UDP_SRC_MASK")?,
+            ),
+            TCA_FLOWER_KEY_UDP_DST_MASK => UdpDstMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_UDP_DST_MASK")?,
+            ),
+            TCA_FLOWER_KEY_SCTP_SRC_MASK => SctpSrcMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_SCTP_SRC_MASK")?,
+            ),
+            TCA_FLOWER_KEY_SCTP_DST_MASK => SctpDstMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_SCTP_DST_MASK")?,
+            ),
+            TCA_FLOWER_KEY_SCTP_SRC => {
+                SctpSrc(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_SCTP_SRC")?)
+            }
+            TCA_FLOWER_KEY_SCTP_DST => {
+                SctpDst(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_SCTP_DST")?)
+            }
+            TCA_FLOWER_KEY_ENC_UDP_SRC_PORT => EncUdpSrcPort(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_ENC_UDP_SRC_PORT")?,
+            ),
+            TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK => EncUdpSrcPortMask(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ENC_UDP_DST_PORT => EncUdpDstPort(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_ENC_UDP_DST_PORT")?,
+            ),
+            TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK => EncUdpDstPortMask(
+                parse_u16_be(payload)
+                    .context("failed to parse TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK")?,
+            ),
+            TCA_FLOWER_KEY_FLAGS => {
+                KeyFlags(parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_FLAGS")?)
+            }
+            TCA_FLOWER_KEY_FLAGS_MASK => KeyFlagsMask(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_FLAGS_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV4_CODE => {
+                Icmpv4Code(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV4_CODE")?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_CODE_MASK => Icmpv4CodeMask(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV4_CODE_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV4_TYPE => {
+                Icmpv4Type(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV4_TYPE")?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_TYPE_MASK => Icmpv4TypeMask(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV4_TYPE_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV6_CODE => {
+                Icmpv6Code(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV6_CODE")?)
+            }
+            TCA_FLOWER_KEY_ICMPV6_CODE_MASK => Icmpv6CodeMask(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV6_CODE_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ICMPV6_TYPE => {
+                Icmpv6Type(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV6_TYPE")?)
+            }
+            TCA_FLOWER_KEY_ICMPV6_TYPE_MASK => Icmpv6TypeMask(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ICMPV6_TYPE_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ARP_SIP => ArpSip(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ARP_SIP")?,
+            )),
+            TCA_FLOWER_KEY_ARP_SIP_MASK => ArpSipMask(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ARP_SIP_MASK")?,
+            )),
+            TCA_FLOWER_KEY_ARP_TIP => ArpTip(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ARP_TIP")?,
+            )),
+            TCA_FLOWER_KEY_ARP_TIP_MASK => ArpTipMask(Ipv4Addr::from(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_ARP_TIP_MASK")?,
+            )),
+            TCA_FLOWER_KEY_ARP_OP => {
+                ArpOp(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ARP_OP")?)
+            }
+            TCA_FLOWER_KEY_ARP_OP_MASK => {
+                ArpOpMask(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ARP_OP_MASK")?)
+            }
+            TCA_FLOWER_KEY_ARP_SHA => {
+                ArpSha(parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ARP_SHA")?)
+            }
+            TCA_FLOWER_KEY_ARP_SHA_MASK => ArpShaMask(
+                parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ARP_SHA_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ARP_THA => {
+                ArpTha(parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ARP_THA")?)
+            }
+            TCA_FLOWER_KEY_ARP_THA_MASK => ArpThaMask(
+                parse_mac(payload).context("failed to parse TCA_FLOWER_KEY_ARP_THA_MASK")?,
+            ),
+            TCA_FLOWER_KEY_MPLS_TTL => {
+                MplsTtl(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_MPLS_TTL")?)
+            }
+            TCA_FLOWER_KEY_MPLS_BOS => {
+                MplsBos(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_MPLS_BOS")?)
+            }
+            TCA_FLOWER_KEY_MPLS_TC => {
+                MplsTc(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_MPLS_TC")?)
+            }
+            TCA_FLOWER_KEY_MPLS_LABEL => {
+                MplsLabel(parse_u32(payload).context("failed to parse TCA_FLOWER_KEY_MPLS_LABEL")?)
+            }
+            TCA_FLOWER_KEY_TCP_FLAGS => {
+                TcpFlags(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_TCP_FLAGS")?)
+            }
+            TCA_FLOWER_KEY_TCP_FLAGS_MASK => TcpFlagsMask(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_TCP_FLAGS_MASK")?,
+            ),
+            TCA_FLOWER_KEY_IP_TOS => {
+                IpTos(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_IP_TOS")?)
+            }
+            TCA_FLOWER_KEY_IP_TOS_MASK => {
+                IpTosMask(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_IP_TOS_MASK")?)
+            }
+            TCA_FLOWER_KEY_IP_TTL => {
+                IpTtl(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_IP_TTL")?)
+            }
+            TCA_FLOWER_KEY_IP_TTL_MASK => {
+                IpTtlMask(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_IP_TTL_MASK")?)
+            }
+            TCA_FLOWER_KEY_CVLAN_ID => {
+                CvlanId(parse_u16(payload).context("failed to parse TCA_FLOWER_KEY_CVLAN_ID")?)
+            }
+            TCA_FLOWER_KEY_CVLAN_PRIO => {
+                CvlanPrio(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_CVLAN_PRIO")?)
+            }
+            TCA_FLOWER_KEY_CVLAN_ETH_TYPE => CvlanEthType(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_CVLAN_ETH_TYPE")?,
+            ),
+            TCA_FLOWER_KEY_ENC_IP_TOS => {
+                EncIpTos(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IP_TOS")?)
+            }
+            TCA_FLOWER_KEY_ENC_IP_TOS_MASK => EncIpTosMask(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IP_TOS_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ENC_IP_TTL => {
+                EncIpTtl(parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IP_TTL")?)
+            }
+            TCA_FLOWER_KEY_ENC_IP_TTL_MASK => EncIpTtlMask(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_ENC_IP_TTL_MASK")?,
+            ),
+            TCA_FLOWER_KEY_ENC_OPTS => EncOpts(payload.to_vec()),
+            TCA_FLOWER_KEY_ENC_OPTS_MASK => EncOptsMask(payload.to_vec()),
+            TCA_FLOWER_IN_HW_COUNT => {
+                InHwCount(parse_u32(payload).context("failed to parse TCA_FLOWER_IN_HW_COUNT")?)
+            }
+            TCA_FLOWER_KEY_PORT_SRC_MIN => PortSrcMin(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_PORT_SRC_MIN")?,
+            ),
+            TCA_FLOWER_KEY_PORT_SRC_MAX => PortSrcMax(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_PORT_SRC_MAX")?,
+            ),
+            TCA_FLOWER_KEY_PORT_DST_MIN => PortDstMin(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_PORT_DST_MIN")?,
+            ),
+            TCA_FLOWER_KEY_PORT_DST_MAX => PortDstMax(
+                parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_PORT_DST_MAX")?,
+            ),
+            TCA_FLOWER_KEY_CT_STATE => {
+                CtState(parse_u16(payload).context("failed to parse TCA_FLOWER_KEY_CT_STATE")?)
+            }
+            TCA_FLOWER_KEY_CT_STATE_MASK => CtStateMask(
+                parse_u16(payload).context("failed to parse TCA_FLOWER_KEY_CT_STATE_MASK")?,
+            ),
+            TCA_FLOWER_KEY_CT_ZONE => {
+                CtZone(parse_u16(payload).context("failed to parse TCA_FLOWER_KEY_CT_ZONE")?)
+            }
+            TCA_FLOWER_KEY_CT_ZONE_MASK => CtZoneMask(
+                parse_u16(payload).context("failed to parse TCA_FLOWER_KEY_CT_ZONE_MASK")?,
+            ),
+            TCA_FLOWER_KEY_CT_MARK => {
+                CtMark(parse_u32(payload).context("failed to parse TCA_FLOWER_KEY_CT_MARK")?)
+            }
+            TCA_FLOWER_KEY_CT_MARK_MASK => CtMarkMask(
+                parse_u32(payload).context("failed to parse TCA_FLOWER_KEY_CT_MARK_MASK")?,
+            ),
+            TCA_FLOWER_KEY_CT_LABELS => CtLabels(payload.to_vec()),
+            TCA_FLOWER_KEY_CT_LABELS_MASK => CtLabelsMask(payload.to_vec()),
+            TCA_FLOWER_KEY_MPLS_OPTS => MplsOpts(payload.to_vec()),
+            TCA_FLOWER_KEY_HASH => {
+                Hash(parse_u32(payload).context("failed to parse TCA_FLOWER_KEY_HASH")?)
+            }
+            TCA_FLOWER_KEY_HASH_MASK => {
+                HashMask(parse_u32(payload).context("failed to parse TCA_FLOWER_KEY_HASH_MASK")?)
+            }
+            TCA_FLOWER_KEY_NUM_OF_VLANS => NumOfVlans(
+                parse_u8(payload).context("failed to parse TCA_FLOWER_KEY_NUM_OF_VLANS")?,
+            ),
+            TCA_FLOWER_KEY_PPPOE_SID => {
+                PppoeSid(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_PPPOE_SID")?)
+            }
+            TCA_FLOWER_KEY_PPP_PROTO => {
+                PppProto(parse_u16_be(payload).context("failed to parse TCA_FLOWER_KEY_PPP_PROTO")?)
+            }
+            TCA_FLOWER_KEY_L2TPV3_SID => L2tpv3Sid(
+                parse_u32_be(payload).context("failed to parse TCA_FLOWER_KEY_L2TPV3_SID")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse flower nla")?),
+        })
+    }
+}
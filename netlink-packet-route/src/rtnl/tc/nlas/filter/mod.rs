@@ -1,3 +1,5 @@
 // SPDX-License-Identifier: MIT
 
+pub mod bpf;
+pub mod flower;
 pub mod u32;
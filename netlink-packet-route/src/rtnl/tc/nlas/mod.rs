@@ -9,9 +9,18 @@ pub use self::stats_queue::*;
 mod stats_basic;
 pub use self::stats_basic::*;
 
+mod stats_rate_est;
+pub use self::stats_rate_est::*;
+
 mod options;
 pub use self::options::*;
 
+mod ratespec;
+pub use self::ratespec::*;
+
+mod xstats;
+pub use self::xstats::*;
+
 mod qdisc;
 pub use self::qdisc::*;
 
@@ -24,9 +33,13 @@ pub use self::action::*;
 #[cfg(test)]
 mod test;
 
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
 use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u64,
     traits::{Emitable, Parseable},
     DecodeError,
 };
@@ -42,7 +55,7 @@ pub enum Nla {
     /// Statistics
     Stats(Stats),
     /// Module-specific statistics
-    XStats(Vec<u8>),
+    XStats(XStats),
     /// Rate limit
     Rate(Vec<u8>),
     Fcnt(Vec<u8>),
@@ -50,6 +63,10 @@ pub enum Nla {
     Stab(Vec<u8>),
     Chain(Vec<u8>),
     HwOffload(u8),
+    /// Index of the shared block of the ingress filters of the qdisc
+    IngressBlock(u32),
+    /// Index of the shared block of the egress filters of the qdisc
+    EgressBlock(u32),
     Other(DefaultNla),
 }
 
@@ -59,13 +76,15 @@ impl nlas::Nla for Nla {
         use self::Nla::*;
         match *self {
             // Vec<u8>
-            Unspec(ref bytes) | XStats(ref bytes) | Rate(ref bytes) | Fcnt(ref bytes)
+            Unspec(ref bytes) | Rate(ref bytes) | Fcnt(ref bytes)
             | Stab(ref bytes) | Chain(ref bytes) => bytes.len(),
+            XStats(ref stats) => stats.buffer_len(),
             HwOffload(_) => 1,
+            IngressBlock(_) | EgressBlock(_) => 4,
             Stats2(ref thing) => thing.as_slice().buffer_len(),
             Stats(_) => STATS_LEN,
             Kind(ref string) => string.as_bytes().len() + 1,
-            Options(ref opt) => opt.as_slice().buffer_len(),
+            Options(ref opt) => options_buffer_len(opt),
             // Defaults
             Other(ref attr) => attr.value_len(),
         }
@@ -77,21 +96,22 @@ impl nlas::Nla for Nla {
         match *self {
             // Vec<u8>
             Unspec(ref bytes)
-                | XStats(ref bytes)
                 | Rate(ref bytes)
                 | Fcnt(ref bytes)
                 | Stab(ref bytes)
                 | Chain(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
 
             HwOffload(ref val) => buffer[0] = *val,
+            IngressBlock(val) | EgressBlock(val) => NativeEndian::write_u32(buffer, val),
             Stats2(ref stats) => stats.as_slice().emit(buffer),
             Stats(ref stats) => stats.emit(buffer),
+            XStats(ref stats) => stats.emit(buffer),
 
             Kind(ref string) => {
                 buffer[..string.as_bytes().len()].copy_from_slice(string.as_bytes());
                 buffer[string.as_bytes().len()] = 0;
             }
-            Options(ref opt) => opt.as_slice().emit(buffer),
+            Options(ref opt) => emit_options(opt, buffer),
 
             // Default
             Other(ref attr) => attr.emit_value(buffer),
@@ -112,16 +132,28 @@ impl nlas::Nla for Nla {
             Stab(_) => TCA_STAB,
             Chain(_) => TCA_CHAIN,
             HwOffload(_) => TCA_HW_OFFLOAD,
+            IngressBlock(_) => TCA_INGRESS_BLOCK,
+            EgressBlock(_) => TCA_EGRESS_BLOCK,
             Other(ref nla) => nla.kind(),
         }
     }
 }
 
+/// `struct gnet_stats_basic` is padded to the alignment of its `u64` field
+const GNET_STATS_BASIC_LEN: usize = 16;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Stats2 {
+    /// Statistics specific to the kind of the object, see `XStats`
     StatsApp(Vec<u8>),
-    StatsBasic(Vec<u8>),
-    StatsQueue(Vec<u8>),
+    StatsBasic(StatsBasic),
+    StatsRateEst(StatsRateEst),
+    StatsQueue(StatsQueue),
+    StatsRateEst64(StatsRateEst64),
+    /// Statistics of the traffic handled by the hardware
+    StatsBasicHw(StatsBasic),
+    /// Number of packets, when it does not fit in `StatsBasic::packets`
+    StatsPkt64(u64),
     Other(DefaultNla),
 }
 
@@ -129,7 +161,12 @@ impl nlas::Nla for Stats2 {
     fn value_len(&self) -> usize {
         use self::Stats2::*;
         match *self {
-            StatsBasic(ref bytes) | StatsQueue(ref bytes) | StatsApp(ref bytes) => bytes.len(),
+            StatsApp(ref bytes) => bytes.len(),
+            StatsBasic(_) | StatsBasicHw(_) => GNET_STATS_BASIC_LEN,
+            StatsRateEst(ref stats) => stats.buffer_len(),
+            StatsQueue(ref stats) => stats.buffer_len(),
+            StatsRateEst64(ref stats) => stats.buffer_len(),
+            StatsPkt64(_) => 8,
             Other(ref nla) => nla.value_len(),
         }
     }
@@ -137,9 +174,17 @@ impl nlas::Nla for Stats2 {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::Stats2::*;
         match *self {
-            StatsBasic(ref bytes) | StatsQueue(ref bytes) | StatsApp(ref bytes) => {
-                buffer.copy_from_slice(bytes.as_slice())
+            StatsApp(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            StatsBasic(ref stats) | StatsBasicHw(ref stats) => {
+                stats.emit(buffer);
+                for byte in &mut buffer[STATS_BASIC_LEN..] {
+                    *byte = 0;
+                }
             }
+            StatsRateEst(ref stats) => stats.emit(buffer),
+            StatsQueue(ref stats) => stats.emit(buffer),
+            StatsRateEst64(ref stats) => stats.emit(buffer),
+            StatsPkt64(value) => NativeEndian::write_u64(buffer, value),
             Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -149,7 +194,11 @@ impl nlas::Nla for Stats2 {
         match *self {
             StatsApp(_) => TCA_STATS_APP,
             StatsBasic(_) => TCA_STATS_BASIC,
+            StatsRateEst(_) => TCA_STATS_RATE_EST,
             StatsQueue(_) => TCA_STATS_QUEUE,
+            StatsRateEst64(_) => TCA_STATS_RATE_EST64,
+            StatsBasicHw(_) => TCA_STATS_BASIC_HW,
+            StatsPkt64(_) => TCA_STATS_PKT64,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -160,8 +209,42 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Stats2 {
         let payload = buf.value();
         Ok(match buf.kind() {
             TCA_STATS_APP => Self::StatsApp(payload.to_vec()),
-            TCA_STATS_BASIC => Self::StatsBasic(payload.to_vec()),
-            TCA_STATS_QUEUE => Self::StatsQueue(payload.to_vec()),
+            TCA_STATS_BASIC => Self::StatsBasic(
+                StatsBasic::parse(
+                    &StatsBasicBuffer::new_checked(payload).context("invalid TCA_STATS_BASIC")?,
+                )
+                .context("failed to parse TCA_STATS_BASIC")?,
+            ),
+            TCA_STATS_RATE_EST => Self::StatsRateEst(
+                StatsRateEst::parse(
+                    &StatsRateEstBuffer::new_checked(payload)
+                        .context("invalid TCA_STATS_RATE_EST")?,
+                )
+                .context("failed to parse TCA_STATS_RATE_EST")?,
+            ),
+            TCA_STATS_QUEUE => Self::StatsQueue(
+                StatsQueue::parse(
+                    &StatsQueueBuffer::new_checked(payload).context("invalid TCA_STATS_QUEUE")?,
+                )
+                .context("failed to parse TCA_STATS_QUEUE")?,
+            ),
+            TCA_STATS_RATE_EST64 => Self::StatsRateEst64(
+                StatsRateEst64::parse(
+                    &StatsRateEst64Buffer::new_checked(payload)
+                        .context("invalid TCA_STATS_RATE_EST64")?,
+                )
+                .context("failed to parse TCA_STATS_RATE_EST64")?,
+            ),
+            TCA_STATS_BASIC_HW => Self::StatsBasicHw(
+                StatsBasic::parse(
+                    &StatsBasicBuffer::new_checked(payload)
+                        .context("invalid TCA_STATS_BASIC_HW")?,
+                )
+                .context("failed to parse TCA_STATS_BASIC_HW")?,
+            ),
+            TCA_STATS_PKT64 => {
+                Self::StatsPkt64(parse_u64(payload).context("failed to parse TCA_STATS_PKT64")?)
+            }
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
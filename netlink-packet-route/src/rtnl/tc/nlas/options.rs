@@ -2,9 +2,24 @@
 use anyhow::Context;
 
 use crate::{
-    nlas::{self, DefaultNla, NlaBuffer},
-    tc::{ingress, u32},
-    traits::{Parseable, ParseableParametrized},
+    nlas::{self, DefaultNla, NlaBuffer, NLA_ALIGNTO},
+    tc::{
+        bpf,
+        flower,
+        fq,
+        fq_codel,
+        htb,
+        ingress,
+        mqprio,
+        netem,
+        prio,
+        tbf,
+        u32,
+        mqprio::TcMqprioQoptBuffer,
+        netem::TcNetemQoptBuffer,
+        prio::TcPrioQoptBuffer,
+    },
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
@@ -12,8 +27,22 @@ use crate::{
 pub enum TcOpt {
     // Qdisc specific options
     Ingress,
+    Htb(htb::Nla),
+    Tbf(tbf::Nla),
+    FqCodel(fq_codel::Nla),
+    Fq(fq::Nla),
+    /// Parameters at the start of the netem options
+    NetemQopt(netem::TcNetemQopt),
+    Netem(netem::Nla),
+    /// The prio options are a single structure, without attributes
+    Prio(prio::TcPrioQopt),
+    /// Parameters at the start of the mqprio options
+    MqprioQopt(mqprio::TcMqprioQopt),
+    Mqprio(mqprio::Nla),
     // Filter specific options
     U32(u32::Nla),
+    Flower(flower::Nla),
+    Bpf(bpf::Nla),
     // Other options
     Other(DefaultNla),
 }
@@ -22,7 +51,18 @@ impl nlas::Nla for TcOpt {
     fn value_len(&self) -> usize {
         match self {
             Self::Ingress => 0,
+            Self::Htb(h) => h.value_len(),
+            Self::Tbf(t) => t.value_len(),
+            Self::FqCodel(f) => f.value_len(),
+            Self::Fq(f) => f.value_len(),
+            Self::NetemQopt(q) => q.buffer_len(),
+            Self::Netem(n) => n.value_len(),
+            Self::Prio(q) => q.buffer_len(),
+            Self::MqprioQopt(q) => q.buffer_len(),
+            Self::Mqprio(m) => m.value_len(),
             Self::U32(u) => u.value_len(),
+            Self::Flower(f) => f.value_len(),
+            Self::Bpf(b) => b.value_len(),
             Self::Other(o) => o.value_len(),
         }
     }
@@ -30,15 +70,46 @@ impl nlas::Nla for TcOpt {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::Ingress => unreachable!(),
+            Self::Htb(h) => h.emit_value(buffer),
+            Self::Tbf(t) => t.emit_value(buffer),
+            Self::FqCodel(f) => f.emit_value(buffer),
+            Self::Fq(f) => f.emit_value(buffer),
+            Self::NetemQopt(q) => q.emit(buffer),
+            Self::Netem(n) => n.emit_value(buffer),
+            Self::Prio(q) => q.emit(buffer),
+            Self::MqprioQopt(q) => q.emit(buffer),
+            Self::Mqprio(m) => m.emit_value(buffer),
             Self::U32(u) => u.emit_value(buffer),
+            Self::Flower(f) => f.emit_value(buffer),
+            Self::Bpf(b) => b.emit_value(buffer),
             Self::Other(o) => o.emit_value(buffer),
         }
     }
 
+    fn is_nested(&self) -> bool {
+        match self {
+            Self::Netem(n) => n.is_nested(),
+            Self::Mqprio(m) => m.is_nested(),
+            Self::Flower(f) => f.is_nested(),
+            Self::Bpf(b) => b.is_nested(),
+            _ => false,
+        }
+    }
+
     fn kind(&self) -> u16 {
         match self {
-            Self::Ingress => unreachable!(),
+            Self::Ingress | Self::NetemQopt(_) | Self::Prio(_) | Self::MqprioQopt(_) => {
+                unreachable!()
+            }
+            Self::Htb(h) => h.kind(),
+            Self::Tbf(t) => t.kind(),
+            Self::FqCodel(f) => f.kind(),
+            Self::Fq(f) => f.kind(),
+            Self::Netem(n) => n.kind(),
+            Self::Mqprio(m) => m.kind(),
             Self::U32(u) => u.kind(),
+            Self::Flower(f) => f.kind(),
+            Self::Bpf(b) => b.kind(),
             Self::Other(o) => o.kind(),
         }
     }
@@ -52,8 +123,108 @@ where
     fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: S) -> Result<Self, DecodeError> {
         Ok(match kind.as_ref() {
             ingress::KIND => TcOpt::Ingress,
+            htb::KIND => Self::Htb(htb::Nla::parse(buf).context("failed to parse htb nlas")?),
+            tbf::KIND => Self::Tbf(tbf::Nla::parse(buf).context("failed to parse tbf nlas")?),
+            fq_codel::KIND => {
+                Self::FqCodel(fq_codel::Nla::parse(buf).context("failed to parse fq_codel nlas")?)
+            }
+            fq::KIND => Self::Fq(fq::Nla::parse(buf).context("failed to parse fq nlas")?),
+            netem::KIND => {
+                Self::Netem(netem::Nla::parse(buf).context("failed to parse netem nlas")?)
+            }
+            mqprio::KIND => {
+                Self::Mqprio(mqprio::Nla::parse(buf).context("failed to parse mqprio nlas")?)
+            }
             u32::KIND => Self::U32(u32::Nla::parse(buf).context("failed to parse u32 nlas")?),
+            flower::KIND => {
+                Self::Flower(flower::Nla::parse(buf).context("failed to parse flower nlas")?)
+            }
+            bpf::KIND => Self::Bpf(bpf::Nla::parse(buf).context("failed to parse bpf nlas")?),
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
 }
+
+impl TcOpt {
+    // The structure at the start of the options of the prio, netem and mqprio
+    // qdiscs, which is emitted without attribute header
+    fn qopt(&self) -> Option<&dyn Emitable> {
+        match self {
+            Self::NetemQopt(q) => Some(q),
+            Self::Prio(q) => Some(q),
+            Self::MqprioQopt(q) => Some(q),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn options_buffer_len(opts: &[TcOpt]) -> usize {
+    opts.iter()
+        .map(|opt| match opt.qopt() {
+            Some(qopt) => nla_align!(qopt.buffer_len()),
+            None => opt.buffer_len(),
+        })
+        .sum()
+}
+
+pub(crate) fn emit_options(opts: &[TcOpt], buffer: &mut [u8]) {
+    let mut offset = 0;
+    for opt in opts {
+        let len = match opt.qopt() {
+            Some(qopt) => {
+                let len = nla_align!(qopt.buffer_len());
+                for byte in buffer[offset..offset + len].iter_mut() {
+                    *byte = 0;
+                }
+                qopt.emit(&mut buffer[offset..]);
+                len
+            }
+            None => {
+                let len = opt.buffer_len();
+                opt.emit(&mut buffer[offset..offset + len]);
+                len
+            }
+        };
+        offset += len;
+    }
+}
+
+/// Parse the structure at the start of the options of the prio, netem and
+/// mqprio qdiscs, and return the remaining attributes
+pub(crate) fn parse_qopt<'a>(
+    payload: &'a [u8],
+    kind: &str,
+) -> Result<(Vec<TcOpt>, &'a [u8]), DecodeError> {
+    let (qopt, len) = match kind {
+        prio::KIND => (
+            TcOpt::Prio(
+                prio::TcPrioQopt::parse(
+                    &TcPrioQoptBuffer::new_checked(payload).context("invalid prio options")?,
+                )
+                .context("failed to parse prio options")?,
+            ),
+            prio::TC_PRIO_QOPT_LEN,
+        ),
+        netem::KIND => (
+            TcOpt::NetemQopt(
+                netem::TcNetemQopt::parse(
+                    &TcNetemQoptBuffer::new_checked(payload).context("invalid netem options")?,
+                )
+                .context("failed to parse netem options")?,
+            ),
+            netem::TC_NETEM_QOPT_LEN,
+        ),
+        mqprio::KIND => (
+            TcOpt::MqprioQopt(
+                mqprio::TcMqprioQopt::parse(
+                    &TcMqprioQoptBuffer::new_checked(payload).context("invalid mqprio options")?,
+                )
+                .context("failed to parse mqprio options")?,
+            ),
+            mqprio::TC_MQPRIO_QOPT_LEN,
+        ),
+        _ => return Ok((vec![], payload)),
+    };
+    let len = std::cmp::min(nla_align!(len), payload.len());
+    Ok((vec![qopt], &payload[len..]))
+}
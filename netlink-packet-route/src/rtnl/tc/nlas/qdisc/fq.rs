@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: MIT
+
+/// FQ (Fair Queue) qdisc
+///
+/// The fq qdisc serves the flows in round robin and paces each of them,
+/// either at the rate requested by the socket (`SO_MAX_PACING_RATE`) or
+/// at `FlowMaxRate`. The rates are in bytes per second.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    tc::constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "fq";
+pub const TC_FQ_QD_STATS_LEN: usize = 104;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Maximum number of packets in the qdisc
+    Plimit(u32),
+    /// Maximum number of packets of a flow
+    FlowPlimit(u32),
+    Quantum(u32),
+    InitialQuantum(u32),
+    /// Enable the pacing when non zero
+    RateEnable(u32),
+    FlowDefaultRate(u32),
+    FlowMaxRate(u32),
+    /// log2 of the number of buckets of the flow table
+    BucketsLog(u32),
+    /// In microseconds
+    FlowRefillDelay(u32),
+    OrphanMask(u32),
+    LowRateThreshold(u32),
+    /// In microseconds
+    CeThreshold(u32),
+    /// In nanoseconds
+    TimerSlack(u32),
+    /// In microseconds
+    Horizon(u32),
+    /// Drop the packets beyond the horizon when non zero, cap their time
+    /// stamp otherwise
+    HorizonDrop(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            HorizonDrop(_) => 1,
+            Plimit(_) | FlowPlimit(_) | Quantum(_) | InitialQuantum(_) | RateEnable(_)
+            | FlowDefaultRate(_) | FlowMaxRate(_) | BucketsLog(_) | FlowRefillDelay(_)
+            | OrphanMask(_) | LowRateThreshold(_) | CeThreshold(_) | TimerSlack(_) | Horizon(_) => {
+                4
+            }
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            HorizonDrop(value) => buffer[0] = *value,
+            Plimit(value)
+            | FlowPlimit(value)
+            | Quantum(value)
+            | InitialQuantum(value)
+            | RateEnable(value)
+            | FlowDefaultRate(value)
+            | FlowMaxRate(value)
+            | BucketsLog(value)
+            | FlowRefillDelay(value)
+            | OrphanMask(value)
+            | LowRateThreshold(value)
+            | CeThreshold(value)
+            | TimerSlack(value)
+            | Horizon(value) => NativeEndian::write_u32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_FQ_UNSPEC,
+            Plimit(_) => TCA_FQ_PLIMIT,
+            FlowPlimit(_) => TCA_FQ_FLOW_PLIMIT,
+            Quantum(_) => TCA_FQ_QUANTUM,
+            InitialQuantum(_) => TCA_FQ_INITIAL_QUANTUM,
+            RateEnable(_) => TCA_FQ_RATE_ENABLE,
+            FlowDefaultRate(_) => TCA_FQ_FLOW_DEFAULT_RATE,
+            FlowMaxRate(_) => TCA_FQ_FLOW_MAX_RATE,
+            BucketsLog(_) => TCA_FQ_BUCKETS_LOG,
+            FlowRefillDelay(_) => TCA_FQ_FLOW_REFILL_DELAY,
+            OrphanMask(_) => TCA_FQ_ORPHAN_MASK,
+            LowRateThreshold(_) => TCA_FQ_LOW_RATE_THRESHOLD,
+            CeThreshold(_) => TCA_FQ_CE_THRESHOLD,
+            TimerSlack(_) => TCA_FQ_TIMER_SLACK,
+            Horizon(_) => TCA_FQ_HORIZON,
+            HorizonDrop(_) => TCA_FQ_HORIZON_DROP,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_UNSPEC => Unspec(payload.to_vec()),
+            TCA_FQ_PLIMIT => Plimit(parse_u32(payload).context("failed to parse TCA_FQ_PLIMIT")?),
+            TCA_FQ_FLOW_PLIMIT => {
+                FlowPlimit(parse_u32(payload).context("failed to parse TCA_FQ_FLOW_PLIMIT")?)
+            }
+            TCA_FQ_QUANTUM => {
+                Quantum(parse_u32(payload).context("failed to parse TCA_FQ_QUANTUM")?)
+            }
+            TCA_FQ_INITIAL_QUANTUM => InitialQuantum(
+                parse_u32(payload).context("failed to parse TCA_FQ_INITIAL_QUANTUM")?,
+            ),
+            TCA_FQ_RATE_ENABLE => {
+                RateEnable(parse_u32(payload).context("failed to parse TCA_FQ_RATE_ENABLE")?)
+            }
+            TCA_FQ_FLOW_DEFAULT_RATE => FlowDefaultRate(
+                parse_u32(payload).context("failed to parse TCA_FQ_FLOW_DEFAULT_RATE")?,
+            ),
+            TCA_FQ_FLOW_MAX_RATE => {
+                FlowMaxRate(parse_u32(payload).context("failed to parse TCA_FQ_FLOW_MAX_RATE")?)
+            }
+            TCA_FQ_BUCKETS_LOG => {
+                BucketsLog(parse_u32(payload).context("failed to parse TCA_FQ_BUCKETS_LOG")?)
+            }
+            TCA_FQ_FLOW_REFILL_DELAY => FlowRefillDelay(
+                parse_u32(payload).context("failed to parse TCA_FQ_FLOW_REFILL_DELAY")?,
+            ),
+            TCA_FQ_ORPHAN_MASK => {
+                OrphanMask(parse_u32(payload).context("failed to parse TCA_FQ_ORPHAN_MASK")?)
+            }
+            TCA_FQ_LOW_RATE_THRESHOLD => LowRateThreshold(
+                parse_u32(payload).context("failed to parse TCA_FQ_LOW_RATE_THRESHOLD")?,
+            ),
+            TCA_FQ_CE_THRESHOLD => {
+                CeThreshold(parse_u32(payload).context("failed to parse TCA_FQ_CE_THRESHOLD")?)
+            }
+            TCA_FQ_TIMER_SLACK => {
+                TimerSlack(parse_u32(payload).context("failed to parse TCA_FQ_TIMER_SLACK")?)
+            }
+            TCA_FQ_HORIZON => {
+                Horizon(parse_u32(payload).context("failed to parse TCA_FQ_HORIZON")?)
+            }
+            TCA_FQ_HORIZON_DROP => {
+                HorizonDrop(parse_u8(payload).context("failed to parse TCA_FQ_HORIZON_DROP")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("failed to parse fq nla")?),
+        })
+    }
+}
+
+/// Extended statistics of the fq qdisc. The counters appended by recent
+/// kernels after `horizon_caps` are not decoded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcFqQdStats {
+    pub gc_flows: u64,
+    pub highprio_packets: u64,
+    pub tcp_retrans: u64,
+    pub throttled: u64,
+    /// Packets dropped because their flow was full
+    pub flows_plimit: u64,
+    pub pkts_too_long: u64,
+    pub allocation_errors: u64,
+    /// Time until the next throttled flow is served, in nanoseconds
+    pub time_next_delayed_flow: i64,
+    pub flows: u32,
+    pub inactive_flows: u32,
+    pub throttled_flows: u32,
+    pub unthrottle_latency_ns: u32,
+    /// Packets marked above the `CeThreshold`
+    pub ce_mark: u64,
+    pub horizon_drops: u64,
+    pub horizon_caps: u64,
+}
+
+buffer!(TcFqQdStatsBuffer(TC_FQ_QD_STATS_LEN) {
+    gc_flows: (u64, 0..8),
+    highprio_packets: (u64, 8..16),
+    tcp_retrans: (u64, 16..24),
+    throttled: (u64, 24..32),
+    flows_plimit: (u64, 32..40),
+    pkts_too_long: (u64, 40..48),
+    allocation_errors: (u64, 48..56),
+    time_next_delayed_flow: (i64, 56..64),
+    flows: (u32, 64..68),
+    inactive_flows: (u32, 68..72),
+    throttled_flows: (u32, 72..76),
+    unthrottle_latency_ns: (u32, 76..80),
+    ce_mark: (u64, 80..88),
+    horizon_drops: (u64, 88..96),
+    horizon_caps: (u64, 96..TC_FQ_QD_STATS_LEN),
+});
+
+impl Emitable for TcFqQdStats {
+    fn buffer_len(&self) -> usize {
+        TC_FQ_QD_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcFqQdStatsBuffer::new(buffer);
+        packet.set_gc_flows(self.gc_flows);
+        packet.set_highprio_packets(self.highprio_packets);
+        packet.set_tcp_retrans(self.tcp_retrans);
+        packet.set_throttled(self.throttled);
+        packet.set_flows_plimit(self.flows_plimit);
+        packet.set_pkts_too_long(self.pkts_too_long);
+        packet.set_allocation_errors(self.allocation_errors);
+        packet.set_time_next_delayed_flow(self.time_next_delayed_flow);
+        packet.set_flows(self.flows);
+        packet.set_inactive_flows(self.inactive_flows);
+        packet.set_throttled_flows(self.throttled_flows);
+        packet.set_unthrottle_latency_ns(self.unthrottle_latency_ns);
+        packet.set_ce_mark(self.ce_mark);
+        packet.set_horizon_drops(self.horizon_drops);
+        packet.set_horizon_caps(self.horizon_caps);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcFqQdStatsBuffer<T>> for TcFqQdStats {
+    fn parse(buf: &TcFqQdStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            gc_flows: buf.gc_flows(),
+            highprio_packets: buf.highprio_packets(),
+            tcp_retrans: buf.tcp_retrans(),
+            throttled: buf.throttled(),
+            flows_plimit: buf.flows_plimit(),
+            pkts_too_long: buf.pkts_too_long(),
+            allocation_errors: buf.allocation_errors(),
+            time_next_delayed_flow: buf.time_next_delayed_flow(),
+            flows: buf.flows(),
+            inactive_flows: buf.inactive_flows(),
+            throttled_flows: buf.throttled_flows(),
+            unthrottle_latency_ns: buf.unthrottle_latency_ns(),
+            ce_mark: buf.ce_mark(),
+            horizon_drops: buf.horizon_drops(),
+            horizon_caps: buf.horizon_caps(),
+        })
+    }
+}
@@ -0,0 +1,312 @@
+// SPDX-License-Identifier: MIT
+
+/// FQ_CoDel (Fair Queuing Controlled Delay) qdisc
+///
+/// The fq_codel qdisc hashes the packets into flows served in round robin,
+/// and drops or marks the packets of a flow whose queuing delay stays above
+/// the target for an interval. The delays are in microseconds.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    tc::constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "fq_codel";
+pub const TC_FQ_CODEL_QD_STATS_LEN: usize = 36;
+pub const TC_FQ_CODEL_CL_STATS_LEN: usize = 24;
+pub const TC_FQ_CODEL_XSTATS_LEN: usize = 4 + TC_FQ_CODEL_QD_STATS_LEN;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Target(u32),
+    /// Maximum number of packets in the qdisc
+    Limit(u32),
+    Interval(u32),
+    /// Mark the packets with ECN instead of dropping them when non zero
+    Ecn(u32),
+    /// Number of flow buckets, only set at creation
+    Flows(u32),
+    /// Bytes served from a flow before moving to the next one
+    Quantum(u32),
+    /// Queuing delay above which ECT packets are marked
+    CeThreshold(u32),
+    DropBatchSize(u32),
+    /// Maximum number of bytes in the qdisc
+    MemoryLimit(u32),
+    CeThresholdSelector(u8),
+    CeThresholdMask(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Target(_) | Limit(_) | Interval(_) | Ecn(_) | Flows(_) | Quantum(_)
+            | CeThreshold(_) | DropBatchSize(_) | MemoryLimit(_) => 4,
+            CeThresholdSelector(_) | CeThresholdMask(_) => 1,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Target(value) | Limit(value) | Interval(value) | Ecn(value) | Flows(value)
+            | Quantum(value) | CeThreshold(value) | DropBatchSize(value) | MemoryLimit(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            CeThresholdSelector(value) | CeThresholdMask(value) => buffer[0] = *value,
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_FQ_CODEL_UNSPEC,
+            Target(_) => TCA_FQ_CODEL_TARGET,
+            Limit(_) => TCA_FQ_CODEL_LIMIT,
+            Interval(_) => TCA_FQ_CODEL_INTERVAL,
+            Ecn(_) => TCA_FQ_CODEL_ECN,
+            Flows(_) => TCA_FQ_CODEL_FLOWS,
+            Quantum(_) => TCA_FQ_CODEL_QUANTUM,
+            CeThreshold(_) => TCA_FQ_CODEL_CE_THRESHOLD,
+            DropBatchSize(_) => TCA_FQ_CODEL_DROP_BATCH_SIZE,
+            MemoryLimit(_) => TCA_FQ_CODEL_MEMORY_LIMIT,
+            CeThresholdSelector(_) => TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR,
+            CeThresholdMask(_) => TCA_FQ_CODEL_CE_THRESHOLD_MASK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_CODEL_UNSPEC => Unspec(payload.to_vec()),
+            TCA_FQ_CODEL_TARGET => {
+                Target(parse_u32(payload).context("failed to parse TCA_FQ_CODEL_TARGET")?)
+            }
+            TCA_FQ_CODEL_LIMIT => {
+                Limit(parse_u32(payload).context("failed to parse TCA_FQ_CODEL_LIMIT")?)
+            }
+            TCA_FQ_CODEL_INTERVAL => {
+                Interval(parse_u32(payload).context("failed to parse TCA_FQ_CODEL_INTERVAL")?)
+            }
+            TCA_FQ_CODEL_ECN => {
+                Ecn(parse_u32(payload).context("failed to parse TCA_FQ_CODEL_ECN")?)
+            }
+            TCA_FQ_CODEL_FLOWS => {
+                Flows(parse_u32(payload).context("failed to parse TCA_FQ_CODEL_FLOWS")?)
+            }
+            TCA_FQ_CODEL_QUANTUM => {
+                Quantum(parse_u32(payload).context("failed to parse TCA_FQ_CODEL_QUANTUM")?)
+            }
+            TCA_FQ_CODEL_CE_THRESHOLD => CeThreshold(
+                parse_u32(payload).context("failed to parse TCA_FQ_CODEL_CE_THRESHOLD")?,
+            ),
+            TCA_FQ_CODEL_DROP_BATCH_SIZE => DropBatchSize(
+                parse_u32(payload).context("failed to parse TCA_FQ_CODEL_DROP_BATCH_SIZE")?,
+            ),
+            TCA_FQ_CODEL_MEMORY_LIMIT => MemoryLimit(
+                parse_u32(payload).context("failed to parse TCA_FQ_CODEL_MEMORY_LIMIT")?,
+            ),
+            TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR => CeThresholdSelector(
+                parse_u8(payload).context("failed to parse TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR")?,
+            ),
+            TCA_FQ_CODEL_CE_THRESHOLD_MASK => CeThresholdMask(
+                parse_u8(payload).context("failed to parse TCA_FQ_CODEL_CE_THRESHOLD_MASK")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse fq_codel nla")?),
+        })
+    }
+}
+
+/// Extended statistics of the fq_codel qdisc or of one of its flows
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TcFqCodelXstats {
+    Qdisc(TcFqCodelQdStats),
+    Class(TcFqCodelClStats),
+    Other(Vec<u8>),
+}
+
+buffer!(TcFqCodelXstatsBuffer(4) {
+    xstats_type: (u32, 0..4),
+    payload: (slice, 4..),
+});
+
+impl Emitable for TcFqCodelXstats {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Qdisc(_) | Self::Class(_) => TC_FQ_CODEL_XSTATS_LEN,
+            Self::Other(bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Qdisc(stats) => {
+                let mut packet = TcFqCodelXstatsBuffer::new(buffer);
+                packet.set_xstats_type(TCA_FQ_CODEL_XSTATS_QDISC);
+                stats.emit(packet.payload_mut());
+            }
+            Self::Class(stats) => {
+                let mut packet = TcFqCodelXstatsBuffer::new(buffer);
+                packet.set_xstats_type(TCA_FQ_CODEL_XSTATS_CLASS);
+                let payload = packet.payload_mut();
+                for byte in payload[TC_FQ_CODEL_CL_STATS_LEN..].iter_mut() {
+                    *byte = 0;
+                }
+                stats.emit(payload);
+            }
+            Self::Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcFqCodelXstatsBuffer<&'a T>> for TcFqCodelXstats {
+    fn parse(buf: &TcFqCodelXstatsBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.xstats_type() {
+            TCA_FQ_CODEL_XSTATS_QDISC => Self::Qdisc(
+                TcFqCodelQdStats::parse(
+                    &TcFqCodelQdStatsBuffer::new_checked(buf.payload())
+                        .context("invalid fq_codel qdisc xstats")?,
+                )
+                .context("failed to parse fq_codel qdisc xstats")?,
+            ),
+            TCA_FQ_CODEL_XSTATS_CLASS => Self::Class(
+                TcFqCodelClStats::parse(
+                    &TcFqCodelClStatsBuffer::new_checked(buf.payload())
+                        .context("invalid fq_codel class xstats")?,
+                )
+                .context("failed to parse fq_codel class xstats")?,
+            ),
+            _ => Self::Other(buf.inner().to_vec()),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcFqCodelQdStats {
+    /// Largest packet seen so far
+    pub maxpacket: u32,
+    /// Packets dropped because the qdisc was full
+    pub drop_overlimit: u32,
+    /// Packets marked with ECN instead of being dropped
+    pub ecn_mark: u32,
+    pub new_flow_count: u32,
+    pub new_flows_len: u32,
+    pub old_flows_len: u32,
+    /// Packets marked above the `CeThreshold`
+    pub ce_mark: u32,
+    pub memory_usage: u32,
+    /// Packets dropped because of the `MemoryLimit`
+    pub drop_overmemory: u32,
+}
+
+buffer!(TcFqCodelQdStatsBuffer(TC_FQ_CODEL_QD_STATS_LEN) {
+    maxpacket: (u32, 0..4),
+    drop_overlimit: (u32, 4..8),
+    ecn_mark: (u32, 8..12),
+    new_flow_count: (u32, 12..16),
+    new_flows_len: (u32, 16..20),
+    old_flows_len: (u32, 20..24),
+    ce_mark: (u32, 24..28),
+    memory_usage: (u32, 28..32),
+    drop_overmemory: (u32, 32..TC_FQ_CODEL_QD_STATS_LEN),
+});
+
+impl Emitable for TcFqCodelQdStats {
+    fn buffer_len(&self) -> usize {
+        TC_FQ_CODEL_QD_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcFqCodelQdStatsBuffer::new(buffer);
+        packet.set_maxpacket(self.maxpacket);
+        packet.set_drop_overlimit(self.drop_overlimit);
+        packet.set_ecn_mark(self.ecn_mark);
+        packet.set_new_flow_count(self.new_flow_count);
+        packet.set_new_flows_len(self.new_flows_len);
+        packet.set_old_flows_len(self.old_flows_len);
+        packet.set_ce_mark(self.ce_mark);
+        packet.set_memory_usage(self.memory_usage);
+        packet.set_drop_overmemory(self.drop_overmemory);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcFqCodelQdStatsBuffer<T>> for TcFqCodelQdStats {
+    fn parse(buf: &TcFqCodelQdStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            maxpacket: buf.maxpacket(),
+            drop_overlimit: buf.drop_overlimit(),
+            ecn_mark: buf.ecn_mark(),
+            new_flow_count: buf.new_flow_count(),
+            new_flows_len: buf.new_flows_len(),
+            old_flows_len: buf.old_flows_len(),
+            ce_mark: buf.ce_mark(),
+            memory_usage: buf.memory_usage(),
+            drop_overmemory: buf.drop_overmemory(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcFqCodelClStats {
+    pub deficit: i32,
+    /// Queuing delay of the last dequeued packet, in microseconds
+    pub ldelay: u32,
+    pub count: u32,
+    pub lastcount: u32,
+    pub dropping: u32,
+    pub drop_next: i32,
+}
+
+buffer!(TcFqCodelClStatsBuffer(TC_FQ_CODEL_CL_STATS_LEN) {
+    deficit: (i32, 0..4),
+    ldelay: (u32, 4..8),
+    count: (u32, 8..12),
+    lastcount: (u32, 12..16),
+    dropping: (u32, 16..20),
+    drop_next: (i32, 20..TC_FQ_CODEL_CL_STATS_LEN),
+});
+
+impl Emitable for TcFqCodelClStats {
+    fn buffer_len(&self) -> usize {
+        TC_FQ_CODEL_CL_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcFqCodelClStatsBuffer::new(buffer);
+        packet.set_deficit(self.deficit);
+        packet.set_ldelay(self.ldelay);
+        packet.set_count(self.count);
+        packet.set_lastcount(self.lastcount);
+        packet.set_dropping(self.dropping);
+        packet.set_drop_next(self.drop_next);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcFqCodelClStatsBuffer<T>> for TcFqCodelClStats {
+    fn parse(buf: &TcFqCodelClStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            deficit: buf.deficit(),
+            ldelay: buf.ldelay(),
+            count: buf.count(),
+            lastcount: buf.lastcount(),
+            dropping: buf.dropping(),
+            drop_next: buf.drop_next(),
+        })
+    }
+}
@@ -0,0 +1,293 @@
+// SPDX-License-Identifier: MIT
+
+/// HTB (Hierarchy Token Bucket) qdisc and class
+///
+/// The qdisc takes the `Init` parameters, which hold the class of the
+/// unclassified traffic, and the classes take the `Parms` parameters,
+/// which hold their guaranteed rate and their ceiling. The rates are in
+/// bytes per second and the buffers in scheduler ticks. When a rate does not
+/// fit in 32 bits, set it to `u32::MAX` and give it with `Rate64` or
+/// `Ceil64`.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    tc::{constants::*, TcRateSpec, TcRateSpecBuffer, TC_RATESPEC_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "htb";
+pub const TC_HTB_GLOB_LEN: usize = 20;
+pub const TC_HTB_OPT_LEN: usize = 2 * TC_RATESPEC_LEN + 20;
+pub const TC_HTB_XSTATS_LEN: usize = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Class parameters
+    Parms(TcHtbOpt),
+    /// Qdisc parameters
+    Init(TcHtbGlob),
+    /// Rate table of the ceiling
+    Ctab(Vec<u8>),
+    /// Rate table of the rate
+    Rtab(Vec<u8>),
+    DirectQlen(u32),
+    Rate64(u64),
+    Ceil64(u64),
+    Pad(Vec<u8>),
+    Offload,
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Ctab(bytes) | Rtab(bytes) | Pad(bytes) => bytes.len(),
+            Parms(p) => p.buffer_len(),
+            Init(p) => p.buffer_len(),
+            DirectQlen(_) => 4,
+            Rate64(_) | Ceil64(_) => 8,
+            Offload => 0,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Ctab(bytes) | Rtab(bytes) | Pad(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Parms(p) => p.emit(buffer),
+            Init(p) => p.emit(buffer),
+            DirectQlen(value) => NativeEndian::write_u32(buffer, *value),
+            Rate64(value) | Ceil64(value) => NativeEndian::write_u64(buffer, *value),
+            Offload => {}
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_HTB_UNSPEC,
+            Parms(_) => TCA_HTB_PARMS,
+            Init(_) => TCA_HTB_INIT,
+            Ctab(_) => TCA_HTB_CTAB,
+            Rtab(_) => TCA_HTB_RTAB,
+            DirectQlen(_) => TCA_HTB_DIRECT_QLEN,
+            Rate64(_) => TCA_HTB_RATE64,
+            Ceil64(_) => TCA_HTB_CEIL64,
+            Pad(_) => TCA_HTB_PAD,
+            Offload => TCA_HTB_OFFLOAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_HTB_UNSPEC => Unspec(payload.to_vec()),
+            TCA_HTB_PARMS => Parms(
+                TcHtbOpt::parse(
+                    &TcHtbOptBuffer::new_checked(payload).context("invalid TCA_HTB_PARMS")?,
+                )
+                .context("failed to parse TCA_HTB_PARMS")?,
+            ),
+            TCA_HTB_INIT => Init(
+                TcHtbGlob::parse(
+                    &TcHtbGlobBuffer::new_checked(payload).context("invalid TCA_HTB_INIT")?,
+                )
+                .context("failed to parse TCA_HTB_INIT")?,
+            ),
+            TCA_HTB_CTAB => Ctab(payload.to_vec()),
+            TCA_HTB_RTAB => Rtab(payload.to_vec()),
+            TCA_HTB_DIRECT_QLEN => {
+                DirectQlen(parse_u32(payload).context("failed to parse TCA_HTB_DIRECT_QLEN")?)
+            }
+            TCA_HTB_RATE64 => Rate64(parse_u64(payload).context("failed to parse TCA_HTB_RATE64")?),
+            TCA_HTB_CEIL64 => Ceil64(parse_u64(payload).context("failed to parse TCA_HTB_CEIL64")?),
+            TCA_HTB_PAD => Pad(payload.to_vec()),
+            TCA_HTB_OFFLOAD => Offload,
+            _ => Other(DefaultNla::parse(buf).context("failed to parse htb nla")?),
+        })
+    }
+}
+
+/// Parameters of the HTB qdisc
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TcHtbGlob {
+    /// Must be `TC_HTB_PROTOVER`
+    pub version: u32,
+    /// Divisor of the rate used to compute the quantum of the classes
+    pub rate2quantum: u32,
+    /// Minor number of the class of the unclassified traffic
+    pub defcls: u32,
+    pub debug: u32,
+    /// Number of packets sent without being classified, read only
+    pub direct_pkts: u32,
+}
+
+impl Default for TcHtbGlob {
+    fn default() -> Self {
+        Self {
+            version: TC_HTB_PROTOVER,
+            rate2quantum: 10,
+            defcls: 0,
+            debug: 0,
+            direct_pkts: 0,
+        }
+    }
+}
+
+buffer!(TcHtbGlobBuffer(TC_HTB_GLOB_LEN) {
+    version: (u32, 0..4),
+    rate2quantum: (u32, 4..8),
+    defcls: (u32, 8..12),
+    debug: (u32, 12..16),
+    direct_pkts: (u32, 16..TC_HTB_GLOB_LEN),
+});
+
+impl Emitable for TcHtbGlob {
+    fn buffer_len(&self) -> usize {
+        TC_HTB_GLOB_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcHtbGlobBuffer::new(buffer);
+        packet.set_version(self.version);
+        packet.set_rate2quantum(self.rate2quantum);
+        packet.set_defcls(self.defcls);
+        packet.set_debug(self.debug);
+        packet.set_direct_pkts(self.direct_pkts);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcHtbGlobBuffer<T>> for TcHtbGlob {
+    fn parse(buf: &TcHtbGlobBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            version: buf.version(),
+            rate2quantum: buf.rate2quantum(),
+            defcls: buf.defcls(),
+            debug: buf.debug(),
+            direct_pkts: buf.direct_pkts(),
+        })
+    }
+}
+
+/// Parameters of an HTB class
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcHtbOpt {
+    /// Guaranteed rate
+    pub rate: TcRateSpec,
+    /// Maximum rate, when borrowing from the parent
+    pub ceil: TcRateSpec,
+    /// Size of the bucket of the rate, in scheduler ticks
+    pub buffer: u32,
+    /// Size of the bucket of the ceiling, in scheduler ticks
+    pub cbuffer: u32,
+    /// Bytes served before moving to the next class, computed from the rate
+    /// when zero
+    pub quantum: u32,
+    pub level: u32,
+    /// Priority of the class, lower is served first
+    pub prio: u32,
+}
+
+buffer!(TcHtbOptBuffer(TC_HTB_OPT_LEN) {
+    rate: (slice, 0..12),
+    ceil: (slice, 12..24),
+    buffer: (u32, 24..28),
+    cbuffer: (u32, 28..32),
+    quantum: (u32, 32..36),
+    level: (u32, 36..40),
+    prio: (u32, 40..TC_HTB_OPT_LEN),
+});
+
+impl Emitable for TcHtbOpt {
+    fn buffer_len(&self) -> usize {
+        TC_HTB_OPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcHtbOptBuffer::new(buffer);
+        self.rate.emit(packet.rate_mut());
+        self.ceil.emit(packet.ceil_mut());
+        packet.set_buffer(self.buffer);
+        packet.set_cbuffer(self.cbuffer);
+        packet.set_quantum(self.quantum);
+        packet.set_level(self.level);
+        packet.set_prio(self.prio);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcHtbOptBuffer<&'a T>> for TcHtbOpt {
+    fn parse(buf: &TcHtbOptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            ceil: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.ceil()))?,
+            buffer: buf.buffer(),
+            cbuffer: buf.cbuffer(),
+            quantum: buf.quantum(),
+            level: buf.level(),
+            prio: buf.prio(),
+        })
+    }
+}
+
+/// Extended statistics of an HTB class
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcHtbXstats {
+    /// Packets sent with the class own tokens
+    pub lends: u32,
+    /// Packets sent with tokens borrowed from the ancestors
+    pub borrows: u32,
+    pub giants: u32,
+    /// Tokens left in the bucket of the rate, in nanoseconds
+    pub tokens: i32,
+    /// Tokens left in the bucket of the ceiling, in nanoseconds
+    pub ctokens: i32,
+}
+
+buffer!(TcHtbXstatsBuffer(TC_HTB_XSTATS_LEN) {
+    lends: (u32, 0..4),
+    borrows: (u32, 4..8),
+    giants: (u32, 8..12),
+    tokens: (i32, 12..16),
+    ctokens: (i32, 16..TC_HTB_XSTATS_LEN),
+});
+
+impl Emitable for TcHtbXstats {
+    fn buffer_len(&self) -> usize {
+        TC_HTB_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcHtbXstatsBuffer::new(buffer);
+        packet.set_lends(self.lends);
+        packet.set_borrows(self.borrows);
+        packet.set_giants(self.giants);
+        packet.set_tokens(self.tokens);
+        packet.set_ctokens(self.ctokens);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcHtbXstatsBuffer<T>> for TcHtbXstats {
+    fn parse(buf: &TcHtbXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            lends: buf.lends(),
+            borrows: buf.borrows(),
+            giants: buf.giants(),
+            tokens: buf.tokens(),
+            ctokens: buf.ctokens(),
+        })
+    }
+}
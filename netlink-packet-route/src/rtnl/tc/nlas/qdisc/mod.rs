@@ -1,5 +1,17 @@
 // SPDX-License-Identifier: MIT
 
+pub mod fq;
+pub mod fq_codel;
+pub mod htb;
+pub mod mqprio;
+pub mod netem;
+pub mod prio;
+pub mod tbf;
+
 pub mod ingress {
     pub const KIND: &str = "ingress";
 }
+
+pub mod clsact {
+    pub const KIND: &str = "clsact";
+}
@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+
+/// Mqprio (Multiqueue Priority) qdisc
+///
+/// The mqprio qdisc maps the priorities of the packets to traffic classes,
+/// and each traffic class to a range of transmit queues of the device. Its
+/// options start with a `TcMqprioQopt` structure, followed by the
+/// attributes below.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u64},
+    tc::constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "mqprio";
+pub const TC_MQPRIO_QOPT_LEN: usize = 82;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// `TC_MQPRIO_MODE_*`
+    Mode(u16),
+    /// `TC_MQPRIO_SHAPER_*`
+    Shaper(u16),
+    /// Minimum rate of each traffic class, in bytes per second
+    MinRate64(Vec<u64>),
+    /// Maximum rate of each traffic class, in bytes per second
+    MaxRate64(Vec<u64>),
+    Other(DefaultNla),
+}
+
+// The rates are nested attributes, all of the type of their parent
+struct RateNla(u16, u64);
+
+impl nlas::Nla for RateNla {
+    fn value_len(&self) -> usize {
+        8
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u64(buffer, self.1)
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+}
+
+fn rate_nlas(kind: u16, rates: &[u64]) -> Vec<RateNla> {
+    rates.iter().map(|rate| RateNla(kind, *rate)).collect()
+}
+
+fn parse_rates(payload: &[u8], kind: u16) -> Result<Vec<u64>, DecodeError> {
+    let mut rates = vec![];
+    for nla in NlasIterator::new(payload) {
+        let nla = nla?;
+        if nla.kind() != kind {
+            return Err(format!("unexpected mqprio rate attribute {}", nla.kind()).into());
+        }
+        rates.push(parse_u64(nla.value())?);
+    }
+    Ok(rates)
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Mode(_) | Shaper(_) => 2,
+            MinRate64(rates) | MaxRate64(rates) => rate_nlas(0, rates).as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mode(value) | Shaper(value) => NativeEndian::write_u16(buffer, *value),
+            MinRate64(rates) | MaxRate64(rates) => {
+                rate_nlas(self.kind(), rates).as_slice().emit(buffer)
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Self::MinRate64(_) | Self::MaxRate64(_))
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_MQPRIO_UNSPEC,
+            Mode(_) => TCA_MQPRIO_MODE,
+            Shaper(_) => TCA_MQPRIO_SHAPER,
+            MinRate64(_) => TCA_MQPRIO_MIN_RATE64,
+            MaxRate64(_) => TCA_MQPRIO_MAX_RATE64,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MQPRIO_UNSPEC => Unspec(payload.to_vec()),
+            TCA_MQPRIO_MODE => Mode(parse_u16(payload).context("failed to parse TCA_MQPRIO_MODE")?),
+            TCA_MQPRIO_SHAPER => {
+                Shaper(parse_u16(payload).context("failed to parse TCA_MQPRIO_SHAPER")?)
+            }
+            TCA_MQPRIO_MIN_RATE64 => MinRate64(
+                parse_rates(payload, TCA_MQPRIO_MIN_RATE64)
+                    .context("failed to parse TCA_MQPRIO_MIN_RATE64")?,
+            ),
+            TCA_MQPRIO_MAX_RATE64 => MaxRate64(
+                parse_rates(payload, TCA_MQPRIO_MAX_RATE64)
+                    .context("failed to parse TCA_MQPRIO_MAX_RATE64")?,
+            ),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse mqprio nla")?),
+        })
+    }
+}
+
+/// Parameters of the mqprio qdisc, at the start of its options
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcMqprioQopt {
+    /// Number of traffic classes
+    pub num_tc: u8,
+    /// Traffic class of each priority
+    pub prio_tc_map: [u8; TC_QOPT_BITMASK + 1],
+    /// `TC_MQPRIO_HW_OFFLOAD_*`
+    pub hw: u8,
+    /// Number of queues of each traffic class
+    pub count: [u16; TC_QOPT_MAX_QUEUE],
+    /// First queue of each traffic class
+    pub offset: [u16; TC_QOPT_MAX_QUEUE],
+}
+
+buffer!(TcMqprioQoptBuffer(TC_MQPRIO_QOPT_LEN) {
+    num_tc: (u8, 0),
+    prio_tc_map: (slice, 1..17),
+    hw: (u8, 17),
+    count: (slice, 18..50),
+    offset: (slice, 50..TC_MQPRIO_QOPT_LEN),
+});
+
+impl Emitable for TcMqprioQopt {
+    fn buffer_len(&self) -> usize {
+        TC_MQPRIO_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcMqprioQoptBuffer::new(buffer);
+        packet.set_num_tc(self.num_tc);
+        packet
+            .prio_tc_map_mut()
+            .copy_from_slice(&self.prio_tc_map[..]);
+        packet.set_hw(self.hw);
+        NativeEndian::write_u16_into(&self.count[..], packet.count_mut());
+        NativeEndian::write_u16_into(&self.offset[..], packet.offset_mut());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcMqprioQoptBuffer<&'a T>> for TcMqprioQopt {
+    fn parse(buf: &TcMqprioQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut qopt = Self {
+            num_tc: buf.num_tc(),
+            hw: buf.hw(),
+            ..Default::default()
+        };
+        qopt.prio_tc_map.copy_from_slice(buf.prio_tc_map());
+        NativeEndian::read_u16_into(buf.count(), &mut qopt.count[..]);
+        NativeEndian::read_u16_into(buf.offset(), &mut qopt.offset[..]);
+        Ok(qopt)
+    }
+}
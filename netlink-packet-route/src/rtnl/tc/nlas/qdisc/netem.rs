@@ -0,0 +1,390 @@
+// SPDX-License-Identifier: MIT
+
+/// Netem (Network Emulator) qdisc
+///
+/// The netem qdisc delays, drops, duplicates, corrupts and reorders the
+/// packets. Its options start with a `TcNetemQopt` structure, followed by
+/// the attributes below. The probabilities are scaled to `u32::MAX` (100%),
+/// the delays of the `TcNetemQopt` are in scheduler ticks, and those of
+/// `Latency64` and `Jitter64` in nanoseconds.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    tc::constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "netem";
+pub const TC_NETEM_QOPT_LEN: usize = 24;
+pub const TC_NETEM_CORR_LEN: usize = 12;
+pub const TC_NETEM_PROBABILITY_LEN: usize = 8;
+pub const TC_NETEM_RATE_LEN: usize = 16;
+pub const TC_NETEM_SLOT_LEN: usize = 40;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Corr(TcNetemCorr),
+    /// Distribution table of the delay, as an array of `i16`
+    DelayDist(Vec<u8>),
+    Reorder(TcNetemProbability),
+    Corrupt(TcNetemProbability),
+    /// Loss models (`NETEM_LOSS_GI`, `NETEM_LOSS_GE`), kept as raw nested
+    /// attributes
+    Loss(Vec<u8>),
+    Rate(TcNetemRate),
+    /// Mark the packets with ECN instead of dropping them when non zero
+    Ecn(u32),
+    Rate64(u64),
+    Pad(Vec<u8>),
+    Latency64(i64),
+    Jitter64(i64),
+    Slot(TcNetemSlot),
+    /// Distribution table of the slot delay, as an array of `i16`
+    SlotDist(Vec<u8>),
+    PrngSeed(u64),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | DelayDist(bytes) | Loss(bytes) | Pad(bytes) | SlotDist(bytes) => {
+                bytes.len()
+            }
+            Corr(p) => p.buffer_len(),
+            Reorder(p) | Corrupt(p) => p.buffer_len(),
+            Rate(p) => p.buffer_len(),
+            Slot(p) => p.buffer_len(),
+            Ecn(_) => 4,
+            Rate64(_) | Latency64(_) | Jitter64(_) | PrngSeed(_) => 8,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | DelayDist(bytes) | Loss(bytes) | Pad(bytes) | SlotDist(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Corr(p) => p.emit(buffer),
+            Reorder(p) | Corrupt(p) => p.emit(buffer),
+            Rate(p) => p.emit(buffer),
+            Slot(p) => p.emit(buffer),
+            Ecn(value) => NativeEndian::write_u32(buffer, *value),
+            Rate64(value) | PrngSeed(value) => NativeEndian::write_u64(buffer, *value),
+            Latency64(value) | Jitter64(value) => NativeEndian::write_i64(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        matches!(self, Self::Loss(_))
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_NETEM_UNSPEC,
+            Corr(_) => TCA_NETEM_CORR,
+            DelayDist(_) => TCA_NETEM_DELAY_DIST,
+            Reorder(_) => TCA_NETEM_REORDER,
+            Corrupt(_) => TCA_NETEM_CORRUPT,
+            Loss(_) => TCA_NETEM_LOSS,
+            Rate(_) => TCA_NETEM_RATE,
+            Ecn(_) => TCA_NETEM_ECN,
+            Rate64(_) => TCA_NETEM_RATE64,
+            Pad(_) => TCA_NETEM_PAD,
+            Latency64(_) => TCA_NETEM_LATENCY64,
+            Jitter64(_) => TCA_NETEM_JITTER64,
+            Slot(_) => TCA_NETEM_SLOT,
+            SlotDist(_) => TCA_NETEM_SLOT_DIST,
+            PrngSeed(_) => TCA_NETEM_PRNG_SEED,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_NETEM_UNSPEC => Unspec(payload.to_vec()),
+            TCA_NETEM_CORR => Corr(
+                TcNetemCorr::parse(
+                    &TcNetemCorrBuffer::new_checked(payload).context("invalid TCA_NETEM_CORR")?,
+                )
+                .context("failed to parse TCA_NETEM_CORR")?,
+            ),
+            TCA_NETEM_DELAY_DIST => DelayDist(payload.to_vec()),
+            TCA_NETEM_REORDER => Reorder(
+                TcNetemProbability::parse(
+                    &TcNetemProbabilityBuffer::new_checked(payload)
+                        .context("invalid TCA_NETEM_REORDER")?,
+                )
+                .context("failed to parse TCA_NETEM_REORDER")?,
+            ),
+            TCA_NETEM_CORRUPT => Corrupt(
+                TcNetemProbability::parse(
+                    &TcNetemProbabilityBuffer::new_checked(payload)
+                        .context("invalid TCA_NETEM_CORRUPT")?,
+                )
+                .context("failed to parse TCA_NETEM_CORRUPT")?,
+            ),
+            TCA_NETEM_LOSS => Loss(payload.to_vec()),
+            TCA_NETEM_RATE => Rate(
+                TcNetemRate::parse(
+                    &TcNetemRateBuffer::new_checked(payload).context("invalid TCA_NETEM_RATE")?,
+                )
+                .context("failed to parse TCA_NETEM_RATE")?,
+            ),
+            TCA_NETEM_ECN => Ecn(parse_u32(payload).context("failed to parse TCA_NETEM_ECN")?),
+            TCA_NETEM_RATE64 => {
+                Rate64(parse_u64(payload).context("failed to parse TCA_NETEM_RATE64")?)
+            }
+            TCA_NETEM_PAD => Pad(payload.to_vec()),
+            TCA_NETEM_LATENCY64 => {
+                Latency64(parse_u64(payload).context("failed to parse TCA_NETEM_LATENCY64")? as i64)
+            }
+            TCA_NETEM_JITTER64 => {
+                Jitter64(parse_u64(payload).context("failed to parse TCA_NETEM_JITTER64")? as i64)
+            }
+            TCA_NETEM_SLOT => Slot(
+                TcNetemSlot::parse(
+                    &TcNetemSlotBuffer::new_checked(payload).context("invalid TCA_NETEM_SLOT")?,
+                )
+                .context("failed to parse TCA_NETEM_SLOT")?,
+            ),
+            TCA_NETEM_SLOT_DIST => SlotDist(payload.to_vec()),
+            TCA_NETEM_PRNG_SEED => {
+                PrngSeed(parse_u64(payload).context("failed to parse TCA_NETEM_PRNG_SEED")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("failed to parse netem nla")?),
+        })
+    }
+}
+
+/// Parameters of the netem qdisc, at the start of its options
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcNetemQopt {
+    /// Added delay, in scheduler ticks
+    pub latency: u32,
+    /// Maximum number of packets in the qdisc
+    pub limit: u32,
+    /// Probability of dropping a packet
+    pub loss: u32,
+    /// Reorder every `gap` packets
+    pub gap: u32,
+    /// Probability of duplicating a packet
+    pub duplicate: u32,
+    /// Random variation of the delay, in scheduler ticks
+    pub jitter: u32,
+}
+
+buffer!(TcNetemQoptBuffer(TC_NETEM_QOPT_LEN) {
+    latency: (u32, 0..4),
+    limit: (u32, 4..8),
+    loss: (u32, 8..12),
+    gap: (u32, 12..16),
+    duplicate: (u32, 16..20),
+    jitter: (u32, 20..TC_NETEM_QOPT_LEN),
+});
+
+impl Emitable for TcNetemQopt {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcNetemQoptBuffer::new(buffer);
+        packet.set_latency(self.latency);
+        packet.set_limit(self.limit);
+        packet.set_loss(self.loss);
+        packet.set_gap(self.gap);
+        packet.set_duplicate(self.duplicate);
+        packet.set_jitter(self.jitter);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemQoptBuffer<T>> for TcNetemQopt {
+    fn parse(buf: &TcNetemQoptBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            latency: buf.latency(),
+            limit: buf.limit(),
+            loss: buf.loss(),
+            gap: buf.gap(),
+            duplicate: buf.duplicate(),
+            jitter: buf.jitter(),
+        })
+    }
+}
+
+/// Correlations of the delay, loss and duplication with the previous packet
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcNetemCorr {
+    pub delay_corr: u32,
+    pub loss_corr: u32,
+    pub dup_corr: u32,
+}
+
+buffer!(TcNetemCorrBuffer(TC_NETEM_CORR_LEN) {
+    delay_corr: (u32, 0..4),
+    loss_corr: (u32, 4..8),
+    dup_corr: (u32, 8..TC_NETEM_CORR_LEN),
+});
+
+impl Emitable for TcNetemCorr {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_CORR_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcNetemCorrBuffer::new(buffer);
+        packet.set_delay_corr(self.delay_corr);
+        packet.set_loss_corr(self.loss_corr);
+        packet.set_dup_corr(self.dup_corr);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemCorrBuffer<T>> for TcNetemCorr {
+    fn parse(buf: &TcNetemCorrBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            delay_corr: buf.delay_corr(),
+            loss_corr: buf.loss_corr(),
+            dup_corr: buf.dup_corr(),
+        })
+    }
+}
+
+/// Probability and correlation of the reordering or of the corruption
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcNetemProbability {
+    pub probability: u32,
+    pub correlation: u32,
+}
+
+buffer!(TcNetemProbabilityBuffer(TC_NETEM_PROBABILITY_LEN) {
+    probability: (u32, 0..4),
+    correlation: (u32, 4..TC_NETEM_PROBABILITY_LEN),
+});
+
+impl Emitable for TcNetemProbability {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_PROBABILITY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcNetemProbabilityBuffer::new(buffer);
+        packet.set_probability(self.probability);
+        packet.set_correlation(self.correlation);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemProbabilityBuffer<T>> for TcNetemProbability {
+    fn parse(buf: &TcNetemProbabilityBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            probability: buf.probability(),
+            correlation: buf.correlation(),
+        })
+    }
+}
+
+/// Rate limit of the emulated link
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcNetemRate {
+    /// In bytes per second
+    pub rate: u32,
+    pub packet_overhead: i32,
+    pub cell_size: u32,
+    pub cell_overhead: i32,
+}
+
+buffer!(TcNetemRateBuffer(TC_NETEM_RATE_LEN) {
+    rate: (u32, 0..4),
+    packet_overhead: (i32, 4..8),
+    cell_size: (u32, 8..12),
+    cell_overhead: (i32, 12..TC_NETEM_RATE_LEN),
+});
+
+impl Emitable for TcNetemRate {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_RATE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcNetemRateBuffer::new(buffer);
+        packet.set_rate(self.rate);
+        packet.set_packet_overhead(self.packet_overhead);
+        packet.set_cell_size(self.cell_size);
+        packet.set_cell_overhead(self.cell_overhead);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemRateBuffer<T>> for TcNetemRate {
+    fn parse(buf: &TcNetemRateBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: buf.rate(),
+            packet_overhead: buf.packet_overhead(),
+            cell_size: buf.cell_size(),
+            cell_overhead: buf.cell_overhead(),
+        })
+    }
+}
+
+/// Slotting, releasing the packets in bursts
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcNetemSlot {
+    /// In nanoseconds
+    pub min_delay: i64,
+    /// In nanoseconds
+    pub max_delay: i64,
+    pub max_packets: i32,
+    pub max_bytes: i32,
+    pub dist_delay: i64,
+    pub dist_jitter: i64,
+}
+
+buffer!(TcNetemSlotBuffer(TC_NETEM_SLOT_LEN) {
+    min_delay: (i64, 0..8),
+    max_delay: (i64, 8..16),
+    max_packets: (i32, 16..20),
+    max_bytes: (i32, 20..24),
+    dist_delay: (i64, 24..32),
+    dist_jitter: (i64, 32..TC_NETEM_SLOT_LEN),
+});
+
+impl Emitable for TcNetemSlot {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_SLOT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcNetemSlotBuffer::new(buffer);
+        packet.set_min_delay(self.min_delay);
+        packet.set_max_delay(self.max_delay);
+        packet.set_max_packets(self.max_packets);
+        packet.set_max_bytes(self.max_bytes);
+        packet.set_dist_delay(self.dist_delay);
+        packet.set_dist_jitter(self.dist_jitter);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemSlotBuffer<T>> for TcNetemSlot {
+    fn parse(buf: &TcNetemSlotBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            min_delay: buf.min_delay(),
+            max_delay: buf.max_delay(),
+            max_packets: buf.max_packets(),
+            max_bytes: buf.max_bytes(),
+            dist_delay: buf.dist_delay(),
+            dist_jitter: buf.dist_jitter(),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+/// Prio qdisc
+///
+/// The prio qdisc dispatches the packets into bands served in strict
+/// priority order, the band of a packet being given by the `priomap` entry
+/// of its priority unless a filter classifies it. Its options are a single
+/// `TcPrioQopt` structure instead of attributes.
+use crate::{
+    tc::constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "prio";
+pub const TC_PRIO_QOPT_LEN: usize = 4 + TC_PRIO_MAX + 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TcPrioQopt {
+    /// Number of bands, between 2 and `TCQ_PRIO_BANDS`
+    pub bands: i32,
+    /// Band of each of the `TC_PRIO_*` priorities
+    pub priomap: [u8; TC_PRIO_MAX + 1],
+}
+
+impl Default for TcPrioQopt {
+    fn default() -> Self {
+        Self {
+            bands: 3,
+            priomap: [1, 2, 2, 2, 1, 2, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1],
+        }
+    }
+}
+
+buffer!(TcPrioQoptBuffer(TC_PRIO_QOPT_LEN) {
+    bands: (i32, 0..4),
+    priomap: (slice, 4..TC_PRIO_QOPT_LEN),
+});
+
+impl Emitable for TcPrioQopt {
+    fn buffer_len(&self) -> usize {
+        TC_PRIO_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcPrioQoptBuffer::new(buffer);
+        packet.set_bands(self.bands);
+        packet.priomap_mut().copy_from_slice(&self.priomap[..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcPrioQoptBuffer<&'a T>> for TcPrioQopt {
+    fn parse(buf: &TcPrioQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut priomap = [0; TC_PRIO_MAX + 1];
+        priomap.copy_from_slice(buf.priomap());
+        Ok(Self {
+            bands: buf.bands(),
+            priomap,
+        })
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+
+/// TBF (Token Bucket Filter) qdisc
+///
+/// The tbf qdisc shapes the traffic to the rate of the `Parms`. The rates
+/// are in bytes per second and the buffers in scheduler ticks. When
+/// `Burst` is given, the kernel computes the buffer from it. Rates that do
+/// not fit in 32 bits are given with `Rate64` and `Prate64`.
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    tc::{constants::*, TcRateSpec, TcRateSpecBuffer, TC_RATESPEC_LEN},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "tbf";
+pub const TC_TBF_QOPT_LEN: usize = 2 * TC_RATESPEC_LEN + 12;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Parms(TcTbfQopt),
+    /// Rate table of the rate
+    Rtab(Vec<u8>),
+    /// Rate table of the peak rate
+    Ptab(Vec<u8>),
+    Rate64(u64),
+    Prate64(u64),
+    /// Size of the bucket of the rate, in bytes
+    Burst(u32),
+    /// Size of the bucket of the peak rate, in bytes
+    Pburst(u32),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Rtab(bytes) | Ptab(bytes) | Pad(bytes) => bytes.len(),
+            Parms(p) => p.buffer_len(),
+            Rate64(_) | Prate64(_) => 8,
+            Burst(_) | Pburst(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Rtab(bytes) | Ptab(bytes) | Pad(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Parms(p) => p.emit(buffer),
+            Rate64(value) | Prate64(value) => NativeEndian::write_u64(buffer, *value),
+            Burst(value) | Pburst(value) => NativeEndian::write_u32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_TBF_UNSPEC,
+            Parms(_) => TCA_TBF_PARMS,
+            Rtab(_) => TCA_TBF_RTAB,
+            Ptab(_) => TCA_TBF_PTAB,
+            Rate64(_) => TCA_TBF_RATE64,
+            Prate64(_) => TCA_TBF_PRATE64,
+            Burst(_) => TCA_TBF_BURST,
+            Pburst(_) => TCA_TBF_PBURST,
+            Pad(_) => TCA_TBF_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TBF_UNSPEC => Unspec(payload.to_vec()),
+            TCA_TBF_PARMS => Parms(
+                TcTbfQopt::parse(
+                    &TcTbfQoptBuffer::new_checked(payload).context("invalid TCA_TBF_PARMS")?,
+                )
+                .context("failed to parse TCA_TBF_PARMS")?,
+            ),
+            TCA_TBF_RTAB => Rtab(payload.to_vec()),
+            TCA_TBF_PTAB => Ptab(payload.to_vec()),
+            TCA_TBF_RATE64 => Rate64(parse_u64(payload).context("failed to parse TCA_TBF_RATE64")?),
+            TCA_TBF_PRATE64 => {
+                Prate64(parse_u64(payload).context("failed to parse TCA_TBF_PRATE64")?)
+            }
+            TCA_TBF_BURST => Burst(parse_u32(payload).context("failed to parse TCA_TBF_BURST")?),
+            TCA_TBF_PBURST => Pburst(parse_u32(payload).context("failed to parse TCA_TBF_PBURST")?),
+            TCA_TBF_PAD => Pad(payload.to_vec()),
+            _ => Other(DefaultNla::parse(buf).context("failed to parse tbf nla")?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcTbfQopt {
+    pub rate: TcRateSpec,
+    pub peakrate: TcRateSpec,
+    /// Maximum number of bytes waiting for tokens
+    pub limit: u32,
+    /// Size of the bucket of the rate, in scheduler ticks
+    pub buffer: u32,
+    /// Size of the bucket of the peak rate, in scheduler ticks
+    pub mtu: u32,
+}
+
+buffer!(TcTbfQoptBuffer(TC_TBF_QOPT_LEN) {
+    rate: (slice, 0..12),
+    peakrate: (slice, 12..24),
+    limit: (u32, 24..28),
+    buffer: (u32, 28..32),
+    mtu: (u32, 32..TC_TBF_QOPT_LEN),
+});
+
+impl Emitable for TcTbfQopt {
+    fn buffer_len(&self) -> usize {
+        TC_TBF_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcTbfQoptBuffer::new(buffer);
+        self.rate.emit(packet.rate_mut());
+        self.peakrate.emit(packet.peakrate_mut());
+        packet.set_limit(self.limit);
+        packet.set_buffer(self.buffer);
+        packet.set_mtu(self.mtu);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcTbfQoptBuffer<&'a T>> for TcTbfQopt {
+    fn parse(buf: &TcTbfQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            peakrate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.peakrate()))?,
+            limit: buf.limit(),
+            buffer: buf.buffer(),
+            mtu: buf.mtu(),
+        })
+    }
+}
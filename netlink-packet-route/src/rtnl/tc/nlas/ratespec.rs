@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Rate specification shared by the police action and the shaping qdiscs
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcRateSpec {
+    /// log2 of the size of a cell of the rate table
+    pub cell_log: u8,
+    /// `TC_LINKLAYER_*`
+    pub linklayer: u8,
+    pub overhead: u16,
+    pub cell_align: i16,
+    pub mpu: u16,
+    /// Rate in bytes per second. Rates that do not fit are set to `u32::MAX`
+    /// and given in the 64 bits rate attribute of the action or qdisc.
+    pub rate: u32,
+}
+
+pub const TC_RATESPEC_LEN: usize = 12;
+
+buffer!(TcRateSpecBuffer(TC_RATESPEC_LEN) {
+    cell_log: (u8, 0),
+    linklayer: (u8, 1),
+    overhead: (u16, 2..4),
+    cell_align: (i16, 4..6),
+    mpu: (u16, 6..8),
+    rate: (u32, 8..12),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcRateSpecBuffer<T>> for TcRateSpec {
+    fn parse(buf: &TcRateSpecBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(TcRateSpec {
+            cell_log: buf.cell_log(),
+            linklayer: buf.linklayer(),
+            overhead: buf.overhead(),
+            cell_align: buf.cell_align(),
+            mpu: buf.mpu(),
+            rate: buf.rate(),
+        })
+    }
+}
+
+impl Emitable for TcRateSpec {
+    fn buffer_len(&self) -> usize {
+        TC_RATESPEC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcRateSpecBuffer::new(buffer);
+        buffer.set_cell_log(self.cell_log);
+        buffer.set_linklayer(self.linklayer);
+        buffer.set_overhead(self.overhead);
+        buffer.set_cell_align(self.cell_align);
+        buffer.set_mpu(self.mpu);
+        buffer.set_rate(self.rate);
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
 };
 
 /// Byte/Packet throughput statistics
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 pub struct StatsBasic {
     /// number of seen bytes
     pub bytes: u64,
@@ -6,7 +6,7 @@ use crate::{
 };
 
 /// Queuing statistics
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 pub struct StatsQueue {
     /// queue length
     pub qlen: u32,
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Rate estimator statistics
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct StatsRateEst {
+    /// current byte rate, in bytes per second
+    pub bps: u32,
+    /// current packet rate, in packets per second
+    pub pps: u32,
+}
+
+pub const STATS_RATE_EST_LEN: usize = 8;
+
+buffer!(StatsRateEstBuffer(STATS_RATE_EST_LEN) {
+    bps: (u32, 0..4),
+    pps: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<StatsRateEstBuffer<T>> for StatsRateEst {
+    fn parse(buf: &StatsRateEstBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(StatsRateEst {
+            bps: buf.bps(),
+            pps: buf.pps(),
+        })
+    }
+}
+
+impl Emitable for StatsRateEst {
+    fn buffer_len(&self) -> usize {
+        STATS_RATE_EST_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = StatsRateEstBuffer::new(buffer);
+        buffer.set_bps(self.bps);
+        buffer.set_pps(self.pps);
+    }
+}
+
+/// Rate estimator statistics, for rates that do not fit in 32 bits
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct StatsRateEst64 {
+    /// current byte rate, in bytes per second
+    pub bps: u64,
+    /// current packet rate, in packets per second
+    pub pps: u64,
+}
+
+pub const STATS_RATE_EST64_LEN: usize = 16;
+
+buffer!(StatsRateEst64Buffer(STATS_RATE_EST64_LEN) {
+    bps: (u64, 0..8),
+    pps: (u64, 8..16),
+});
+
+impl<T: AsRef<[u8]>> Parseable<StatsRateEst64Buffer<T>> for StatsRateEst64 {
+    fn parse(buf: &StatsRateEst64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(StatsRateEst64 {
+            bps: buf.bps(),
+            pps: buf.pps(),
+        })
+    }
+}
+
+impl Emitable for StatsRateEst64 {
+    fn buffer_len(&self) -> usize {
+        STATS_RATE_EST64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = StatsRateEst64Buffer::new(buffer);
+        buffer.set_bps(self.bps);
+        buffer.set_pps(self.pps);
+    }
+}
@@ -6,7 +6,31 @@ use crate::{
     constants::*,
     nlas::Nla,
     parsers::parse_u32,
-    tc::{self, constants::*, mirred, u32, ActNla, ActOpt, Action, Stats2, TcOpt},
+    nlas::NlaBuffer,
+    tc::{
+        self,
+        act_bpf,
+        bpf,
+        constants::*,
+        flower,
+        gact,
+        mirred,
+        pedit,
+        police,
+        skbedit,
+        tunnel_key,
+        u32,
+        vlan,
+        ActNla,
+        ActOpt,
+        Action,
+        Stats2,
+        StatsBasic,
+        StatsQueue,
+        TcGen,
+        TcOpt,
+        TcRateSpec,
+    },
     traits::{Emitable, Parseable},
     TcHeader,
     TcMessage,
@@ -275,8 +299,8 @@ fn tc_filter_u32_emit() {
                 nlas: vec![
                     ActNla::Kind(mirred::KIND.to_string()),
                     ActNla::Stats(vec![
-                        Stats2::StatsBasic(vec![0u8; 16]),
-                        Stats2::StatsQueue(vec![0u8; 20]),
+                        Stats2::StatsBasic(StatsBasic::default()),
+                        Stats2::StatsQueue(StatsQueue::default()),
                     ]),
                     ActNla::Options(vec![
                         ActOpt::Mirred(mirred::Nla::Parms(mirred::TcMirred {
@@ -304,3 +328,372 @@ fn tc_filter_u32_emit() {
     msg.emit(&mut buf[..]);
     assert_eq!(&buf, &FILTER_U32_ACTION_PACKET);
 }
+
+#[rustfmt::skip]
+static FILTER_FLOWER_PACKET: [u8; 108] = [
+    0, 0, 0, 0, // family, pad1, pad2
+    6, 0, 0, 0, // Interface index
+    1, 0, 0, 0, // handle
+    0, 0, 255, 255, // parent: ingress
+    8, 0, 10, 0, // info: 0x000a_0008 => pref | protocol
+    // nlas
+    11, 0,
+    1, 0, // type: TCA_KIND
+    102, 108, 111, 119, 101, 114, 0, 0, // flower\0
+
+    76, 0,
+    2, 0, // type: TCA_OPTIONS
+
+        6, 0,
+        8, 0, // TCA_FLOWER_KEY_ETH_TYPE
+        8, 0, 0, 0,
+
+        5, 0,
+        9, 0, // TCA_FLOWER_KEY_IP_PROTO
+        6, 0, 0, 0,
+
+        8, 0,
+        12, 0, // TCA_FLOWER_KEY_IPV4_DST
+        10, 1, 2, 0,
+
+        8, 0,
+        13, 0, // TCA_FLOWER_KEY_IPV4_DST_MASK
+        255, 255, 255, 0,
+
+        6, 0,
+        19, 0, // TCA_FLOWER_KEY_TCP_DST
+        0, 80, 0, 0,
+
+        8, 0,
+        22, 0, // TCA_FLOWER_FLAGS
+        9, 0, 0, 0,
+
+        8, 0,
+        86, 0, // TCA_FLOWER_IN_HW_COUNT
+        0, 0, 0, 0,
+
+        6, 0,
+        91, 0, // TCA_FLOWER_KEY_CT_STATE
+        10, 0, 0, 0,
+
+        6, 0,
+        92, 0, // TCA_FLOWER_KEY_CT_STATE_MASK
+        10, 0, 0, 0,
+];
+
+fn flower_nlas() -> Vec<tc::Nla> {
+    vec![
+        tc::Nla::Kind(flower::KIND.to_string()),
+        tc::Nla::Options(vec![
+            TcOpt::Flower(flower::Nla::EthType(0x0800)),
+            TcOpt::Flower(flower::Nla::IpProto(6)),
+            TcOpt::Flower(flower::Nla::Ipv4Dst([10, 1, 2, 0].into())),
+            TcOpt::Flower(flower::Nla::Ipv4DstMask([255, 255, 255, 0].into())),
+            TcOpt::Flower(flower::Nla::TcpDst(80)),
+            TcOpt::Flower(flower::Nla::Flags(
+                TCA_CLS_FLAGS_SKIP_HW | TCA_CLS_FLAGS_NOT_IN_HW,
+            )),
+            TcOpt::Flower(flower::Nla::InHwCount(0)),
+            TcOpt::Flower(flower::Nla::CtState(
+                TCA_FLOWER_KEY_CT_FLAGS_TRACKED | TCA_FLOWER_KEY_CT_FLAGS_ESTABLISHED,
+            )),
+            TcOpt::Flower(flower::Nla::CtStateMask(
+                TCA_FLOWER_KEY_CT_FLAGS_TRACKED | TCA_FLOWER_KEY_CT_FLAGS_ESTABLISHED,
+            )),
+        ]),
+    ]
+}
+
+#[test]
+fn tc_filter_flower_parse() {
+    let packet = TcMessageBuffer::new_checked(&FILTER_FLOWER_PACKET).unwrap();
+    let msg = TcMessage::parse(&packet).unwrap();
+    assert_eq!(msg.header.index, 6);
+    assert_eq!(msg.header.parent, 0xffff0000);
+    assert_eq!(msg.header.info, 0x000a_0008);
+    assert_eq!(msg.nlas, flower_nlas());
+}
+
+#[test]
+fn tc_filter_flower_emit() {
+    let header = TcHeader {
+        index: 6,
+        handle: 1,
+        parent: 0xffff0000,
+        info: 0x000a_0008,
+        ..Default::default()
+    };
+    let msg = TcMessage::from_parts(header, flower_nlas());
+    let mut buf = vec![0; 108];
+    assert_eq!(msg.buffer_len(), 108);
+    msg.emit(&mut buf[..]);
+    assert_eq!(&buf, &FILTER_FLOWER_PACKET);
+}
+
+#[rustfmt::skip]
+static FILTER_BPF_PACKET: [u8; 88] = [
+    0, 0, 0, 0, // family, pad1, pad2
+    10, 0, 0, 0, // Interface index
+    1, 0, 0, 0, // handle
+    242, 255, 255, 255, // parent: clsact ingress
+    0, 3, 5, 0, // info: 0x0005_0300 => pref | protocol
+    // nlas
+    8, 0,
+    1, 0, // type: TCA_KIND
+    98, 112, 102, 0, // bpf\0
+
+    8, 0,
+    11, 0, // type: TCA_CHAIN
+    0, 0, 0, 0,
+
+    52, 0,
+    2, 0, // type: TCA_OPTIONS
+
+        11, 0,
+        7, 0, // TCA_BPF_NAME
+        122, 122, 112, 114, 111, 103, 0, 0, // zzprog\0
+
+        8, 0,
+        11, 0, // TCA_BPF_ID
+        98, 0, 0, 0,
+
+        12, 0,
+        10, 0, // TCA_BPF_TAG
+        89, 244, 169, 49, 116, 77, 205, 198,
+
+        8, 0,
+        8, 0, // TCA_BPF_FLAGS
+        1, 0, 0, 0,
+
+        8, 0,
+        9, 0, // TCA_BPF_FLAGS_GEN
+        8, 0, 0, 0,
+];
+
+fn bpf_nlas() -> Vec<tc::Nla> {
+    vec![
+        tc::Nla::Kind(bpf::KIND.to_string()),
+        tc::Nla::Chain(vec![0, 0, 0, 0]),
+        tc::Nla::Options(vec![
+            TcOpt::Bpf(bpf::Nla::Name("zzprog".to_string())),
+            TcOpt::Bpf(bpf::Nla::Id(98)),
+            TcOpt::Bpf(bpf::Nla::Tag(vec![89, 244, 169, 49, 116, 77, 205, 198])),
+            TcOpt::Bpf(bpf::Nla::Flags(TCA_BPF_FLAG_ACT_DIRECT)),
+            TcOpt::Bpf(bpf::Nla::FlagsGen(TCA_CLS_FLAGS_NOT_IN_HW)),
+        ]),
+    ]
+}
+
+#[test]
+fn tc_filter_bpf_parse() {
+    let packet = TcMessageBuffer::new_checked(&FILTER_BPF_PACKET).unwrap();
+    let msg = TcMessage::parse(&packet).unwrap();
+    assert_eq!(msg.header.index, 10);
+    assert_eq!(msg.header.parent, 0xfffffff2);
+    assert_eq!(msg.header.info, 0x0005_0300);
+    assert_eq!(msg.nlas, bpf_nlas());
+}
+
+#[test]
+fn tc_filter_bpf_emit() {
+    let header = TcHeader {
+        index: 10,
+        handle: 1,
+        parent: 0xfffffff2,
+        info: 0x0005_0300,
+        ..Default::default()
+    };
+    let msg = TcMessage::from_parts(header, bpf_nlas());
+    let mut buf = vec![0; 88];
+    assert_eq!(msg.buffer_len(), 88);
+    msg.emit(&mut buf[..]);
+    assert_eq!(&buf, &FILTER_BPF_PACKET);
+}
+
+#[test]
+fn tc_action_bpf_round_trip() {
+    let action = Action {
+        tab: TCA_ACT_TAB,
+        nlas: vec![
+            ActNla::Kind(act_bpf::KIND.to_string()),
+            ActNla::Options(vec![
+                ActOpt::Bpf(act_bpf::Nla::Parms(TcGen {
+                    action: TC_ACT_PIPE,
+                    ..Default::default()
+                })),
+                ActOpt::Bpf(act_bpf::Nla::Fd(3)),
+                ActOpt::Bpf(act_bpf::Nla::Name("prog".to_string())),
+            ]),
+        ],
+    };
+    let mut buf = vec![0; action.buffer_len()];
+    action.emit(&mut buf[..]);
+    let parsed = Action::parse(&NlaBuffer::new_checked(&buf[..]).unwrap()).unwrap();
+    assert_eq!(parsed, action);
+}
+
+fn action_round_trip(kind: &str, opts: Vec<ActOpt>) {
+    let action = Action {
+        tab: TCA_ACT_TAB,
+        nlas: vec![ActNla::Kind(kind.to_string()), ActNla::Options(opts)],
+    };
+    let mut buf = vec![0; action.buffer_len()];
+    action.emit(&mut buf[..]);
+    let parsed = Action::parse(&NlaBuffer::new_checked(&buf[..]).unwrap()).unwrap();
+    assert_eq!(parsed, action);
+}
+
+#[test]
+fn tc_action_gact_round_trip() {
+    action_round_trip(
+        gact::KIND,
+        vec![
+            ActOpt::Gact(gact::Nla::Parms(TcGen {
+                action: TC_ACT_GOTO_CHAIN | 1,
+                ..Default::default()
+            })),
+            ActOpt::Gact(gact::Nla::Prob(gact::TcGactProb {
+                ptype: PGACT_NETRAND,
+                pval: 100,
+                paction: TC_ACT_SHOT,
+            })),
+        ],
+    );
+}
+
+#[test]
+fn tc_action_police_round_trip() {
+    action_round_trip(
+        police::KIND,
+        vec![
+            ActOpt::Police(police::Nla::Tbf(police::TcPolice {
+                action: TC_ACT_SHOT,
+                burst: 12_500_000,
+                mtu: 2040,
+                rate: TcRateSpec {
+                    cell_log: 3,
+                    linklayer: TC_LINKLAYER_ETHERNET,
+                    rate: 125_000,
+                    ..Default::default()
+                },
+                ..Default::default()
+            })),
+            ActOpt::Police(police::Nla::Rate(vec![0; TC_RTAB_SIZE])),
+            ActOpt::Police(police::Nla::Result(TC_ACT_OK)),
+            ActOpt::Police(police::Nla::Rate64(125_000)),
+        ],
+    );
+}
+
+#[test]
+fn tc_action_skbedit_round_trip() {
+    action_round_trip(
+        skbedit::KIND,
+        vec![
+            ActOpt::Skbedit(skbedit::Nla::Parms(TcGen {
+                action: TC_ACT_PIPE,
+                ..Default::default()
+            })),
+            ActOpt::Skbedit(skbedit::Nla::Priority(0x10002)),
+            ActOpt::Skbedit(skbedit::Nla::QueueMapping(3)),
+            ActOpt::Skbedit(skbedit::Nla::Mark(0x42)),
+            ActOpt::Skbedit(skbedit::Nla::Mask(0xff)),
+        ],
+    );
+}
+
+#[test]
+fn tc_action_vlan_round_trip() {
+    action_round_trip(
+        vlan::KIND,
+        vec![
+            ActOpt::Vlan(vlan::Nla::Parms(vlan::TcVlan {
+                action: TC_ACT_PIPE,
+                v_action: TCA_VLAN_ACT_PUSH,
+                ..Default::default()
+            })),
+            ActOpt::Vlan(vlan::Nla::PushVlanId(100)),
+            ActOpt::Vlan(vlan::Nla::PushVlanProtocol(0x8100)),
+            ActOpt::Vlan(vlan::Nla::PushVlanPriority(5)),
+        ],
+    );
+}
+
+#[test]
+fn tc_action_vlan_protocol_is_big_endian() {
+    let nla = vlan::Nla::PushVlanProtocol(0x88a8);
+    let mut buf = vec![0; nla.buffer_len()];
+    nla.emit(&mut buf);
+    assert_eq!(&buf[4..6], &[0x88, 0xa8]);
+}
+
+#[test]
+fn tc_action_tunnel_key_round_trip() {
+    action_round_trip(
+        tunnel_key::KIND,
+        vec![
+            ActOpt::TunnelKey(tunnel_key::Nla::Parms(tunnel_key::TcTunnelKey {
+                action: TC_ACT_PIPE,
+                t_action: TCA_TUNNEL_KEY_ACT_SET,
+                ..Default::default()
+            })),
+            ActOpt::TunnelKey(tunnel_key::Nla::EncIpv4Src("10.0.0.1".parse().unwrap())),
+            ActOpt::TunnelKey(tunnel_key::Nla::EncIpv4Dst("10.0.0.2".parse().unwrap())),
+            ActOpt::TunnelKey(tunnel_key::Nla::EncKeyId(42)),
+            ActOpt::TunnelKey(tunnel_key::Nla::EncDstPort(4789)),
+            ActOpt::TunnelKey(tunnel_key::Nla::NoCsum(1)),
+            ActOpt::TunnelKey(tunnel_key::Nla::NoFrag),
+        ],
+    );
+}
+
+#[test]
+fn tc_action_pedit_round_trip() {
+    let sel = pedit::TcPeditSel {
+        action: TC_ACT_PIPE,
+        keys: vec![
+            pedit::TcPeditKey {
+                mask: 0x00ff_ffff,
+                val: 0x4000_0000,
+                off: 8,
+                ..Default::default()
+            },
+            pedit::TcPeditKey {
+                mask: 0xffff_0000,
+                val: 0x0000_5000,
+                off: 0,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    action_round_trip(
+        pedit::KIND,
+        vec![
+            ActOpt::Pedit(pedit::Nla::ParmsEx(sel)),
+            ActOpt::Pedit(pedit::Nla::KeysEx(vec![
+                pedit::TcPeditKeyEx {
+                    htype: TCA_PEDIT_KEY_EX_HDR_TYPE_IP4,
+                    cmd: TCA_PEDIT_KEY_EX_CMD_SET,
+                },
+                pedit::TcPeditKeyEx {
+                    htype: TCA_PEDIT_KEY_EX_HDR_TYPE_TCP,
+                    cmd: TCA_PEDIT_KEY_EX_CMD_SET,
+                },
+            ])),
+        ],
+    );
+}
+
+#[test]
+fn tc_action_pedit_truncated_keys() {
+    let sel = pedit::TcPeditSel {
+        keys: vec![pedit::TcPeditKey::default()],
+        ..Default::default()
+    };
+    let mut buf = vec![0; sel.buffer_len()];
+    sel.emit(&mut buf);
+    buf.truncate(buf.len() - 4);
+    let sel_buf = pedit::TcPeditSelBuffer::new_checked(&buf[..]).unwrap();
+    assert!(pedit::TcPeditSel::parse(&sel_buf).is_err());
+}
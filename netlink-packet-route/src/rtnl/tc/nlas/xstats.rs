@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::NlaBuffer,
+    tc::{
+        fq,
+        fq_codel,
+        htb,
+        fq::TcFqQdStatsBuffer,
+        fq_codel::TcFqCodelXstatsBuffer,
+        htb::TcHtbXstatsBuffer,
+    },
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+/// Module specific statistics of a qdisc or a class (`TCA_XSTATS`). The
+/// statistics of older kernels that are shorter than expected are kept as
+/// raw bytes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum XStats {
+    Htb(htb::TcHtbXstats),
+    FqCodel(fq_codel::TcFqCodelXstats),
+    Fq(fq::TcFqQdStats),
+    Other(Vec<u8>),
+}
+
+impl Emitable for XStats {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Htb(stats) => stats.buffer_len(),
+            Self::FqCodel(stats) => stats.buffer_len(),
+            Self::Fq(stats) => stats.buffer_len(),
+            Self::Other(bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Htb(stats) => stats.emit(buffer),
+            Self::FqCodel(stats) => stats.emit(buffer),
+            Self::Fq(stats) => stats.emit(buffer),
+            Self::Other(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+        }
+    }
+}
+
+impl<'a, T, S> ParseableParametrized<NlaBuffer<&'a T>, S> for XStats
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: AsRef<str>,
+{
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: S) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match kind.as_ref() {
+            htb::KIND if payload.len() >= htb::TC_HTB_XSTATS_LEN => Self::Htb(
+                htb::TcHtbXstats::parse(&TcHtbXstatsBuffer::new(payload))
+                    .context("failed to parse htb xstats")?,
+            ),
+            fq_codel::KIND if payload.len() >= fq_codel::TC_FQ_CODEL_XSTATS_LEN => Self::FqCodel(
+                fq_codel::TcFqCodelXstats::parse(&TcFqCodelXstatsBuffer::new(payload))
+                    .context("failed to parse fq_codel xstats")?,
+            ),
+            fq::KIND if payload.len() >= fq::TC_FQ_QD_STATS_LEN => Self::Fq(
+                fq::TcFqQdStats::parse(&TcFqQdStatsBuffer::new(payload))
+                    .context("failed to parse fq xstats")?,
+            ),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
@@ -5,8 +5,37 @@
 use crate::{
     constants::*,
     nlas::NlasIterator,
-    tc::{ingress, Nla, Stats, Stats2, StatsBuffer, TC_HEADER_LEN},
+    tc::{
+        constants::*,
+        fq_codel,
+        gact,
+        htb,
+        ingress,
+        mqprio,
+        netem,
+        police,
+        prio,
+        ActNla,
+        ActOpt,
+        Action,
+        Nla,
+        NlaBitfield32,
+        Stats,
+        Stats2,
+        StatsBasic,
+        StatsBuffer,
+        StatsQueue,
+        StatsRateEst,
+        TcActionMessageNla,
+        TcOpt,
+        TcRateSpec,
+        XStats,
+        TC_HEADER_LEN,
+    },
     traits::{Emitable, Parseable},
+    TcActionHeader,
+    TcActionMessage,
+    TcActionMessageBuffer,
     TcHeader,
     TcMessage,
     TcMessageBuffer,
@@ -113,7 +142,7 @@ fn tc_packet_nlas_read() {
     assert_eq!(stats2_nla.kind(), TCA_STATS_BASIC);
     assert_eq!(stats2_nla.value(), [0; 16]);
     let s2 = Stats2::parse(&stats2_nla).unwrap();
-    assert!(matches!(s2, Stats2::StatsBasic(_)));
+    assert_eq!(s2, Stats2::StatsBasic(StatsBasic::default()));
 
     let stats2_nla = stats2_iter.next().unwrap().unwrap();
     stats2_nla.check_buffer_length().unwrap();
@@ -121,7 +150,7 @@ fn tc_packet_nlas_read() {
     assert_eq!(stats2_nla.kind(), TCA_STATS_QUEUE);
     assert_eq!(stats2_nla.value(), [0; 20]);
     let s2 = Stats2::parse(&stats2_nla).unwrap();
-    assert!(matches!(s2, Stats2::StatsQueue(_)));
+    assert_eq!(s2, Stats2::StatsQueue(StatsQueue::default()));
 
     let nla = nlas.next().unwrap().unwrap();
     nla.check_buffer_length().unwrap();
@@ -171,3 +200,372 @@ fn tc_qdisc_ingress_read() {
     let nla = iter.next().unwrap();
     assert_eq!(nla, &Nla::HwOffload(0));
 }
+
+#[rustfmt::skip]
+static QDISC_PRIO_PACKET: [u8; 56] = [
+    0,       // family
+    0, 0, 0, // pad1 + pad2
+    3, 0, 0, 0, // Interface index = 3
+    0, 0, 1, 0, // handle: 0x10000
+    255, 255, 255, 255, // parent: root
+    0, 0, 0, 0, // info
+
+    9, 0, // length
+    1, 0, // type: TCA_KIND
+    112, 114, 105, 111, 0, // prio\0
+    0, 0, 0, // padding
+
+    24, 0, // length
+    2, 0, // type: TCA_OPTIONS
+    3, 0, 0, 0, // bands: 3
+    1, 2, 2, 2, 1, 2, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, // priomap
+];
+
+#[test]
+fn tc_qdisc_prio_read() {
+    let packet = TcMessageBuffer::new_checked(&QDISC_PRIO_PACKET).unwrap();
+    let msg = TcMessage::parse(&packet).unwrap();
+    assert_eq!(
+        msg.nlas,
+        vec![
+            Nla::Kind(prio::KIND.to_string()),
+            Nla::Options(vec![TcOpt::Prio(prio::TcPrioQopt::default())]),
+        ]
+    );
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    assert_eq!(&buf[..], &QDISC_PRIO_PACKET[..]);
+}
+
+#[rustfmt::skip]
+static QDISC_NETEM_PACKET: [u8; 84] = [
+    0,       // family
+    0, 0, 0, // pad1 + pad2
+    3, 0, 0, 0, // Interface index = 3
+    0, 0, 1, 0, // handle: 0x10000
+    255, 255, 255, 255, // parent: root
+    0, 0, 0, 0, // info
+
+    10, 0, // length
+    1, 0, // type: TCA_KIND
+    110, 101, 116, 101, 109, 0, // netem\0
+    0, 0, // padding
+
+    52, 0, // length
+    2, 0, // type: TCA_OPTIONS
+    // struct tc_netem_qopt
+    0x5a, 0x62, 0x02, 0x00, // latency: 10ms in ticks
+    0xe8, 0x03, 0, 0, // limit: 1000
+    0x5c, 0x8f, 0xc2, 0x02, // loss: 1%
+    0, 0, 0, 0, // gap
+    0, 0, 0, 0, // duplicate
+    0, 0, 0, 0, // jitter
+        12, 0, // length
+        10, 0, // type: TCA_NETEM_LATENCY64
+        0x80, 0x96, 0x98, 0, 0, 0, 0, 0, // 10ms
+        12, 0, // length
+        11, 0, // type: TCA_NETEM_JITTER64
+        0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+#[test]
+fn tc_qdisc_netem_read() {
+    let packet = TcMessageBuffer::new_checked(&QDISC_NETEM_PACKET).unwrap();
+    let msg = TcMessage::parse(&packet).unwrap();
+    assert_eq!(
+        msg.nlas,
+        vec![
+            Nla::Kind(netem::KIND.to_string()),
+            Nla::Options(vec![
+                TcOpt::NetemQopt(netem::TcNetemQopt {
+                    latency: 156_250,
+                    limit: 1000,
+                    loss: 0x02c2_8f5c,
+                    ..Default::default()
+                }),
+                TcOpt::Netem(netem::Nla::Latency64(10_000_000)),
+                TcOpt::Netem(netem::Nla::Jitter64(0)),
+            ]),
+        ]
+    );
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    assert_eq!(&buf[..], &QDISC_NETEM_PACKET[..]);
+}
+
+fn tc_message_round_trip(msg: TcMessage) {
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    let parsed = TcMessage::parse(&TcMessageBuffer::new_checked(&buf).unwrap()).unwrap();
+    assert_eq!(parsed, msg);
+}
+
+#[test]
+fn tc_qdisc_mqprio_round_trip() {
+    let mut qopt = mqprio::TcMqprioQopt {
+        num_tc: 2,
+        ..Default::default()
+    };
+    qopt.prio_tc_map[1] = 1;
+    qopt.count[0] = 2;
+    qopt.count[1] = 2;
+    qopt.offset[1] = 2;
+
+    let msg = TcMessage::from_parts(
+        TcHeader {
+            index: 3,
+            parent: 0xffff_ffff,
+            ..Default::default()
+        },
+        vec![
+            Nla::Kind(mqprio::KIND.to_string()),
+            Nla::Options(vec![
+                TcOpt::MqprioQopt(qopt),
+                TcOpt::Mqprio(mqprio::Nla::Mode(TC_MQPRIO_MODE_CHANNEL)),
+                TcOpt::Mqprio(mqprio::Nla::Shaper(TC_MQPRIO_SHAPER_BW_RATE)),
+                TcOpt::Mqprio(mqprio::Nla::MaxRate64(vec![125_000, 250_000])),
+            ]),
+        ],
+    );
+    // The structure is padded to 84 bytes
+    assert_eq!(msg.nlas[1].buffer_len(), 4 + 84 + 8 + 8 + 28);
+    tc_message_round_trip(msg);
+}
+
+#[test]
+fn tc_class_htb_round_trip() {
+    let rate = TcRateSpec {
+        linklayer: TC_LINKLAYER_ETHERNET,
+        rate: 125_000,
+        ..Default::default()
+    };
+    tc_message_round_trip(TcMessage::from_parts(
+        TcHeader {
+            index: 3,
+            handle: 0x10010,
+            parent: 0x10000,
+            ..Default::default()
+        },
+        vec![
+            Nla::Kind(htb::KIND.to_string()),
+            Nla::Options(vec![TcOpt::Htb(htb::Nla::Parms(htb::TcHtbOpt {
+                rate,
+                ceil: rate,
+                buffer: 200_000,
+                cbuffer: 200_000,
+                quantum: 12_500,
+                ..Default::default()
+            }))]),
+            Nla::XStats(XStats::Htb(htb::TcHtbXstats {
+                lends: 12,
+                tokens: 200_000,
+                ctokens: -5,
+                ..Default::default()
+            })),
+        ],
+    ));
+}
+
+#[test]
+fn tc_qdisc_fq_codel_round_trip() {
+    tc_message_round_trip(TcMessage::from_parts(
+        TcHeader {
+            index: 3,
+            parent: 0xffff_ffff,
+            ..Default::default()
+        },
+        vec![
+            Nla::Kind(fq_codel::KIND.to_string()),
+            Nla::Options(vec![
+                TcOpt::FqCodel(fq_codel::Nla::Target(5000)),
+                TcOpt::FqCodel(fq_codel::Nla::Interval(100_000)),
+                TcOpt::FqCodel(fq_codel::Nla::Ecn(1)),
+                TcOpt::FqCodel(fq_codel::Nla::CeThresholdSelector(1)),
+            ]),
+            Nla::XStats(XStats::FqCodel(fq_codel::TcFqCodelXstats::Qdisc(
+                fq_codel::TcFqCodelQdStats {
+                    maxpacket: 1514,
+                    ecn_mark: 3,
+                    ..Default::default()
+                },
+            ))),
+        ],
+    ));
+}
+
+#[test]
+fn tc_qdisc_short_xstats() {
+    let msg = TcMessage::from_parts(
+        TcHeader::default(),
+        vec![
+            Nla::Kind(htb::KIND.to_string()),
+            Nla::XStats(XStats::Other(vec![1, 2, 3, 4])),
+        ],
+    );
+    tc_message_round_trip(msg);
+}
+
+#[rustfmt::skip]
+static CLASS_STATS2: [u8; 72] = [
+    72, 0, // length
+    7, 0,  // type: TCA_STATS2
+        20, 0, // length
+        1, 0,  // type: TCA_STATS_BASIC
+        0x60, 0xe3, 0x16, 0, 0, 0, 0, 0, // bytes: 1_500_000
+        0xe8, 0x03, 0, 0, // packets: 1000
+        0, 0, 0, 0, // padding
+
+        12, 0, // length
+        2, 0,  // type: TCA_STATS_RATE_EST
+        0x48, 0xe8, 0x01, 0, // bps: 125_000
+        83, 0, 0, 0, // pps: 83
+
+        24, 0, // length
+        3, 0,  // type: TCA_STATS_QUEUE
+        2, 0, 0, 0, // qlen: 2
+        0xb8, 0x0b, 0, 0, // backlog: 3000
+        5, 0, 0, 0, // drops: 5
+        0, 0, 0, 0, // requeues: 0
+        40, 0, 0, 0, // overlimits: 40
+
+        12, 0, // length
+        8, 0,  // type: TCA_STATS_PKT64
+        0xe8, 0x03, 0, 0, 0, 0, 0, 0, // 1000
+];
+
+#[test]
+fn tc_class_stats2_read_and_emit() {
+    let expected = Nla::Stats2(vec![
+        Stats2::StatsBasic(StatsBasic {
+            bytes: 1_500_000,
+            packets: 1000,
+        }),
+        Stats2::StatsRateEst(StatsRateEst {
+            bps: 125_000,
+            pps: 83,
+        }),
+        Stats2::StatsQueue(StatsQueue {
+            qlen: 2,
+            backlog: 3000,
+            drops: 5,
+            requeues: 0,
+            overlimits: 40,
+        }),
+        Stats2::StatsPkt64(1000),
+    ]);
+
+    let mut buf = vec![0; TC_HEADER_LEN];
+    buf.extend_from_slice(&CLASS_STATS2);
+    let msg = TcMessage::parse(&TcMessageBuffer::new_checked(&buf).unwrap()).unwrap();
+    assert_eq!(msg.nlas, vec![expected.clone()]);
+
+    let mut emitted = vec![0xff; expected.buffer_len()];
+    expected.emit(&mut emitted);
+    assert_eq!(&emitted[..], &CLASS_STATS2[..]);
+}
+
+#[test]
+fn tc_qdisc_clsact_blocks_round_trip() {
+    tc_message_round_trip(TcMessage::from_parts(
+        TcHeader {
+            index: 3,
+            handle: 0xffff_0000,
+            parent: 0xffff_fff1,
+            ..Default::default()
+        },
+        vec![
+            Nla::Kind("clsact".to_string()),
+            Nla::IngressBlock(22),
+            Nla::EgressBlock(23),
+            Nla::Options(vec![]),
+        ],
+    ));
+}
+
+// `tc actions list action gact`
+#[rustfmt::skip]
+static ACTION_DUMP_REQUEST: [u8; 36] = [
+    0, 0, 0, 0, // family, pad1, pad2
+
+    20, 0, // length
+    1, 0,  // type: TCA_ROOT_TAB
+        16, 0, // length
+        1, 0,  // type: action 1
+            9, 0, // length
+            1, 0, // type: TCA_ACT_KIND
+            103, 97, 99, 116, 0, // gact\0
+            0, 0, 0, // padding
+
+    12, 0, // length
+    2, 0,  // type: TCA_ROOT_FLAGS
+    1, 0, 0, 0, // value: TCA_ACT_FLAG_LARGE_DUMP_ON
+    1, 0, 0, 0, // selector: TCA_ACT_FLAG_LARGE_DUMP_ON
+];
+
+#[test]
+fn tc_action_dump_request() {
+    let msg = TcActionMessage::from_parts(
+        TcActionHeader::default(),
+        vec![
+            TcActionMessageNla::Actions(vec![Action {
+                tab: 1,
+                nlas: vec![ActNla::Kind(gact::KIND.to_string())],
+            }]),
+            TcActionMessageNla::Flags(NlaBitfield32 {
+                value: TCA_ACT_FLAG_LARGE_DUMP_ON,
+                selector: TCA_ACT_FLAG_LARGE_DUMP_ON,
+            }),
+        ],
+    );
+
+    let mut buf = vec![0xff; msg.buffer_len()];
+    msg.emit(&mut buf);
+    assert_eq!(&buf[..], &ACTION_DUMP_REQUEST[..]);
+
+    let parsed =
+        TcActionMessage::parse(&TcActionMessageBuffer::new_checked(&ACTION_DUMP_REQUEST).unwrap())
+            .unwrap();
+    assert_eq!(parsed, msg);
+}
+
+#[test]
+fn tc_action_police_round_trip() {
+    let rate = TcRateSpec {
+        linklayer: TC_LINKLAYER_ETHERNET,
+        rate: 125_000,
+        ..Default::default()
+    };
+    let msg = TcActionMessage::from_parts(
+        TcActionHeader::default(),
+        vec![
+            TcActionMessageNla::Count(1),
+            TcActionMessageNla::Actions(vec![Action {
+                tab: 1,
+                nlas: vec![
+                    ActNla::Kind(police::KIND.to_string()),
+                    ActNla::Stats(vec![Stats2::StatsBasic(StatsBasic {
+                        bytes: 3000,
+                        packets: 2,
+                    })]),
+                    ActNla::Options(vec![ActOpt::Police(police::Nla::Tbf(police::TcPolice {
+                        index: 7,
+                        action: TC_ACT_SHOT,
+                        burst: 200_000,
+                        mtu: 2040,
+                        rate,
+                        refcnt: 1,
+                        ..Default::default()
+                    }))]),
+                ],
+            }]),
+        ],
+    );
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    let parsed =
+        TcActionMessage::parse(&TcActionMessageBuffer::new_checked(&buf).unwrap()).unwrap();
+    assert_eq!(parsed, msg);
+}
@@ -3,8 +3,11 @@
 use crate::{
     constants::*,
     inet,
+    netlink,
+    packet,
     traits::{Parseable, ParseableParametrized},
     unix,
+    xdp,
     DecodeError,
     SockDiagMessage,
 };
@@ -83,9 +86,32 @@ impl<'a, T: AsRef<[u8]>> ParseableParametrized<SockDiagBuffer<&'a T>, u16> for S
                 let buf = unix::UnixResponseBuffer::new_checked(buf.inner()).context(err)?;
                 UnixResponse(Box::new(unix::UnixResponse::parse(&buf).context(err)?))
             }
+            (SOCK_DIAG_BY_FAMILY, AF_NETLINK) => {
+                let err = "invalid AF_NETLINK response";
+                let buf = netlink::NetlinkResponseBuffer::new_checked(buf.inner()).context(err)?;
+                NetlinkResponse(Box::new(
+                    netlink::NetlinkResponse::parse(&buf).context(err)?,
+                ))
+            }
+            (SOCK_DIAG_BY_FAMILY, AF_PACKET) => {
+                let err = "invalid AF_PACKET response";
+                let buf = packet::PacketResponseBuffer::new_checked(buf.inner()).context(err)?;
+                PacketResponse(Box::new(packet::PacketResponse::parse(&buf).context(err)?))
+            }
+            (SOCK_DIAG_BY_FAMILY, AF_XDP) => {
+                let err = "invalid AF_XDP response";
+                let buf = xdp::XdpResponseBuffer::new_checked(buf.inner()).context(err)?;
+                XdpResponse(Box::new(xdp::XdpResponse::parse(&buf).context(err)?))
+            }
             (SOCK_DIAG_BY_FAMILY, af) => {
                 return Err(format!("unknown address family {}", af).into())
             }
+            (SOCK_DESTROY, AF_INET) | (SOCK_DESTROY, AF_INET6) => {
+                let err = "invalid SOCK_DESTROY request";
+                let buf = inet::InetRequestBuffer::new_checked(buf.inner()).context(err)?;
+                InetDestroy(inet::InetRequest::parse(&buf).context(err)?)
+            }
+            (SOCK_DESTROY, af) => return Err(format!("unknown address family {}", af).into()),
             _ => return Err(format!("unknown message type {}", message_type).into()),
         };
         Ok(message)
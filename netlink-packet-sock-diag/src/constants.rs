@@ -44,6 +44,7 @@ pub const AF_PHONET: u8 = libc::AF_PHONET as u8;
 pub const AF_IEEE802154: u8 = libc::AF_IEEE802154 as u8;
 pub const AF_CAIF: u8 = libc::AF_CAIF as u8;
 pub const AF_ALG: u8 = libc::AF_ALG as u8;
+pub const AF_XDP: u8 = 44;
 
 /// Dummy protocol for TCP
 pub const IPPROTO_IP: u8 = 0;
@@ -131,6 +132,63 @@ pub const INET_DIAG_MARK: u16 = 15;
 pub const INET_DIAG_BBRINFO: u16 = 16;
 pub const INET_DIAG_CLASS_ID: u16 = 17;
 pub const INET_DIAG_MD5SIG: u16 = 18;
+pub const INET_DIAG_ULP_INFO: u16 = 19;
+
+// Attributes nested in INET_DIAG_ULP_INFO
+pub const INET_ULP_INFO_UNSPEC: u16 = 0;
+pub const INET_ULP_INFO_NAME: u16 = 1;
+pub const INET_ULP_INFO_TLS: u16 = 2;
+pub const INET_ULP_INFO_MPTCP: u16 = 3;
+
+// Attributes nested in INET_ULP_INFO_TLS
+pub const TLS_INFO_UNSPEC: u16 = 0;
+pub const TLS_INFO_VERSION: u16 = 1;
+pub const TLS_INFO_CIPHER: u16 = 2;
+pub const TLS_INFO_TXCONF: u16 = 3;
+pub const TLS_INFO_RXCONF: u16 = 4;
+pub const TLS_INFO_ZC_RO_TX: u16 = 5;
+pub const TLS_INFO_RX_NO_PAD: u16 = 6;
+
+pub const TLS_1_2_VERSION: u16 = 0x0303;
+pub const TLS_1_3_VERSION: u16 = 0x0304;
+
+pub const TLS_CIPHER_AES_GCM_128: u16 = 51;
+pub const TLS_CIPHER_AES_GCM_256: u16 = 52;
+pub const TLS_CIPHER_AES_CCM_128: u16 = 53;
+pub const TLS_CIPHER_CHACHA20_POLY1305: u16 = 54;
+pub const TLS_CIPHER_SM4_GCM: u16 = 55;
+pub const TLS_CIPHER_SM4_CCM: u16 = 56;
+
+/// The TLS records are not offloaded
+pub const TLS_CONF_BASE: u16 = 1;
+/// The TLS records are encrypted or decrypted by the kernel
+pub const TLS_CONF_SW: u16 = 2;
+/// The TLS records are encrypted or decrypted by the NIC
+pub const TLS_CONF_HW: u16 = 3;
+/// The TLS records are fully offloaded to the NIC
+pub const TLS_CONF_HW_RECORD: u16 = 4;
+
+// Attributes of inet requests
+pub const INET_DIAG_REQ_NONE: u16 = 0;
+pub const INET_DIAG_REQ_BYTECODE: u16 = 1;
+pub const INET_DIAG_REQ_SK_BPF_STORAGES: u16 = 2;
+pub const INET_DIAG_REQ_PROTOCOL: u16 = 3;
+
+// Operations of the inet requests bytecode
+pub const INET_DIAG_BC_NOP: u8 = 0;
+pub const INET_DIAG_BC_JMP: u8 = 1;
+pub const INET_DIAG_BC_S_GE: u8 = 2;
+pub const INET_DIAG_BC_S_LE: u8 = 3;
+pub const INET_DIAG_BC_D_GE: u8 = 4;
+pub const INET_DIAG_BC_D_LE: u8 = 5;
+pub const INET_DIAG_BC_AUTO: u8 = 6;
+pub const INET_DIAG_BC_S_COND: u8 = 7;
+pub const INET_DIAG_BC_D_COND: u8 = 8;
+pub const INET_DIAG_BC_DEV_COND: u8 = 9;
+pub const INET_DIAG_BC_MARK_COND: u8 = 10;
+pub const INET_DIAG_BC_S_EQ: u8 = 11;
+pub const INET_DIAG_BC_D_EQ: u8 = 12;
+pub const INET_DIAG_BC_CGROUP_COND: u8 = 13;
 
 /// (both server and client) represents an open connection, data
 /// received can be delivered to the user. The normal state for the
@@ -212,6 +270,100 @@ pub const UNIX_DIAG_RQLEN: u16 = 4;
 pub const UNIX_DIAG_MEMINFO: u16 = 5;
 pub const UNIX_DIAG_SHUTDOWN: u16 = 6;
 
+/// Value of the protocol of netlink requests to dump the sockets of
+/// all the netlink protocols
+pub const NDIAG_PROTO_ALL: u8 = 0xff;
+
+/// Report the memory information of the netlink sockets
+/// (`NETLINK_DIAG_MEMINFO`)
+pub const NDIAG_SHOW_MEMINFO: u32 = 1;
+/// Report the multicast groups the netlink sockets are subscribed to
+/// (`NETLINK_DIAG_GROUPS`)
+pub const NDIAG_SHOW_GROUPS: u32 = 2;
+/// Report the configuration of the memory mapped rings. Since these
+/// rings have been removed in Linux 4.6, this flag is ignored.
+pub const NDIAG_SHOW_RING_CFG: u32 = 4;
+/// Report the flags of the netlink sockets (`NETLINK_DIAG_FLAGS`)
+pub const NDIAG_SHOW_FLAGS: u32 = 8;
+
+pub const NDIAG_FLAG_CB_RUNNING: u32 = 1;
+pub const NDIAG_FLAG_PKTINFO: u32 = 2;
+pub const NDIAG_FLAG_BROADCAST_ERROR: u32 = 4;
+pub const NDIAG_FLAG_NO_ENOBUFS: u32 = 8;
+pub const NDIAG_FLAG_LISTEN_ALL_NSID: u32 = 16;
+pub const NDIAG_FLAG_CAP_ACK: u32 = 32;
+
+pub const NETLINK_DIAG_MEMINFO: u16 = 0;
+pub const NETLINK_DIAG_GROUPS: u16 = 1;
+pub const NETLINK_DIAG_RX_RING: u16 = 2;
+pub const NETLINK_DIAG_TX_RING: u16 = 3;
+pub const NETLINK_DIAG_FLAGS: u16 = 4;
+
+/// Report the basic information of the packet sockets
+/// (`PACKET_DIAG_INFO`)
+pub const PACKET_SHOW_INFO: u32 = 1;
+/// Report the multicast memberships of the packet sockets
+/// (`PACKET_DIAG_MCLIST`)
+pub const PACKET_SHOW_MCLIST: u32 = 2;
+/// Report the configuration of the memory mapped rings
+/// (`PACKET_DIAG_RX_RING` and `PACKET_DIAG_TX_RING`)
+pub const PACKET_SHOW_RING_CFG: u32 = 4;
+/// Report the fanout group of the packet sockets
+/// (`PACKET_DIAG_FANOUT`)
+pub const PACKET_SHOW_FANOUT: u32 = 8;
+/// Report the memory information of the packet sockets
+/// (`PACKET_DIAG_MEMINFO`)
+pub const PACKET_SHOW_MEMINFO: u32 = 16;
+/// Report the BPF filter attached to the packet sockets
+/// (`PACKET_DIAG_FILTER`)
+pub const PACKET_SHOW_FILTER: u32 = 32;
+
+pub const PACKET_DIAG_INFO: u16 = 0;
+pub const PACKET_DIAG_MCLIST: u16 = 1;
+pub const PACKET_DIAG_RX_RING: u16 = 2;
+pub const PACKET_DIAG_TX_RING: u16 = 3;
+pub const PACKET_DIAG_FANOUT: u16 = 4;
+pub const PACKET_DIAG_UID: u16 = 5;
+pub const PACKET_DIAG_MEMINFO: u16 = 6;
+pub const PACKET_DIAG_FILTER: u16 = 7;
+
+/// The packet socket is bound to a device and receives packets
+pub const PDI_RUNNING: u32 = 1;
+/// `PACKET_AUXDATA` is enabled
+pub const PDI_AUXDATA: u32 = 2;
+/// `PACKET_ORIGDEV` is enabled
+pub const PDI_ORIGDEV: u32 = 4;
+/// `PACKET_VNET_HDR` is enabled
+pub const PDI_VNETHDR: u32 = 8;
+/// `PACKET_LOSS` is enabled
+pub const PDI_LOSS: u32 = 16;
+
+/// Report the interface and queue of the XDP sockets (`XDP_DIAG_INFO`)
+pub const XDP_SHOW_INFO: u32 = 1;
+/// Report the size of the rings of the XDP sockets
+pub const XDP_SHOW_RING_CFG: u32 = 2;
+/// Report the UMEM of the XDP sockets (`XDP_DIAG_UMEM`)
+pub const XDP_SHOW_UMEM: u32 = 4;
+/// Report the memory information of the XDP sockets
+/// (`XDP_DIAG_MEMINFO`)
+pub const XDP_SHOW_MEMINFO: u32 = 8;
+/// Report the statistics of the XDP sockets (`XDP_DIAG_STATS`)
+pub const XDP_SHOW_STATS: u32 = 16;
+
+pub const XDP_DIAG_NONE: u16 = 0;
+pub const XDP_DIAG_INFO: u16 = 1;
+pub const XDP_DIAG_UID: u16 = 2;
+pub const XDP_DIAG_RX_RING: u16 = 3;
+pub const XDP_DIAG_TX_RING: u16 = 4;
+pub const XDP_DIAG_UMEM: u16 = 5;
+pub const XDP_DIAG_UMEM_FILL_RING: u16 = 6;
+pub const XDP_DIAG_UMEM_COMPLETION_RING: u16 = 7;
+pub const XDP_DIAG_MEMINFO: u16 = 8;
+pub const XDP_DIAG_STATS: u16 = 9;
+
+/// The UMEM is used in zero-copy mode
+pub const XDP_DU_F_ZEROCOPY: u32 = 1;
+
 /// Provides sequenced, reliable, two-way, connection-based byte
 /// streams. An out-of-band data transmission mechanism may be
 /// supported.
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+
+use std::{convert::TryFrom, net::IpAddr, ops::Not};
+
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{constants::*, EncodeError};
+
+/// Length of a bytecode operation (`struct inet_diag_bc_op`)
+const OP_LEN: usize = 4;
+
+/// A filter of IPv4 and IPv6 sockets. It is compiled to the bytecode of the
+/// `INET_DIAG_REQ_BYTECODE` attribute of the requests, which the kernel runs
+/// on each socket of a dump, so that only the matching sockets are returned.
+/// This is equivalent to the filters of `ss`, such as `ss 'sport = :443 and
+/// not dst 10.0.0.0/8'`, which becomes:
+///
+/// ```rust
+/// # use std::net::{IpAddr, Ipv4Addr};
+/// # use netlink_packet_sock_diag::inet::InetFilter;
+/// let filter = InetFilter::SourcePortEq(443)
+///     .and(!InetFilter::DestinationPrefix(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InetFilter {
+    /// `sport = :port`
+    SourcePortEq(u16),
+    /// `sport >= :port`
+    SourcePortGe(u16),
+    /// `sport <= :port`
+    SourcePortLe(u16),
+    /// `dport = :port`
+    DestinationPortEq(u16),
+    /// `dport >= :port`
+    DestinationPortGe(u16),
+    /// `dport <= :port`
+    DestinationPortLe(u16),
+    /// `src address/prefix_len`
+    SourcePrefix(IpAddr, u8),
+    /// `dst address/prefix_len`
+    DestinationPrefix(IpAddr, u8),
+    /// `autobound`: the socket is bound to a port chosen by the kernel
+    AutoBound,
+    /// `dev = index`: the socket is bound to the given interface
+    Device(u32),
+    /// `fwmark = mark/mask`. Filtering on the mark requires
+    /// `CAP_NET_ADMIN`.
+    Mark {
+        mark: u32,
+        mask: u32,
+    },
+    /// `cgroup = path`, with the id of the cgroup v2, which is the inode
+    /// number of its directory
+    Cgroup(u64),
+    And(Box<InetFilter>, Box<InetFilter>),
+    Or(Box<InetFilter>, Box<InetFilter>),
+    Not(Box<InetFilter>),
+}
+
+impl InetFilter {
+    /// Match the sockets matched by both filters.
+    pub fn and(self, other: InetFilter) -> Self {
+        InetFilter::And(Box::new(self), Box::new(other))
+    }
+
+    /// Match the sockets matched by either filter.
+    pub fn or(self, other: InetFilter) -> Self {
+        InetFilter::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Compile the filter to the bytecode of the `INET_DIAG_REQ_BYTECODE`
+    /// attribute, see [`RequestNla::Bytecode`](crate::inet::nlas::RequestNla).
+    ///
+    /// This fails when the filter is so large that a jump does not fit in
+    /// its operation.
+    pub fn to_bytecode(&self) -> Result<Vec<u8>, EncodeError> {
+        use self::InetFilter::*;
+
+        // Each operation jumps `yes` bytes forward when its condition is
+        // true and `no` bytes forward otherwise. A socket is matched when
+        // the end of the bytecode is reached, and rejected when jumping 4
+        // bytes past it. Like `ss`, conditions always jump to the next
+        // operation when true, which `patch_rejects` relies on.
+        let bytecode = match self {
+            SourcePortEq(port) => port_cond(INET_DIAG_BC_S_EQ, *port)?,
+            SourcePortGe(port) => port_cond(INET_DIAG_BC_S_GE, *port)?,
+            SourcePortLe(port) => port_cond(INET_DIAG_BC_S_LE, *port)?,
+            DestinationPortEq(port) => port_cond(INET_DIAG_BC_D_EQ, *port)?,
+            DestinationPortGe(port) => port_cond(INET_DIAG_BC_D_GE, *port)?,
+            DestinationPortLe(port) => port_cond(INET_DIAG_BC_D_LE, *port)?,
+            SourcePrefix(addr, prefix_len) => host_cond(INET_DIAG_BC_S_COND, addr, *prefix_len)?,
+            DestinationPrefix(addr, prefix_len) => {
+                host_cond(INET_DIAG_BC_D_COND, addr, *prefix_len)?
+            }
+            AutoBound => cond(INET_DIAG_BC_AUTO, &[])?,
+            Device(index) => cond(INET_DIAG_BC_DEV_COND, &index.to_ne_bytes())?,
+            Mark { mark, mask } => {
+                let mut data = [0; 8];
+                NativeEndian::write_u32(&mut data[..4], *mark);
+                NativeEndian::write_u32(&mut data[4..], *mask);
+                cond(INET_DIAG_BC_MARK_COND, &data)?
+            }
+            Cgroup(id) => cond(INET_DIAG_BC_CGROUP_COND, &id.to_ne_bytes())?,
+            And(left, right) => {
+                // The left side rejects the socket by jumping past the
+                // right side instead of jumping to it
+                let mut bytecode = left.to_bytecode()?;
+                let right = right.to_bytecode()?;
+                patch_rejects(&mut bytecode, right.len())?;
+                bytecode.extend_from_slice(&right);
+                bytecode
+            }
+            Or(left, right) => {
+                // When the left side matches, jump over the right side
+                let mut bytecode = left.to_bytecode()?;
+                let right = right.to_bytecode()?;
+                bytecode.extend_from_slice(&op(INET_DIAG_BC_JMP, OP_LEN, OP_LEN + right.len())?);
+                bytecode.extend_from_slice(&right);
+                bytecode
+            }
+            Not(filter) => {
+                // When the filter matches, reject the socket, and its rejects
+                // now jump to the end of the bytecode
+                let mut bytecode = filter.to_bytecode()?;
+                bytecode.extend_from_slice(&op(INET_DIAG_BC_JMP, OP_LEN, 2 * OP_LEN)?);
+                bytecode
+            }
+        };
+        Ok(bytecode)
+    }
+}
+
+impl Not for InetFilter {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        InetFilter::Not(Box::new(self))
+    }
+}
+
+fn op(code: u8, yes: usize, no: usize) -> Result<[u8; OP_LEN], EncodeError> {
+    let mut op = [code, jump_u8(yes)?, 0, 0];
+    NativeEndian::write_u16(&mut op[2..], jump_u16(no)?);
+    Ok(op)
+}
+
+fn jump_u8(jump: usize) -> Result<u8, EncodeError> {
+    u8::try_from(jump)
+        .map_err(|_| format!("jump of {} bytes exceeds {} bytes", jump, u8::MAX).into())
+}
+
+fn jump_u16(jump: usize) -> Result<u16, EncodeError> {
+    u16::try_from(jump)
+        .map_err(|_| format!("jump of {} bytes exceeds {} bytes", jump, u16::MAX).into())
+}
+
+// A condition followed by its data, which jumps to the next operation when
+// true and rejects the socket otherwise
+fn cond(code: u8, data: &[u8]) -> Result<Vec<u8>, EncodeError> {
+    let len = OP_LEN + data.len();
+    let mut bytecode = op(code, len, len + OP_LEN)?.to_vec();
+    bytecode.extend_from_slice(data);
+    Ok(bytecode)
+}
+
+// The port is held in the `no` field of a second operation
+fn port_cond(code: u8, port: u16) -> Result<Vec<u8>, EncodeError> {
+    cond(code, &op(INET_DIAG_BC_NOP, 0, port.into())?)
+}
+
+// See `struct inet_diag_hostcond`
+fn host_cond(code: u8, addr: &IpAddr, prefix_len: u8) -> Result<Vec<u8>, EncodeError> {
+    let (family, octets) = match addr {
+        IpAddr::V4(addr) => (AF_INET, addr.octets().to_vec()),
+        IpAddr::V6(addr) => (AF_INET6, addr.octets().to_vec()),
+    };
+    let mut data = vec![family, prefix_len, 0, 0];
+    // any port
+    data.extend_from_slice(&(-1i32).to_ne_bytes());
+    data.extend_from_slice(&octets);
+    cond(code, &data)
+}
+
+// Make the operations rejecting the socket jump `reloc` bytes further
+fn patch_rejects(bytecode: &mut [u8], reloc: usize) -> Result<(), EncodeError> {
+    let mut pos = 0;
+    while pos < bytecode.len() {
+        let remaining = bytecode.len() - pos;
+        let yes = bytecode[pos + 1] as usize;
+        let no = NativeEndian::read_u16(&bytecode[pos + 2..pos + 4]) as usize;
+        if no == remaining + OP_LEN {
+            NativeEndian::write_u16(&mut bytecode[pos + 2..pos + 4], jump_u16(no + reloc)?);
+        }
+        pos += yes;
+    }
+    Ok(())
+}
@@ -9,6 +9,9 @@ pub use self::request::*;
 mod response;
 pub use self::response::*;
 
+mod bytecode;
+pub use self::bytecode::*;
+
 pub mod nlas;
 
 #[cfg(test)]
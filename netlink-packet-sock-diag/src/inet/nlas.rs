@@ -7,7 +7,7 @@ pub use crate::utils::nla::{DefaultNla, NlaBuffer, NlasIterator};
 
 use crate::{
     constants::*,
-    parsers::{parse_string, parse_u32, parse_u8},
+    parsers::{parse_string, parse_u16, parse_u32, parse_u8},
     traits::{Emitable, Parseable},
     DecodeError,
 };
@@ -62,9 +62,31 @@ impl Emitable for LegacyMemInfo {
 }
 
 pub const MEM_INFO_LEN: usize = 36;
+/// Length of the `INET_DIAG_SKMEMINFO` attribute on the oldest
+/// kernels, which do not report the backlog queue length and the
+/// drops.
+pub const MEM_INFO_MIN_LEN: usize = 28;
+
+/// Some attributes are structures that the kernel extended over time
+/// by appending new fields. Older kernels send a shorter payload,
+/// which we pad with zeros so that it can be parsed with the buffer
+/// of the most recent layout we know about. Extra trailing bytes sent
+/// by newer kernels are ignored.
+pub(crate) fn zero_extend(
+    payload: &[u8],
+    min_len: usize,
+    len: usize,
+) -> Result<Vec<u8>, DecodeError> {
+    if payload.len() < min_len {
+        return Err(format!("invalid payload: length {} < {}", payload.len(), min_len).into());
+    }
+    let mut buf = payload.to_vec();
+    if buf.len() < len {
+        buf.resize(len, 0);
+    }
+    Ok(buf)
+}
 
-// FIXME: the last 2 fields are not present on old linux kernels. We
-// should support optional fields in the `buffer!` macro.
 buffer!(MemInfoBuffer(MEM_INFO_LEN) {
     receive_queue: (u32, 0..4),
     receive_queue_max: (u32, 4..8),
@@ -169,14 +191,11 @@ pub struct MemInfo {
     /// half of network stack waiting for the process to release the
     /// socket, the packets are enqueued in the backlog queue. Upon
     /// releasing the socket, those packets are processed and put in
-    /// the regular receive queue.
-    // FIXME: this should be an Option because it's not present on old
-    // linux kernels.
+    /// the regular receive queue. This is always 0 on old kernels that
+    /// do not report it.
     pub backlog_queue_length: u32,
-    /// The amount of packets dropped. Depending on the kernel
-    /// version, this field may not be present.
-    // FIXME: this should be an Option because it's not present on old
-    // linux kernels.
+    /// The amount of packets dropped. This is always 0 on old kernels
+    /// that do not report it.
     pub drops: u32,
 }
 
@@ -215,6 +234,167 @@ impl Emitable for MemInfo {
     }
 }
 
+pub const VEGAS_INFO_LEN: usize = 16;
+
+buffer!(VegasInfoBuffer(VEGAS_INFO_LEN) {
+    enabled: (u32, 0..4),
+    rtt_count: (u32, 4..8),
+    rtt: (u32, 8..12),
+    min_rtt: (u32, 12..16),
+});
+
+/// Information reported by the Vegas congestion control algorithm.
+/// The kernel also uses this structure for Westwood, Illinois and
+/// the other algorithms that track the RTT.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VegasInfo {
+    /// Whether the algorithm is currently enabled. This attribute is
+    /// known as `tcpv_enabled` in the kernel.
+    pub enabled: u32,
+    /// Number of RTT samples taken during the last RTT. This
+    /// attribute is known as `tcpv_rttcnt` in the kernel.
+    pub rtt_count: u32,
+    /// RTT (in microseconds) seen during the last RTT. This attribute
+    /// is known as `tcpv_rtt` in the kernel.
+    pub rtt: u32,
+    /// Minimum RTT (in microseconds) seen by the connection. This
+    /// attribute is known as `tcpv_minrtt` in the kernel.
+    pub min_rtt: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<VegasInfoBuffer<T>> for VegasInfo {
+    fn parse(buf: &VegasInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            enabled: buf.enabled(),
+            rtt_count: buf.rtt_count(),
+            rtt: buf.rtt(),
+            min_rtt: buf.min_rtt(),
+        })
+    }
+}
+
+impl Emitable for VegasInfo {
+    fn buffer_len(&self) -> usize {
+        VEGAS_INFO_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = VegasInfoBuffer::new(buf);
+        buf.set_enabled(self.enabled);
+        buf.set_rtt_count(self.rtt_count);
+        buf.set_rtt(self.rtt);
+        buf.set_min_rtt(self.min_rtt);
+    }
+}
+
+pub const DCTCP_INFO_LEN: usize = 16;
+
+buffer!(DctcpInfoBuffer(DCTCP_INFO_LEN) {
+    enabled: (u16, 0..2),
+    ce_state: (u16, 2..4),
+    alpha: (u32, 4..8),
+    ab_ecn: (u32, 8..12),
+    ab_tot: (u32, 12..16),
+});
+
+/// Information reported by the DCTCP congestion control algorithm
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DctcpInfo {
+    /// Whether DCTCP is enabled. When it is not (for instance because
+    /// ECN could not be negotiated), the other fields are 0.
+    pub enabled: u16,
+    /// Whether the last received segment had the CE
+    /// (Congestion Experienced) codepoint set.
+    pub ce_state: u16,
+    /// Estimation of the fraction of marked bytes, scaled by 1024.
+    pub alpha: u32,
+    /// Number of bytes acknowledged with the ECE flag during the
+    /// current observation window.
+    pub ab_ecn: u32,
+    /// Total number of bytes acknowledged during the current
+    /// observation window.
+    pub ab_tot: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<DctcpInfoBuffer<T>> for DctcpInfo {
+    fn parse(buf: &DctcpInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            enabled: buf.enabled(),
+            ce_state: buf.ce_state(),
+            alpha: buf.alpha(),
+            ab_ecn: buf.ab_ecn(),
+            ab_tot: buf.ab_tot(),
+        })
+    }
+}
+
+impl Emitable for DctcpInfo {
+    fn buffer_len(&self) -> usize {
+        DCTCP_INFO_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = DctcpInfoBuffer::new(buf);
+        buf.set_enabled(self.enabled);
+        buf.set_ce_state(self.ce_state);
+        buf.set_alpha(self.alpha);
+        buf.set_ab_ecn(self.ab_ecn);
+        buf.set_ab_tot(self.ab_tot);
+    }
+}
+
+pub const BBR_INFO_LEN: usize = 20;
+
+buffer!(BbrInfoBuffer(BBR_INFO_LEN) {
+    bandwidth_lo: (u32, 0..4),
+    bandwidth_hi: (u32, 4..8),
+    min_rtt: (u32, 8..12),
+    pacing_gain: (u32, 12..16),
+    cwnd_gain: (u32, 16..20),
+});
+
+/// Information reported by the BBR congestion control algorithm
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BbrInfo {
+    /// Max-filtered estimation of the bottleneck bandwidth, in bytes
+    /// per second. The kernel splits it into `bbr_bw_lo` and
+    /// `bbr_bw_hi`.
+    pub bandwidth: u64,
+    /// Min-filtered RTT, in microseconds.
+    pub min_rtt: u32,
+    /// Pacing gain, shifted left by 8 bits (256 means 1.0).
+    pub pacing_gain: u32,
+    /// Congestion window gain, shifted left by 8 bits (256 means
+    /// 1.0).
+    pub cwnd_gain: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BbrInfoBuffer<T>> for BbrInfo {
+    fn parse(buf: &BbrInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bandwidth: (u64::from(buf.bandwidth_hi()) << 32) | u64::from(buf.bandwidth_lo()),
+            min_rtt: buf.min_rtt(),
+            pacing_gain: buf.pacing_gain(),
+            cwnd_gain: buf.cwnd_gain(),
+        })
+    }
+}
+
+impl Emitable for BbrInfo {
+    fn buffer_len(&self) -> usize {
+        BBR_INFO_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = BbrInfoBuffer::new(buf);
+        buf.set_bandwidth_lo(self.bandwidth as u32);
+        buf.set_bandwidth_hi((self.bandwidth >> 32) as u32);
+        buf.set_min_rtt(self.min_rtt);
+        buf.set_pacing_gain(self.pacing_gain);
+        buf.set_cwnd_gain(self.cwnd_gain);
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Nla {
     /// The memory information of the socket. This attribute is
@@ -229,6 +409,19 @@ pub enum Nla {
     TcpInfo(Vec<u8>),
     /// the congestion control algorithm used
     Congestion(String),
+    /// Vegas congestion control information. This is reported when
+    /// [`ExtensionFlags::VEGASINFO`](crate::inet::ExtensionFlags::VEGASINFO)
+    /// is requested, for sockets using Vegas or another algorithm
+    /// that tracks the RTT (Westwood, Illinois, ...).
+    Vegas(VegasInfo),
+    /// DCTCP congestion control information. This is reported when
+    /// [`ExtensionFlags::VEGASINFO`](crate::inet::ExtensionFlags::VEGASINFO)
+    /// is requested, for sockets using DCTCP.
+    Dctcp(DctcpInfo),
+    /// BBR congestion control information. This is reported when
+    /// [`ExtensionFlags::VEGASINFO`](crate::inet::ExtensionFlags::VEGASINFO)
+    /// is requested, for sockets using BBR.
+    Bbr(BbrInfo),
     /// the TOS of the socket.
     Tos(u8),
     /// the traffic class of the socket.
@@ -245,6 +438,9 @@ pub enum Nla {
     Mark(u32),
     /// The class ID of the socket.
     ClassId(u32),
+    /// The upper layer protocol (ULP) of the socket, such as kernel
+    /// TLS. It is reported along with [`Nla::TcpInfo`].
+    UlpInfo(Vec<UlpInfo>),
     /// other attribute
     Other(DefaultNla),
 }
@@ -260,9 +456,13 @@ impl crate::utils::nla::Nla for Nla {
             TcpInfo(ref bytes) => bytes.len(),
             // +1 because we need to append a null byte
             Congestion(ref s) => s.as_bytes().len() + 1,
+            Vegas(_) => VEGAS_INFO_LEN,
+            Dctcp(_) => DCTCP_INFO_LEN,
+            Bbr(_) => BBR_INFO_LEN,
             Tos(_) | Tc(_) | Shutdown(_) | Protocol(_) | SkV6Only(_) => 1,
             MemInfo(_) => MEM_INFO_LEN,
             Mark(_) | ClassId(_) => 4,
+            UlpInfo(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref attr) => attr.value_len(),
         }
     }
@@ -273,6 +473,9 @@ impl crate::utils::nla::Nla for Nla {
             LegacyMemInfo(_) => INET_DIAG_MEMINFO,
             TcpInfo(_) => INET_DIAG_INFO,
             Congestion(_) => INET_DIAG_CONG,
+            Vegas(_) => INET_DIAG_VEGASINFO,
+            Dctcp(_) => INET_DIAG_DCTCPINFO,
+            Bbr(_) => INET_DIAG_BBRINFO,
             Tos(_) => INET_DIAG_TOS,
             Tc(_) => INET_DIAG_TCLASS,
             MemInfo(_) => INET_DIAG_SKMEMINFO,
@@ -281,6 +484,7 @@ impl crate::utils::nla::Nla for Nla {
             SkV6Only(_) => INET_DIAG_SKV6ONLY,
             Mark(_) => INET_DIAG_MARK,
             ClassId(_) => INET_DIAG_CLASS_ID,
+            UlpInfo(_) => INET_DIAG_ULP_INFO,
             Other(ref attr) => attr.kind(),
         }
     }
@@ -297,10 +501,14 @@ impl crate::utils::nla::Nla for Nla {
                 buffer[..s.len()].copy_from_slice(s.as_bytes());
                 buffer[s.len()] = 0;
             }
+            Vegas(ref value) => value.emit(buffer),
+            Dctcp(ref value) => value.emit(buffer),
+            Bbr(ref value) => value.emit(buffer),
             Tos(b) | Tc(b) | Shutdown(b) | Protocol(b) => buffer[0] = b,
             SkV6Only(value) => buffer[0] = value.into(),
             MemInfo(ref value) => value.emit(buffer),
             Mark(value) | ClassId(value) => NativeEndian::write_u32(buffer, value),
+            UlpInfo(ref nlas) => nlas.as_slice().emit(buffer),
             Other(ref attr) => attr.emit_value(buffer),
         }
     }
@@ -318,7 +526,8 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             #[cfg(feature = "rich_nlas")]
             INET_DIAG_INFO => {
                 let err = "invalid INET_DIAG_INFO value";
-                let buf = TcpInfoBuffer::new_checked(payload).context(err)?;
+                let payload = zero_extend(payload, TCP_INFO_MIN_LEN, TCP_INFO_LEN).context(err)?;
+                let buf = TcpInfoBuffer::new_checked(&payload[..]).context(err)?;
                 Self::TcpInfo(TcpInfo::parse(&buf).context(err)?)
             }
             #[cfg(not(feature = "rich_nlas"))]
@@ -326,13 +535,29 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             INET_DIAG_CONG => {
                 Self::Congestion(parse_string(payload).context("invalid INET_DIAG_CONG value")?)
             }
+            INET_DIAG_VEGASINFO => {
+                let err = "invalid INET_DIAG_VEGASINFO value";
+                let buf = VegasInfoBuffer::new_checked(payload).context(err)?;
+                Self::Vegas(VegasInfo::parse(&buf).context(err)?)
+            }
+            INET_DIAG_DCTCPINFO => {
+                let err = "invalid INET_DIAG_DCTCPINFO value";
+                let buf = DctcpInfoBuffer::new_checked(payload).context(err)?;
+                Self::Dctcp(DctcpInfo::parse(&buf).context(err)?)
+            }
+            INET_DIAG_BBRINFO => {
+                let err = "invalid INET_DIAG_BBRINFO value";
+                let buf = BbrInfoBuffer::new_checked(payload).context(err)?;
+                Self::Bbr(BbrInfo::parse(&buf).context(err)?)
+            }
             INET_DIAG_TOS => Self::Tos(parse_u8(payload).context("invalid INET_DIAG_TOS value")?),
             INET_DIAG_TCLASS => {
                 Self::Tc(parse_u8(payload).context("invalid INET_DIAG_TCLASS value")?)
             }
             INET_DIAG_SKMEMINFO => {
                 let err = "invalid INET_DIAG_SKMEMINFO value";
-                let buf = MemInfoBuffer::new_checked(payload).context(err)?;
+                let payload = zero_extend(payload, MEM_INFO_MIN_LEN, MEM_INFO_LEN).context(err)?;
+                let buf = MemInfoBuffer::new_checked(&payload[..]).context(err)?;
                 Self::MemInfo(MemInfo::parse(&buf).context(err)?)
             }
             INET_DIAG_SHUTDOWN => {
@@ -350,6 +575,161 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             INET_DIAG_CLASS_ID => {
                 Self::ClassId(parse_u32(payload).context("invalid INET_DIAG_CLASS_ID value")?)
             }
+            INET_DIAG_ULP_INFO => {
+                let err = "invalid INET_DIAG_ULP_INFO value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(UlpInfo::parse(&nla.context(err)?).context(err)?);
+                }
+                Self::UlpInfo(nlas)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
+
+/// Attributes nested in [`Nla::UlpInfo`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum UlpInfo {
+    /// The name of the ULP, such as `tls` or `mptcp`
+    Name(String),
+    /// The kernel TLS state of the socket
+    Tls(Vec<TlsInfo>),
+    /// other attribute
+    Other(DefaultNla),
+}
+
+impl crate::utils::nla::Nla for UlpInfo {
+    fn value_len(&self) -> usize {
+        use self::UlpInfo::*;
+        match *self {
+            // +1 because we need to append a null byte
+            Name(ref s) => s.len() + 1,
+            Tls(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::UlpInfo::*;
+        match *self {
+            Name(_) => INET_ULP_INFO_NAME,
+            Tls(_) => INET_ULP_INFO_TLS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::UlpInfo::*;
+        match *self {
+            Name(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Tls(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for UlpInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            INET_ULP_INFO_NAME => {
+                Self::Name(parse_string(payload).context("invalid INET_ULP_INFO_NAME value")?)
+            }
+            INET_ULP_INFO_TLS => {
+                let err = "invalid INET_ULP_INFO_TLS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(TlsInfo::parse(&nla.context(err)?).context(err)?);
+                }
+                Self::Tls(nlas)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
+
+/// Attributes nested in [`UlpInfo::Tls`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TlsInfo {
+    /// The TLS version, such as [`TLS_1_3_VERSION`]
+    Version(u16),
+    /// The cipher, one of the `TLS_CIPHER_*` constants
+    Cipher(u16),
+    /// How the transmitted records are handled, one of the
+    /// `TLS_CONF_*` constants
+    TxConf(u16),
+    /// How the received records are handled, one of the `TLS_CONF_*`
+    /// constants
+    RxConf(u16),
+    /// `TLS_TX_ZEROCOPY_RO` is enabled
+    ZeroCopyReadOnlyTx,
+    /// `TLS_RX_EXPECT_NO_PAD` is enabled
+    RxNoPad,
+    /// other attribute
+    Other(DefaultNla),
+}
+
+impl crate::utils::nla::Nla for TlsInfo {
+    fn value_len(&self) -> usize {
+        use self::TlsInfo::*;
+        match *self {
+            Version(_) | Cipher(_) | TxConf(_) | RxConf(_) => 2,
+            ZeroCopyReadOnlyTx | RxNoPad => 0,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::TlsInfo::*;
+        match *self {
+            Version(_) => TLS_INFO_VERSION,
+            Cipher(_) => TLS_INFO_CIPHER,
+            TxConf(_) => TLS_INFO_TXCONF,
+            RxConf(_) => TLS_INFO_RXCONF,
+            ZeroCopyReadOnlyTx => TLS_INFO_ZC_RO_TX,
+            RxNoPad => TLS_INFO_RX_NO_PAD,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::TlsInfo::*;
+        match *self {
+            Version(value) | Cipher(value) | TxConf(value) | RxConf(value) => {
+                NativeEndian::write_u16(buffer, value)
+            }
+            ZeroCopyReadOnlyTx | RxNoPad => {}
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TlsInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TLS_INFO_VERSION => {
+                Self::Version(parse_u16(payload).context("invalid TLS_INFO_VERSION value")?)
+            }
+            TLS_INFO_CIPHER => {
+                Self::Cipher(parse_u16(payload).context("invalid TLS_INFO_CIPHER value")?)
+            }
+            TLS_INFO_TXCONF => {
+                Self::TxConf(parse_u16(payload).context("invalid TLS_INFO_TXCONF value")?)
+            }
+            TLS_INFO_RXCONF => {
+                Self::RxConf(parse_u16(payload).context("invalid TLS_INFO_RXCONF value")?)
+            }
+            TLS_INFO_ZC_RO_TX => Self::ZeroCopyReadOnlyTx,
+            TLS_INFO_RX_NO_PAD => Self::RxNoPad,
             kind => {
                 Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
             }
@@ -359,6 +739,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
 
 #[cfg(feature = "rich_nlas")]
 pub const TCP_INFO_LEN: usize = 232;
+/// Length of the `INET_DIAG_INFO` attribute on the oldest kernels,
+/// which stop at `total_retrans`.
+#[cfg(feature = "rich_nlas")]
+pub const TCP_INFO_MIN_LEN: usize = 104;
 
 #[cfg(feature = "rich_nlas")]
 buffer!(TcpInfoBuffer(TCP_INFO_LEN) {
@@ -688,3 +1072,60 @@ impl Emitable for TcpInfo {
         buf.set_snd_wnd(self.snd_wnd);
     }
 }
+
+/// Attributes of [`InetRequest`](crate::inet::InetRequest)s
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RequestNla {
+    /// A filter run by the kernel on each socket, see
+    /// [`InetFilter::to_bytecode`](crate::inet::InetFilter::to_bytecode)
+    Bytecode(Vec<u8>),
+    /// The IP protocol, for protocols that do not fit in the `protocol`
+    /// field of the request, such as `IPPROTO_MPTCP`
+    Protocol(u32),
+    /// other attribute
+    Other(DefaultNla),
+}
+
+impl crate::utils::nla::Nla for RequestNla {
+    fn value_len(&self) -> usize {
+        use self::RequestNla::*;
+        match *self {
+            Bytecode(ref bytes) => bytes.len(),
+            Protocol(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::RequestNla::*;
+        match *self {
+            Bytecode(_) => INET_DIAG_REQ_BYTECODE,
+            Protocol(_) => INET_DIAG_REQ_PROTOCOL,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::RequestNla::*;
+        match *self {
+            Bytecode(ref bytes) => buffer[..bytes.len()].copy_from_slice(&bytes[..]),
+            Protocol(value) => NativeEndian::write_u32(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RequestNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            INET_DIAG_REQ_BYTECODE => Self::Bytecode(payload.to_vec()),
+            INET_DIAG_REQ_PROTOCOL => {
+                Self::Protocol(parse_u32(payload).context("invalid INET_DIAG_REQ_PROTOCOL value")?)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
@@ -4,7 +4,11 @@ use anyhow::Context;
 
 use crate::{
     constants::*,
-    inet::{SocketId, SocketIdBuffer},
+    inet::{
+        nlas::{NlaBuffer, NlasIterator, RequestNla},
+        SocketId,
+        SocketIdBuffer,
+    },
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
@@ -18,6 +22,7 @@ buffer!(InetRequestBuffer(REQUEST_LEN) {
     pad: (u8, 3),
     states: (u32, 4..8),
     socket_id: (slice, 8..56),
+    payload: (slice, REQUEST_LEN..),
 });
 
 /// A request for Ipv4 and Ipv6 sockets
@@ -41,6 +46,9 @@ pub struct InetRequest {
     /// Unlike UNIX domain sockets, IPv4 and IPv6 sockets are
     /// identified using addresses and ports.
     pub socket_id: SocketId,
+    /// Attributes of the request, such as a filter compiled with
+    /// [`InetFilter`](crate::inet::InetFilter).
+    pub nlas: Vec<RequestNla>,
 }
 
 bitflags! {
@@ -105,7 +113,13 @@ bitflags! {
     }
 }
 
-impl<'a, T: AsRef<[u8]> + 'a> Parseable<InetRequestBuffer<&'a T>> for InetRequest {
+impl<'a, T: AsRef<[u8]> + ?Sized> InetRequestBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<InetRequestBuffer<&'a T>> for InetRequest {
     fn parse(buf: &InetRequestBuffer<&'a T>) -> Result<Self, DecodeError> {
         let err = "invalid socket_id value";
         let socket_id = SocketId::parse_with_param(
@@ -114,19 +128,26 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<InetRequestBuffer<&'a T>> for InetReques
         )
         .context(err)?;
 
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            let err = "invalid inet request NLA";
+            nlas.push(RequestNla::parse(&nla_buf.context(err)?).context(err)?);
+        }
+
         Ok(Self {
             family: buf.family(),
             protocol: buf.protocol(),
             extensions: ExtensionFlags::from_bits_truncate(buf.extensions()),
             states: StateFlags::from_bits_truncate(buf.states()),
             socket_id,
+            nlas,
         })
     }
 }
 
 impl Emitable for InetRequest {
     fn buffer_len(&self) -> usize {
-        REQUEST_LEN
+        REQUEST_LEN + self.nlas.as_slice().buffer_len()
     }
 
     fn emit(&self, buf: &mut [u8]) {
@@ -136,6 +157,7 @@ impl Emitable for InetRequest {
         buf.set_extensions(self.extensions.bits());
         buf.set_pad(0);
         buf.set_states(self.states.bits());
-        self.socket_id.emit(buf.socket_id_mut())
+        self.socket_id.emit(buf.socket_id_mut());
+        self.nlas.as_slice().emit(buf.payload_mut());
     }
 }
@@ -1,15 +1,16 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
-    net::{IpAddr, Ipv4Addr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
     time::Duration,
 };
 
 use crate::{
     constants::*,
     inet::{
-        nlas::Nla,
+        nlas::{BbrInfo, DctcpInfo, MemInfo, Nla, NlaBuffer, RequestNla, TlsInfo, UlpInfo},
         ExtensionFlags,
+        InetFilter,
         InetRequest,
         InetRequestBuffer,
         InetResponse,
@@ -19,7 +20,9 @@ use crate::{
         StateFlags,
         Timer,
     },
-    traits::{Emitable, Parseable},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    SockDiagBuffer,
+    SockDiagMessage,
 };
 
 lazy_static! {
@@ -29,6 +32,7 @@ lazy_static! {
         extensions: ExtensionFlags::empty(),
         states: StateFlags::ESTABLISHED,
         socket_id: SocketId::new_v4(),
+        nlas: vec![],
     };
 }
 
@@ -137,3 +141,279 @@ fn emit_tcp_resp() {
     RESP_TCP.emit(&mut buf);
     assert_eq!(&buf[..], &RESP_TCP_BUF[..]);
 }
+
+#[rustfmt::skip]
+#[test]
+fn compile_port_filter() {
+    // ss 'sport = :443'
+    let expected = [
+        0x0b, 0x08, 0x0c, 0x00, // INET_DIAG_BC_S_EQ, yes = 8, no = 12 (reject)
+        0x00, 0x00, 0xbb, 0x01, // port 443
+    ];
+    assert_eq!(InetFilter::SourcePortEq(443).to_bytecode().unwrap(), expected);
+}
+
+#[rustfmt::skip]
+#[test]
+fn compile_and_not_filter() {
+    // ss 'not dport >= :1024 and src 10.0.0.0/8'
+    let filter = (!InetFilter::DestinationPortGe(1024))
+        .and(InetFilter::SourcePrefix(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8));
+    let expected = [
+        0x04, 0x08, 0x0c, 0x00, // INET_DIAG_BC_D_GE, yes = 8, no = 12 (src)
+        0x00, 0x00, 0x00, 0x04, // port 1024
+        0x01, 0x04, 0x18, 0x00, // INET_DIAG_BC_JMP, no = 24 (reject)
+        0x07, 0x10, 0x14, 0x00, // INET_DIAG_BC_S_COND, yes = 16, no = 20 (reject)
+        0x02, 0x08, 0x00, 0x00, // AF_INET, prefix length = 8
+        0xff, 0xff, 0xff, 0xff, // any port
+        0x0a, 0x00, 0x00, 0x00, // 10.0.0.0
+    ];
+    assert_eq!(filter.to_bytecode().unwrap(), expected);
+}
+
+#[rustfmt::skip]
+#[test]
+fn compile_or_filter() {
+    // ss 'dst ::1 or autobound or dev 2'
+    let filter = InetFilter::DestinationPrefix(IpAddr::V6(Ipv6Addr::LOCALHOST), 128)
+        .or(InetFilter::AutoBound.or(InetFilter::Device(2)));
+    let expected = [
+        0x08, 0x1c, 0x20, 0x00, // INET_DIAG_BC_D_COND, yes = 28, no = 32 (autobound)
+        0x0a, 0x80, 0x00, 0x00, // AF_INET6, prefix length = 128
+        0xff, 0xff, 0xff, 0xff, // any port
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, // ::1
+        0x01, 0x04, 0x14, 0x00, // INET_DIAG_BC_JMP, no = 20 (end)
+        0x06, 0x04, 0x08, 0x00, // INET_DIAG_BC_AUTO, yes = 4, no = 8 (dev)
+        0x01, 0x04, 0x0c, 0x00, // INET_DIAG_BC_JMP, no = 12 (end)
+        0x09, 0x08, 0x0c, 0x00, // INET_DIAG_BC_DEV_COND, yes = 8, no = 12 (reject)
+        0x02, 0x00, 0x00, 0x00, // ifindex 2
+    ];
+    assert_eq!(filter.to_bytecode().unwrap(), expected);
+}
+
+#[test]
+fn compile_oversize_filter() {
+    // `cgroup = id` conditions are 12 bytes long and reject the sockets by
+    // jumping 4 bytes past the end of the bytecode, which is too far for the
+    // first condition of 5461 of them
+    fn cgroups(ids: std::ops::Range<u64>) -> InetFilter {
+        if ids.end - ids.start == 1 {
+            InetFilter::Cgroup(ids.start)
+        } else {
+            let middle = ids.start + (ids.end - ids.start) / 2;
+            cgroups(ids.start..middle).and(cgroups(middle..ids.end))
+        }
+    }
+    assert_eq!(cgroups(0..5460).to_bytecode().unwrap().len(), 65520);
+    assert!(cgroups(0..5461).to_bytecode().is_err());
+}
+
+#[test]
+fn emit_and_parse_req_with_bytecode() {
+    let req = InetRequest {
+        nlas: vec![RequestNla::Bytecode(
+            InetFilter::SourcePortEq(443).to_bytecode().unwrap(),
+        )],
+        ..REQ_UDP.clone()
+    };
+    assert_eq!(req.buffer_len(), 56 + 12);
+    let mut buf = vec![0; req.buffer_len()];
+    req.emit(&mut buf);
+    assert_eq!(&buf[..56], &REQ_UDP_BUF[..]);
+    assert_eq!(&buf[56..60], &[0x0c, 0x00, 0x01, 0x00]);
+
+    let parsed = InetRequest::parse(&InetRequestBuffer::new_checked(&&buf[..]).unwrap()).unwrap();
+    assert_eq!(parsed, req);
+}
+
+#[test]
+fn emit_and_parse_destroy_req() {
+    let msg = SockDiagMessage::InetDestroy(REQ_UDP.clone());
+    assert_eq!(msg.message_type(), SOCK_DESTROY);
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    assert_eq!(&buf[..], &REQ_UDP_BUF[..]);
+
+    let buffer = SockDiagBuffer::new_checked(&buf).unwrap();
+    let parsed = SockDiagMessage::parse_with_param(&buffer, SOCK_DESTROY).unwrap();
+    assert_eq!(parsed, msg);
+}
+
+#[rustfmt::skip]
+static ULP_INFO_BUF: [u8; 52] = [
+    0x34, 0x00, // length: 52
+    0x13, 0x00, // type: INET_DIAG_ULP_INFO
+
+    0x08, 0x00, // length: 8
+    0x01, 0x00, // type: INET_ULP_INFO_NAME
+    0x74, 0x6c, 0x73, 0x00, // value: "tls"
+
+    0x28, 0x00, // length: 40
+    0x02, 0x00, // type: INET_ULP_INFO_TLS
+    0x06, 0x00, 0x01, 0x00, 0x04, 0x03, 0x00, 0x00, // TLS_INFO_VERSION: TLS_1_3_VERSION
+    0x06, 0x00, 0x02, 0x00, 0x34, 0x00, 0x00, 0x00, // TLS_INFO_CIPHER: TLS_CIPHER_AES_GCM_256
+    0x06, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, // TLS_INFO_TXCONF: TLS_CONF_SW
+    0x06, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00, // TLS_INFO_RXCONF: TLS_CONF_SW
+    0x04, 0x00, 0x06, 0x00, // TLS_INFO_RX_NO_PAD
+];
+
+#[test]
+fn parse_and_emit_ulp_info() {
+    let nla = Nla::UlpInfo(vec![
+        UlpInfo::Name("tls".to_string()),
+        UlpInfo::Tls(vec![
+            TlsInfo::Version(TLS_1_3_VERSION),
+            TlsInfo::Cipher(TLS_CIPHER_AES_GCM_256),
+            TlsInfo::TxConf(TLS_CONF_SW),
+            TlsInfo::RxConf(TLS_CONF_SW),
+            TlsInfo::RxNoPad,
+        ]),
+    ]);
+    let parsed = Nla::parse(&NlaBuffer::new_checked(&&ULP_INFO_BUF[..]).unwrap()).unwrap();
+    assert_eq!(parsed, nla);
+
+    assert_eq!(nla.buffer_len(), 52);
+    let mut buf = vec![0xff; nla.buffer_len()];
+    nla.emit(&mut buf);
+    assert_eq!(&buf[..], &ULP_INFO_BUF[..]);
+}
+
+#[rustfmt::skip]
+static BBR_INFO_BUF: [u8; 24] = [
+    0x18, 0x00, // length: 24
+    0x10, 0x00, // type: INET_DIAG_BBRINFO
+    0x00, 0xe1, 0xf5, 0x05, // bw_lo
+    0x01, 0x00, 0x00, 0x00, // bw_hi
+    0x10, 0x27, 0x00, 0x00, // min_rtt: 10000us
+    0xb9, 0x02, 0x00, 0x00, // pacing_gain: 697 (~2.72)
+    0x00, 0x02, 0x00, 0x00, // cwnd_gain: 512 (2.0)
+];
+
+#[test]
+fn parse_and_emit_bbr_info() {
+    let nla = Nla::Bbr(BbrInfo {
+        bandwidth: 0x0000_0001_05f5_e100,
+        min_rtt: 10000,
+        pacing_gain: 697,
+        cwnd_gain: 512,
+    });
+    let parsed = Nla::parse(&NlaBuffer::new_checked(&&BBR_INFO_BUF[..]).unwrap()).unwrap();
+    assert_eq!(parsed, nla);
+
+    assert_eq!(nla.buffer_len(), 24);
+    let mut buf = vec![0xff; nla.buffer_len()];
+    nla.emit(&mut buf);
+    assert_eq!(&buf[..], &BBR_INFO_BUF[..]);
+}
+
+#[rustfmt::skip]
+static DCTCP_INFO_BUF: [u8; 20] = [
+    0x14, 0x00, // length: 20
+    0x09, 0x00, // type: INET_DIAG_DCTCPINFO
+    0x01, 0x00, // enabled
+    0x00, 0x00, // ce_state
+    0x00, 0x04, 0x00, 0x00, // alpha: 1024
+    0xa8, 0x05, 0x00, 0x00, // ab_ecn: 1448
+    0x40, 0x1f, 0x00, 0x00, // ab_tot: 8000
+];
+
+#[test]
+fn parse_dctcp_info() {
+    let parsed = Nla::parse(&NlaBuffer::new_checked(&&DCTCP_INFO_BUF[..]).unwrap()).unwrap();
+    let expected = Nla::Dctcp(DctcpInfo {
+        enabled: 1,
+        ce_state: 0,
+        alpha: 1024,
+        ab_ecn: 1448,
+        ab_tot: 8000,
+    });
+    assert_eq!(parsed, expected);
+}
+
+// INET_DIAG_SKMEMINFO as sent by kernels that do not report the
+// backlog queue length and the drops
+#[rustfmt::skip]
+static SHORT_MEM_INFO_BUF: [u8; 32] = [
+    0x20, 0x00, // length: 32
+    0x07, 0x00, // type: INET_DIAG_SKMEMINFO
+    0x01, 0x00, 0x00, 0x00, // rmem_alloc
+    0x00, 0x00, 0x02, 0x00, // rcvbuf
+    0x02, 0x00, 0x00, 0x00, // wmem_alloc
+    0x00, 0x40, 0x00, 0x00, // sndbuf
+    0x03, 0x00, 0x00, 0x00, // fwd_alloc
+    0x04, 0x00, 0x00, 0x00, // wmem_queued
+    0x05, 0x00, 0x00, 0x00, // optmem
+];
+
+#[test]
+fn parse_short_mem_info() {
+    let parsed = Nla::parse(&NlaBuffer::new_checked(&&SHORT_MEM_INFO_BUF[..]).unwrap()).unwrap();
+    let expected = Nla::MemInfo(MemInfo {
+        receive_queue: 1,
+        receive_queue_max: 0x0002_0000,
+        bottom_send_queues: 2,
+        send_queue_max: 0x4000,
+        cache: 3,
+        send_queue: 4,
+        options: 5,
+        backlog_queue_length: 0,
+        drops: 0,
+    });
+    assert_eq!(parsed, expected);
+
+    // anything shorter than the oldest layout is invalid
+    let mut buf = SHORT_MEM_INFO_BUF[..28].to_vec();
+    buf[0] = 28;
+    assert!(Nla::parse(&NlaBuffer::new_checked(&&buf[..]).unwrap()).is_err());
+}
+
+#[cfg(feature = "rich_nlas")]
+#[test]
+fn parse_tcp_info_of_any_length() {
+    use crate::inet::nlas::{TCP_INFO_LEN, TCP_INFO_MIN_LEN};
+
+    fn tcp_info_nla(len: usize) -> Vec<u8> {
+        let mut buf = vec![0; 4 + len];
+        buf[0..2].copy_from_slice(&(4 + len as u16).to_ne_bytes());
+        buf[2..4].copy_from_slice(&INET_DIAG_INFO.to_ne_bytes());
+        // tcpi_state
+        buf[4] = TCP_ESTABLISHED;
+        // tcpi_total_retrans
+        buf[104..108].copy_from_slice(&3u32.to_ne_bytes());
+        if len >= TCP_INFO_LEN {
+            // tcpi_delivery_rate
+            buf[164..172].copy_from_slice(&125_000_000u64.to_ne_bytes());
+            // tcpi_snd_wnd
+            buf[232..236].copy_from_slice(&65535u32.to_ne_bytes());
+        }
+        buf
+    }
+
+    // old kernels send a shorter structure: the missing fields are 0
+    let buf = tcp_info_nla(TCP_INFO_MIN_LEN);
+    let info = match Nla::parse(&NlaBuffer::new_checked(&&buf[..]).unwrap()).unwrap() {
+        Nla::TcpInfo(info) => info,
+        nla => panic!("unexpected nla {:?}", nla),
+    };
+    assert_eq!(info.state, TCP_ESTABLISHED);
+    assert_eq!(info.total_retrans, 3);
+    assert_eq!(info.delivery_rate, 0);
+    assert_eq!(info.snd_wnd, 0);
+
+    // newer kernels send a longer structure: the extra fields are ignored
+    let buf = tcp_info_nla(TCP_INFO_LEN + 16);
+    let info = match Nla::parse(&NlaBuffer::new_checked(&&buf[..]).unwrap()).unwrap() {
+        Nla::TcpInfo(info) => info,
+        nla => panic!("unexpected nla {:?}", nla),
+    };
+    assert_eq!(info.total_retrans, 3);
+    assert_eq!(info.delivery_rate, 125_000_000);
+    assert_eq!(info.snd_wnd, 65535);
+
+    // anything shorter than the oldest layout is invalid
+    let mut buf = tcp_info_nla(TCP_INFO_MIN_LEN);
+    buf.truncate(TCP_INFO_MIN_LEN);
+    buf[0..2].copy_from_slice(&(TCP_INFO_MIN_LEN as u16).to_ne_bytes());
+    assert!(Nla::parse(&NlaBuffer::new_checked(&&buf[..]).unwrap()).is_err());
+}
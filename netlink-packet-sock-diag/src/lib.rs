@@ -6,7 +6,7 @@ extern crate bitflags;
 #[macro_use]
 pub(crate) extern crate netlink_packet_utils as utils;
 pub(crate) use self::utils::parsers;
-pub use self::utils::{traits, DecodeError};
+pub use self::utils::{traits, DecodeError, EncodeError};
 pub use netlink_packet_core::{
     ErrorMessage,
     NetlinkBuffer,
@@ -27,5 +27,8 @@ pub mod buffer;
 pub mod constants;
 pub mod inet;
 pub mod message;
+pub mod netlink;
+pub mod packet;
 pub mod unix;
+pub mod xdp;
 pub use self::{buffer::*, constants::*, message::*};
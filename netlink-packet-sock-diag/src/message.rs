@@ -2,14 +2,18 @@
 
 use crate::{
     inet,
+    netlink,
+    packet,
     traits::{Emitable, ParseableParametrized},
     unix,
+    xdp,
     DecodeError,
     NetlinkDeserializable,
     NetlinkHeader,
     NetlinkPayload,
     NetlinkSerializable,
     SockDiagBuffer,
+    SOCK_DESTROY,
     SOCK_DIAG_BY_FAMILY,
 };
 
@@ -19,6 +23,15 @@ pub enum SockDiagMessage {
     InetResponse(Box<inet::InetResponse>),
     UnixRequest(unix::UnixRequest),
     UnixResponse(Box<unix::UnixResponse>),
+    NetlinkRequest(netlink::NetlinkRequest),
+    NetlinkResponse(Box<netlink::NetlinkResponse>),
+    PacketRequest(packet::PacketRequest),
+    PacketResponse(Box<packet::PacketResponse>),
+    XdpRequest(xdp::XdpRequest),
+    XdpResponse(Box<xdp::XdpResponse>),
+    /// A `SOCK_DESTROY` request, closing the IPv4 or IPv6 socket
+    /// identified by the socket ID of the request
+    InetDestroy(inet::InetRequest),
 }
 
 impl SockDiagMessage {
@@ -37,8 +50,39 @@ impl SockDiagMessage {
         matches!(self, SockDiagMessage::UnixResponse(_))
     }
 
+    pub fn is_netlink_request(&self) -> bool {
+        matches!(self, SockDiagMessage::NetlinkRequest(_))
+    }
+
+    pub fn is_netlink_response(&self) -> bool {
+        matches!(self, SockDiagMessage::NetlinkResponse(_))
+    }
+
+    pub fn is_packet_request(&self) -> bool {
+        matches!(self, SockDiagMessage::PacketRequest(_))
+    }
+
+    pub fn is_packet_response(&self) -> bool {
+        matches!(self, SockDiagMessage::PacketResponse(_))
+    }
+
+    pub fn is_xdp_request(&self) -> bool {
+        matches!(self, SockDiagMessage::XdpRequest(_))
+    }
+
+    pub fn is_xdp_response(&self) -> bool {
+        matches!(self, SockDiagMessage::XdpResponse(_))
+    }
+
+    pub fn is_inet_destroy(&self) -> bool {
+        matches!(self, SockDiagMessage::InetDestroy(_))
+    }
+
     pub fn message_type(&self) -> u16 {
-        SOCK_DIAG_BY_FAMILY
+        match self {
+            SockDiagMessage::InetDestroy(_) => SOCK_DESTROY,
+            _ => SOCK_DIAG_BY_FAMILY,
+        }
     }
 }
 
@@ -51,6 +95,13 @@ impl Emitable for SockDiagMessage {
             InetResponse(ref msg) => msg.buffer_len(),
             UnixRequest(ref msg) => msg.buffer_len(),
             UnixResponse(ref msg) => msg.buffer_len(),
+            NetlinkRequest(ref msg) => msg.buffer_len(),
+            NetlinkResponse(ref msg) => msg.buffer_len(),
+            PacketRequest(ref msg) => msg.buffer_len(),
+            PacketResponse(ref msg) => msg.buffer_len(),
+            XdpRequest(ref msg) => msg.buffer_len(),
+            XdpResponse(ref msg) => msg.buffer_len(),
+            InetDestroy(ref msg) => msg.buffer_len(),
         }
     }
 
@@ -62,6 +113,13 @@ impl Emitable for SockDiagMessage {
             InetResponse(ref msg) => msg.emit(buffer),
             UnixRequest(ref msg) => msg.emit(buffer),
             UnixResponse(ref msg) => msg.emit(buffer),
+            NetlinkRequest(ref msg) => msg.emit(buffer),
+            NetlinkResponse(ref msg) => msg.emit(buffer),
+            PacketRequest(ref msg) => msg.emit(buffer),
+            PacketResponse(ref msg) => msg.emit(buffer),
+            XdpRequest(ref msg) => msg.emit(buffer),
+            XdpResponse(ref msg) => msg.emit(buffer),
+            InetDestroy(ref msg) => msg.emit(buffer),
         }
     }
 }
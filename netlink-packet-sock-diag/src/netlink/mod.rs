@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+
+mod request;
+pub use self::request::*;
+
+mod response;
+pub use self::response::*;
+
+pub mod nlas;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use std::{convert::TryInto, mem::size_of};
+
+pub use crate::utils::nla::{DefaultNla, NlaBuffer, NlasIterator};
+
+use crate::{
+    constants::*,
+    inet::nlas::{zero_extend, MemInfo, MemInfoBuffer, MEM_INFO_LEN, MEM_INFO_MIN_LEN},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Nla {
+    /// Socket memory information. This attribute is known as
+    /// `NETLINK_DIAG_MEMINFO` in the kernel.
+    MemInfo(MemInfo),
+    /// Multicast groups the socket is subscribed to. This attribute
+    /// is known as `NETLINK_DIAG_GROUPS` in the kernel, which reports
+    /// them as a bitmap.
+    Groups(Vec<u32>),
+    /// Flags of the socket, a combination of the `NDIAG_FLAG_*`
+    /// constants. This attribute is known as `NETLINK_DIAG_FLAGS` in
+    /// the kernel.
+    Flags(u32),
+    /// Unknown attribute
+    Other(DefaultNla),
+}
+
+// The kernel reports the groups as an array of `unsigned long`, padded
+// to a multiple of 64 bits. Bit `n` is set if the socket is subscribed
+// to group `n + 1`.
+const GROUPS_WORD_LEN: usize = size_of::<usize>();
+
+fn groups_len(groups: &[u32]) -> usize {
+    let max = groups.iter().copied().max().unwrap_or(0) as usize;
+    max.div_ceil(64) * 8
+}
+
+fn parse_groups(payload: &[u8]) -> Result<Vec<u32>, DecodeError> {
+    let words = payload.chunks_exact(GROUPS_WORD_LEN);
+    if !words.remainder().is_empty() {
+        return Err(DecodeError::from("invalid NETLINK_DIAG_GROUPS"));
+    }
+    let mut groups = vec![];
+    for (i, word) in words.enumerate() {
+        // Unwrapping is safe because the chunks are exactly
+        // GROUPS_WORD_LEN bytes long.
+        let word = usize::from_ne_bytes(word.try_into().unwrap());
+        for bit in 0..GROUPS_WORD_LEN * 8 {
+            if word & (1 << bit) != 0 {
+                groups.push((i * GROUPS_WORD_LEN * 8 + bit + 1) as u32);
+            }
+        }
+    }
+    Ok(groups)
+}
+
+fn emit_groups(groups: &[u32], buffer: &mut [u8]) {
+    let mut words = vec![0usize; groups_len(groups) / GROUPS_WORD_LEN];
+    for group in groups.iter().filter(|group| **group > 0) {
+        let bit = *group as usize - 1;
+        words[bit / (GROUPS_WORD_LEN * 8)] |= 1 << (bit % (GROUPS_WORD_LEN * 8));
+    }
+    for (i, word) in words.iter().enumerate() {
+        buffer[i * GROUPS_WORD_LEN..(i + 1) * GROUPS_WORD_LEN].copy_from_slice(&word.to_ne_bytes());
+    }
+}
+
+impl crate::utils::nla::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            MemInfo(_) => MEM_INFO_LEN,
+            Groups(ref groups) => groups_len(groups),
+            Flags(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            MemInfo(ref value) => value.emit(buffer),
+            Groups(ref groups) => emit_groups(groups, buffer),
+            Flags(value) => NativeEndian::write_u32(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            MemInfo(_) => NETLINK_DIAG_MEMINFO,
+            Groups(_) => NETLINK_DIAG_GROUPS,
+            Flags(_) => NETLINK_DIAG_FLAGS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NETLINK_DIAG_MEMINFO => {
+                let err = "invalid NETLINK_DIAG_MEMINFO value";
+                let payload = zero_extend(payload, MEM_INFO_MIN_LEN, MEM_INFO_LEN).context(err)?;
+                let buf = MemInfoBuffer::new_checked(&payload[..]).context(err)?;
+                Self::MemInfo(MemInfo::parse(&buf).context(err)?)
+            }
+            NETLINK_DIAG_GROUPS => Self::Groups(parse_groups(payload)?),
+            NETLINK_DIAG_FLAGS => {
+                Self::Flags(parse_u32(payload).context("invalid NETLINK_DIAG_FLAGS value")?)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const NETLINK_REQUEST_LEN: usize = 20;
+
+buffer!(NetlinkRequestBuffer(NETLINK_REQUEST_LEN) {
+    // The address family; it should be set to `AF_NETLINK`
+    family: (u8, 0),
+    // The netlink protocol of the sockets to dump, or `NDIAG_PROTO_ALL`
+    protocol: (u8, 1),
+    // This field should be set to `0`
+    pad: (u16, 2..4),
+    // Ignored by the kernel, which only supports dump requests
+    inode: (u32, 4..8),
+    // This is a set of flags defining what kind of information to
+    // report. Supported values are the `NDIAG_SHOW_*` constants.
+    show_flags: (u32, 8..12),
+    // Ignored by the kernel, which only supports dump requests
+    cookie: (slice, 12..NETLINK_REQUEST_LEN),
+});
+
+/// The request for netlink sockets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NetlinkRequest {
+    /// The netlink protocol of the sockets to report, such as
+    /// `NETLINK_ROUTE`, or [`NDIAG_PROTO_ALL`] to report the sockets
+    /// of all the protocols.
+    pub protocol: u8,
+    /// This is an inode number when querying for an individual socket.
+    ///
+    /// The kernel only supports dump requests and ignores it.
+    pub inode: u32,
+    /// This is a set of flags defining what kind of information to report.
+    ///
+    /// Each requested kind of information is reported back as a netlink attribute
+    pub show_flags: ShowFlags,
+    /// This is an opaque identifiers that could be used to specify an individual socket.
+    pub cookie: [u8; 8],
+}
+
+bitflags! {
+    /// Bitmask that defines what kind of information to
+    /// report. Supported values are the `NDIAG_SHOW_*` constants.
+    pub struct ShowFlags: u32 {
+        const MEMINFO = NDIAG_SHOW_MEMINFO;
+        const GROUPS = NDIAG_SHOW_GROUPS;
+        const RING_CFG = NDIAG_SHOW_RING_CFG;
+        const FLAGS = NDIAG_SHOW_FLAGS;
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NetlinkRequestBuffer<&'a T>> for NetlinkRequest {
+    fn parse(buf: &NetlinkRequestBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            protocol: buf.protocol(),
+            inode: buf.inode(),
+            show_flags: ShowFlags::from_bits_truncate(buf.show_flags()),
+            // Unwrapping is safe because NetlinkRequestBuffer::cookie()
+            // returns a slice of exactly 8 bytes.
+            cookie: TryFrom::try_from(buf.cookie()).unwrap(),
+        })
+    }
+}
+
+impl Emitable for NetlinkRequest {
+    fn buffer_len(&self) -> usize {
+        NETLINK_REQUEST_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buffer = NetlinkRequestBuffer::new(buf);
+        buffer.set_family(AF_NETLINK);
+        buffer.set_protocol(self.protocol);
+        buffer.set_pad(0);
+        buffer.set_inode(self.inode);
+        buffer.set_show_flags(self.show_flags.bits());
+        buffer.cookie_mut().copy_from_slice(&self.cookie[..]);
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use smallvec::SmallVec;
+use std::convert::TryFrom;
+
+use crate::{
+    constants::*,
+    netlink::nlas::{Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const NETLINK_RESPONSE_HEADER_LEN: usize = 28;
+
+buffer!(NetlinkResponseBuffer(NETLINK_RESPONSE_HEADER_LEN) {
+    family: (u8, 0),
+    kind: (u8, 1),
+    protocol: (u8, 2),
+    state: (u8, 3),
+    port_id: (u32, 4..8),
+    destination_port_id: (u32, 8..12),
+    destination_group: (u32, 12..16),
+    inode: (u32, 16..20),
+    cookie: (slice, 20..NETLINK_RESPONSE_HEADER_LEN),
+    payload: (slice, NETLINK_RESPONSE_HEADER_LEN..),
+});
+
+/// The response to a query for netlink sockets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NetlinkResponseHeader {
+    /// Either `SOCK_RAW` or `SOCK_DGRAM`
+    pub kind: u8,
+    /// The netlink protocol of the socket, such as `NETLINK_ROUTE`
+    pub protocol: u8,
+    /// `TCP_ESTABLISHED` if the socket is connected, `TCP_CLOSE`
+    /// otherwise
+    pub state: u8,
+    /// Port ID of the socket
+    pub port_id: u32,
+    /// Port ID the socket is connected to
+    pub destination_port_id: u32,
+    /// Multicast group the socket is connected to
+    pub destination_group: u32,
+    /// Socket inode number.
+    pub inode: u32,
+    pub cookie: [u8; 8],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NetlinkResponseBuffer<&'a T>>
+    for NetlinkResponseHeader
+{
+    fn parse(buf: &NetlinkResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            kind: buf.kind(),
+            protocol: buf.protocol(),
+            state: buf.state(),
+            port_id: buf.port_id(),
+            destination_port_id: buf.destination_port_id(),
+            destination_group: buf.destination_group(),
+            inode: buf.inode(),
+            // Unwrapping is safe because NetlinkResponseBuffer::cookie()
+            // returns a slice of exactly 8 bytes.
+            cookie: TryFrom::try_from(buf.cookie()).unwrap(),
+        })
+    }
+}
+
+impl Emitable for NetlinkResponseHeader {
+    fn buffer_len(&self) -> usize {
+        NETLINK_RESPONSE_HEADER_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = NetlinkResponseBuffer::new(buf);
+        buf.set_family(AF_NETLINK);
+        buf.set_kind(self.kind);
+        buf.set_protocol(self.protocol);
+        buf.set_state(self.state);
+        buf.set_port_id(self.port_id);
+        buf.set_destination_port_id(self.destination_port_id);
+        buf.set_destination_group(self.destination_group);
+        buf.set_inode(self.inode);
+        buf.cookie_mut().copy_from_slice(&self.cookie[..]);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NetlinkResponse {
+    pub header: NetlinkResponseHeader,
+    pub nlas: SmallVec<[Nla; 8]>,
+}
+
+impl NetlinkResponse {
+    pub fn groups(&self) -> Option<&[u32]> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Groups(groups) = nla {
+                Some(&groups[..])
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn flags(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Flags(flags) = nla {
+                Some(*flags)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NetlinkResponseBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NetlinkResponseBuffer<&'a T>> for SmallVec<[Nla; 8]> {
+    fn parse(buf: &NetlinkResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = smallvec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NetlinkResponseBuffer<&'a T>> for NetlinkResponse {
+    fn parse(buf: &NetlinkResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header =
+            NetlinkResponseHeader::parse(buf).context("failed to parse netlink response header")?;
+        let nlas =
+            SmallVec::<[Nla; 8]>::parse(buf).context("failed to parse netlink response NLAs")?;
+        Ok(NetlinkResponse { header, nlas })
+    }
+}
+
+impl Emitable for NetlinkResponse {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
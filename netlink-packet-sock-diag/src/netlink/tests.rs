@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    netlink::{
+        nlas::Nla,
+        NetlinkRequest,
+        NetlinkResponse,
+        NetlinkResponseBuffer,
+        NetlinkResponseHeader,
+        ShowFlags,
+    },
+    traits::{Emitable, Parseable},
+};
+
+lazy_static! {
+    static ref DUMP_ALL: NetlinkRequest = NetlinkRequest {
+        protocol: NDIAG_PROTO_ALL,
+        inode: 0,
+        show_flags: ShowFlags::GROUPS | ShowFlags::FLAGS,
+        cookie: [0xff; 8],
+    };
+}
+
+#[rustfmt::skip]
+static DUMP_ALL_BUF: [u8; 20] = [
+    0x10, // family: AF_NETLINK
+    0xff, // protocol: NDIAG_PROTO_ALL
+    0x00, 0x00, // padding
+    0x00, 0x00, 0x00, 0x00, // inode
+    0x0a, 0x00, 0x00, 0x00, // show_flags: NDIAG_SHOW_GROUPS | NDIAG_SHOW_FLAGS
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // cookie
+];
+
+#[test]
+fn emit_dump_all() {
+    assert_eq!(DUMP_ALL.buffer_len(), 20);
+    let mut buf = vec![0xff; DUMP_ALL.buffer_len()];
+    DUMP_ALL.emit(&mut buf);
+    assert_eq!(&buf[..], &DUMP_ALL_BUF[..]);
+}
+
+lazy_static! {
+    static ref RTNETLINK: NetlinkResponse = NetlinkResponse {
+        header: NetlinkResponseHeader {
+            kind: SOCK_RAW,
+            protocol: 0,
+            state: TCP_CLOSE,
+            port_id: 0x1234,
+            destination_port_id: 0,
+            destination_group: 0,
+            inode: 31927,
+            cookie: [0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        },
+        nlas: smallvec![Nla::Groups(vec![1, 3]), Nla::Flags(NDIAG_FLAG_CAP_ACK)],
+    };
+}
+
+#[rustfmt::skip]
+static RTNETLINK_BUF: [u8; 48] = [
+    0x10, // family: AF_NETLINK
+    0x03, // type: SOCK_RAW
+    0x00, // protocol: NETLINK_ROUTE
+    0x07, // state: TCP_CLOSE
+    0x34, 0x12, 0x00, 0x00, // port ID
+    0x00, 0x00, 0x00, 0x00, // destination port ID
+    0x00, 0x00, 0x00, 0x00, // destination group
+    0xb7, 0x7c, 0x00, 0x00, // inode: 31927
+    0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // cookie
+
+    // NLAs
+    0x0c, 0x00, // length: 12
+    0x01, 0x00, // type: NETLINK_DIAG_GROUPS
+    // value: groups 1 and 3
+    0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+    0x08, 0x00, // length: 8
+    0x04, 0x00, // type: NETLINK_DIAG_FLAGS
+    0x20, 0x00, 0x00, 0x00, // value: NDIAG_FLAG_CAP_ACK
+];
+
+#[test]
+fn parse_rtnetlink() {
+    let parsed =
+        NetlinkResponse::parse(&NetlinkResponseBuffer::new_checked(&&RTNETLINK_BUF[..]).unwrap())
+            .unwrap();
+    assert_eq!(parsed, *RTNETLINK);
+    assert_eq!(parsed.groups(), Some(&[1, 3][..]));
+}
+
+#[test]
+fn emit_rtnetlink() {
+    assert_eq!(RTNETLINK.buffer_len(), 48);
+    let mut buf = vec![0xff; RTNETLINK.buffer_len()];
+    RTNETLINK.emit(&mut buf);
+    assert_eq!(&buf[..], &RTNETLINK_BUF[..]);
+}
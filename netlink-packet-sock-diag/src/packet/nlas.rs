@@ -0,0 +1,383 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+pub use crate::utils::nla::{DefaultNla, NlaBuffer, NlasIterator};
+
+use crate::{
+    constants::*,
+    inet::nlas::{zero_extend, MemInfo, MemInfoBuffer, MEM_INFO_LEN, MEM_INFO_MIN_LEN},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Nla {
+    /// Basic information about the socket. This attribute is known
+    /// as `PACKET_DIAG_INFO` in the kernel.
+    Info(PacketInfo),
+    /// Multicast memberships of the socket, added with
+    /// `PACKET_ADD_MEMBERSHIP`. This attribute is known as
+    /// `PACKET_DIAG_MCLIST` in the kernel.
+    MulticastList(Vec<MulticastMembership>),
+    /// Configuration of the memory mapped receive ring. This attribute
+    /// is known as `PACKET_DIAG_RX_RING` in the kernel.
+    RxRing(RingConfig),
+    /// Configuration of the memory mapped transmit ring. This
+    /// attribute is known as `PACKET_DIAG_TX_RING` in the kernel.
+    TxRing(RingConfig),
+    /// Fanout group of the socket: the group ID is in the lower 16
+    /// bits, and the mode and flags given to `PACKET_FANOUT` (such as
+    /// `PACKET_FANOUT_HASH`) in the upper 16 bits. This attribute is
+    /// known as `PACKET_DIAG_FANOUT` in the kernel.
+    Fanout(u32),
+    /// User ID of the owner of the socket. This attribute is known as
+    /// `PACKET_DIAG_UID` in the kernel.
+    Uid(u32),
+    /// Socket memory information. This attribute is known as
+    /// `PACKET_DIAG_MEMINFO` in the kernel.
+    MemInfo(MemInfo),
+    /// Classic BPF program attached to the socket with
+    /// `SO_ATTACH_FILTER`. This attribute is known as
+    /// `PACKET_DIAG_FILTER` in the kernel.
+    Filter(Vec<FilterInstruction>),
+    /// Unknown attribute
+    Other(DefaultNla),
+}
+
+pub const PACKET_INFO_LEN: usize = 24;
+
+buffer!(PacketInfoBuffer(PACKET_INFO_LEN) {
+    ifindex: (u32, 0..4),
+    version: (u32, 4..8),
+    reserve: (u32, 8..12),
+    copy_threshold: (u32, 12..16),
+    timestamp: (u32, 16..20),
+    flags: (u32, 20..24),
+});
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PacketInfo {
+    /// Index of the interface the socket is bound to, or `0`
+    pub ifindex: u32,
+    /// Version of the memory mapped rings, as set by `PACKET_VERSION`
+    /// (`0` for `TPACKET_V1`)
+    pub version: u32,
+    /// Headroom reserved in the frames of the rings, as set by
+    /// `PACKET_RESERVE`
+    pub reserve: u32,
+    /// Value of `PACKET_COPY_THRESH`
+    pub copy_threshold: u32,
+    /// Timestamp source of the rings, as set by `PACKET_TIMESTAMP`
+    pub timestamp: u32,
+    /// A combination of the `PDI_*` constants
+    pub flags: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<PacketInfoBuffer<T>> for PacketInfo {
+    fn parse(buf: &PacketInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            version: buf.version(),
+            reserve: buf.reserve(),
+            copy_threshold: buf.copy_threshold(),
+            timestamp: buf.timestamp(),
+            flags: buf.flags(),
+        })
+    }
+}
+
+impl Emitable for PacketInfo {
+    fn buffer_len(&self) -> usize {
+        PACKET_INFO_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = PacketInfoBuffer::new(buf);
+        buf.set_ifindex(self.ifindex);
+        buf.set_version(self.version);
+        buf.set_reserve(self.reserve);
+        buf.set_copy_threshold(self.copy_threshold);
+        buf.set_timestamp(self.timestamp);
+        buf.set_flags(self.flags);
+    }
+}
+
+pub const MULTICAST_MEMBERSHIP_LEN: usize = 44;
+const MAX_ADDR_LEN: usize = 32;
+
+buffer!(MulticastMembershipBuffer(MULTICAST_MEMBERSHIP_LEN) {
+    ifindex: (u32, 0..4),
+    count: (u32, 4..8),
+    kind: (u16, 8..10),
+    address_len: (u16, 10..12),
+    address: (slice, 12..MULTICAST_MEMBERSHIP_LEN),
+});
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MulticastMembership {
+    /// Index of the interface
+    pub ifindex: u32,
+    /// Number of times the membership was added
+    pub count: u32,
+    /// One of `PACKET_MR_MULTICAST`, `PACKET_MR_PROMISC`,
+    /// `PACKET_MR_ALLMULTI` or `PACKET_MR_UNICAST`
+    pub kind: u16,
+    /// Link layer address of the membership, if any
+    pub address: Vec<u8>,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MulticastMembershipBuffer<&'a T>>
+    for MulticastMembership
+{
+    fn parse(buf: &MulticastMembershipBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let len = buf.address_len() as usize;
+        if len > MAX_ADDR_LEN {
+            return Err(format!("invalid address length {}", len).into());
+        }
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            count: buf.count(),
+            kind: buf.kind(),
+            address: buf.address()[..len].to_vec(),
+        })
+    }
+}
+
+impl Emitable for MulticastMembership {
+    fn buffer_len(&self) -> usize {
+        MULTICAST_MEMBERSHIP_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = MulticastMembershipBuffer::new(buf);
+        buf.set_ifindex(self.ifindex);
+        buf.set_count(self.count);
+        buf.set_kind(self.kind);
+        buf.set_address_len(self.address.len() as u16);
+        let address = buf.address_mut();
+        address.iter_mut().for_each(|byte| *byte = 0);
+        address[..self.address.len()].copy_from_slice(&self.address[..]);
+    }
+}
+
+pub const RING_CONFIG_LEN: usize = 28;
+
+buffer!(RingConfigBuffer(RING_CONFIG_LEN) {
+    block_size: (u32, 0..4),
+    block_count: (u32, 4..8),
+    frame_size: (u32, 8..12),
+    frame_count: (u32, 12..16),
+    retire_timeout: (u32, 16..20),
+    private_size: (u32, 20..24),
+    features: (u32, 24..28),
+});
+
+/// Configuration of a memory mapped ring, as set by `PACKET_RX_RING`
+/// or `PACKET_TX_RING`
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RingConfig {
+    pub block_size: u32,
+    pub block_count: u32,
+    pub frame_size: u32,
+    pub frame_count: u32,
+    /// Timeout in milliseconds after which a block is retired
+    /// (`TPACKET_V3` only)
+    pub retire_timeout: u32,
+    /// Size of the private area of the blocks (`TPACKET_V3` only)
+    pub private_size: u32,
+    /// Features of the ring, such as `TP_FT_REQ_FILL_RXHASH`
+    /// (`TPACKET_V3` only)
+    pub features: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<RingConfigBuffer<T>> for RingConfig {
+    fn parse(buf: &RingConfigBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            block_size: buf.block_size(),
+            block_count: buf.block_count(),
+            frame_size: buf.frame_size(),
+            frame_count: buf.frame_count(),
+            retire_timeout: buf.retire_timeout(),
+            private_size: buf.private_size(),
+            features: buf.features(),
+        })
+    }
+}
+
+impl Emitable for RingConfig {
+    fn buffer_len(&self) -> usize {
+        RING_CONFIG_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = RingConfigBuffer::new(buf);
+        buf.set_block_size(self.block_size);
+        buf.set_block_count(self.block_count);
+        buf.set_frame_size(self.frame_size);
+        buf.set_frame_count(self.frame_count);
+        buf.set_retire_timeout(self.retire_timeout);
+        buf.set_private_size(self.private_size);
+        buf.set_features(self.features);
+    }
+}
+
+pub const FILTER_INSTRUCTION_LEN: usize = 8;
+
+buffer!(FilterInstructionBuffer(FILTER_INSTRUCTION_LEN) {
+    code: (u16, 0..2),
+    jump_true: (u8, 2),
+    jump_false: (u8, 3),
+    k: (u32, 4..8),
+});
+
+/// An instruction of a classic BPF program (`struct sock_filter`)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FilterInstruction {
+    pub code: u16,
+    pub jump_true: u8,
+    pub jump_false: u8,
+    pub k: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<FilterInstructionBuffer<T>> for FilterInstruction {
+    fn parse(buf: &FilterInstructionBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            code: buf.code(),
+            jump_true: buf.jump_true(),
+            jump_false: buf.jump_false(),
+            k: buf.k(),
+        })
+    }
+}
+
+impl Emitable for FilterInstruction {
+    fn buffer_len(&self) -> usize {
+        FILTER_INSTRUCTION_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = FilterInstructionBuffer::new(buf);
+        buf.set_code(self.code);
+        buf.set_jump_true(self.jump_true);
+        buf.set_jump_false(self.jump_false);
+        buf.set_k(self.k);
+    }
+}
+
+impl crate::utils::nla::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Info(_) => PACKET_INFO_LEN,
+            MulticastList(ref memberships) => memberships.len() * MULTICAST_MEMBERSHIP_LEN,
+            RxRing(_) | TxRing(_) => RING_CONFIG_LEN,
+            Fanout(_) | Uid(_) => 4,
+            MemInfo(_) => MEM_INFO_LEN,
+            Filter(ref instructions) => instructions.len() * FILTER_INSTRUCTION_LEN,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Info(ref value) => value.emit(buffer),
+            MulticastList(ref memberships) => {
+                for (i, membership) in memberships.iter().enumerate() {
+                    membership.emit(&mut buffer[i * MULTICAST_MEMBERSHIP_LEN..]);
+                }
+            }
+            RxRing(ref value) | TxRing(ref value) => value.emit(buffer),
+            Fanout(value) | Uid(value) => NativeEndian::write_u32(buffer, value),
+            MemInfo(ref value) => value.emit(buffer),
+            Filter(ref instructions) => {
+                for (i, instruction) in instructions.iter().enumerate() {
+                    instruction.emit(&mut buffer[i * FILTER_INSTRUCTION_LEN..]);
+                }
+            }
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Info(_) => PACKET_DIAG_INFO,
+            MulticastList(_) => PACKET_DIAG_MCLIST,
+            RxRing(_) => PACKET_DIAG_RX_RING,
+            TxRing(_) => PACKET_DIAG_TX_RING,
+            Fanout(_) => PACKET_DIAG_FANOUT,
+            Uid(_) => PACKET_DIAG_UID,
+            MemInfo(_) => PACKET_DIAG_MEMINFO,
+            Filter(_) => PACKET_DIAG_FILTER,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            PACKET_DIAG_INFO => {
+                let err = "invalid PACKET_DIAG_INFO value";
+                let buf = PacketInfoBuffer::new_checked(payload).context(err)?;
+                Self::Info(PacketInfo::parse(&buf).context(err)?)
+            }
+            PACKET_DIAG_MCLIST => {
+                let chunks = payload.chunks_exact(MULTICAST_MEMBERSHIP_LEN);
+                if !chunks.remainder().is_empty() {
+                    return Err(DecodeError::from("invalid PACKET_DIAG_MCLIST"));
+                }
+                let mut memberships = vec![];
+                for chunk in chunks {
+                    let buf = MulticastMembershipBuffer::new(chunk);
+                    memberships.push(
+                        MulticastMembership::parse(&buf).context("invalid PACKET_DIAG_MCLIST")?,
+                    );
+                }
+                Self::MulticastList(memberships)
+            }
+            PACKET_DIAG_RX_RING => {
+                let err = "invalid PACKET_DIAG_RX_RING value";
+                let buf = RingConfigBuffer::new_checked(payload).context(err)?;
+                Self::RxRing(RingConfig::parse(&buf).context(err)?)
+            }
+            PACKET_DIAG_TX_RING => {
+                let err = "invalid PACKET_DIAG_TX_RING value";
+                let buf = RingConfigBuffer::new_checked(payload).context(err)?;
+                Self::TxRing(RingConfig::parse(&buf).context(err)?)
+            }
+            PACKET_DIAG_FANOUT => {
+                Self::Fanout(parse_u32(payload).context("invalid PACKET_DIAG_FANOUT value")?)
+            }
+            PACKET_DIAG_UID => {
+                Self::Uid(parse_u32(payload).context("invalid PACKET_DIAG_UID value")?)
+            }
+            PACKET_DIAG_MEMINFO => {
+                let err = "invalid PACKET_DIAG_MEMINFO value";
+                let payload = zero_extend(payload, MEM_INFO_MIN_LEN, MEM_INFO_LEN).context(err)?;
+                let buf = MemInfoBuffer::new_checked(&payload[..]).context(err)?;
+                Self::MemInfo(MemInfo::parse(&buf).context(err)?)
+            }
+            PACKET_DIAG_FILTER => {
+                let chunks = payload.chunks_exact(FILTER_INSTRUCTION_LEN);
+                if !chunks.remainder().is_empty() {
+                    return Err(DecodeError::from("invalid PACKET_DIAG_FILTER"));
+                }
+                Self::Filter(
+                    chunks
+                        .map(|chunk| FilterInstruction::parse(&FilterInstructionBuffer::new(chunk)))
+                        .collect::<Result<Vec<_>, _>>()
+                        .context("invalid PACKET_DIAG_FILTER")?,
+                )
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
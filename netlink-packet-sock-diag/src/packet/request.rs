@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const PACKET_REQUEST_LEN: usize = 20;
+
+buffer!(PacketRequestBuffer(PACKET_REQUEST_LEN) {
+    // The address family; it should be set to `AF_PACKET`
+    family: (u8, 0),
+    // This field should be set to `0`
+    protocol: (u8, 1),
+    // This field should be set to `0`
+    pad: (u16, 2..4),
+    // Ignored by the kernel, which only supports dump requests
+    inode: (u32, 4..8),
+    // This is a set of flags defining what kind of information to
+    // report. Supported values are the `PACKET_SHOW_*` constants.
+    show_flags: (u32, 8..12),
+    // Ignored by the kernel, which only supports dump requests
+    cookie: (slice, 12..PACKET_REQUEST_LEN),
+});
+
+/// The request for packet (`AF_PACKET`) sockets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PacketRequest {
+    /// This is an inode number when querying for an individual socket.
+    ///
+    /// The kernel only supports dump requests and ignores it.
+    pub inode: u32,
+    /// This is a set of flags defining what kind of information to report.
+    ///
+    /// Each requested kind of information is reported back as a netlink attribute
+    pub show_flags: ShowFlags,
+    /// This is an opaque identifiers that could be used to specify an individual socket.
+    pub cookie: [u8; 8],
+}
+
+bitflags! {
+    /// Bitmask that defines what kind of information to
+    /// report. Supported values are the `PACKET_SHOW_*` constants.
+    pub struct ShowFlags: u32 {
+        const INFO = PACKET_SHOW_INFO;
+        const MCLIST = PACKET_SHOW_MCLIST;
+        const RING_CFG = PACKET_SHOW_RING_CFG;
+        const FANOUT = PACKET_SHOW_FANOUT;
+        const MEMINFO = PACKET_SHOW_MEMINFO;
+        const FILTER = PACKET_SHOW_FILTER;
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<PacketRequestBuffer<&'a T>> for PacketRequest {
+    fn parse(buf: &PacketRequestBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            inode: buf.inode(),
+            show_flags: ShowFlags::from_bits_truncate(buf.show_flags()),
+            // Unwrapping is safe because PacketRequestBuffer::cookie()
+            // returns a slice of exactly 8 bytes.
+            cookie: TryFrom::try_from(buf.cookie()).unwrap(),
+        })
+    }
+}
+
+impl Emitable for PacketRequest {
+    fn buffer_len(&self) -> usize {
+        PACKET_REQUEST_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buffer = PacketRequestBuffer::new(buf);
+        buffer.set_family(AF_PACKET);
+        buffer.set_protocol(0);
+        buffer.set_pad(0);
+        buffer.set_inode(self.inode);
+        buffer.set_show_flags(self.show_flags.bits());
+        buffer.cookie_mut().copy_from_slice(&self.cookie[..]);
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use smallvec::SmallVec;
+use std::convert::TryFrom;
+
+use crate::{
+    constants::*,
+    packet::nlas::{Nla, NlaBuffer, NlasIterator, PacketInfo},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const PACKET_RESPONSE_HEADER_LEN: usize = 16;
+
+buffer!(PacketResponseBuffer(PACKET_RESPONSE_HEADER_LEN) {
+    family: (u8, 0),
+    kind: (u8, 1),
+    protocol: (u16, 2..4),
+    inode: (u32, 4..8),
+    cookie: (slice, 8..PACKET_RESPONSE_HEADER_LEN),
+    payload: (slice, PACKET_RESPONSE_HEADER_LEN..),
+});
+
+/// The response to a query for packet sockets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PacketResponseHeader {
+    /// Either `SOCK_RAW` or `SOCK_DGRAM`
+    pub kind: u8,
+    /// The ethernet protocol the socket receives, such as `ETH_P_ALL`
+    /// (in host byte order)
+    pub protocol: u16,
+    /// Socket inode number.
+    pub inode: u32,
+    pub cookie: [u8; 8],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<PacketResponseBuffer<&'a T>> for PacketResponseHeader {
+    fn parse(buf: &PacketResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            kind: buf.kind(),
+            protocol: buf.protocol(),
+            inode: buf.inode(),
+            // Unwrapping is safe because PacketResponseBuffer::cookie()
+            // returns a slice of exactly 8 bytes.
+            cookie: TryFrom::try_from(buf.cookie()).unwrap(),
+        })
+    }
+}
+
+impl Emitable for PacketResponseHeader {
+    fn buffer_len(&self) -> usize {
+        PACKET_RESPONSE_HEADER_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = PacketResponseBuffer::new(buf);
+        buf.set_family(AF_PACKET);
+        buf.set_kind(self.kind);
+        buf.set_protocol(self.protocol);
+        buf.set_inode(self.inode);
+        buf.cookie_mut().copy_from_slice(&self.cookie[..]);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PacketResponse {
+    pub header: PacketResponseHeader,
+    pub nlas: SmallVec<[Nla; 8]>,
+}
+
+impl PacketResponse {
+    pub fn info(&self) -> Option<&PacketInfo> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Info(info) = nla {
+                Some(info)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn uid(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Uid(uid) = nla {
+                Some(*uid)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> PacketResponseBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<PacketResponseBuffer<&'a T>> for SmallVec<[Nla; 8]> {
+    fn parse(buf: &PacketResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = smallvec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<PacketResponseBuffer<&'a T>> for PacketResponse {
+    fn parse(buf: &PacketResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header =
+            PacketResponseHeader::parse(buf).context("failed to parse packet response header")?;
+        let nlas =
+            SmallVec::<[Nla; 8]>::parse(buf).context("failed to parse packet response NLAs")?;
+        Ok(PacketResponse { header, nlas })
+    }
+}
+
+impl Emitable for PacketResponse {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
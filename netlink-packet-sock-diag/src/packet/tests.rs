@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    packet::{
+        nlas::{FilterInstruction, MulticastMembership, Nla, PacketInfo},
+        PacketRequest,
+        PacketResponse,
+        PacketResponseBuffer,
+        PacketResponseHeader,
+        ShowFlags,
+    },
+    traits::{Emitable, Parseable},
+};
+
+lazy_static! {
+    static ref DUMP: PacketRequest = PacketRequest {
+        inode: 0,
+        show_flags: ShowFlags::INFO | ShowFlags::FANOUT,
+        cookie: [0xff; 8],
+    };
+}
+
+#[rustfmt::skip]
+static DUMP_BUF: [u8; 20] = [
+    0x11, // family: AF_PACKET
+    0x00, // protocol
+    0x00, 0x00, // padding
+    0x00, 0x00, 0x00, 0x00, // inode
+    0x09, 0x00, 0x00, 0x00, // show_flags: PACKET_SHOW_INFO | PACKET_SHOW_FANOUT
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // cookie
+];
+
+#[test]
+fn emit_dump() {
+    assert_eq!(DUMP.buffer_len(), 20);
+    let mut buf = vec![0xff; DUMP.buffer_len()];
+    DUMP.emit(&mut buf);
+    assert_eq!(&buf[..], &DUMP_BUF[..]);
+}
+
+lazy_static! {
+    static ref CAPTURE: PacketResponse = PacketResponse {
+        header: PacketResponseHeader {
+            kind: SOCK_RAW,
+            protocol: 0x0003,
+            inode: 0x4321,
+            cookie: [0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        },
+        nlas: smallvec![
+            Nla::Info(PacketInfo {
+                ifindex: 2,
+                version: 0,
+                reserve: 0,
+                copy_threshold: 0,
+                timestamp: 0,
+                flags: PDI_RUNNING,
+            }),
+            Nla::MulticastList(vec![MulticastMembership {
+                ifindex: 2,
+                count: 1,
+                kind: 1,
+                address: vec![],
+            }]),
+            Nla::Uid(1000),
+            Nla::Filter(vec![FilterInstruction {
+                code: 0x06,
+                jump_true: 0,
+                jump_false: 0,
+                k: 0xffff,
+            }]),
+        ],
+    };
+}
+
+#[rustfmt::skip]
+static CAPTURE_BUF: [u8; 112] = [
+    0x11, // family: AF_PACKET
+    0x03, // type: SOCK_RAW
+    0x03, 0x00, // protocol: ETH_P_ALL
+    0x21, 0x43, 0x00, 0x00, // inode
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // cookie
+
+    // NLAs
+    0x1c, 0x00, // length: 28
+    0x00, 0x00, // type: PACKET_DIAG_INFO
+    0x02, 0x00, 0x00, 0x00, // ifindex
+    0x00, 0x00, 0x00, 0x00, // version: TPACKET_V1
+    0x00, 0x00, 0x00, 0x00, // reserve
+    0x00, 0x00, 0x00, 0x00, // copy threshold
+    0x00, 0x00, 0x00, 0x00, // timestamp
+    0x01, 0x00, 0x00, 0x00, // flags: PDI_RUNNING
+
+    0x30, 0x00, // length: 48
+    0x01, 0x00, // type: PACKET_DIAG_MCLIST
+    0x02, 0x00, 0x00, 0x00, // ifindex
+    0x01, 0x00, 0x00, 0x00, // count
+    0x01, 0x00, // type: PACKET_MR_PROMISC
+    0x00, 0x00, // address length
+    // address
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+    0x08, 0x00, // length: 8
+    0x05, 0x00, // type: PACKET_DIAG_UID
+    0xe8, 0x03, 0x00, 0x00, // value: 1000
+
+    0x0c, 0x00, // length: 12
+    0x07, 0x00, // type: PACKET_DIAG_FILTER
+    // value: ret #0xffff
+    0x06, 0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00,
+];
+
+#[test]
+fn parse_capture() {
+    let parsed =
+        PacketResponse::parse(&PacketResponseBuffer::new_checked(&&CAPTURE_BUF[..]).unwrap())
+            .unwrap();
+    assert_eq!(parsed, *CAPTURE);
+}
+
+#[test]
+fn emit_capture() {
+    assert_eq!(CAPTURE.buffer_len(), 112);
+    let mut buf = vec![0xff; CAPTURE.buffer_len()];
+    CAPTURE.emit(&mut buf);
+    assert_eq!(&buf[..], &CAPTURE_BUF[..]);
+}
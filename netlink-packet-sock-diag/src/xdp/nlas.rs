@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+pub use crate::utils::nla::{DefaultNla, NlaBuffer, NlasIterator};
+
+use crate::{
+    constants::*,
+    inet::nlas::{zero_extend, MemInfo, MemInfoBuffer, MEM_INFO_LEN, MEM_INFO_MIN_LEN},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Nla {
+    /// Interface and queue the socket is bound to. This attribute is
+    /// known as `XDP_DIAG_INFO` in the kernel.
+    Info(XdpInfo),
+    /// User ID of the owner of the socket. This attribute is known as
+    /// `XDP_DIAG_UID` in the kernel.
+    Uid(u32),
+    /// Number of descriptors of the receive ring. This attribute is
+    /// known as `XDP_DIAG_RX_RING` in the kernel.
+    RxRing(u32),
+    /// Number of descriptors of the transmit ring. This attribute is
+    /// known as `XDP_DIAG_TX_RING` in the kernel.
+    TxRing(u32),
+    /// The UMEM registered by the socket. This attribute is known as
+    /// `XDP_DIAG_UMEM` in the kernel.
+    Umem(Umem),
+    /// Number of descriptors of the fill ring of the UMEM. This
+    /// attribute is known as `XDP_DIAG_UMEM_FILL_RING` in the kernel.
+    UmemFillRing(u32),
+    /// Number of descriptors of the completion ring of the UMEM. This
+    /// attribute is known as `XDP_DIAG_UMEM_COMPLETION_RING` in the
+    /// kernel.
+    UmemCompletionRing(u32),
+    /// Socket memory information. This attribute is known as
+    /// `XDP_DIAG_MEMINFO` in the kernel.
+    MemInfo(MemInfo),
+    /// Statistics of the socket. This attribute is known as
+    /// `XDP_DIAG_STATS` in the kernel.
+    Stats(XdpStats),
+    /// Unknown attribute
+    Other(DefaultNla),
+}
+
+pub const XDP_INFO_LEN: usize = 8;
+
+buffer!(XdpInfoBuffer(XDP_INFO_LEN) {
+    ifindex: (u32, 0..4),
+    queue_id: (u32, 4..8),
+});
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct XdpInfo {
+    /// Index of the interface the socket is bound to
+    pub ifindex: u32,
+    /// Queue of the interface the socket is bound to
+    pub queue_id: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<XdpInfoBuffer<T>> for XdpInfo {
+    fn parse(buf: &XdpInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            queue_id: buf.queue_id(),
+        })
+    }
+}
+
+impl Emitable for XdpInfo {
+    fn buffer_len(&self) -> usize {
+        XDP_INFO_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = XdpInfoBuffer::new(buf);
+        buf.set_ifindex(self.ifindex);
+        buf.set_queue_id(self.queue_id);
+    }
+}
+
+pub const UMEM_LEN: usize = 40;
+
+buffer!(UmemBuffer(UMEM_LEN) {
+    size: (u64, 0..8),
+    id: (u32, 8..12),
+    num_pages: (u32, 12..16),
+    chunk_size: (u32, 16..20),
+    headroom: (u32, 20..24),
+    ifindex: (u32, 24..28),
+    queue_id: (u32, 28..32),
+    flags: (u32, 32..36),
+    refs: (u32, 36..40),
+});
+
+/// A UMEM, the memory area shared by the XDP sockets and the kernel to
+/// store the packets
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Umem {
+    /// Size of the UMEM in bytes
+    pub size: u64,
+    pub id: u32,
+    pub num_pages: u32,
+    /// Size of the frames the UMEM is divided in
+    pub chunk_size: u32,
+    /// Headroom reserved at the beginning of each frame
+    pub headroom: u32,
+    /// Index of the interface the UMEM is bound to
+    pub ifindex: u32,
+    /// Queue of the interface the UMEM is bound to
+    pub queue_id: u32,
+    /// A combination of the `XDP_DU_F_*` constants
+    pub flags: u32,
+    /// Number of sockets sharing the UMEM
+    pub refs: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<UmemBuffer<T>> for Umem {
+    fn parse(buf: &UmemBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            size: buf.size(),
+            id: buf.id(),
+            num_pages: buf.num_pages(),
+            chunk_size: buf.chunk_size(),
+            headroom: buf.headroom(),
+            ifindex: buf.ifindex(),
+            queue_id: buf.queue_id(),
+            flags: buf.flags(),
+            refs: buf.refs(),
+        })
+    }
+}
+
+impl Emitable for Umem {
+    fn buffer_len(&self) -> usize {
+        UMEM_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = UmemBuffer::new(buf);
+        buf.set_size(self.size);
+        buf.set_id(self.id);
+        buf.set_num_pages(self.num_pages);
+        buf.set_chunk_size(self.chunk_size);
+        buf.set_headroom(self.headroom);
+        buf.set_ifindex(self.ifindex);
+        buf.set_queue_id(self.queue_id);
+        buf.set_flags(self.flags);
+        buf.set_refs(self.refs);
+    }
+}
+
+pub const XDP_STATS_LEN: usize = 48;
+
+buffer!(XdpStatsBuffer(XDP_STATS_LEN) {
+    rx_dropped: (u64, 0..8),
+    rx_invalid: (u64, 8..16),
+    rx_full: (u64, 16..24),
+    fill_ring_empty: (u64, 24..32),
+    tx_invalid: (u64, 32..40),
+    tx_ring_empty: (u64, 40..48),
+});
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct XdpStats {
+    /// Packets dropped for other reasons than invalid descriptors
+    pub rx_dropped: u64,
+    /// Packets dropped because of invalid descriptors
+    pub rx_invalid: u64,
+    /// Packets dropped because the receive ring was full
+    pub rx_full: u64,
+    /// Number of times the fill ring was empty
+    pub fill_ring_empty: u64,
+    /// Packets dropped because of invalid descriptors in the transmit
+    /// ring
+    pub tx_invalid: u64,
+    /// Number of times the transmit ring was empty
+    pub tx_ring_empty: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<XdpStatsBuffer<T>> for XdpStats {
+    fn parse(buf: &XdpStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_dropped: buf.rx_dropped(),
+            rx_invalid: buf.rx_invalid(),
+            rx_full: buf.rx_full(),
+            fill_ring_empty: buf.fill_ring_empty(),
+            tx_invalid: buf.tx_invalid(),
+            tx_ring_empty: buf.tx_ring_empty(),
+        })
+    }
+}
+
+impl Emitable for XdpStats {
+    fn buffer_len(&self) -> usize {
+        XDP_STATS_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = XdpStatsBuffer::new(buf);
+        buf.set_rx_dropped(self.rx_dropped);
+        buf.set_rx_invalid(self.rx_invalid);
+        buf.set_rx_full(self.rx_full);
+        buf.set_fill_ring_empty(self.fill_ring_empty);
+        buf.set_tx_invalid(self.tx_invalid);
+        buf.set_tx_ring_empty(self.tx_ring_empty);
+    }
+}
+
+impl crate::utils::nla::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Info(_) => XDP_INFO_LEN,
+            Uid(_) | RxRing(_) | TxRing(_) | UmemFillRing(_) | UmemCompletionRing(_) => 4,
+            Umem(_) => UMEM_LEN,
+            MemInfo(_) => MEM_INFO_LEN,
+            Stats(_) => XDP_STATS_LEN,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Info(ref value) => value.emit(buffer),
+            Uid(value)
+            | RxRing(value)
+            | TxRing(value)
+            | UmemFillRing(value)
+            | UmemCompletionRing(value) => NativeEndian::write_u32(buffer, value),
+            Umem(ref value) => value.emit(buffer),
+            MemInfo(ref value) => value.emit(buffer),
+            Stats(ref value) => value.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Info(_) => XDP_DIAG_INFO,
+            Uid(_) => XDP_DIAG_UID,
+            RxRing(_) => XDP_DIAG_RX_RING,
+            TxRing(_) => XDP_DIAG_TX_RING,
+            Umem(_) => XDP_DIAG_UMEM,
+            UmemFillRing(_) => XDP_DIAG_UMEM_FILL_RING,
+            UmemCompletionRing(_) => XDP_DIAG_UMEM_COMPLETION_RING,
+            MemInfo(_) => XDP_DIAG_MEMINFO,
+            Stats(_) => XDP_DIAG_STATS,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            XDP_DIAG_INFO => {
+                let err = "invalid XDP_DIAG_INFO value";
+                let buf = XdpInfoBuffer::new_checked(payload).context(err)?;
+                Self::Info(XdpInfo::parse(&buf).context(err)?)
+            }
+            XDP_DIAG_UID => Self::Uid(parse_u32(payload).context("invalid XDP_DIAG_UID value")?),
+            XDP_DIAG_RX_RING => {
+                Self::RxRing(parse_u32(payload).context("invalid XDP_DIAG_RX_RING value")?)
+            }
+            XDP_DIAG_TX_RING => {
+                Self::TxRing(parse_u32(payload).context("invalid XDP_DIAG_TX_RING value")?)
+            }
+            XDP_DIAG_UMEM => {
+                let err = "invalid XDP_DIAG_UMEM value";
+                let buf = UmemBuffer::new_checked(payload).context(err)?;
+                Self::Umem(Umem::parse(&buf).context(err)?)
+            }
+            XDP_DIAG_UMEM_FILL_RING => Self::UmemFillRing(
+                parse_u32(payload).context("invalid XDP_DIAG_UMEM_FILL_RING value")?,
+            ),
+            XDP_DIAG_UMEM_COMPLETION_RING => Self::UmemCompletionRing(
+                parse_u32(payload).context("invalid XDP_DIAG_UMEM_COMPLETION_RING value")?,
+            ),
+            XDP_DIAG_MEMINFO => {
+                let err = "invalid XDP_DIAG_MEMINFO value";
+                let payload = zero_extend(payload, MEM_INFO_MIN_LEN, MEM_INFO_LEN).context(err)?;
+                let buf = MemInfoBuffer::new_checked(&payload[..]).context(err)?;
+                Self::MemInfo(MemInfo::parse(&buf).context(err)?)
+            }
+            XDP_DIAG_STATS => {
+                let err = "invalid XDP_DIAG_STATS value";
+                let buf = XdpStatsBuffer::new_checked(payload).context(err)?;
+                Self::Stats(XdpStats::parse(&buf).context(err)?)
+            }
+            kind => {
+                Self::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use smallvec::SmallVec;
+use std::convert::TryFrom;
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    xdp::nlas::{Nla, NlaBuffer, NlasIterator, XdpInfo},
+    DecodeError,
+};
+
+pub const XDP_RESPONSE_HEADER_LEN: usize = 16;
+
+buffer!(XdpResponseBuffer(XDP_RESPONSE_HEADER_LEN) {
+    family: (u8, 0),
+    kind: (u8, 1),
+    pad: (u16, 2..4),
+    inode: (u32, 4..8),
+    cookie: (slice, 8..XDP_RESPONSE_HEADER_LEN),
+    payload: (slice, XDP_RESPONSE_HEADER_LEN..),
+});
+
+/// The response to a query for XDP sockets
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct XdpResponseHeader {
+    /// Always `SOCK_RAW`
+    pub kind: u8,
+    /// Socket inode number.
+    pub inode: u32,
+    pub cookie: [u8; 8],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<XdpResponseBuffer<&'a T>> for XdpResponseHeader {
+    fn parse(buf: &XdpResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            kind: buf.kind(),
+            inode: buf.inode(),
+            // Unwrapping is safe because XdpResponseBuffer::cookie()
+            // returns a slice of exactly 8 bytes.
+            cookie: TryFrom::try_from(buf.cookie()).unwrap(),
+        })
+    }
+}
+
+impl Emitable for XdpResponseHeader {
+    fn buffer_len(&self) -> usize {
+        XDP_RESPONSE_HEADER_LEN
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let mut buf = XdpResponseBuffer::new(buf);
+        buf.set_family(AF_XDP);
+        buf.set_kind(self.kind);
+        buf.set_pad(0);
+        buf.set_inode(self.inode);
+        buf.cookie_mut().copy_from_slice(&self.cookie[..]);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct XdpResponse {
+    pub header: XdpResponseHeader,
+    pub nlas: SmallVec<[Nla; 8]>,
+}
+
+impl XdpResponse {
+    pub fn info(&self) -> Option<&XdpInfo> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Info(info) = nla {
+                Some(info)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn uid(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Uid(uid) = nla {
+                Some(*uid)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> XdpResponseBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<XdpResponseBuffer<&'a T>> for SmallVec<[Nla; 8]> {
+    fn parse(buf: &XdpResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = smallvec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<XdpResponseBuffer<&'a T>> for XdpResponse {
+    fn parse(buf: &XdpResponseBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header =
+            XdpResponseHeader::parse(buf).context("failed to parse xdp response header")?;
+        let nlas = SmallVec::<[Nla; 8]>::parse(buf).context("failed to parse xdp response NLAs")?;
+        Ok(XdpResponse { header, nlas })
+    }
+}
+
+impl Emitable for XdpResponse {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    xdp::{
+        nlas::{Nla, Umem, XdpInfo, XdpStats},
+        ShowFlags,
+        XdpRequest,
+        XdpResponse,
+        XdpResponseBuffer,
+        XdpResponseHeader,
+    },
+};
+
+lazy_static! {
+    static ref DUMP: XdpRequest = XdpRequest {
+        inode: 0,
+        show_flags: ShowFlags::INFO | ShowFlags::UMEM | ShowFlags::STATS,
+        cookie: [0xff; 8],
+    };
+}
+
+#[rustfmt::skip]
+static DUMP_BUF: [u8; 20] = [
+    0x2c, // family: AF_XDP
+    0x00, // protocol
+    0x00, 0x00, // padding
+    0x00, 0x00, 0x00, 0x00, // inode
+    0x15, 0x00, 0x00, 0x00, // show_flags: XDP_SHOW_INFO | XDP_SHOW_UMEM | XDP_SHOW_STATS
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // cookie
+];
+
+#[test]
+fn emit_dump() {
+    assert_eq!(DUMP.buffer_len(), 20);
+    let mut buf = vec![0xff; DUMP.buffer_len()];
+    DUMP.emit(&mut buf);
+    assert_eq!(&buf[..], &DUMP_BUF[..]);
+}
+
+lazy_static! {
+    static ref ZERO_COPY: XdpResponse = XdpResponse {
+        header: XdpResponseHeader {
+            kind: SOCK_RAW,
+            inode: 0x5555,
+            cookie: [0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        },
+        nlas: smallvec![
+            Nla::Info(XdpInfo {
+                ifindex: 3,
+                queue_id: 0,
+            }),
+            Nla::RxRing(2048),
+            Nla::Umem(Umem {
+                size: 0x400000,
+                id: 0,
+                num_pages: 1024,
+                chunk_size: 4096,
+                headroom: 0,
+                ifindex: 3,
+                queue_id: 0,
+                flags: XDP_DU_F_ZEROCOPY,
+                refs: 1,
+            }),
+            Nla::Stats(XdpStats {
+                rx_dropped: 1,
+                rx_invalid: 0,
+                rx_full: 0,
+                fill_ring_empty: 0,
+                tx_invalid: 0,
+                tx_ring_empty: 0,
+            }),
+        ],
+    };
+}
+
+#[rustfmt::skip]
+static ZERO_COPY_BUF: [u8; 132] = [
+    0x2c, // family: AF_XDP
+    0x03, // type: SOCK_RAW
+    0x00, 0x00, // padding
+    0x55, 0x55, 0x00, 0x00, // inode
+    0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // cookie
+
+    // NLAs
+    0x0c, 0x00, // length: 12
+    0x01, 0x00, // type: XDP_DIAG_INFO
+    0x03, 0x00, 0x00, 0x00, // ifindex
+    0x00, 0x00, 0x00, 0x00, // queue ID
+
+    0x08, 0x00, // length: 8
+    0x03, 0x00, // type: XDP_DIAG_RX_RING
+    0x00, 0x08, 0x00, 0x00, // entries: 2048
+
+    0x2c, 0x00, // length: 44
+    0x05, 0x00, // type: XDP_DIAG_UMEM
+    0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, // size: 4MiB
+    0x00, 0x00, 0x00, 0x00, // ID
+    0x00, 0x04, 0x00, 0x00, // number of pages: 1024
+    0x00, 0x10, 0x00, 0x00, // chunk size: 4096
+    0x00, 0x00, 0x00, 0x00, // headroom
+    0x03, 0x00, 0x00, 0x00, // ifindex
+    0x00, 0x00, 0x00, 0x00, // queue ID
+    0x01, 0x00, 0x00, 0x00, // flags: XDP_DU_F_ZEROCOPY
+    0x01, 0x00, 0x00, 0x00, // references
+
+    0x34, 0x00, // length: 52
+    0x09, 0x00, // type: XDP_DIAG_STATS
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx dropped
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx invalid
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx full
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // fill ring empty
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx invalid
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx ring empty
+];
+
+#[test]
+fn parse_zero_copy() {
+    let parsed =
+        XdpResponse::parse(&XdpResponseBuffer::new_checked(&&ZERO_COPY_BUF[..]).unwrap()).unwrap();
+    assert_eq!(parsed, *ZERO_COPY);
+}
+
+#[test]
+fn emit_zero_copy() {
+    assert_eq!(ZERO_COPY.buffer_len(), 132);
+    let mut buf = vec![0xff; ZERO_COPY.buffer_len()];
+    ZERO_COPY.emit(&mut buf);
+    assert_eq!(&buf[..], &ZERO_COPY_BUF[..]);
+}
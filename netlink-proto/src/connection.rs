@@ -45,7 +45,7 @@ where
 {
     socket: NetlinkFramed<T, S, C>,
 
-    protocol: Protocol<T, UnboundedSender<NetlinkMessage<T>>>,
+    protocol: Protocol<T, UnboundedSender<(usize, NetlinkMessage<T>)>>,
 
     /// Channel used by the user to pass requests to the connection.
     requests_rx: Option<UnboundedReceiver<Request<T>>>,
@@ -82,6 +82,13 @@ where
         self.socket.get_mut()
     }
 
+    /// Ask the kernel for extended error reporting (`NETLINK_EXT_ACK`), so
+    /// that errors carry a message. This fails on kernels older than 4.12,
+    /// which is fine: errors just don't have the extra details.
+    pub fn enable_ext_ack(&mut self) {
+        let _ = self.socket_mut().socket_mut().set_ext_ack(true);
+    }
+
     pub fn poll_send_messages(&mut self, cx: &mut Context) {
         trace!("poll_send_messages called");
         let Connection {
@@ -101,14 +108,19 @@ where
                 return;
             }
 
-            let (mut message, addr) = protocol.outgoing_messages.pop_front().unwrap();
-            message.finalize();
+            // All the messages of a request are encoded in the same
+            // buffer, so that they are sent in a single datagram when
+            // the socket is flushed.
+            let (messages, addr) = protocol.outgoing_messages.pop_front().unwrap();
+            for mut message in messages {
+                message.finalize();
 
-            trace!("sending outgoing message");
-            if let Err(e) = Pin::as_mut(&mut socket).start_send((message, addr)) {
-                error!("failed to send message: {:?}", e);
-                self.socket_closed = true;
-                return;
+                trace!("sending outgoing message");
+                if let Err(e) = Pin::as_mut(&mut socket).start_send((message, addr)) {
+                    error!("failed to send message: {:?}", e);
+                    self.socket_closed = true;
+                    return;
+                }
             }
         }
 
@@ -217,6 +229,7 @@ where
             let Response {
                 message,
                 done,
+                index,
                 metadata: tx,
             } = response;
             if done {
@@ -234,18 +247,16 @@ where
                         trace!("not forwarding Noop/Ack/Done message to the handle");
                         continue;
                     }
-                    // I'm not sure how we should handle overrun messages
-                    Overrun(_) => unimplemented!("overrun is not handled yet"),
-                    // We need to forward error messages and messages
-                    // that are part of the netlink subprotocol,
-                    // because only the user knows how they want to
-                    // handle them.
-                    Error(_) | InnerMessage(_) => {}
+                    // We need to forward error and overrun messages
+                    // and messages that are part of the netlink
+                    // subprotocol, because only the user knows how
+                    // they want to handle them.
+                    Error(_) | Overrun(_) | InnerMessage(_) => {}
                 }
             }
 
             trace!("forwarding response to the handle");
-            if tx.unbounded_send(message).is_err() {
+            if tx.unbounded_send((index, message)).is_err() {
                 // With an unboundedsender, an error can
                 // only happen if the receiver is closed.
                 warn!("failed to forward response back to the handle");
@@ -295,3 +306,110 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::{task::noop_waker, FutureExt, StreamExt};
+    use netlink_packet_core::{constants::*, NetlinkPayload};
+    use netlink_packet_route::{LinkMessage, RtnlMessage};
+    use netlink_sys::{constants::NETLINK_ROUTE, Socket};
+
+    use super::*;
+    use crate::new_connection_with_socket;
+
+    /// A socket that accepts all the datagrams it is given, and whose
+    /// receive buffer overruns once something was sent.
+    struct OverrunSocket {
+        socket: Socket,
+        sent: bool,
+        overrun: bool,
+    }
+
+    impl AsyncSocket for OverrunSocket {
+        fn socket_ref(&self) -> &Socket {
+            &self.socket
+        }
+
+        fn socket_mut(&mut self) -> &mut Socket {
+            &mut self.socket
+        }
+
+        fn new(protocol: isize) -> io::Result<Self> {
+            Ok(Self {
+                socket: Socket::new(protocol)?,
+                sent: false,
+                overrun: false,
+            })
+        }
+
+        fn poll_send(&mut self, _cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+            self.sent = true;
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_send_to(
+            &mut self,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+            _addr: &SocketAddr,
+        ) -> Poll<io::Result<usize>> {
+            self.poll_send(cx, buf)
+        }
+
+        fn poll_recv<B>(&mut self, cx: &mut Context<'_>, buf: &mut B) -> Poll<io::Result<()>>
+        where
+            B: bytes::BufMut,
+        {
+            self.poll_recv_from(cx, buf).map_ok(|_| ())
+        }
+
+        fn poll_recv_from<B>(
+            &mut self,
+            _cx: &mut Context<'_>,
+            _buf: &mut B,
+        ) -> Poll<io::Result<SocketAddr>>
+        where
+            B: bytes::BufMut,
+        {
+            if self.sent && !self.overrun {
+                self.overrun = true;
+                Poll::Ready(Err(io::Error::from_raw_os_error(libc::ENOBUFS)))
+            } else {
+                Poll::Pending
+            }
+        }
+
+        fn poll_recv_from_full(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<io::Result<(Vec<u8>, SocketAddr)>> {
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn overrun_fails_pending_requests() {
+        let (mut conn, mut handle, mut messages) =
+            new_connection_with_socket::<RtnlMessage, OverrunSocket>(NETLINK_ROUTE).unwrap();
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut request = NetlinkMessage::from(RtnlMessage::NewLink(LinkMessage::default()));
+        request.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(request, SocketAddr::new(0, 0)).unwrap();
+
+        // The first poll sends the request, the second one reads from
+        // the socket, which overruns before the ACK is received
+        assert!(Pin::new(&mut conn).poll(&mut cx).is_pending());
+        assert!(Pin::new(&mut conn).poll(&mut cx).is_pending());
+
+        let message = response.next().now_or_never().unwrap().unwrap();
+        assert_eq!(message.payload, NetlinkPayload::Overrun(vec![]));
+        assert!(response.next().now_or_never().unwrap().is_none());
+
+        // The connection is still usable, and the overrun is also
+        // reported as an unsolicited message
+        let (message, _) = messages.next().now_or_never().unwrap().unwrap();
+        assert_eq!(message.payload, NetlinkPayload::Overrun(vec![]));
+    }
+}
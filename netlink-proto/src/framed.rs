@@ -10,13 +10,21 @@ use std::{
 };
 
 use futures::{Sink, Stream};
-use log::error;
+use log::{error, warn};
 
 use crate::{
     codecs::NetlinkMessageCodec,
     sys::{AsyncSocket, SocketAddr},
 };
-use netlink_packet_core::{NetlinkDeserializable, NetlinkMessage, NetlinkSerializable};
+use netlink_packet_core::{
+    NetlinkDeserializable,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    NetlinkSerializable,
+    NETLINK_HEADER_LEN,
+    NLMSG_OVERRUN,
+};
 
 pub struct NetlinkFramed<T, S, C> {
     socket: S,
@@ -63,6 +71,21 @@ where
 
             *in_addr = match ready!(socket.poll_recv_from(cx, reader)) {
                 Ok(addr) => addr,
+                // The socket receive buffer overflowed and the kernel
+                // dropped messages. The socket is still usable, so
+                // report the loss as an overrun message instead of
+                // giving up.
+                Err(e) if e.raw_os_error() == Some(libc::ENOBUFS) => {
+                    warn!("netlink socket receive buffer overrun, messages were lost");
+                    reader.clear();
+                    let header = NetlinkHeader {
+                        length: NETLINK_HEADER_LEN as u32,
+                        message_type: NLMSG_OVERRUN,
+                        ..Default::default()
+                    };
+                    let message = NetlinkMessage::new(header, NetlinkPayload::Overrun(vec![]));
+                    return Poll::Ready(Some((message, SocketAddr::new(0, 0))));
+                }
                 Err(e) => {
                     error!("failed to read from netlink socket: {:?}", e);
                     return Poll::Ready(None);
@@ -3,6 +3,7 @@
 use futures::{
     channel::mpsc::{unbounded, UnboundedSender},
     Stream,
+    StreamExt,
 };
 use netlink_packet_core::NetlinkMessage;
 use std::fmt::Debug;
@@ -37,8 +38,36 @@ where
         message: NetlinkMessage<T>,
         destination: SocketAddr,
     ) -> Result<impl Stream<Item = NetlinkMessage<T>>, Error<T>> {
-        let (tx, rx) = unbounded::<NetlinkMessage<T>>();
+        let (tx, rx) = unbounded::<(usize, NetlinkMessage<T>)>();
         let request = Request::from((message, destination, tx));
+        self.forward_request(request)?;
+        Ok(rx.map(|(_, message)| message))
+    }
+
+    /// Send several messages in a single datagram, and get the responses as a stream of
+    /// `(index, message)` tuples, where `index` is the position in `messages` of the message the
+    /// response belongs to. Each message gets its own sequence number, so the responses are
+    /// handled exactly as if the messages had been sent with [`request`](#method.request):
+    ///
+    /// - **acknowledgements** and **end of dump messages** are not part of the response stream
+    /// - error messages are forwarded, so a message that has the `NLM_F_ACK` flag succeeded if no
+    ///   error message with its index was received
+    /// - the stream is closed once all the messages have been fully processed
+    ///
+    /// The kernel rejects datagrams larger than the socket send buffer, so large batches must be
+    /// split by the caller.
+    pub fn request_batch(
+        &mut self,
+        messages: Vec<NetlinkMessage<T>>,
+        destination: SocketAddr,
+    ) -> Result<impl Stream<Item = (usize, NetlinkMessage<T>)>, Error<T>> {
+        let (tx, rx) = unbounded::<(usize, NetlinkMessage<T>)>();
+        let request = Request::from((messages, destination, tx));
+        self.forward_request(request)?;
+        Ok(rx)
+    }
+
+    fn forward_request(&mut self, request: Request<T>) -> Result<(), Error<T>> {
         debug!("handle: forwarding new request to connection");
         UnboundedSender::unbounded_send(&self.requests_tx, request).map_err(|e| {
             // the channel is unbounded, so it can't be full. If this
@@ -50,8 +79,7 @@ where
             } else {
                 panic!("unknown error: {:?}", e);
             }
-        })?;
-        Ok(rx)
+        })
     }
 
     pub fn notify(
@@ -59,7 +87,7 @@ where
         message: NetlinkMessage<T>,
         destination: SocketAddr,
     ) -> Result<(), Error<T>> {
-        let (tx, _rx) = unbounded::<NetlinkMessage<T>>();
+        let (tx, _rx) = unbounded::<(usize, NetlinkMessage<T>)>();
         let request = Request::from((message, destination, tx));
         debug!("handle: forwarding new request to connection");
         UnboundedSender::unbounded_send(&self.requests_tx, request)
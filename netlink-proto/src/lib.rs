@@ -177,7 +177,7 @@ pub use crate::framed::*;
 mod protocol;
 pub(crate) use self::protocol::{Protocol, Response};
 pub(crate) type Request<T> =
-    self::protocol::Request<T, UnboundedSender<crate::packet::NetlinkMessage<T>>>;
+    self::protocol::Request<T, UnboundedSender<(usize, crate::packet::NetlinkMessage<T>)>>;
 
 mod connection;
 pub use crate::connection::*;
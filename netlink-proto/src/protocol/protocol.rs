@@ -8,6 +8,7 @@ use std::{
 use netlink_packet_core::{
     constants::*,
     NetlinkDeserializable,
+    NetlinkHeader,
     NetlinkMessage,
     NetlinkPayload,
     NetlinkSerializable,
@@ -35,12 +36,16 @@ impl RequestId {
 pub(crate) struct Response<T, M> {
     pub done: bool,
     pub message: NetlinkMessage<T>,
+    /// Index of the message this is a response to, within the
+    /// request it was sent with
+    pub index: usize,
     pub metadata: M,
 }
 
 #[derive(Debug)]
 struct PendingRequest<M> {
     expecting_ack: bool,
+    index: usize,
     metadata: M,
 }
 
@@ -53,14 +58,19 @@ pub(crate) struct Protocol<T, M> {
     /// associated with each request.
     pending_requests: HashMap<RequestId, PendingRequest<M>>,
 
+    /// Requests that were terminated by a socket overrun, and whether
+    /// they expect an ACK. Their remaining responses are dropped.
+    overrun_requests: HashMap<RequestId, bool>,
+
     /// Responses to pending requests
     pub incoming_responses: VecDeque<Response<T, M>>,
 
     /// Requests from remote peers
     pub incoming_requests: VecDeque<(NetlinkMessage<T>, SocketAddr)>,
 
-    /// The messages to be sent out
-    pub outgoing_messages: VecDeque<(NetlinkMessage<T>, SocketAddr)>,
+    /// The messages to be sent out. Each entry is sent as a single
+    /// datagram.
+    pub outgoing_messages: VecDeque<(Vec<NetlinkMessage<T>>, SocketAddr)>,
 }
 
 impl<T, M> Protocol<T, M>
@@ -72,6 +82,7 @@ where
         Self {
             sequence_id: 0,
             pending_requests: HashMap::new(),
+            overrun_requests: HashMap::new(),
             incoming_responses: VecDeque::new(),
             incoming_requests: VecDeque::new(),
             outgoing_messages: VecDeque::new(),
@@ -79,15 +90,45 @@ where
     }
 
     pub fn handle_message(&mut self, message: NetlinkMessage<T>, source: SocketAddr) {
+        if let NetlinkPayload::Overrun(ref data) = message.payload {
+            self.handle_overrun(&message.header, data);
+            self.incoming_requests.push_back((message, source));
+            return;
+        }
         let request_id = RequestId::new(message.header.sequence_number, source.port_number());
         debug!("handling messages (request id = {:?})", request_id);
-        if let hash_map::Entry::Occupied(entry) = self.pending_requests.entry(request_id) {
+        if let Some(&expecting_ack) = self.overrun_requests.get(&request_id) {
+            debug!("dropping response to failed request {:?}", request_id);
+            if Self::is_last_response(&message, expecting_ack) {
+                self.overrun_requests.remove(&request_id);
+            }
+        } else if let hash_map::Entry::Occupied(entry) = self.pending_requests.entry(request_id) {
             Self::handle_response(&mut self.incoming_responses, entry, message);
         } else {
             self.incoming_requests.push_back((message, source));
         }
     }
 
+    /// The socket receive buffer overran, so the kernel dropped some
+    /// messages. They may be responses to any of the pending requests,
+    /// which would then never complete: terminate them all with an
+    /// overrun message.
+    fn handle_overrun(&mut self, header: &NetlinkHeader, data: &[u8]) {
+        for (request_id, pending) in self.pending_requests.drain() {
+            debug!("request {:?} failed: socket overrun", request_id);
+            let mut header = *header;
+            header.sequence_number = request_id.sequence_number;
+            self.overrun_requests
+                .insert(request_id, pending.expecting_ack);
+            self.incoming_responses.push_back(Response {
+                done: true,
+                message: NetlinkMessage::new(header, NetlinkPayload::Overrun(data.to_vec())),
+                index: pending.index,
+                metadata: pending.metadata,
+            });
+        }
+    }
+
     fn handle_response(
         incoming_responses: &mut VecDeque<Response<T, M>>,
         entry: hash_map::OccupiedEntry<RequestId, PendingRequest<M>>,
@@ -97,71 +138,88 @@ where
         let mut request_id = entry.key();
         debug!("handling response to request {:?}", request_id);
 
-        // A request is processed if we receive an Ack, Error,
-        // Done, Overrun, or InnerMessage without the
-        // multipart flag and we were not expecting an Ack
-        let done = match message.payload {
-            NetlinkPayload::InnerMessage(_)
-                if message.header.flags & NLM_F_MULTIPART == NLM_F_MULTIPART =>
-            {
-                false
-            }
-            NetlinkPayload::InnerMessage(_) => !entry.get().expecting_ack,
-            _ => true,
-        };
+        let done = Self::is_last_response(&message, entry.get().expecting_ack);
 
-        let metadata = if done {
+        let (index, metadata) = if done {
             trace!("request {:?} fully processed", request_id);
             let (k, v) = entry.remove_entry();
             entry_key = k;
             request_id = &entry_key;
-            v.metadata
+            (v.index, v.metadata)
         } else {
             trace!("more responses to request {:?} may come", request_id);
-            entry.get().metadata.clone()
+            let pending = entry.get();
+            (pending.index, pending.metadata.clone())
         };
 
         let response = Response::<T, M> {
             done,
             message,
+            index,
             metadata,
         };
         incoming_responses.push_back(response);
         debug!("done handling response to request {:?}", request_id);
     }
 
+    /// A request is processed if we receive an Ack, Error, Done,
+    /// Overrun, or InnerMessage without the multipart flag and we
+    /// were not expecting an Ack
+    fn is_last_response(message: &NetlinkMessage<T>, expecting_ack: bool) -> bool {
+        match message.payload {
+            NetlinkPayload::InnerMessage(_)
+                if message.header.flags & NLM_F_MULTIPART == NLM_F_MULTIPART =>
+            {
+                false
+            }
+            NetlinkPayload::InnerMessage(_) => !expecting_ack,
+            _ => true,
+        }
+    }
+
     pub fn request(&mut self, request: Request<T, M>) {
         let Request {
-            mut message,
+            mut messages,
             metadata,
             destination,
         } = request;
 
-        self.set_sequence_id(&mut message);
-        let request_id = RequestId::new(self.sequence_id, destination.port_number());
-        let flags = message.header.flags;
-        self.outgoing_messages.push_back((message, destination));
-
-        // If we expect a response, we store the request id so that we
-        // can map the response to this specific request.
-        //
-        // Note that we expect responses in three cases only:
-        //  - when the request has the NLM_F_REQUEST flag
-        //  - when the request has the NLM_F_ACK flag
-        //  - when the request has the NLM_F_ECHO flag
-        let expecting_ack = flags & NLM_F_ACK == NLM_F_ACK;
-        if flags & NLM_F_REQUEST == NLM_F_REQUEST
-            || flags & NLM_F_ECHO == NLM_F_ECHO
-            || expecting_ack
-        {
-            self.pending_requests.insert(
-                request_id,
-                PendingRequest {
-                    expecting_ack,
-                    metadata,
-                },
-            );
+        if messages.is_empty() {
+            return;
+        }
+
+        // Each message of the request gets its own sequence number,
+        // so that the responses can be mapped back to the message
+        // that triggered them, even though they are all sent in the
+        // same datagram.
+        for (index, message) in messages.iter_mut().enumerate() {
+            self.set_sequence_id(message);
+            let request_id = RequestId::new(self.sequence_id, destination.port_number());
+            let flags = message.header.flags;
+
+            // If we expect a response, we store the request id so that we
+            // can map the response to this specific request.
+            //
+            // Note that we expect responses in three cases only:
+            //  - when the request has the NLM_F_REQUEST flag
+            //  - when the request has the NLM_F_ACK flag
+            //  - when the request has the NLM_F_ECHO flag
+            let expecting_ack = flags & NLM_F_ACK == NLM_F_ACK;
+            if flags & NLM_F_REQUEST == NLM_F_REQUEST
+                || flags & NLM_F_ECHO == NLM_F_ECHO
+                || expecting_ack
+            {
+                self.pending_requests.insert(
+                    request_id,
+                    PendingRequest {
+                        expecting_ack,
+                        index,
+                        metadata: metadata.clone(),
+                    },
+                );
+            }
         }
+        self.outgoing_messages.push_back((messages, destination));
     }
 
     fn set_sequence_id(&mut self, message: &mut NetlinkMessage<T>) {
@@ -169,3 +227,249 @@ where
         message.header.sequence_number = self.sequence_id;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::{
+        channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+        FutureExt,
+        StreamExt,
+    };
+    use netlink_packet_core::ErrorMessage;
+    use netlink_packet_route::{LinkMessage, RtnlMessage};
+
+    use super::*;
+
+    type TestProtocol =
+        Protocol<RtnlMessage, UnboundedSender<(usize, NetlinkMessage<RtnlMessage>)>>;
+    type TestReceiver = UnboundedReceiver<(usize, NetlinkMessage<RtnlMessage>)>;
+
+    fn kernel() -> SocketAddr {
+        SocketAddr::new(0, 0)
+    }
+
+    fn request(flags: u16) -> NetlinkMessage<RtnlMessage> {
+        let mut message = NetlinkMessage::from(RtnlMessage::NewLink(LinkMessage::default()));
+        message.header.flags = flags;
+        message
+    }
+
+    fn response(
+        sequence_number: u32,
+        flags: u16,
+        payload: NetlinkPayload<RtnlMessage>,
+    ) -> NetlinkMessage<RtnlMessage> {
+        let header = NetlinkHeader {
+            sequence_number,
+            flags,
+            ..Default::default()
+        };
+        NetlinkMessage::new(header, payload)
+    }
+
+    fn error(code: i32) -> ErrorMessage {
+        ErrorMessage {
+            code,
+            header: vec![],
+            nlas: vec![],
+        }
+    }
+
+    // Send a batch and return the sequence numbers of its messages,
+    // along with the receiving end of the channel of the request
+    fn send_batch(
+        protocol: &mut TestProtocol,
+        messages: Vec<NetlinkMessage<RtnlMessage>>,
+    ) -> (Vec<u32>, TestReceiver) {
+        let (tx, rx) = unbounded();
+        protocol.request(Request::from((messages, kernel(), tx)));
+        let (sent, _) = protocol.outgoing_messages.pop_front().unwrap();
+        assert!(protocol.outgoing_messages.is_empty());
+        let sequence_numbers = sent.iter().map(|m| m.header.sequence_number).collect();
+        (sequence_numbers, rx)
+    }
+
+    // Forward the responses to their channel, dropping the sender of
+    // the messages that are done, like the `Connection` does
+    fn forward_responses(protocol: &mut TestProtocol) {
+        while let Some(response) = protocol.incoming_responses.pop_front() {
+            let _ = response
+                .metadata
+                .unbounded_send((response.index, response.message));
+        }
+    }
+
+    fn is_closed(rx: &mut TestReceiver) -> bool {
+        loop {
+            match rx.next().now_or_never() {
+                Some(Some(_)) => continue,
+                Some(None) => return true,
+                None => return false,
+            }
+        }
+    }
+
+    #[test]
+    fn batch_messages_get_distinct_sequence_numbers() {
+        let mut protocol = TestProtocol::new();
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+        let (first, _rx1) = send_batch(&mut protocol, vec![request(flags), request(flags)]);
+        let (second, _rx2) = send_batch(&mut protocol, vec![request(flags)]);
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(second, vec![3]);
+        assert_eq!(protocol.pending_requests.len(), 3);
+    }
+
+    #[test]
+    fn batch_responses_are_forwarded_with_their_index() {
+        let mut protocol = TestProtocol::new();
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+        let (seqs, mut rx) = send_batch(
+            &mut protocol,
+            vec![request(flags), request(flags), request(flags)],
+        );
+
+        // The kernel may reply in any order
+        protocol.handle_message(
+            response(seqs[2], 0, NetlinkPayload::Error(error(-17))),
+            kernel(),
+        );
+        protocol.handle_message(
+            response(seqs[0], 0, NetlinkPayload::Ack(error(0))),
+            kernel(),
+        );
+        protocol.handle_message(
+            response(seqs[1], 0, NetlinkPayload::Error(error(-22))),
+            kernel(),
+        );
+        assert!(protocol.incoming_requests.is_empty());
+
+        let indices: Vec<_> = protocol
+            .incoming_responses
+            .iter()
+            .map(|r| r.index)
+            .collect();
+        assert_eq!(indices, vec![2, 0, 1]);
+        assert!(protocol.incoming_responses.iter().all(|r| r.done));
+
+        forward_responses(&mut protocol);
+        let mut received = vec![];
+        while let Some(Some((index, message))) = rx.next().now_or_never() {
+            received.push((index, message.payload));
+        }
+        assert_eq!(
+            received,
+            vec![
+                (2, NetlinkPayload::Error(error(-17))),
+                (0, NetlinkPayload::Ack(error(0))),
+                (1, NetlinkPayload::Error(error(-22))),
+            ]
+        );
+    }
+
+    #[test]
+    fn batch_stream_closes_once_every_message_is_done() {
+        let mut protocol = TestProtocol::new();
+        let (seqs, mut rx) = send_batch(
+            &mut protocol,
+            vec![
+                request(NLM_F_REQUEST | NLM_F_ACK),
+                request(NLM_F_REQUEST | NLM_F_DUMP),
+            ],
+        );
+
+        let link = || NetlinkPayload::InnerMessage(RtnlMessage::NewLink(LinkMessage::default()));
+
+        // A part of the dump does not end the request
+        protocol.handle_message(response(seqs[1], NLM_F_MULTIPART, link()), kernel());
+        forward_responses(&mut protocol);
+        assert_eq!(rx.next().now_or_never().unwrap().unwrap().0, 1);
+        assert!(!is_closed(&mut rx));
+
+        // The first message is acknowledged, but the dump is still
+        // running
+        protocol.handle_message(
+            response(seqs[0], 0, NetlinkPayload::Ack(error(0))),
+            kernel(),
+        );
+        forward_responses(&mut protocol);
+        assert!(!is_closed(&mut rx));
+
+        protocol.handle_message(
+            response(seqs[1], NLM_F_MULTIPART, NetlinkPayload::Done),
+            kernel(),
+        );
+        forward_responses(&mut protocol);
+        assert!(protocol.pending_requests.is_empty());
+        assert!(is_closed(&mut rx));
+    }
+
+    #[test]
+    fn overrun_terminates_pending_requests() {
+        let mut protocol = TestProtocol::new();
+        let flags = NLM_F_REQUEST | NLM_F_ACK;
+        let (seqs, mut rx) = send_batch(&mut protocol, vec![request(flags), request(flags)]);
+
+        // The first message is acknowledged, then the socket overruns
+        // and the ACK of the second message is lost
+        protocol.handle_message(
+            response(seqs[0], 0, NetlinkPayload::Ack(error(0))),
+            kernel(),
+        );
+        protocol.handle_message(response(0, 0, NetlinkPayload::Overrun(vec![])), kernel());
+        assert!(protocol.pending_requests.is_empty());
+
+        forward_responses(&mut protocol);
+        let mut received = vec![];
+        while let Some(Some((index, message))) = rx.next().now_or_never() {
+            received.push((index, message.header.sequence_number, message.payload));
+        }
+        assert_eq!(
+            received,
+            vec![
+                (0, seqs[0], NetlinkPayload::Ack(error(0))),
+                (1, seqs[1], NetlinkPayload::Overrun(vec![])),
+            ]
+        );
+        assert!(is_closed(&mut rx));
+
+        // Monitors still get the overrun
+        assert_eq!(protocol.incoming_requests.len(), 1);
+        assert_eq!(
+            protocol.incoming_requests[0].0.payload,
+            NetlinkPayload::Overrun(vec![])
+        );
+    }
+
+    #[test]
+    fn overrun_drops_remaining_dump_responses() {
+        let mut protocol = TestProtocol::new();
+        let (seqs, mut rx) = send_batch(&mut protocol, vec![request(NLM_F_REQUEST | NLM_F_DUMP)]);
+        let link = || NetlinkPayload::InnerMessage(RtnlMessage::NewLink(LinkMessage::default()));
+
+        protocol.handle_message(response(0, 0, NetlinkPayload::Overrun(vec![])), kernel());
+        forward_responses(&mut protocol);
+        assert_eq!(
+            rx.next().now_or_never().unwrap().unwrap().1.payload,
+            NetlinkPayload::Overrun(vec![])
+        );
+        assert!(is_closed(&mut rx));
+        protocol.incoming_requests.clear();
+
+        // The rest of the dump is neither a response nor a message
+        // for the monitors
+        protocol.handle_message(response(seqs[0], NLM_F_MULTIPART, link()), kernel());
+        protocol.handle_message(
+            response(seqs[0], NLM_F_MULTIPART, NetlinkPayload::Done),
+            kernel(),
+        );
+        assert!(protocol.incoming_responses.is_empty());
+        assert!(protocol.incoming_requests.is_empty());
+        assert!(protocol.overrun_requests.is_empty());
+
+        // Later messages with the same sequence number, such as
+        // notifications, are forwarded again
+        protocol.handle_message(response(seqs[0], 0, link()), kernel());
+        assert_eq!(protocol.incoming_requests.len(), 1);
+    }
+}
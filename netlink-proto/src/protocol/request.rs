@@ -6,10 +6,13 @@ use netlink_packet_core::NetlinkMessage;
 
 use crate::sys::SocketAddr;
 
+/// One or more messages to be sent in a single datagram. The
+/// responses to each message are identified by the index of the
+/// message in `messages`.
 #[derive(Debug)]
 pub(crate) struct Request<T, M> {
     pub metadata: M,
-    pub message: NetlinkMessage<T>,
+    pub messages: Vec<NetlinkMessage<T>>,
     pub destination: SocketAddr,
 }
 
@@ -20,19 +23,33 @@ where
 {
     fn from(parts: (NetlinkMessage<T>, SocketAddr, M)) -> Self {
         Request {
-            message: parts.0,
+            messages: vec![parts.0],
             destination: parts.1,
             metadata: parts.2,
         }
     }
 }
 
-impl<T, M> From<Request<T, M>> for (NetlinkMessage<T>, SocketAddr, M)
+impl<T, M> From<(Vec<NetlinkMessage<T>>, SocketAddr, M)> for Request<T, M>
 where
     T: Debug,
     M: Debug,
 {
-    fn from(req: Request<T, M>) -> (NetlinkMessage<T>, SocketAddr, M) {
-        (req.message, req.destination, req.metadata)
+    fn from(parts: (Vec<NetlinkMessage<T>>, SocketAddr, M)) -> Self {
+        Request {
+            messages: parts.0,
+            destination: parts.1,
+            metadata: parts.2,
+        }
+    }
+}
+
+impl<T, M> From<Request<T, M>> for (Vec<NetlinkMessage<T>>, SocketAddr, M)
+where
+    T: Debug,
+    M: Debug,
+{
+    fn from(req: Request<T, M>) -> (Vec<NetlinkMessage<T>>, SocketAddr, M) {
+        (req.messages, req.destination, req.metadata)
     }
 }
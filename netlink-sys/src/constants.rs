@@ -133,5 +133,6 @@ pub const NETLINK_LISTEN_ALL_NSID: int = 8;
 pub const NETLINK_LIST_MEMBERSHIPS: int = 9;
 pub const NETLINK_CAP_ACK: int = 10;
 pub const NETLINK_EXT_ACK: int = 11;
+pub const NETLINK_GET_STRICT_CHK: int = 12;
 pub const NL_MMAP_MSG_ALIGNMENT: int = 4;
 pub const NET_MAJOR: int = 36;
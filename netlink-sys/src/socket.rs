@@ -6,7 +6,10 @@ use std::{
     os::unix::io::{AsRawFd, FromRawFd, RawFd},
 };
 
-use crate::SocketAddr;
+use crate::{
+    constants::{NETLINK_EXT_ACK, NETLINK_GET_STRICT_CHK},
+    SocketAddr,
+};
 
 /// A netlink socket.
 ///
@@ -446,6 +449,33 @@ impl Socket {
         let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_CAP_ACK)?;
         Ok(res == 1)
     }
+
+    /// `NETLINK_EXT_ACK` (since Linux 4.12). When enabled, error and acknowledgment messages may
+    /// carry extended ACK attributes, such as a human readable error message or the offset of the
+    /// attribute that caused the error.
+    pub fn set_ext_ack(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(self.0, libc::SOL_NETLINK, NETLINK_EXT_ACK, value)
+    }
+
+    pub fn get_ext_ack(&self) -> Result<bool> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, NETLINK_EXT_ACK)?;
+        Ok(res == 1)
+    }
+
+    /// `NETLINK_GET_STRICT_CHK` (since Linux 4.20). When enabled, the kernel strictly checks the
+    /// header and attributes of `RTM_GET*` dump requests, rejecting unknown ones instead of
+    /// ignoring them, and honours the filters they carry (for instance the table, protocol and
+    /// output interface of route dumps).
+    pub fn set_get_strict_chk(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK, value)
+    }
+
+    pub fn get_get_strict_chk(&self) -> Result<bool> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK)?;
+        Ok(res == 1)
+    }
 }
 
 /// Wrapper around `getsockopt`:
@@ -538,6 +568,16 @@ mod test {
         sock.set_cap_ack(false).unwrap();
         assert!(!sock.get_cap_ack().unwrap());
 
+        sock.set_ext_ack(true).unwrap();
+        assert!(sock.get_ext_ack().unwrap());
+        sock.set_ext_ack(false).unwrap();
+        assert!(!sock.get_ext_ack().unwrap());
+
+        sock.set_get_strict_chk(true).unwrap();
+        assert!(sock.get_get_strict_chk().unwrap());
+        sock.set_get_strict_chk(false).unwrap();
+        assert!(!sock.get_get_strict_chk().unwrap());
+
         sock.set_no_enobufs(true).unwrap();
         assert!(sock.get_no_enobufs().unwrap());
         sock.set_no_enobufs(false).unwrap();
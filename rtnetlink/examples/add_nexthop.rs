@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+
+use std::{env, net::IpAddr};
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{new_connection, packet::nlas::nexthop::NexthopGroupEntry, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 4 {
+        usage();
+        return Ok(());
+    }
+    let link_name = &args[1];
+    let gateways: Vec<IpAddr> = args[2..]
+        .iter()
+        .map(|arg| {
+            arg.parse().unwrap_or_else(|_| {
+                eprintln!("invalid gateway address");
+                std::process::exit(1);
+            })
+        })
+        .collect();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    if let Err(e) = add_nexthops(link_name, &gateways, handle.clone()).await {
+        eprintln!("{}", e);
+    }
+    Ok(())
+}
+
+async fn add_nexthops(link_name: &str, gateways: &[IpAddr], handle: Handle) -> Result<(), Error> {
+    let mut links = handle
+        .link()
+        .get()
+        .match_name(link_name.to_string())
+        .execute();
+    let link = match links.try_next().await? {
+        Some(link) => link,
+        None => {
+            eprintln!("link {} not found", link_name);
+            return Ok(());
+        }
+    };
+
+    // One nexthop object per gateway, with identifiers 1, 2, ...
+    let mut entries = vec![];
+    for (id, gateway) in (1..).zip(gateways) {
+        handle
+            .nexthop()
+            .add(id)
+            .output_interface(link.header.index)
+            .gateway(*gateway)
+            .execute()
+            .await?;
+        entries.push(NexthopGroupEntry { id, weight: 0 });
+    }
+
+    // A multipath group balancing traffic between them
+    handle.nexthop().add(100).group(entries).execute().await?;
+
+    let mut nexthops = handle.nexthop().get().execute();
+    while let Some(nexthop) = nexthops.try_next().await? {
+        println!("{:?}", nexthop);
+    }
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example add_nexthop -- <link name> <gateway> <gateway>
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd rtnetlink ; cargo build --example add_nexthop
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./add_nexthop <link name> <gateway> <gateway>"
+    );
+}
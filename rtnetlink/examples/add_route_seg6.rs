@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: MIT
+
+use std::{env, net::Ipv6Addr};
+
+use futures::stream::TryStreamExt;
+use ipnetwork::Ipv6Network;
+use netlink_packet_route::constants::SEG6_IPTUN_MODE_ENCAP;
+use rtnetlink::{new_connection, Error, Handle};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 4 {
+        usage();
+        return Ok(());
+    }
+
+    let dest: Ipv6Network = args[1].parse().unwrap_or_else(|_| {
+        eprintln!("invalid destination");
+        std::process::exit(1);
+    });
+    let link_name = &args[2];
+    let segments: Vec<Ipv6Addr> = args[3..]
+        .iter()
+        .map(|segment| {
+            segment.parse().unwrap_or_else(|_| {
+                eprintln!("invalid segment {}", segment);
+                std::process::exit(1);
+            })
+        })
+        .collect();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    if let Err(e) = add_route(&dest, link_name, &segments, handle.clone()).await {
+        eprintln!("{}", e);
+    }
+    Ok(())
+}
+
+async fn add_route(
+    dest: &Ipv6Network,
+    link_name: &str,
+    segments: &[Ipv6Addr],
+    handle: Handle,
+) -> Result<(), Error> {
+    let mut links = handle
+        .link()
+        .get()
+        .match_name(link_name.to_string())
+        .execute();
+    let link = match links.try_next().await? {
+        Some(link) => link,
+        None => {
+            eprintln!("link {} not found", link_name);
+            return Ok(());
+        }
+    };
+    handle
+        .route()
+        .add()
+        .v6()
+        .destination_prefix(dest.ip(), dest.prefix())
+        .output_interface(link.header.index)
+        .seg6_encap(SEG6_IPTUN_MODE_ENCAP, segments)
+        .execute()
+        .await?;
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example add_route_seg6 -- <destination>/<prefix_length> <link name> <segment>...
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd rtnetlink ; cargo build --example add_route_seg6
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./add_route_seg6 <destination>/<prefix_length> <link name> <segment>..."
+    );
+}
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+
+use std::env;
+
+use rtnetlink::{
+    new_connection,
+    packet::tc::{
+        constants::*,
+        htb::{Nla, TcHtbGlob, TcHtbOpt},
+        TcRateSpec,
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    env_logger::init();
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        usage();
+        return Ok(());
+    }
+
+    let index: u32 = args[1].parse().unwrap_or_else(|_| {
+        eprintln!("invalid index");
+        std::process::exit(1);
+    });
+    let rate: u32 = args[2].parse().unwrap_or_else(|_| {
+        eprintln!("invalid rate");
+        std::process::exit(1);
+    });
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    // tc qdisc add dev <index> root handle 1: htb default 10
+    if let Err(e) = handle
+        .qdisc()
+        .add(index as i32)
+        .root()
+        .handle(1, 0)
+        .htb(vec![Nla::Init(TcHtbGlob {
+            defcls: 0x10,
+            ..Default::default()
+        })])
+        .execute()
+        .await
+    {
+        eprintln!("{}", e);
+        return Ok(());
+    }
+
+    // tc class add dev <index> parent 1: classid 1:10 htb rate <rate>
+    let rate = TcRateSpec {
+        linklayer: TC_LINKLAYER_ETHERNET,
+        rate,
+        ..Default::default()
+    };
+    if let Err(e) = handle
+        .traffic_class(index as i32)
+        .add()
+        .parent(0x10000)
+        .handle(1, 0x10)
+        .htb(vec![Nla::Parms(TcHtbOpt {
+            rate,
+            ceil: rate,
+            // 10ms worth of tokens, in ticks of 64ns
+            buffer: 156_250,
+            cbuffer: 156_250,
+            ..Default::default()
+        })])
+        .execute()
+        .await
+    {
+        eprintln!("{}", e);
+    }
+
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example add_tc_htb_class -- <index> <rate in bytes per second>
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd rtnetlink ; cargo build --example add_tc_htb_class
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./add_tc_htb_class <index> <rate>"
+    );
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{new_connection, Error, Handle};
+use std::{env, net::Ipv4Addr};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        usage();
+        return Ok(());
+    }
+    let link_name = &args[1];
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    create_gretap(handle, link_name.to_string())
+        .await
+        .map_err(|e| format!("{}", e))
+}
+
+async fn create_gretap(handle: Handle, name: String) -> Result<(), Error> {
+    let mut links = handle.link().get().match_name(name.clone()).execute();
+    if let Some(link) = links.try_next().await? {
+        handle
+            .link()
+            .add()
+            .gretap("gretap0".into())
+            .link(link.header.index)
+            .local(Ipv4Addr::new(192, 0, 2, 1).into())
+            .remote(Ipv4Addr::new(192, 0, 2, 2).into())
+            .key(42)
+            .up()
+            .execute()
+            .await?
+    } else {
+        println!("no link link {} found", name);
+    }
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example create_gretap -- <link name>
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd netlink-ip ; cargo build --example create_gretap
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./create_gretap <link_name>"
+    );
+}
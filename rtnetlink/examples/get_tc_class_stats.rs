@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use std::env;
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{
+    new_connection,
+    packet::tc::{Nla, Stats2},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), ()> {
+    env_logger::init();
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        usage();
+        return Ok(());
+    }
+
+    let index: u32 = args[1].parse().unwrap_or_else(|_| {
+        eprintln!("invalid index");
+        std::process::exit(1);
+    });
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut classes = handle.traffic_class(index as i32).get().execute();
+    loop {
+        let class = match classes.try_next().await {
+            Ok(Some(class)) => class,
+            Ok(None) => break,
+            Err(e) => {
+                eprintln!("{}", e);
+                break;
+            }
+        };
+        let classid = class.header.handle;
+        print!("class {:x}:{:x}", classid >> 16, classid & 0xffff);
+        for nla in class.nlas.iter() {
+            if let Nla::Stats2(stats) = nla {
+                for stat in stats {
+                    match stat {
+                        Stats2::StatsBasic(basic) => {
+                            print!(" sent {} bytes {} pkt", basic.bytes, basic.packets)
+                        }
+                        Stats2::StatsQueue(queue) => print!(
+                            " (dropped {}, overlimits {}) backlog {}b {}p",
+                            queue.drops, queue.overlimits, queue.backlog, queue.qlen
+                        ),
+                        Stats2::StatsRateEst(est) => {
+                            print!(" rate {}bps {}pps", est.bps * 8, est.pps)
+                        }
+                        Stats2::StatsRateEst64(est) => {
+                            print!(" rate {}bps {}pps", est.bps * 8, est.pps)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example get_tc_class_stats -- <index>
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd rtnetlink ; cargo build --example get_tc_class_stats
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./get_tc_class_stats <index>"
+    );
+}
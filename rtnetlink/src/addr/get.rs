@@ -60,6 +60,7 @@ impl AddressGetRequest {
 
     /// Return only the addresses of the given interface.
     pub fn set_link_index_filter(mut self, index: u32) -> Self {
+        self.message.header.index = index;
         self.filter_builder.index = Some(index);
         self
     }
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::link::{AfSpecBridge, BridgeVlanInfo, BridgeVlanTunnelInfo, Nla},
+    LinkMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to add a VLAN, or a range of VLANs, to a bridge or to a
+/// bridge port
+pub struct BridgeVlanAddRequest {
+    handle: Handle,
+    message: LinkMessage,
+    vlan: BridgeVlan,
+}
+
+// The VLANs of `RTM_SETLINK` and `RTM_DELLINK` requests, sent in
+// `IFLA_AF_SPEC` when the request is executed.
+pub(crate) struct BridgeVlan {
+    pub(crate) vid: u16,
+    pub(crate) last: Option<u16>,
+    pub(crate) flags: u16,
+    pub(crate) bridge_flags: u16,
+    pub(crate) tunnel_id: Option<u32>,
+}
+
+impl BridgeVlan {
+    pub(crate) fn new(vid: u16) -> Self {
+        BridgeVlan {
+            vid,
+            last: None,
+            flags: 0,
+            bridge_flags: 0,
+            tunnel_id: None,
+        }
+    }
+
+    // A range is sent as two entries, flagged with
+    // `BRIDGE_VLAN_INFO_RANGE_BEGIN` and `BRIDGE_VLAN_INFO_RANGE_END`. A
+    // tunnel mapping is sent instead of the VLAN entries, with the tunnel ids
+    // of a range following its VLANs.
+    pub(crate) fn to_nla(&self) -> Nla {
+        let mut nlas = vec![];
+        if self.bridge_flags != 0 {
+            nlas.push(AfSpecBridge::Flags(self.bridge_flags));
+        }
+        match (self.tunnel_id, self.last) {
+            (Some(id), Some(last)) => {
+                nlas.push(AfSpecBridge::VlanTunnelInfo(vec![
+                    BridgeVlanTunnelInfo::Id(id),
+                    BridgeVlanTunnelInfo::Vid(self.vid),
+                    BridgeVlanTunnelInfo::Flags(BRIDGE_VLAN_INFO_RANGE_BEGIN),
+                ]));
+                nlas.push(AfSpecBridge::VlanTunnelInfo(vec![
+                    BridgeVlanTunnelInfo::Id(id + u32::from(last.saturating_sub(self.vid))),
+                    BridgeVlanTunnelInfo::Vid(last),
+                    BridgeVlanTunnelInfo::Flags(BRIDGE_VLAN_INFO_RANGE_END),
+                ]));
+            }
+            (Some(id), None) => {
+                nlas.push(AfSpecBridge::VlanTunnelInfo(vec![
+                    BridgeVlanTunnelInfo::Id(id),
+                    BridgeVlanTunnelInfo::Vid(self.vid),
+                    BridgeVlanTunnelInfo::Flags(0),
+                ]));
+            }
+            (None, Some(last)) => {
+                nlas.push(AfSpecBridge::VlanInfo(BridgeVlanInfo {
+                    flags: self.flags | BRIDGE_VLAN_INFO_RANGE_BEGIN,
+                    vid: self.vid,
+                }));
+                nlas.push(AfSpecBridge::VlanInfo(BridgeVlanInfo {
+                    flags: self.flags | BRIDGE_VLAN_INFO_RANGE_END,
+                    vid: last,
+                }));
+            }
+            (None, None) => {
+                nlas.push(AfSpecBridge::VlanInfo(BridgeVlanInfo {
+                    flags: self.flags,
+                    vid: self.vid,
+                }));
+            }
+        }
+        Nla::AfSpecBridge(nlas)
+    }
+}
+
+impl BridgeVlanAddRequest {
+    pub(crate) fn new(handle: Handle, index: u32, vid: u16) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.interface_family = AF_BRIDGE as u8;
+        message.header.index = index;
+
+        BridgeVlanAddRequest {
+            handle,
+            message,
+            vlan: BridgeVlan::new(vid),
+        }
+    }
+
+    /// Add all the VLANs from the first one up to `last`.
+    pub fn range(mut self, last: u16) -> Self {
+        self.vlan.last = Some(last);
+        self
+    }
+
+    /// Use the VLAN for the untagged packets received on the port.
+    pub fn pvid(mut self) -> Self {
+        self.vlan.flags |= BRIDGE_VLAN_INFO_PVID;
+        self
+    }
+
+    /// Send the packets of the VLAN untagged.
+    pub fn untagged(mut self) -> Self {
+        self.vlan.flags |= BRIDGE_VLAN_INFO_UNTAGGED;
+        self
+    }
+
+    /// Add the VLAN to the bridge the port is attached to. This is the
+    /// default.
+    pub fn master(mut self) -> Self {
+        self.vlan.bridge_flags |= BRIDGE_FLAGS_MASTER;
+        self
+    }
+
+    /// Add the VLAN to the interface itself, such as the bridge device
+    /// or the driver of a switch port.
+    pub fn self_(mut self) -> Self {
+        self.vlan.bridge_flags |= BRIDGE_FLAGS_SELF;
+        self
+    }
+
+    /// Map the VLAN to the given tunnel id, such as a VNI on a VXLAN bridge
+    /// port in collect metadata mode. For a range, the VLANs are mapped to
+    /// consecutive tunnel ids. The VLANs must already exist on the port, as
+    /// only the mapping is added.
+    pub fn tunnel_id(mut self, id: u32) -> Self {
+        self.vlan.tunnel_id = Some(id);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanAddRequest {
+            mut handle,
+            mut message,
+            vlan,
+        } = self;
+
+        message.nlas.push(vlan.to_nla());
+        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+}
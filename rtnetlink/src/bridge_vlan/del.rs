@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{constants::*, LinkMessage, NetlinkMessage, RtnlMessage};
+
+use crate::{bridge_vlan::add::BridgeVlan, try_nl, Error, Handle};
+
+/// A request to delete a VLAN, or a range of VLANs, from a bridge or from a
+/// bridge port
+pub struct BridgeVlanDelRequest {
+    handle: Handle,
+    message: LinkMessage,
+    vlan: BridgeVlan,
+}
+
+impl BridgeVlanDelRequest {
+    pub(crate) fn new(handle: Handle, index: u32, vid: u16) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.interface_family = AF_BRIDGE as u8;
+        message.header.index = index;
+
+        BridgeVlanDelRequest {
+            handle,
+            message,
+            vlan: BridgeVlan::new(vid),
+        }
+    }
+
+    /// Delete all the VLANs from the first one up to `last`.
+    pub fn range(mut self, last: u16) -> Self {
+        self.vlan.last = Some(last);
+        self
+    }
+
+    /// Delete the VLAN from the bridge the port is attached to. This is the
+    /// default.
+    pub fn master(mut self) -> Self {
+        self.vlan.bridge_flags |= BRIDGE_FLAGS_MASTER;
+        self
+    }
+
+    /// Delete the VLAN from the interface itself.
+    pub fn self_(mut self) -> Self {
+        self.vlan.bridge_flags |= BRIDGE_FLAGS_SELF;
+        self
+    }
+
+    /// Delete the mapping of the VLAN to the given tunnel id, instead of
+    /// the VLAN itself.
+    pub fn tunnel_id(mut self, id: u32) -> Self {
+        self.vlan.tunnel_id = Some(id);
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanDelRequest {
+            mut handle,
+            mut message,
+            vlan,
+        } = self;
+
+        message.nlas.push(vlan.to_nla());
+        let mut req = NetlinkMessage::from(RtnlMessage::DelLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+}
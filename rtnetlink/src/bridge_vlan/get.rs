@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    bridge_vlan::{BridgeVlanMessage, Nla},
+    constants::*,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+/// A request to dump the VLANs of the bridges and of their ports
+pub struct BridgeVlanGetRequest {
+    handle: Handle,
+    message: BridgeVlanMessage,
+    dump_flags: u32,
+}
+
+impl BridgeVlanGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = BridgeVlanMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        BridgeVlanGetRequest {
+            handle,
+            message,
+            dump_flags: 0,
+        }
+    }
+
+    /// Return only the VLANs of the given bridge or bridge port (equivalent
+    /// to `bridge vlan show dev`).
+    pub fn match_interface(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self
+    }
+
+    /// Include the traffic statistics of each VLAN (equivalent to `bridge
+    /// -s vlan show`). Ranges are then split into single VLANs.
+    pub fn stats(mut self) -> Self {
+        self.dump_flags |= BRIDGE_VLANDB_DUMPF_STATS;
+        self
+    }
+
+    /// Return the global options of the VLANs of the bridges, instead of
+    /// the VLANs of their ports (equivalent to `bridge vlan global show`).
+    pub fn global(mut self) -> Self {
+        self.dump_flags |= BRIDGE_VLANDB_DUMPF_GLOBAL;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut BridgeVlanMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = BridgeVlanMessage, Error = Error> {
+        let BridgeVlanGetRequest {
+            mut handle,
+            mut message,
+            dump_flags,
+        } = self;
+
+        if dump_flags != 0 {
+            message.nlas.push(Nla::DumpFlags(dump_flags));
+        }
+        let mut req = NetlinkMessage::from(RtnlMessage::GetBridgeVlan(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewBridgeVlan))),
+            ),
+            Err(e) => Either::Right(future::err::<BridgeVlanMessage, Error>(e).into_stream()),
+        }
+    }
+}
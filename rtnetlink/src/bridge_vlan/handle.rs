@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    BridgeVlanAddRequest,
+    BridgeVlanDelRequest,
+    BridgeVlanGetRequest,
+    BridgeVlanSetRequest,
+    Handle,
+};
+
+pub struct BridgeVlanHandle(Handle);
+
+impl BridgeVlanHandle {
+    pub fn new(handle: Handle) -> Self {
+        BridgeVlanHandle(handle)
+    }
+
+    /// List the VLANs of the bridges and of their ports, along with their
+    /// options (equivalent to `bridge vlan show`)
+    pub fn get(&self) -> BridgeVlanGetRequest {
+        BridgeVlanGetRequest::new(self.0.clone())
+    }
+
+    /// Add a VLAN, or a range of VLANs, to the given bridge or bridge port
+    /// (equivalent to `bridge vlan add`)
+    pub fn add(&self, index: u32, vid: u16) -> BridgeVlanAddRequest {
+        BridgeVlanAddRequest::new(self.0.clone(), index, vid)
+    }
+
+    /// Delete a VLAN, or a range of VLANs, from the given bridge or bridge
+    /// port (equivalent to `bridge vlan del`)
+    pub fn del(&self, index: u32, vid: u16) -> BridgeVlanDelRequest {
+        BridgeVlanDelRequest::new(self.0.clone(), index, vid)
+    }
+
+    /// Change the options, such as the STP state, of a VLAN or of a range
+    /// of VLANs of the given bridge or bridge port (equivalent to `bridge
+    /// vlan set`)
+    pub fn set(&self, index: u32, vid: u16) -> BridgeVlanSetRequest {
+        BridgeVlanSetRequest::new(self.0.clone(), index, vid)
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+mod set;
+pub use self::set::*;
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{
+    bridge_vlan::{BridgeVlanMessage, Nla, VlanEntry, VlanTunnelInfo},
+    constants::*,
+    nlas::link::BridgeVlanInfo,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to change the options of existing VLANs of a bridge or of a
+/// bridge port
+pub struct BridgeVlanSetRequest {
+    handle: Handle,
+    message: BridgeVlanMessage,
+    entry: Vec<VlanEntry>,
+}
+
+impl BridgeVlanSetRequest {
+    pub(crate) fn new(handle: Handle, index: u32, vid: u16) -> Self {
+        let mut message = BridgeVlanMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+
+        // Only change the options of the VLANs, without creating them or
+        // changing their flags
+        let entry = vec![VlanEntry::Info(BridgeVlanInfo {
+            flags: BRIDGE_VLAN_INFO_ONLY_OPTS,
+            vid,
+        })];
+
+        BridgeVlanSetRequest {
+            handle,
+            message,
+            entry,
+        }
+    }
+
+    /// Change all the VLANs from the first one up to `last`.
+    pub fn range(mut self, last: u16) -> Self {
+        self.entry.push(VlanEntry::Range(last));
+        self
+    }
+
+    /// Set the STP state of the VLANs, one of the `BR_STATE_*` constants.
+    pub fn state(mut self, state: u8) -> Self {
+        self.entry.push(VlanEntry::State(state));
+        self
+    }
+
+    /// Set the multicast router mode of the port for the VLANs: `0` to
+    /// disable it, `1` to learn it from queries, `2` to always enable it.
+    pub fn mcast_router(mut self, mode: u8) -> Self {
+        self.entry.push(VlanEntry::McastRouter(mode));
+        self
+    }
+
+    /// Enable or disable the suppression of ARP and neighbour discovery
+    /// packets for the VLANs.
+    pub fn neighbour_suppress(mut self, enabled: bool) -> Self {
+        self.entry.push(VlanEntry::NeighSuppress(enabled.into()));
+        self
+    }
+
+    /// Map the VLAN to the given tunnel id.
+    pub fn tunnel_id(mut self, id: u32) -> Self {
+        self.entry.push(VlanEntry::TunnelInfo(vec![
+            VlanTunnelInfo::Id(id),
+            VlanTunnelInfo::Cmd(RTM_SETLINK as u32),
+        ]));
+        self
+    }
+
+    /// Return a mutable reference to the request message. The options of the
+    /// request are added to its `BRIDGE_VLANDB_ENTRY` attribute when it is
+    /// executed.
+    pub fn message_mut(&mut self) -> &mut BridgeVlanMessage {
+        &mut self.message
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanSetRequest {
+            mut handle,
+            mut message,
+            entry,
+        } = self;
+
+        message.nlas.push(Nla::Entry(entry));
+        let mut req = NetlinkMessage::from(RtnlMessage::NewBridgeVlan(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+}
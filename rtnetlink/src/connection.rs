@@ -30,6 +30,12 @@ pub fn new_connection_with_socket<S>() -> io::Result<(
 where
     S: AsyncSocket,
 {
-    let (conn, handle, messages) = netlink_proto::new_connection_with_socket(NETLINK_ROUTE)?;
+    let (mut conn, handle, messages) =
+        netlink_proto::new_connection_with_socket::<RtnlMessage, S>(NETLINK_ROUTE)?;
+    conn.enable_ext_ack();
+    // Have the kernel check and honour the filters of dump requests.
+    // Older kernels (before 4.20) ignore most of them, so the get
+    // requests still filter the dumped messages.
+    let _ = conn.socket_mut().socket_mut().set_get_strict_chk(true);
     Ok((conn, Handle::new(handle), messages))
 }
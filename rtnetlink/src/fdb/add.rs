@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    neighbour::{NeighbourMessage, Nla},
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to add a bridge or VXLAN forwarding database entry
+pub struct FdbAddRequest {
+    handle: Handle,
+    message: NeighbourMessage,
+    replace: bool,
+    append: bool,
+}
+
+impl FdbAddRequest {
+    pub(crate) fn new(handle: Handle, index: u32, address: &[u8]) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message.header.state = NUD_NOARP;
+        message.nlas.push(Nla::LinkLocalAddress(address.to_vec()));
+
+        FdbAddRequest {
+            handle,
+            message,
+            replace: false,
+            append: false,
+        }
+    }
+
+    /// Add the entry to the forwarding database of the master device, the
+    /// bridge the interface is a port of.
+    pub fn master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Add the entry to the forwarding database of the interface itself,
+    /// such as a VXLAN device or a port whose driver has its own database.
+    /// This is the default when [`master`](Self::master) is not set.
+    pub fn self_(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Make the entry permanent. For a bridge, it is then a local address,
+    /// which is not forwarded. This is the default when
+    /// [`static_`](Self::static_) is not set, as with `bridge fdb add`.
+    pub fn permanent(mut self) -> Self {
+        self.message.header.state |= NUD_PERMANENT;
+        self
+    }
+
+    /// Make the entry a static entry, forwarded to the interface.
+    pub fn static_(mut self) -> Self {
+        self.message.header.state |= NUD_REACHABLE;
+        self
+    }
+
+    /// Make the entry dynamic, so that it ages out.
+    pub fn dynamic(mut self) -> Self {
+        self.message.header.state |= NUD_REACHABLE;
+        self.message.header.state &= !NUD_NOARP;
+        self
+    }
+
+    /// Mark the entry as learned by an external control plane.
+    pub fn extern_learn(mut self) -> Self {
+        self.message.header.flags |= NTF_EXT_LEARNED;
+        self
+    }
+
+    /// Prevent the entry from moving to another port. It can't be set on
+    /// permanent entries.
+    pub fn sticky(mut self) -> Self {
+        self.message.header.flags |= NTF_STICKY;
+        self
+    }
+
+    /// Set the VLAN of the entry, for VLAN-aware bridges.
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.message.nlas.push(Nla::Vlan(vlan));
+        self
+    }
+
+    /// Set the remote VTEP address of a VXLAN entry.
+    pub fn destination(mut self, addr: IpAddr) -> Self {
+        self.message.nlas.push(Nla::Destination(match addr {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        }));
+        self
+    }
+
+    /// Set the VNI of a VXLAN entry, when it differs from the VNI of the
+    /// device.
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::Vni(vni));
+        self
+    }
+
+    /// Set the source VNI of an entry of a VXLAN device in collect
+    /// metadata mode.
+    pub fn source_vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::SourceVni(vni));
+        self
+    }
+
+    /// Set the UDP destination port of a VXLAN entry.
+    pub fn port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::Port(port));
+        self
+    }
+
+    /// Set the interface used to reach the remote VTEP of a VXLAN entry.
+    pub fn via(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
+    /// Set the nexthop group of a VXLAN entry, see
+    /// [`Handle::nexthop`](crate::Handle::nexthop).
+    pub fn nexthop_id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::NhId(id));
+        self
+    }
+
+    /// Replace the existing entry, if any.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Add a remote to the existing entry, instead of failing if it exists
+    /// (equivalent to `bridge fdb append`). The all-zeros address entry of
+    /// a VXLAN device may have several remotes, to which broadcast, unknown
+    /// unicast and multicast traffic is replicated.
+    pub fn append(self) -> Self {
+        Self {
+            append: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let (mut handle, req) = self.build();
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    fn build(self) -> (Handle, NetlinkMessage<RtnlMessage>) {
+        let FdbAddRequest {
+            handle,
+            mut message,
+            replace,
+            append,
+        } = self;
+
+        if message.header.state & (NUD_PERMANENT | NUD_REACHABLE) == 0 {
+            message.header.state |= NUD_PERMANENT;
+        }
+        if message.header.flags & (NTF_MASTER | NTF_SELF) == 0 {
+            message.header.flags |= NTF_SELF;
+        }
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNeighbour(message));
+        let mode = if replace {
+            NLM_F_REPLACE
+        } else if append {
+            NLM_F_APPEND
+        } else {
+            NLM_F_EXCL
+        };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | mode | NLM_F_CREATE;
+        (handle, req)
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use netlink_packet_route::NetlinkPayload;
+
+    use super::*;
+    use crate::new_connection;
+
+    const MAC: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+
+    fn state(request: FdbAddRequest) -> u16 {
+        let (_, req) = request.build();
+        match req.payload {
+            NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(message)) => {
+                message.header.state
+            }
+            payload => panic!("unexpected payload {:?}", payload),
+        }
+    }
+
+    #[tokio::test]
+    async fn entry_state() {
+        let (_, handle, _) = new_connection().unwrap();
+        let fdb = handle.fdb();
+
+        assert_eq!(state(fdb.add(1, &MAC)), NUD_NOARP | NUD_PERMANENT);
+        assert_eq!(
+            state(fdb.add(1, &MAC).permanent()),
+            NUD_NOARP | NUD_PERMANENT
+        );
+        assert_eq!(state(fdb.add(1, &MAC).static_()), NUD_NOARP | NUD_REACHABLE);
+        assert_eq!(state(fdb.add(1, &MAC).dynamic()), NUD_REACHABLE);
+    }
+}
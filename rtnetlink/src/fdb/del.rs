@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    neighbour::{NeighbourMessage, Nla},
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to delete a bridge or VXLAN forwarding database entry
+pub struct FdbDelRequest {
+    handle: Handle,
+    message: NeighbourMessage,
+}
+
+impl FdbDelRequest {
+    pub(crate) fn new(handle: Handle, index: u32, address: &[u8]) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message.nlas.push(Nla::LinkLocalAddress(address.to_vec()));
+
+        FdbDelRequest { handle, message }
+    }
+
+    /// Delete the entry from the forwarding database of the master device.
+    pub fn master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Delete the entry from the forwarding database of the interface
+    /// itself. This is the default when [`master`](Self::master) is not set.
+    pub fn self_(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Set the VLAN of the entry.
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.message.nlas.push(Nla::Vlan(vlan));
+        self
+    }
+
+    /// Set the remote VTEP address of the VXLAN entry. Without it, all the
+    /// remotes of the entry are deleted.
+    pub fn destination(mut self, addr: IpAddr) -> Self {
+        self.message.nlas.push(Nla::Destination(match addr {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        }));
+        self
+    }
+
+    /// Set the VNI of the VXLAN entry.
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::Vni(vni));
+        self
+    }
+
+    /// Set the source VNI of the VXLAN entry.
+    pub fn source_vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::SourceVni(vni));
+        self
+    }
+
+    /// Set the UDP destination port of the VXLAN entry.
+    pub fn port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::Port(port));
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let FdbDelRequest {
+            mut handle,
+            mut message,
+        } = self;
+
+        if message.header.flags & (NTF_MASTER | NTF_SELF) == 0 {
+            message.header.flags |= NTF_SELF;
+        }
+        let mut req = NetlinkMessage::from(RtnlMessage::DelNeighbour(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+}
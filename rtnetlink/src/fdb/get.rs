@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    neighbour::{NeighbourMessage, Nla},
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+/// A request to dump the bridge and VXLAN forwarding database entries
+pub struct FdbGetRequest {
+    handle: Handle,
+    message: NeighbourMessage,
+}
+
+impl FdbGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        FdbGetRequest { handle, message }
+    }
+
+    /// Return only the entries of the given interface, a bridge port or a
+    /// VXLAN device (equivalent to `bridge fdb show brport`).
+    pub fn match_port(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self
+    }
+
+    /// Return only the entries of the given bridge and of its ports
+    /// (equivalent to `bridge fdb show br`).
+    pub fn match_bridge(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(index));
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = NeighbourMessage, Error = Error> {
+        let FdbGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let filter = FdbFilter::new(&message);
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNeighbour(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNeighbour)))
+                    .try_filter(move |msg| future::ready(filter.matches(msg))),
+            ),
+            Err(e) => Either::Right(future::err::<NeighbourMessage, Error>(e).into_stream()),
+        }
+    }
+}
+
+// Kernels without `NETLINK_GET_STRICT_CHK` support ignore the filters of
+// the request, so they are applied again on the dumped entries.
+struct FdbFilter {
+    port: u32,
+    bridge: Option<u32>,
+}
+
+impl FdbFilter {
+    fn new(request: &NeighbourMessage) -> Self {
+        FdbFilter {
+            port: request.header.ifindex,
+            bridge: master(request),
+        }
+    }
+
+    fn matches(&self, entry: &NeighbourMessage) -> bool {
+        if self.port != 0 && entry.header.ifindex != self.port {
+            return false;
+        }
+        if let Some(bridge) = self.bridge {
+            // The entries of the bridge device itself have no master
+            return entry.header.ifindex == bridge || master(entry) == Some(bridge);
+        }
+        true
+    }
+}
+
+fn master(message: &NeighbourMessage) -> Option<u32> {
+    message.nlas.iter().find_map(|nla| match nla {
+        Nla::Master(index) => Some(*index),
+        _ => None,
+    })
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{FdbAddRequest, FdbDelRequest, FdbGetRequest, Handle};
+
+pub struct FdbHandle(Handle);
+
+impl FdbHandle {
+    pub fn new(handle: Handle) -> Self {
+        FdbHandle(handle)
+    }
+
+    /// List the forwarding database entries of the bridges and of the
+    /// VXLAN devices (equivalent to `bridge fdb show`)
+    pub fn get(&self) -> FdbGetRequest {
+        FdbGetRequest::new(self.0.clone())
+    }
+
+    /// Add a forwarding database entry for the given link layer address on
+    /// the given interface (equivalent to `bridge fdb add`)
+    pub fn add(&self, index: u32, address: &[u8]) -> FdbAddRequest {
+        FdbAddRequest::new(self.0.clone(), index, address)
+    }
+
+    /// Delete the forwarding database entry of the given link layer
+    /// address on the given interface (equivalent to `bridge fdb del`)
+    pub fn del(&self, index: u32, address: &[u8]) -> FdbDelRequest {
+        FdbDelRequest::new(self.0.clone(), index, address)
+    }
+}
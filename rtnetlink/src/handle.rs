@@ -5,12 +5,17 @@ use futures::Stream;
 use crate::{
     packet::{NetlinkMessage, RtnlMessage},
     AddressHandle,
+    BridgeVlanHandle,
     Error,
+    FdbHandle,
     LinkHandle,
     NeighbourHandle,
+    NexthopHandle,
     QDiscHandle,
     RouteHandle,
     RuleHandle,
+    StatsHandle,
+    TrafficActionHandle,
     TrafficChainHandle,
     TrafficClassHandle,
     TrafficFilterHandle,
@@ -34,6 +39,18 @@ impl Handle {
             .map_err(|_| Error::RequestFailed)
     }
 
+    /// Send several messages in a single datagram. The responses are tagged with the index of
+    /// the message they belong to. See
+    /// [`ConnectionHandle::request_batch`](netlink_proto::ConnectionHandle::request_batch).
+    pub fn request_batch(
+        &mut self,
+        messages: Vec<NetlinkMessage<RtnlMessage>>,
+    ) -> Result<impl Stream<Item = (usize, NetlinkMessage<RtnlMessage>)>, Error> {
+        self.0
+            .request_batch(messages, SocketAddr::new(0, 0))
+            .map_err(|_| Error::RequestFailed)
+    }
+
     pub fn notify(&mut self, msg: NetlinkMessage<RtnlMessage>) -> Result<(), Error> {
         self.0
             .notify(msg, SocketAddr::new(0, 0))
@@ -61,11 +78,35 @@ impl Handle {
         RuleHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for nexthop object requests (equivalent to `ip nexthop`
+    /// commands)
+    pub fn nexthop(&self) -> NexthopHandle {
+        NexthopHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for routing neighbours requests (equivalent to `ip neighbour` commands)
     pub fn neighbours(&self) -> NeighbourHandle {
         NeighbourHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for bridge forwarding database requests (equivalent to
+    /// `bridge fdb` commands)
+    pub fn fdb(&self) -> FdbHandle {
+        FdbHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge VLAN requests (equivalent to `bridge vlan`
+    /// commands)
+    pub fn bridge_vlan(&self) -> BridgeVlanHandle {
+        BridgeVlanHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for link statistics requests (equivalent to `ip
+    /// stats` commands)
+    pub fn stats(&self) -> StatsHandle {
+        StatsHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for traffic control qdisc requests
     /// (equivalent to `tc qdisc show` commands)
     pub fn qdisc(&self) -> QDiscHandle {
@@ -89,4 +130,10 @@ impl Handle {
     pub fn traffic_chain(&self, ifindex: i32) -> TrafficChainHandle {
         TrafficChainHandle::new(self.clone(), ifindex)
     }
+
+    /// Create a new handle, specifically for traffic control action requests
+    /// (equivalent to `tc actions` commands)
+    pub fn traffic_action(&self) -> TrafficActionHandle {
+        TrafficActionHandle::new(self.clone())
+    }
 }
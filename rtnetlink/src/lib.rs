@@ -26,15 +26,30 @@ pub use crate::route::*;
 mod rule;
 pub use crate::rule::*;
 
+mod nexthop;
+pub use crate::nexthop::*;
+
+mod stats;
+pub use crate::stats::*;
+
 mod connection;
 pub use crate::connection::*;
 
+mod monitor;
+pub use crate::monitor::*;
+
 mod traffic_control;
 pub use crate::traffic_control::*;
 
 mod neighbour;
 pub use crate::neighbour::*;
 
+mod fdb;
+pub use crate::fdb::*;
+
+mod bridge_vlan;
+pub use crate::bridge_vlan::*;
+
 pub mod constants;
 
 pub use netlink_packet_route as packet;
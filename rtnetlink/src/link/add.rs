@@ -2,7 +2,7 @@
 
 use futures::stream::StreamExt;
 use netlink_packet_route::link::nlas::InfoMacVtap;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use crate::{
     packet::{
@@ -10,9 +10,12 @@ use crate::{
             Info,
             InfoBond,
             InfoData,
+            InfoGreTun,
+            InfoIpTun,
             InfoKind,
             InfoMacVlan,
             InfoVlan,
+            InfoVti,
             InfoVxlan,
             Nla,
             VethInfo,
@@ -20,6 +23,9 @@ use crate::{
         LinkMessage,
         NetlinkMessage,
         RtnlMessage,
+        GRE_CSUM,
+        GRE_KEY,
+        GRE_SEQ,
         IFF_UP,
         NLM_F_ACK,
         NLM_F_CREATE,
@@ -494,6 +500,367 @@ impl VxlanAddRequest {
     }
 }
 
+/// A request to create a new GRE link. This is equivalent to the
+/// `ip link add NAME type { gre | gretap | ip6gre | ip6gretap } ...` commands.
+/// It provides methods to customize the creation of the tunnel interface.
+pub struct GreAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    iflags: u16,
+    oflags: u16,
+    info_data: Vec<InfoGreTun>,
+}
+
+impl GreAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let GreAddRequest {
+            request,
+            kind,
+            iflags,
+            oflags,
+            mut info_data,
+        } = self;
+        if iflags != 0 {
+            info_data.push(InfoGreTun::IFlags(iflags));
+        }
+        if oflags != 0 {
+            info_data.push(InfoGreTun::OFlags(oflags));
+        }
+        let data = match kind {
+            InfoKind::GreTap => InfoData::GreTap(info_data),
+            InfoKind::GreTap6 => InfoData::GreTap6(info_data),
+            InfoKind::GreTun6 => InfoData::GreTun6(info_data),
+            _ => InfoData::GreTun(info_data),
+        };
+        request.link_info(kind, Some(data)).execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre dev LINK`,
+    /// but instead of specifing a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoGreTun::Link(index));
+        self
+    }
+
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre local ADDR`.
+    /// The address must be an IPv4 address for `gre` and `gretap` links, and
+    /// an IPv6 address for `ip6gre` and `ip6gretap` links.
+    pub fn local(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoGreTun::Local(addr));
+        self
+    }
+
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre remote ADDR`.
+    /// The address must be an IPv4 address for `gre` and `gretap` links, and
+    /// an IPv6 address for `ip6gre` and `ip6gretap` links.
+    pub fn remote(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoGreTun::Remote(addr));
+        self
+    }
+
+    /// Adds the `ttl` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre ttl TTL`.
+    /// For `ip6gre` links, this is the `hoplimit`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoGreTun::Ttl(ttl));
+        self
+    }
+
+    /// Adds the `tos` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre tos TOS`.
+    /// For `ip6gre` links, this is the `tclass`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoGreTun::Tos(tos));
+        self
+    }
+
+    /// Adds the `pmtudisc` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre [no]pmtudisc`.
+    pub fn pmtudisc(mut self, pmtudisc: u8) -> Self {
+        self.info_data.push(InfoGreTun::PMtuDisc(pmtudisc));
+        self
+    }
+
+    /// Adds the `ikey` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre ikey KEY`.
+    pub fn ikey(mut self, key: u32) -> Self {
+        self.iflags |= GRE_KEY;
+        self.info_data.push(InfoGreTun::IKey(key));
+        self
+    }
+
+    /// Adds the `okey` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre okey KEY`.
+    pub fn okey(mut self, key: u32) -> Self {
+        self.oflags |= GRE_KEY;
+        self.info_data.push(InfoGreTun::OKey(key));
+        self
+    }
+
+    /// Adds both the `ikey` and `okey` attributes to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre key KEY`.
+    pub fn key(self, key: u32) -> Self {
+        self.ikey(key).okey(key)
+    }
+
+    /// Enable checksums on incoming and outgoing packets
+    /// This is equivalent to `ip link add name NAME type gre csum`.
+    pub fn csum(mut self) -> Self {
+        self.iflags |= GRE_CSUM;
+        self.oflags |= GRE_CSUM;
+        self
+    }
+
+    /// Enable sequencing of incoming and outgoing packets
+    /// This is equivalent to `ip link add name NAME type gre seq`.
+    pub fn seq(mut self) -> Self {
+        self.iflags |= GRE_SEQ;
+        self.oflags |= GRE_SEQ;
+        self
+    }
+
+    /// Adds the `encaplimit` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ip6gre encaplimit LIMIT`.
+    pub fn encap_limit(mut self, limit: u8) -> Self {
+        self.info_data.push(InfoGreTun::EncapLimit(limit));
+        self
+    }
+
+    /// Adds the `flowlabel` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ip6gre flowlabel FLOWLABEL`.
+    pub fn flowinfo(mut self, flowinfo: u32) -> Self {
+        self.info_data.push(InfoGreTun::FlowInfo(flowinfo));
+        self
+    }
+
+    /// Adds the IPv6 tunnel flags to the tunnel (see the `IP6_TNL_F_*` constants).
+    /// This is only meaningful for `ip6gre` and `ip6gretap` links.
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.info_data.push(InfoGreTun::Flags(flags));
+        self
+    }
+
+    /// Adds the `encap` attributes to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre encap TYPE encap-sport SPORT encap-dport DPORT`.
+    /// `encap_type` is one of the `TUNNEL_ENCAP_*` constants.
+    pub fn encap(mut self, encap_type: u16, sport: u16, dport: u16) -> Self {
+        self.info_data.push(InfoGreTun::EncapType(encap_type));
+        self.info_data.push(InfoGreTun::EncapSport(sport));
+        self.info_data.push(InfoGreTun::EncapDport(dport));
+        self
+    }
+
+    /// Adds the `external` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoGreTun::CollectMetadata);
+        self
+    }
+
+    /// Adds the `ignore-df` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre [no]ignore-df`.
+    pub fn ignore_df(mut self, ignore_df: u8) -> Self {
+        self.info_data.push(InfoGreTun::IgnoreDf(ignore_df));
+        self
+    }
+
+    /// Adds the `fwmark` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type gre fwmark MARK`.
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.info_data.push(InfoGreTun::FwMark(mark));
+        self
+    }
+}
+
+/// A request to create a new IP-in-IP link. This is equivalent to the
+/// `ip link add NAME type { ipip | sit } ...` commands.
+pub struct IpTunAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    info_data: Vec<InfoIpTun>,
+}
+
+impl IpTunAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let data = match self.kind {
+            InfoKind::SitTun => InfoData::SitTun(self.info_data),
+            _ => InfoData::IpTun(self.info_data),
+        };
+        self.request
+            .link_info(self.kind, Some(data))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip dev LINK`,
+    /// but instead of specifing a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoIpTun::Link(index));
+        self
+    }
+
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip local ADDR`.
+    pub fn local(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoIpTun::Local(addr.into()));
+        self
+    }
+
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip remote ADDR`.
+    pub fn remote(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoIpTun::Remote(addr.into()));
+        self
+    }
+
+    /// Adds the `ttl` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip ttl TTL`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoIpTun::Ttl(ttl));
+        self
+    }
+
+    /// Adds the `tos` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoIpTun::Tos(tos));
+        self
+    }
+
+    /// Adds the `pmtudisc` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip [no]pmtudisc`.
+    pub fn pmtudisc(mut self, pmtudisc: u8) -> Self {
+        self.info_data.push(InfoIpTun::PMtuDisc(pmtudisc));
+        self
+    }
+
+    /// Adds the `mode` attribute to the tunnel, where `protocol` is the
+    /// inner IP protocol (`IPPROTO_IPIP`, `IPPROTO_IPV6`, `IPPROTO_MPLS`).
+    /// This is equivalent to `ip link add name NAME type sit mode MODE`.
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.info_data.push(InfoIpTun::Protocol(protocol));
+        self
+    }
+
+    /// Adds the tunnel flags (such as `SIT_ISATAP`) to the tunnel
+    /// This is equivalent to `ip link add name NAME type sit isatap`.
+    pub fn flags(mut self, flags: u16) -> Self {
+        self.info_data.push(InfoIpTun::Flags(flags));
+        self
+    }
+
+    /// Adds the `encap` attributes to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip encap TYPE encap-sport SPORT encap-dport DPORT`.
+    /// `encap_type` is one of the `TUNNEL_ENCAP_*` constants.
+    pub fn encap(mut self, encap_type: u16, sport: u16, dport: u16) -> Self {
+        self.info_data.push(InfoIpTun::EncapType(encap_type));
+        self.info_data.push(InfoIpTun::EncapSport(sport));
+        self.info_data.push(InfoIpTun::EncapDport(dport));
+        self
+    }
+
+    /// Adds the `external` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoIpTun::CollectMetadata);
+        self
+    }
+
+    /// Adds the `fwmark` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip fwmark MARK`.
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.info_data.push(InfoIpTun::FwMark(mark));
+        self
+    }
+}
+
+/// A request to create a new vti link. This is equivalent to the
+/// `ip link add NAME type vti ...` commands.
+pub struct VtiAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoVti>,
+}
+
+impl VtiAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::Vti, Some(InfoData::Vti(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti dev LINK`,
+    /// but instead of specifing a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoVti::Link(index));
+        self
+    }
+
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti local ADDR`.
+    pub fn local(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoVti::Local(addr.into()));
+        self
+    }
+
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti remote ADDR`.
+    pub fn remote(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoVti::Remote(addr.into()));
+        self
+    }
+
+    /// Adds the `ikey` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti ikey KEY`.
+    pub fn ikey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoVti::IKey(key));
+        self
+    }
+
+    /// Adds the `okey` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti okey KEY`.
+    pub fn okey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoVti::OKey(key));
+        self
+    }
+
+    /// Adds the `fwmark` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type vti fwmark MARK`.
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.info_data.push(InfoVti::FwMark(mark));
+        self
+    }
+}
+
 /// A request to create a new link. This is equivalent to the `ip link add` commands.
 ///
 /// A few methods for common actions (creating a veth pair, creating a vlan interface, etc.) are
@@ -638,6 +1005,73 @@ impl LinkAddRequest {
         }
     }
 
+    /// Create a GRE tunnel.
+    /// This is equivalent to `ip link add name NAME type gre`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn gre(self, name: String) -> GreAddRequest {
+        self.gre_request(name, InfoKind::GreTun)
+    }
+
+    /// Create a GRE tap tunnel.
+    /// This is equivalent to `ip link add name NAME type gretap`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn gretap(self, name: String) -> GreAddRequest {
+        self.gre_request(name, InfoKind::GreTap)
+    }
+
+    /// Create a GRE over IPv6 tunnel.
+    /// This is equivalent to `ip link add name NAME type ip6gre`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ip6gre(self, name: String) -> GreAddRequest {
+        self.gre_request(name, InfoKind::GreTun6)
+    }
+
+    /// Create a GRE tap over IPv6 tunnel.
+    /// This is equivalent to `ip link add name NAME type ip6gretap`,
+    /// it returns a GreAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ip6gretap(self, name: String) -> GreAddRequest {
+        self.gre_request(name, InfoKind::GreTap6)
+    }
+
+    /// Create an IPv4 over IPv4 tunnel.
+    /// This is equivalent to `ip link add name NAME type ipip`,
+    /// it returns a IpTunAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn ipip(self, name: String) -> IpTunAddRequest {
+        IpTunAddRequest {
+            request: self.name(name),
+            kind: InfoKind::IpTun,
+            info_data: vec![],
+        }
+    }
+
+    /// Create an IPv6 over IPv4 tunnel.
+    /// This is equivalent to `ip link add name NAME type sit`,
+    /// it returns a IpTunAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn sit(self, name: String) -> IpTunAddRequest {
+        IpTunAddRequest {
+            request: self.name(name),
+            kind: InfoKind::SitTun,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a virtual tunnel interface.
+    /// This is equivalent to `ip link add name NAME type vti`,
+    /// it returns a VtiAddRequest to further customize the tunnel
+    /// interface creation.
+    pub fn vti(self, name: String) -> VtiAddRequest {
+        VtiAddRequest {
+            request: self.name(name),
+            info_data: vec![],
+        }
+    }
+
     /// Create a new bond.
     /// This is equivalent to `ip link add link NAME type bond`.
     pub fn bond(self, name: String) -> BondAddRequest {
@@ -664,6 +1098,16 @@ impl LinkAddRequest {
         }
     }
 
+    fn gre_request(self, name: String, kind: InfoKind) -> GreAddRequest {
+        GreAddRequest {
+            request: self.name(name),
+            kind,
+            iflags: 0,
+            oflags: 0,
+            info_data: vec![],
+        }
+    }
+
     fn up(mut self) -> Self {
         self.message.header.flags = IFF_UP;
         self.message.header.change_mask = IFF_UP;
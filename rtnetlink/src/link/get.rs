@@ -7,7 +7,13 @@ use futures::{
 };
 
 use crate::{
-    packet::{constants::*, nlas::link::Nla, LinkMessage, NetlinkMessage, RtnlMessage},
+    packet::{
+        constants::*,
+        nlas::link::{Info, InfoKind, Nla},
+        LinkMessage,
+        NetlinkMessage,
+        RtnlMessage,
+    },
     try_rtnl,
     Error,
     Handle,
@@ -86,4 +92,17 @@ impl LinkGetRequest {
         self.message.nlas.push(Nla::IfName(name));
         self
     }
+
+    /// Dump only the links enslaved to the given master link, such as the
+    /// ports of a bridge or a bond
+    pub fn match_master(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(index));
+        self
+    }
+
+    /// Dump only the links of the given kind
+    pub fn match_kind(mut self, kind: InfoKind) -> Self {
+        self.message.nlas.push(Nla::Info(vec![Info::Kind(kind)]));
+        self
+    }
 }
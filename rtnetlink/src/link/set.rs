@@ -2,7 +2,7 @@
 
 use crate::{
     packet::{
-        nlas::link::Nla,
+        nlas::link::{Info, InfoBridgePort, InfoSlaveData, InfoSlaveKind, Nla},
         LinkMessage,
         NetlinkMessage,
         RtnlMessage,
@@ -137,4 +137,143 @@ impl LinkSetRequest {
         self.message.nlas.push(Nla::NetNsFd(fd));
         self
     }
+
+    /// Change the bridge port settings of the link with the given index, which must be enslaved
+    /// to a bridge. This is equivalent to `ip link set DEV type bridge_slave ...`, it returns a
+    /// BridgePortSetRequest to specify the settings to change.
+    pub fn bridge_port(self) -> BridgePortSetRequest {
+        BridgePortSetRequest {
+            request: self,
+            info_data: vec![],
+        }
+    }
+}
+
+/// A request to change the settings of a bridge port.
+/// This is equivalent to `ip link set DEV type bridge_slave ...` commands.
+///
+/// The kernel ignores slave attributes in `RTM_SETLINK` messages, so like `ip`, this request is
+/// sent as a `RTM_NEWLINK` message without the `NLM_F_CREATE` flag.
+pub struct BridgePortSetRequest {
+    request: LinkSetRequest,
+    info_data: Vec<InfoBridgePort>,
+}
+
+impl BridgePortSetRequest {
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgePortSetRequest {
+            request:
+                LinkSetRequest {
+                    mut handle,
+                    mut message,
+                },
+            info_data,
+        } = self;
+        message.nlas.push(Nla::Info(vec![
+            Info::SlaveKind(InfoSlaveKind::Bridge),
+            Info::SlaveData(InfoSlaveData::Bridge(info_data)),
+        ]));
+        let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the bridge port attributes of the request
+    pub fn info_data_mut(&mut self) -> &mut Vec<InfoBridgePort> {
+        &mut self.info_data
+    }
+
+    /// Enable or disable hairpin mode, which allows traffic to be sent back out of the port it
+    /// was received on (equivalent to `ip link set DEV type bridge_slave hairpin on/off`)
+    pub fn hairpin(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoBridgePort::Mode(enable as u8));
+        self
+    }
+
+    /// Enable or disable learning of source MAC addresses on the port (equivalent to
+    /// `ip link set DEV type bridge_slave learning on/off`)
+    pub fn learning(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoBridgePort::Learning(enable as u8));
+        self
+    }
+
+    /// Enable or disable ARP/ND suppression on the port (equivalent to
+    /// `ip link set DEV type bridge_slave neigh_suppress on/off`)
+    pub fn neigh_suppress(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoBridgePort::NeighSuppress(enable as u8));
+        self
+    }
+
+    /// Set the STP state of the port (equivalent to `ip link set DEV type bridge_slave state
+    /// STATE`). `0` is disabled, `1` listening, `2` learning, `3` forwarding and `4` blocking.
+    pub fn state(mut self, state: u8) -> Self {
+        self.info_data.push(InfoBridgePort::State(state));
+        self
+    }
+
+    /// Set the STP priority of the port (equivalent to `ip link set DEV type bridge_slave
+    /// priority PRIO`)
+    pub fn priority(mut self, priority: u16) -> Self {
+        self.info_data.push(InfoBridgePort::Priority(priority));
+        self
+    }
+
+    /// Set the STP path cost of the port (equivalent to `ip link set DEV type bridge_slave cost
+    /// COST`)
+    pub fn cost(mut self, cost: u32) -> Self {
+        self.info_data.push(InfoBridgePort::Cost(cost));
+        self
+    }
+
+    /// Enable or disable BPDU guard on the port (equivalent to
+    /// `ip link set DEV type bridge_slave guard on/off`)
+    pub fn guard(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoBridgePort::Guard(enable as u8));
+        self
+    }
+
+    /// Enable or disable flooding of unknown unicast traffic to the port (equivalent to
+    /// `ip link set DEV type bridge_slave flood on/off`)
+    pub fn flood(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoBridgePort::UnicastFlood(enable as u8));
+        self
+    }
+
+    /// Enable or disable flooding of multicast traffic to the port (equivalent to
+    /// `ip link set DEV type bridge_slave mcast_flood on/off`)
+    pub fn mcast_flood(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoBridgePort::McastFlood(enable as u8));
+        self
+    }
+
+    /// Enable or disable flooding of broadcast traffic to the port (equivalent to
+    /// `ip link set DEV type bridge_slave bcast_flood on/off`)
+    pub fn bcast_flood(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoBridgePort::BcastFlood(enable as u8));
+        self
+    }
+
+    /// Enable or disable proxy ARP on the port (equivalent to
+    /// `ip link set DEV type bridge_slave proxy_arp on/off`)
+    pub fn proxy_arp(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoBridgePort::ProxyArp(enable as u8));
+        self
+    }
+
+    /// Isolate the port, so that it can only communicate with non-isolated ports (equivalent to
+    /// `ip link set DEV type bridge_slave isolated on/off`)
+    pub fn isolated(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoBridgePort::Isolated(enable as u8));
+        self
+    }
 }
@@ -22,10 +22,19 @@ macro_rules! try_rtnl {
 #[macro_export]
 macro_rules! try_nl {
     ($msg: expr) => {{
-        use netlink_packet_route::NetlinkPayload;
+        use netlink_packet_route::{NetlinkMessage, NetlinkPayload};
         use $crate::Error;
-        if let NetlinkPayload::Error(err) = $msg.payload {
-            return Err(Error::NetlinkError(err));
+        match $msg.payload {
+            NetlinkPayload::Error(err) => return Err(Error::NetlinkError(err)),
+            // The socket overran and the acknowledgement may have been
+            // lost: we can't tell whether the request succeeded.
+            NetlinkPayload::Overrun(data) => {
+                return Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                    $msg.header,
+                    NetlinkPayload::Overrun(data),
+                )))
+            }
+            _ => {}
         }
     }};
 }
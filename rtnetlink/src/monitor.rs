@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{channel::mpsc::UnboundedReceiver, Stream};
+
+use crate::{
+    new_connection_with_socket,
+    packet::{
+        AddressMessage,
+        LinkMessage,
+        NeighbourMessage,
+        NetlinkMessage,
+        NetlinkPayload,
+        NexthopMessage,
+        NsidMessage,
+        RouteMessage,
+        RtnlMessage,
+        RuleMessage,
+        NLM_F_REPLACE,
+    },
+    proto::Connection,
+    sys::{AsyncSocket, SocketAddr},
+    Handle,
+};
+
+/// An event received by a [`Monitor`].
+///
+/// The kernel sends the same message when an object is created and when it is modified, so
+/// most `*Added` events are also emitted on changes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MonitorEvent {
+    /// A new link was created
+    LinkAdded(LinkMessage),
+    /// The state or attributes of an existing link changed
+    LinkChanged(LinkMessage),
+    LinkRemoved(LinkMessage),
+    AddressAdded(AddressMessage),
+    AddressRemoved(AddressMessage),
+    RouteAdded(RouteMessage),
+    /// A route replaced an existing one
+    RouteChanged(RouteMessage),
+    RouteRemoved(RouteMessage),
+    NeighbourAdded(NeighbourMessage),
+    NeighbourRemoved(NeighbourMessage),
+    RuleAdded(RuleMessage),
+    RuleRemoved(RuleMessage),
+    NexthopAdded(NexthopMessage),
+    NexthopRemoved(NexthopMessage),
+    NsIdAdded(NsidMessage),
+    NsIdRemoved(NsidMessage),
+    /// Any other message, for instance traffic control notifications
+    Other(NetlinkMessage<RtnlMessage>),
+    /// The socket receive buffer overflowed and some events were lost. The state tracked from
+    /// previous events should be considered stale and dumped again.
+    Overrun,
+}
+
+impl From<NetlinkMessage<RtnlMessage>> for MonitorEvent {
+    fn from(message: NetlinkMessage<RtnlMessage>) -> Self {
+        use self::MonitorEvent::*;
+
+        let replace = message.header.flags & NLM_F_REPLACE != 0;
+        match message.payload {
+            // The kernel sets all the bits of the change mask when
+            // announcing a link that was just registered.
+            NetlinkPayload::InnerMessage(RtnlMessage::NewLink(msg))
+                if msg.header.change_mask == u32::MAX =>
+            {
+                LinkAdded(msg)
+            }
+            NetlinkPayload::InnerMessage(RtnlMessage::NewLink(msg)) => LinkChanged(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelLink(msg)) => LinkRemoved(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::NewAddress(msg)) => AddressAdded(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelAddress(msg)) => AddressRemoved(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(msg)) if replace => {
+                RouteChanged(msg)
+            }
+            NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(msg)) => RouteAdded(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelRoute(msg)) => RouteRemoved(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(msg)) => NeighbourAdded(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelNeighbour(msg)) => NeighbourRemoved(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::NewRule(msg)) => RuleAdded(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelRule(msg)) => RuleRemoved(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::NewNexthop(msg)) => NexthopAdded(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelNexthop(msg)) => NexthopRemoved(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::NewNsId(msg)) => NsIdAdded(msg),
+            NetlinkPayload::InnerMessage(RtnlMessage::DelNsId(msg)) => NsIdRemoved(msg),
+            NetlinkPayload::Overrun(_) => Overrun,
+            payload => Other(NetlinkMessage::new(message.header, payload)),
+        }
+    }
+}
+
+/// A stream of [`MonitorEvent`], created with [`new_monitor`].
+pub struct Monitor {
+    messages: UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+}
+
+impl Stream for Monitor {
+    type Item = MonitorEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.messages)
+            .poll_next(cx)
+            .map(|message| message.map(|(message, _)| MonitorEvent::from(message)))
+    }
+}
+
+/// Create a new connection subscribed to the given multicast groups (`RTNLGRP_*` constants),
+/// along with a [`Monitor`] streaming the events sent to these groups (equivalent to `ip
+/// monitor`). The [`Handle`] can be used to dump the current state of the objects, for
+/// instance after a [`MonitorEvent::Overrun`].
+#[cfg(feature = "tokio_socket")]
+pub fn new_monitor(groups: &[u32]) -> io::Result<(Connection<RtnlMessage>, Handle, Monitor)> {
+    new_monitor_with_socket(groups)
+}
+
+/// Variant of [`new_monitor`] for a specific socket type.
+#[allow(clippy::type_complexity)]
+pub fn new_monitor_with_socket<S>(
+    groups: &[u32],
+) -> io::Result<(Connection<RtnlMessage, S>, Handle, Monitor)>
+where
+    S: AsyncSocket,
+{
+    let (mut conn, handle, messages) = new_connection_with_socket::<S>()?;
+    let socket = conn.socket_mut().socket_mut();
+    socket.bind_auto()?;
+    for group in groups {
+        socket.add_membership(*group)?;
+    }
+    Ok((conn, handle, Monitor { messages }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::MonitorEvent;
+    use crate::packet::{
+        LinkMessage,
+        NetlinkHeader,
+        NetlinkMessage,
+        NetlinkPayload,
+        RouteMessage,
+        RtnlMessage,
+        NLM_F_CREATE,
+        NLM_F_REPLACE,
+    };
+
+    fn message(flags: u16, payload: NetlinkPayload<RtnlMessage>) -> NetlinkMessage<RtnlMessage> {
+        let header = NetlinkHeader {
+            flags,
+            ..Default::default()
+        };
+        NetlinkMessage::new(header, payload)
+    }
+
+    #[test]
+    fn link_events() {
+        let mut link = LinkMessage::default();
+        link.header.change_mask = u32::MAX;
+        let msg = message(0, RtnlMessage::NewLink(link.clone()).into());
+        assert_eq!(MonitorEvent::from(msg), MonitorEvent::LinkAdded(link.clone()));
+
+        link.header.change_mask = 1;
+        let msg = message(0, RtnlMessage::NewLink(link.clone()).into());
+        assert_eq!(MonitorEvent::from(msg), MonitorEvent::LinkChanged(link));
+    }
+
+    #[test]
+    fn route_events() {
+        let route = RouteMessage::default();
+        let msg = message(NLM_F_CREATE, RtnlMessage::NewRoute(route.clone()).into());
+        assert_eq!(MonitorEvent::from(msg), MonitorEvent::RouteAdded(route.clone()));
+
+        let msg = message(NLM_F_REPLACE, RtnlMessage::NewRoute(route.clone()).into());
+        assert_eq!(MonitorEvent::from(msg), MonitorEvent::RouteChanged(route));
+    }
+
+    #[test]
+    fn overrun() {
+        let msg = message(0, NetlinkPayload::Overrun(vec![]));
+        assert_eq!(MonitorEvent::from(msg), MonitorEvent::Overrun);
+    }
+}
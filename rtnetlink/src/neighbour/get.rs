@@ -2,13 +2,13 @@
 
 use futures::{
     future::{self, Either},
-    stream::{StreamExt, TryStream},
+    stream::{StreamExt, TryStream, TryStreamExt},
     FutureExt,
 };
 
 use netlink_packet_route::{
     constants::*,
-    neighbour::NeighbourMessage,
+    neighbour::{NeighbourMessage, Nla},
     NetlinkPayload,
     RtnlMessage,
 };
@@ -39,6 +39,21 @@ impl NeighbourGetRequest {
         self
     }
 
+    /// Return only the neighbours of the given interface.
+    pub fn match_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
+    /// Return only the neighbours of the interfaces enslaved to the given
+    /// master interface, such as a bridge or a VRF.
+    ///
+    /// This filter is applied by the kernel (>= 4.18) only.
+    pub fn match_master(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(index));
+        self
+    }
+
     /// Execute the request
     pub fn execute(self) -> impl TryStream<Ok = NeighbourMessage, Error = Error> {
         let NeighbourGetRequest {
@@ -46,20 +61,30 @@ impl NeighbourGetRequest {
             message,
         } = self;
 
+        let index = message.nlas.iter().find_map(|nla| match nla {
+            Nla::IfIndex(index) => Some(*index),
+            _ => None,
+        });
         let mut req = NetlinkMessage::from(RtnlMessage::GetNeighbour(message));
         req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
 
         match handle.request(req) {
-            Ok(response) => Either::Left(response.map(move |msg| {
-                let (header, payload) = msg.into_parts();
-                match payload {
-                    NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(msg)) => Ok(msg),
-                    NetlinkPayload::Error(err) => Err(Error::NetlinkError(err)),
-                    _ => Err(Error::UnexpectedMessage(NetlinkMessage::new(
-                        header, payload,
-                    ))),
-                }
-            })),
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| {
+                        let (header, payload) = msg.into_parts();
+                        match payload {
+                            NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(msg)) => Ok(msg),
+                            NetlinkPayload::Error(err) => Err(Error::NetlinkError(err)),
+                            _ => Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                                header, payload,
+                            ))),
+                        }
+                    })
+                    .try_filter(move |msg| {
+                        future::ready(index.is_none() || index == Some(msg.header.ifindex))
+                    }),
+            ),
             Err(e) => Either::Right(future::err::<NeighbourMessage, Error>(e).into_stream()),
         }
     }
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::nexthop::{NexthopGroupEntry, Nla, ResilientGroup},
+    NetlinkMessage,
+    NexthopMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to create a new nexthop object. This is equivalent to the `ip nexthop add`
+/// command.
+pub struct NexthopAddRequest {
+    handle: Handle,
+    message: NexthopMessage,
+    replace: bool,
+}
+
+impl NexthopAddRequest {
+    pub(crate) fn new(handle: Handle, id: u32) -> Self {
+        let mut message = NexthopMessage::default();
+        message.header.family = AF_UNSPEC as u8;
+        message.header.protocol = RTPROT_STATIC;
+        message.nlas.push(Nla::Id(id));
+
+        NexthopAddRequest {
+            handle,
+            message,
+            replace: false,
+        }
+    }
+
+    /// Sets the output interface index.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Sets the gateway (via) address. This also sets the address family of the nexthop.
+    pub fn gateway(mut self, addr: IpAddr) -> Self {
+        let gtw = match addr {
+            IpAddr::V4(addr) => {
+                self.message.header.family = AF_INET as u8;
+                addr.octets().to_vec()
+            }
+            IpAddr::V6(addr) => {
+                self.message.header.family = AF_INET6 as u8;
+                addr.octets().to_vec()
+            }
+        };
+        self.message.nlas.push(Nla::Gateway(gtw));
+        self
+    }
+
+    /// Make this nexthop a blackhole: packets using it are silently dropped. Unless a gateway
+    /// is set, the nexthop is an IPv4 one.
+    pub fn blackhole(mut self) -> Self {
+        self.message.nlas.push(Nla::Blackhole);
+        self
+    }
+
+    /// Make this nexthop a multipath group of the given nexthops.
+    pub fn group(mut self, entries: Vec<NexthopGroupEntry>) -> Self {
+        self.message.nlas.push(Nla::Group(entries));
+        self
+    }
+
+    /// Make the group resilient, with the given number of hash buckets. Must be used along
+    /// with [`group`](Self::group).
+    pub fn resilient(mut self, buckets: u16) -> Self {
+        self.message.nlas.push(Nla::GroupType(NEXTHOP_GRP_TYPE_RES));
+        self.message
+            .nlas
+            .push(Nla::ResGroup(vec![ResilientGroup::Buckets(buckets)]));
+        self
+    }
+
+    /// Mark the nexthop (or group) as usable by the FDB of vxlan devices.
+    pub fn fdb(mut self) -> Self {
+        self.message.nlas.push(Nla::Fdb);
+        self
+    }
+
+    /// Sets the nexthop protocol.
+    ///
+    /// Default is static protocol.
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Replace the existing nexthop with the same identifier.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let NexthopAddRequest {
+            mut handle,
+            mut message,
+            replace,
+        } = self;
+        // Like iproute2, default to IPv4 for the nexthops that are not groups, since the
+        // kernel rejects them without an address family.
+        if message.header.family == AF_UNSPEC as u8
+            && !message.nlas.iter().any(|nla| matches!(nla, Nla::Group(_)))
+        {
+            message.header.family = AF_INET as u8;
+        }
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNexthop(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NexthopMessage {
+        &mut self.message
+    }
+}
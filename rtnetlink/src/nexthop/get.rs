@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::nexthop::Nla,
+    NetlinkMessage,
+    NexthopMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct NexthopGetRequest {
+    handle: Handle,
+    message: NexthopMessage,
+    dump: bool,
+}
+
+impl NexthopGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        NexthopGetRequest {
+            handle,
+            message: NexthopMessage::default(),
+            dump: true,
+        }
+    }
+
+    /// Only retrieve the nexthop with the given identifier.
+    pub fn match_id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::Id(id));
+        self.dump = false;
+        self
+    }
+
+    /// Only dump nexthops using the given output interface.
+    pub fn match_oif(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Only dump nexthops whose output interface is enslaved to the given master device.
+    pub fn match_master(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(index));
+        self
+    }
+
+    /// Only dump nexthop groups.
+    pub fn match_groups(mut self) -> Self {
+        self.message.nlas.push(Nla::Groups);
+        self
+    }
+
+    /// Only dump FDB nexthops.
+    pub fn match_fdb(mut self) -> Self {
+        self.message.nlas.push(Nla::Fdb);
+        self
+    }
+
+    pub fn message_mut(&mut self) -> &mut NexthopMessage {
+        &mut self.message
+    }
+
+    pub fn execute(self) -> impl TryStream<Ok = NexthopMessage, Error = Error> {
+        let NexthopGetRequest {
+            mut handle,
+            message,
+            dump,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNexthop(message));
+        req.header.flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNexthop))))
+            }
+            Err(e) => Either::Right(future::err::<NexthopMessage, Error>(e).into_stream()),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, NexthopAddRequest, NexthopDelRequest, NexthopGetRequest};
+
+pub struct NexthopHandle(Handle);
+
+impl NexthopHandle {
+    pub fn new(handle: Handle) -> Self {
+        NexthopHandle(handle)
+    }
+
+    /// Retrieve the list of nexthop objects (equivalent to `ip nexthop show`)
+    pub fn get(&self) -> NexthopGetRequest {
+        NexthopGetRequest::new(self.0.clone())
+    }
+
+    /// Add a nexthop object (equivalent to `ip nexthop add`)
+    pub fn add(&self, id: u32) -> NexthopAddRequest {
+        NexthopAddRequest::new(self.0.clone(), id)
+    }
+
+    /// Replace a nexthop object, creating it if it does not exist (equivalent to `ip nexthop
+    /// replace`)
+    pub fn replace(&self, id: u32) -> NexthopAddRequest {
+        NexthopAddRequest::new(self.0.clone(), id).replace()
+    }
+
+    /// Delete the nexthop object with the given identifier (equivalent to `ip nexthop del`)
+    pub fn del(&self, id: u32) -> NexthopDelRequest {
+        NexthopDelRequest::new(self.0.clone(), id)
+    }
+}
@@ -3,13 +3,25 @@
 use futures::stream::StreamExt;
 use std::{
     marker::PhantomData,
-    net::{Ipv4Addr, Ipv6Addr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
 
 use netlink_packet_route::{
     constants::*,
-    nlas::route::Nla,
+    nlas::route::{
+        Encap,
+        Ipv6SrHdr,
+        MplsIpTunnel,
+        MplsLabel,
+        NextHop,
+        Nla,
+        RouteNextHop,
+        Seg6Encap,
+        Seg6IpTunnel,
+        Via,
+    },
     NetlinkMessage,
+    RouteFlags,
     RouteMessage,
     RtnlMessage,
 };
@@ -53,6 +65,99 @@ impl<T> RouteAddRequest<T> {
         self
     }
 
+    /// Sets the nexthop object used by the route, see
+    /// [`Handle::nexthop`](crate::Handle::nexthop). Such routes must not have an output
+    /// interface or a gateway.
+    pub fn nexthop_id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::NhId(id));
+        self
+    }
+
+    /// Sets a gateway of another family than the route, such as an IPv6
+    /// gateway for an IPv4 route, or the next hop of an MPLS route.
+    pub fn via(mut self, addr: IpAddr) -> Self {
+        self.message.nlas.push(Nla::Via(Via::from(addr)));
+        self
+    }
+
+    /// Sets the lightweight tunnel encapsulation of the route, along with its
+    /// type. This is equivalent to `ip route add ... encap`.
+    pub fn encap(mut self, encap: Encap) -> Self {
+        self.message.nlas.push(Nla::EncapType(encap.encap_type()));
+        self.message.nlas.push(Nla::Encap(encap));
+        self
+    }
+
+    /// Push the given MPLS labels on the packets, the first one being the
+    /// outermost. This is equivalent to `ip route add ... encap mpls
+    /// <labels>`.
+    pub fn mpls_encap(self, labels: &[u32]) -> Self {
+        self.encap(Encap::Mpls(vec![MplsIpTunnel::Destination(
+            MplsLabel::stack(labels),
+        )]))
+    }
+
+    /// Steer the packets through the given segments with an IPv6 segment
+    /// routing header, `mode` being one of the `SEG6_IPTUN_MODE_*` constants.
+    /// This is equivalent to `ip route add ... encap seg6 mode <mode> segs
+    /// <segments>`. It panics if `segments` is empty, as the kernel rejects
+    /// headers without segments.
+    pub fn seg6_encap(self, mode: u32, segments: &[Ipv6Addr]) -> Self {
+        assert!(!segments.is_empty());
+        self.encap(Encap::Seg6(vec![Seg6IpTunnel::Srh(Seg6Encap {
+            mode,
+            srh: Ipv6SrHdr {
+                segments: segments.to_vec(),
+                ..Default::default()
+            },
+        })]))
+    }
+
+    /// Sets the next-hops of a multipath (ECMP) route, instead of a single
+    /// output interface and gateway. It must be called after [`v4`](Self::v4)
+    /// or [`v6`](Self::v6), as a gateway of another family than the route is
+    /// sent as `RTA_VIA`. This is equivalent to `ip route add ...
+    /// nexthop via <gateway> dev <interface> weight <weight> nexthop ...`.
+    pub fn multipath(mut self, next_hops: Vec<RouteNextHop>) -> Self {
+        let family = self.message.header.address_family;
+        let next_hops = next_hops
+            .into_iter()
+            .map(|nh| {
+                let mut nlas = vec![];
+                match nh.gateway {
+                    Some(IpAddr::V4(addr)) if family == AF_INET as u8 => {
+                        nlas.push(Nla::Gateway(addr.octets().to_vec()))
+                    }
+                    Some(IpAddr::V6(addr)) if family == AF_INET6 as u8 => {
+                        nlas.push(Nla::Gateway(addr.octets().to_vec()))
+                    }
+                    Some(addr) => nlas.push(Nla::Via(Via::from(addr))),
+                    None => {}
+                }
+                if let Some(encap) = nh.encap {
+                    nlas.push(Nla::EncapType(encap.encap_type()));
+                    nlas.push(Nla::Encap(encap));
+                }
+                NextHop {
+                    flags: nh.flags,
+                    // the weight is sent as its value minus one
+                    hops: (nh.weight.clamp(1, 256) - 1) as u8,
+                    interface_id: nh.interface_id,
+                    nlas,
+                }
+            })
+            .collect();
+        self.message.nlas.push(Nla::MultiPath(next_hops));
+        self
+    }
+
+    /// Use the gateway even if it does not match any prefix of the output
+    /// interface. This is equivalent to `ip route add ... onlink`.
+    pub fn onlink(mut self) -> Self {
+        self.message.header.flags |= RouteFlags::RTNH_F_ONLINK;
+        self
+    }
+
     /// Sets the route table.
     ///
     /// Default is main route table.
@@ -107,6 +212,18 @@ impl<T> RouteAddRequest<T> {
         }
     }
 
+    /// Build an MPLS route request, which swaps, pushes or pops the label of
+    /// the packets received with a given label.
+    pub fn mpls(mut self) -> RouteAddRequest<MplsLabel> {
+        self.message.header.address_family = AF_MPLS as u8;
+        RouteAddRequest {
+            handle: self.handle,
+            message: self.message,
+            replace: false,
+            _phantom: Default::default(),
+        }
+    }
+
     /// Replace existing matching route.
     pub fn replace(self) -> Self {
         Self {
@@ -203,3 +320,25 @@ impl RouteAddRequest<Ipv6Addr> {
         self
     }
 }
+
+impl RouteAddRequest<MplsLabel> {
+    /// Sets the incoming label matched by the route.
+    pub fn label(mut self, label: u32) -> Self {
+        self.message.header.destination_prefix_length = 20;
+        let stack = MplsLabel::stack(&[label]);
+        self.message
+            .nlas
+            .push(Nla::Destination(MplsLabel::encode_stack(&stack)));
+        self
+    }
+
+    /// Sets the outgoing labels, the first one being the outermost. A single
+    /// label swaps the incoming label, several labels swap it and push the
+    /// other ones. Without outgoing labels, the incoming label is popped.
+    pub fn new_destination(mut self, labels: &[u32]) -> Self {
+        self.message
+            .nlas
+            .push(Nla::NewDestination(MplsLabel::stack(labels)));
+        self
+    }
+}
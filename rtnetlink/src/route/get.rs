@@ -2,11 +2,17 @@
 
 use futures::{
     future::{self, Either},
-    stream::{StreamExt, TryStream},
+    stream::{StreamExt, TryStream, TryStreamExt},
     FutureExt,
 };
 
-use netlink_packet_route::{constants::*, NetlinkMessage, RouteMessage, RtnlMessage};
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteMessage,
+    RtnlMessage,
+};
 
 use crate::{try_rtnl, Error, Handle};
 
@@ -61,20 +67,93 @@ impl RouteGetRequest {
         &mut self.message
     }
 
+    /// Return only the routes of the given table.
+    pub fn table(mut self, table: u32) -> Self {
+        self.message
+            .nlas
+            .retain(|nla| !matches!(nla, Nla::Table(_)));
+        self.message.nlas.push(Nla::Table(table));
+        self
+    }
+
+    /// Return only the routes of the given protocol (`RTPROT_KERNEL`,
+    /// `RTPROT_STATIC`...).
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Return only the routes of the given kind (`RTN_UNICAST`,
+    /// `RTN_LOCAL`...).
+    pub fn kind(mut self, kind: u8) -> Self {
+        self.message.header.kind = kind;
+        self
+    }
+
+    /// Return only the routes going through the given interface.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.retain(|nla| !matches!(nla, Nla::Oif(_)));
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
     pub fn execute(self) -> impl TryStream<Ok = RouteMessage, Error = Error> {
         let RouteGetRequest {
             mut handle,
             message,
         } = self;
 
+        let filter = RouteFilter::new(&message);
         let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
         req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
 
         match handle.request(req) {
-            Ok(response) => {
-                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewRoute))))
-            }
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewRoute)))
+                    .try_filter(move |msg| future::ready(filter.matches(msg))),
+            ),
             Err(e) => Either::Right(future::err::<RouteMessage, Error>(e).into_stream()),
         }
     }
 }
+
+// The kernel only honours the filters of the request when the socket has
+// `NETLINK_GET_STRICT_CHK` enabled, so they are applied again on the
+// dumped routes.
+struct RouteFilter {
+    table: u32,
+    protocol: u8,
+    kind: u8,
+    output_interface: Option<u32>,
+}
+
+impl RouteFilter {
+    fn new(request: &RouteMessage) -> Self {
+        RouteFilter {
+            table: request.table(),
+            protocol: request.header.protocol,
+            kind: request.header.kind,
+            output_interface: request.output_interface(),
+        }
+    }
+
+    fn matches(&self, route: &RouteMessage) -> bool {
+        if self.table != u32::from(RT_TABLE_UNSPEC) && route.table() != self.table {
+            return false;
+        }
+        if self.protocol != RTPROT_UNSPEC && route.header.protocol != self.protocol {
+            return false;
+        }
+        if self.kind != RTN_UNSPEC && route.header.kind != self.kind {
+            return false;
+        }
+        if let Some(index) = self.output_interface {
+            return route
+                .next_hops()
+                .iter()
+                .any(|next_hop| next_hop.interface_id == index);
+        }
+        true
+    }
+}
@@ -1,6 +1,15 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Handle, IpVersion, RouteAddRequest, RouteDelRequest, RouteGetRequest};
+use std::net::IpAddr;
+
+use crate::{
+    Handle,
+    IpVersion,
+    RouteAddRequest,
+    RouteDelRequest,
+    RouteGetRequest,
+    RouteLookupRequest,
+};
 use netlink_packet_route::RouteMessage;
 
 pub struct RouteHandle(Handle);
@@ -15,6 +24,12 @@ impl RouteHandle {
         RouteGetRequest::new(self.0.clone(), ip_version)
     }
 
+    /// Look up the route used to reach the given destination (equivalent to
+    /// `ip route get`)
+    pub fn lookup(&self, destination: IpAddr) -> RouteLookupRequest {
+        RouteLookupRequest::new(self.0.clone(), destination)
+    }
+
     /// Add an routing table entry (equivalent to `ip route add`)
     pub fn add(&self) -> RouteAddRequest {
         RouteAddRequest::new(self.0.clone())
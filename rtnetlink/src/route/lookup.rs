@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteFlags,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+/// A request to look up the route the kernel would use to reach a
+/// destination (equivalent to `ip route get`)
+pub struct RouteLookupRequest {
+    handle: Handle,
+    message: RouteMessage,
+}
+
+fn ip_to_bytes(addr: IpAddr) -> Vec<u8> {
+    match addr {
+        IpAddr::V4(addr) => addr.octets().to_vec(),
+        IpAddr::V6(addr) => addr.octets().to_vec(),
+    }
+}
+
+fn host_prefix_length(addr: IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+impl RouteLookupRequest {
+    pub(crate) fn new(handle: Handle, destination: IpAddr) -> Self {
+        let mut message = RouteMessage::default();
+        message.header.address_family = match destination {
+            IpAddr::V4(_) => AF_INET as u8,
+            IpAddr::V6(_) => AF_INET6 as u8,
+        };
+        message.header.destination_prefix_length = host_prefix_length(destination);
+        message
+            .nlas
+            .push(Nla::Destination(ip_to_bytes(destination)));
+
+        RouteLookupRequest { handle, message }
+    }
+
+    /// Sets the source address of the lookup. It must be of the same
+    /// family as the destination.
+    pub fn source(mut self, addr: IpAddr) -> Self {
+        self.message.header.source_prefix_length = host_prefix_length(addr);
+        self.message.nlas.push(Nla::Source(ip_to_bytes(addr)));
+        self
+    }
+
+    /// Sets the interface the packet would be received on. The lookup is
+    /// then done as for a forwarded packet.
+    pub fn input_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Iif(index));
+        self
+    }
+
+    /// Forces the output interface of the lookup.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Sets the firewall mark of the packet, for policy routing.
+    pub fn mark(mut self, mark: u32) -> Self {
+        self.message.nlas.push(Nla::Mark(mark));
+        self
+    }
+
+    /// Sets the user id of the socket sending the packet, for policy routing.
+    pub fn uid(mut self, uid: u32) -> Self {
+        self.message.nlas.push(Nla::Uid(uid.to_ne_bytes().to_vec()));
+        self
+    }
+
+    /// Sets the IP protocol of the packet (`IPPROTO_TCP`, `IPPROTO_UDP`...).
+    pub fn ip_protocol(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::IpProto(protocol));
+        self
+    }
+
+    /// Sets the source port of the packet. It requires
+    /// [`ip_protocol`](Self::ip_protocol) to be set.
+    pub fn source_port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::SourcePort(port));
+        self
+    }
+
+    /// Sets the destination port of the packet. It requires
+    /// [`ip_protocol`](Self::ip_protocol) to be set.
+    pub fn destination_port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::DestinationPort(port));
+        self
+    }
+
+    /// Return the matching FIB entry, instead of the route resolved for the
+    /// destination (equivalent to `ip route get fibmatch`).
+    pub fn fib_match(mut self) -> Self {
+        self.message.header.flags |= RouteFlags::RTM_F_FIB_MATCH;
+        self
+    }
+
+    /// Return the table the route was found in, instead of the main table.
+    pub fn lookup_table(mut self) -> Self {
+        self.message.header.flags |= RouteFlags::RTM_F_LOOKUP_TABLE;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+
+    /// Execute the request, returning the route found by the kernel.
+    pub async fn execute(self) -> Result<RouteMessage, Error> {
+        let RouteLookupRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
+        req.header.flags = NLM_F_REQUEST;
+
+        let mut response = handle.request(req)?;
+        match response.next().await {
+            Some(msg) => Ok(try_rtnl!(msg, RtnlMessage::NewRoute)),
+            None => Err(Error::RequestFailed),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{constants::*, NetlinkMessage, RtnlMessage, StatsMessage};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct StatsGetRequest {
+    handle: Handle,
+    message: StatsMessage,
+}
+
+impl StatsGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        StatsGetRequest {
+            handle,
+            message: StatsMessage::default(),
+        }
+    }
+
+    fn filter(mut self, attribute: u16) -> Self {
+        self.message.header.filter_mask |= 1 << (attribute - 1);
+        self
+    }
+
+    /// Only retrieve the statistics of the link with the given index.
+    pub fn match_index(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self
+    }
+
+    /// Retrieve the generic link statistics (`IFLA_STATS_LINK_64`). This is the default if no
+    /// other statistics are requested.
+    pub fn link64(self) -> Self {
+        self.filter(IFLA_STATS_LINK_64)
+    }
+
+    /// Retrieve the statistics specific to the link type (`IFLA_STATS_LINK_XSTATS`).
+    pub fn link_xstats(self) -> Self {
+        self.filter(IFLA_STATS_LINK_XSTATS)
+    }
+
+    /// Retrieve the statistics specific to the type of the master of the link
+    /// (`IFLA_STATS_LINK_XSTATS_SLAVE`).
+    pub fn link_xstats_slave(self) -> Self {
+        self.filter(IFLA_STATS_LINK_XSTATS_SLAVE)
+    }
+
+    /// Retrieve the offload statistics (`IFLA_STATS_LINK_OFFLOAD_XSTATS`).
+    pub fn offload_xstats(self) -> Self {
+        self.filter(IFLA_STATS_LINK_OFFLOAD_XSTATS)
+    }
+
+    /// Retrieve the address family specific statistics (`IFLA_STATS_AF_SPEC`).
+    pub fn af_spec(self) -> Self {
+        self.filter(IFLA_STATS_AF_SPEC)
+    }
+
+    pub fn message_mut(&mut self) -> &mut StatsMessage {
+        &mut self.message
+    }
+
+    pub fn execute(self) -> impl TryStream<Ok = StatsMessage, Error = Error> {
+        let StatsGetRequest {
+            mut handle,
+            mut message,
+        } = self;
+
+        if message.header.filter_mask == 0 {
+            message.header.filter_mask = 1 << (IFLA_STATS_LINK_64 - 1);
+        }
+        let dump = message.header.ifindex == 0;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetStats(message));
+        req.header.flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewStats))))
+            }
+            Err(e) => Either::Right(future::err::<StatsMessage, Error>(e).into_stream()),
+        }
+    }
+}
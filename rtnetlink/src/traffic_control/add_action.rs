@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        tc::{Action, TcActionMessageNla},
+        NetlinkMessage,
+        RtnlMessage,
+        TcActionMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficActionNewRequest {
+    handle: Handle,
+    message: TcActionMessage,
+    flags: u16,
+}
+
+impl TrafficActionNewRequest {
+    pub(crate) fn new(handle: Handle, flags: u16) -> Self {
+        Self {
+            handle,
+            message: TcActionMessage::default(),
+            flags: NLM_F_REQUEST | flags,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let Self {
+            mut handle,
+            message,
+            flags,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewTrafficAction(message));
+        req.header.flags = NLM_F_ACK | flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Add an action to the request. It holds the kind of the action and its
+    /// options, whose parameters set the index of the action; the kernel
+    /// picks an index when it is 0. The position of the action in the
+    /// request is set by this method.
+    /// Equivalent to `tc actions add action KIND ... index INDEX`.
+    pub fn action(mut self, mut action: Action) -> Self {
+        if !self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, TcActionMessageNla::Actions(_)))
+        {
+            self.message
+                .nlas
+                .push(TcActionMessageNla::Actions(Vec::new()));
+        }
+        for nla in self.message.nlas.iter_mut() {
+            if let TcActionMessageNla::Actions(actions) = nla {
+                action.tab = actions.len() as u16 + 1;
+                actions.push(action);
+                break;
+            }
+        }
+        self
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut TcActionMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        tc::{self, constants::*},
+        NetlinkMessage,
+        RtnlMessage,
+        TcMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        TCM_IFINDEX_MAGIC_BLOCK,
+        TC_H_MAKE,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficChainNewRequest {
+    handle: Handle,
+    message: TcMessage,
+    flags: u16,
+}
+
+impl TrafficChainNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, flags: u16) -> Self {
+        Self {
+            handle,
+            message: TcMessage::with_index(ifindex),
+            flags: NLM_F_REQUEST | flags,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let Self {
+            mut handle,
+            message,
+            flags,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewTrafficChain(message));
+        req.header.flags = NLM_F_ACK | flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Set block index.
+    /// Equivalent to `block BLOCK_INDEX`.
+    pub fn block(mut self, block_index: u32) -> Self {
+        assert_eq!(self.message.header.index, 0);
+        self.message.header.index = TCM_IFINDEX_MAGIC_BLOCK as i32;
+        self.message.header.parent = block_index;
+        self
+    }
+
+    /// Set parent.
+    /// Equivalent to `[ root | ingress | egress | parent CLASSID ]`
+    /// command args. They are mutually exlusive.
+    pub fn parent(mut self, parent: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = parent;
+        self
+    }
+
+    /// Set parent to root.
+    pub fn root(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Set parent to ingress.
+    pub fn ingress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_INGRESS);
+        self
+    }
+
+    /// Set parent to egress.
+    pub fn egress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_EGRESS);
+        self
+    }
+
+    /// Set the index of the chain.
+    /// Equivalent to `chain CHAIN_INDEX`.
+    pub fn chain(mut self, chain: u32) -> Self {
+        self.message
+            .nlas
+            .push(tc::Nla::Chain(chain.to_ne_bytes().to_vec()));
+        self
+    }
+}
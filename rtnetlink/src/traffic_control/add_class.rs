@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        tc::{self, constants::*},
+        NetlinkMessage,
+        RtnlMessage,
+        TcMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        TC_H_MAKE,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficClassNewRequest {
+    handle: Handle,
+    message: TcMessage,
+    flags: u16,
+}
+
+impl TrafficClassNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, flags: u16) -> Self {
+        Self {
+            handle,
+            message: TcMessage::with_index(ifindex),
+            flags: NLM_F_REQUEST | flags,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let Self {
+            mut handle,
+            message,
+            flags,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewTrafficClass(message));
+        req.header.flags = NLM_F_ACK | flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Set the class ID.
+    /// Equivalent to `classid MAJ:MIN`.
+    pub fn handle(mut self, maj: u16, min: u16) -> Self {
+        self.message.header.handle = TC_H_MAKE!((maj as u32) << 16, min as u32);
+        self
+    }
+
+    /// Set parent, either the qdisc or another class.
+    /// Equivalent to `parent CLASSID`.
+    pub fn parent(mut self, parent: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = parent;
+        self
+    }
+
+    /// Set parent to root.
+    pub fn root(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Attach a rate estimator to the class, so that its statistics report
+    /// its current rate. The rate is sampled every `2^interval` seconds, with
+    /// `interval` between -2 and 3, and averaged over `2^ewma_log` samples.
+    /// Equivalent to `estimator INTERVAL TIME_CONST`.
+    pub fn estimator(mut self, interval: i8, ewma_log: u8) -> Self {
+        self.message
+            .nlas
+            .push(tc::Nla::Rate(vec![interval as u8, ewma_log]));
+        self
+    }
+
+    /// New a class of a htb qdisc. Its parameters are given with
+    /// `tc::htb::Nla::Parms`.
+    /// Equivalent to `tc class add dev STRING ... htb`.
+    pub fn htb(mut self, data: Vec<tc::htb::Nla>) -> Self {
+        assert!(!self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, tc::Nla::Kind(_))));
+        self.message
+            .nlas
+            .push(tc::Nla::Kind(tc::htb::KIND.to_string()));
+        self.message.nlas.push(tc::Nla::Options(
+            data.into_iter().map(tc::TcOpt::Htb).collect(),
+        ));
+        self
+    }
+}
@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::os::unix::io::RawFd;
+
 use futures::stream::StreamExt;
 
 use crate::{
@@ -115,6 +117,16 @@ impl TrafficFilterNewRequest {
         self
     }
 
+    /// Set the chain of the filter. Chains other than 0 are only reached
+    /// from the actions with a `TC_ACT_GOTO_CHAIN` verdict.
+    /// Equivalent to `chain CHAIN_INDEX`.
+    pub fn chain(mut self, chain: u32) -> Self {
+        self.message
+            .nlas
+            .push(tc::Nla::Chain(chain.to_ne_bytes().to_vec()));
+        self
+    }
+
     /// The 32bit filter allows to match arbitrary bitfields in the packet.
     /// Equivalent to `tc filter ... u32`.
     pub fn u32(mut self, data: Vec<tc::u32::Nla>) -> Self {
@@ -132,6 +144,55 @@ impl TrafficFilterNewRequest {
         self
     }
 
+    /// The flower filter matches packets on their flow key: L2, L3 and L4
+    /// header fields, VLAN tags, tunnel metadata and connection tracking state.
+    /// Equivalent to `tc filter ... flower`.
+    pub fn flower(mut self, data: Vec<tc::flower::Nla>) -> Self {
+        assert!(!self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, tc::Nla::Kind(_))));
+        self.message
+            .nlas
+            .push(tc::Nla::Kind(tc::flower::KIND.to_string()));
+        self.message.nlas.push(tc::Nla::Options(
+            data.into_iter().map(tc::TcOpt::Flower).collect(),
+        ));
+        self
+    }
+
+    /// The BPF filter runs a classic BPF or an eBPF program on each packet.
+    /// Equivalent to `tc filter ... bpf`.
+    pub fn bpf(mut self, data: Vec<tc::bpf::Nla>) -> Self {
+        assert!(!self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, tc::Nla::Kind(_))));
+        self.message
+            .nlas
+            .push(tc::Nla::Kind(tc::bpf::KIND.to_string()));
+        self.message.nlas.push(tc::Nla::Options(
+            data.into_iter().map(tc::TcOpt::Bpf).collect(),
+        ));
+        self
+    }
+
+    /// Attach the eBPF program referred to by `fd`, which must have been
+    /// loaded as a `BPF_PROG_TYPE_SCHED_CLS` program. `name` is only used
+    /// when displaying the filter. In direct-action mode, the return code of
+    /// the program is the verdict of the filter (`TC_ACT_*`).
+    /// Equivalent to `tc filter ... bpf object-file FILE section NAME [ da ]`.
+    /// You need to set the `parent` and `protocol` before calling this method.
+    pub fn bpf_program(self, fd: RawFd, name: String, direct_action: bool) -> Self {
+        let mut nlas = vec![tc::bpf::Nla::Fd(fd), tc::bpf::Nla::Name(name)];
+        if direct_action {
+            nlas.push(tc::bpf::Nla::Flags(TCA_BPF_FLAG_ACT_DIRECT));
+        }
+        self.bpf(nlas)
+    }
+
     /// Use u32 to implement traffic redirect.
     /// Equivalent to
     /// `tc filter add [dev source] [parent ffff:] [protocol all] u32 match u8 0 0 action mirred egress redirect dev dest`
@@ -4,7 +4,7 @@ use futures::stream::StreamExt;
 
 use crate::{
     packet::{
-        tc::{constants::*, nlas},
+        tc::{self, constants::*, nlas},
         NetlinkMessage,
         RtnlMessage,
         TcMessage,
@@ -80,6 +80,103 @@ impl QDiscNewRequest {
             .push(nlas::Nla::Kind("ingress".to_string()));
         self
     }
+
+    /// Share the ingress filters of the qdisc with the other qdiscs bound
+    /// to the same block. Only for the ingress and clsact qdiscs.
+    /// Equivalent to `ingress_block BLOCK_INDEX`.
+    pub fn ingress_block(mut self, block_index: u32) -> Self {
+        self.message.nlas.push(nlas::Nla::IngressBlock(block_index));
+        self
+    }
+
+    /// Share the egress filters of the qdisc with the other qdiscs bound to
+    /// the same block. Only for the clsact qdisc.
+    /// Equivalent to `egress_block BLOCK_INDEX`.
+    pub fn egress_block(mut self, block_index: u32) -> Self {
+        self.message.nlas.push(nlas::Nla::EgressBlock(block_index));
+        self
+    }
+
+    /// New a clsact qdisc, which provides the ingress and egress hooks of
+    /// the filters.
+    /// Equivalent to `tc qdisc add dev STRING clsact`.
+    pub fn clsact(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_CLSACT;
+        self.message.header.handle = TC_H_MAKE!(TC_H_CLSACT, 0);
+        self.message
+            .nlas
+            .push(nlas::Nla::Kind(tc::clsact::KIND.to_string()));
+        self
+    }
+
+    fn options(mut self, kind: &str, options: Vec<tc::TcOpt>) -> Self {
+        assert!(!self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, nlas::Nla::Kind(_))));
+        self.message.nlas.push(nlas::Nla::Kind(kind.to_string()));
+        self.message.nlas.push(nlas::Nla::Options(options));
+        self
+    }
+
+    /// New a htb qdisc. Its parameters are given with
+    /// `tc::htb::Nla::Init`.
+    /// Equivalent to `tc qdisc add dev STRING ... htb`.
+    pub fn htb(self, data: Vec<tc::htb::Nla>) -> Self {
+        self.options(
+            tc::htb::KIND,
+            data.into_iter().map(tc::TcOpt::Htb).collect(),
+        )
+    }
+
+    /// New a tbf qdisc.
+    /// Equivalent to `tc qdisc add dev STRING ... tbf`.
+    pub fn tbf(self, data: Vec<tc::tbf::Nla>) -> Self {
+        self.options(
+            tc::tbf::KIND,
+            data.into_iter().map(tc::TcOpt::Tbf).collect(),
+        )
+    }
+
+    /// New a fq_codel qdisc.
+    /// Equivalent to `tc qdisc add dev STRING ... fq_codel`.
+    pub fn fq_codel(self, data: Vec<tc::fq_codel::Nla>) -> Self {
+        self.options(
+            tc::fq_codel::KIND,
+            data.into_iter().map(tc::TcOpt::FqCodel).collect(),
+        )
+    }
+
+    /// New a fq qdisc.
+    /// Equivalent to `tc qdisc add dev STRING ... fq`.
+    pub fn fq(self, data: Vec<tc::fq::Nla>) -> Self {
+        self.options(tc::fq::KIND, data.into_iter().map(tc::TcOpt::Fq).collect())
+    }
+
+    /// New a netem qdisc.
+    /// Equivalent to `tc qdisc add dev STRING ... netem`.
+    pub fn netem(self, qopt: tc::netem::TcNetemQopt, data: Vec<tc::netem::Nla>) -> Self {
+        let mut options = vec![tc::TcOpt::NetemQopt(qopt)];
+        options.extend(data.into_iter().map(tc::TcOpt::Netem));
+        self.options(tc::netem::KIND, options)
+    }
+
+    /// New a prio qdisc.
+    /// Equivalent to `tc qdisc add dev STRING ... prio`.
+    pub fn prio(self, qopt: tc::prio::TcPrioQopt) -> Self {
+        self.options(tc::prio::KIND, vec![tc::TcOpt::Prio(qopt)])
+    }
+
+    /// New a mqprio qdisc. It can only be the root qdisc of a multiqueue
+    /// device.
+    /// Equivalent to `tc qdisc add dev STRING root mqprio`.
+    pub fn mqprio(self, qopt: tc::mqprio::TcMqprioQopt, data: Vec<tc::mqprio::Nla>) -> Self {
+        let mut options = vec![tc::TcOpt::MqprioQopt(qopt)];
+        options.extend(data.into_iter().map(tc::TcOpt::Mqprio));
+        self.options(tc::mqprio::KIND, options)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        tc::{ActNla, Action, TcActionMessageNla},
+        NetlinkMessage,
+        RtnlMessage,
+        TcActionMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        NLM_F_ROOT,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficActionDelRequest {
+    handle: Handle,
+    message: TcActionMessage,
+    flags: u16,
+}
+
+impl TrafficActionDelRequest {
+    /// Delete the action of the given kind and index, or all the actions of
+    /// the kind when `index` is `None`
+    pub(crate) fn new(handle: Handle, kind: &str, index: Option<u32>) -> Self {
+        let mut nlas = vec![ActNla::Kind(kind.to_string())];
+        let mut flags = NLM_F_REQUEST | NLM_F_ACK;
+        match index {
+            Some(index) => nlas.push(ActNla::Index(index)),
+            None => flags |= NLM_F_ROOT,
+        }
+        let mut message = TcActionMessage::default();
+        message
+            .nlas
+            .push(TcActionMessageNla::Actions(vec![Action { tab: 1, nlas }]));
+        Self {
+            handle,
+            message,
+            flags,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let Self {
+            mut handle,
+            message,
+            flags,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelTrafficAction(message));
+        req.header.flags = flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut TcActionMessage {
+        &mut self.message
+    }
+}
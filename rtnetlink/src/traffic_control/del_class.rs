@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+use futures::StreamExt;
+
+use crate::{
+    packet::{
+        tc::constants::*,
+        NetlinkMessage,
+        RtnlMessage,
+        TcMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        TC_H_MAKE,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficClassDelRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl TrafficClassDelRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32) -> Self {
+        TrafficClassDelRequest {
+            handle,
+            message: TcMessage::with_index(ifindex),
+        }
+    }
+
+    // Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let TrafficClassDelRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelTrafficClass(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message)
+        }
+        Ok(())
+    }
+
+    /// Set the ID of the class to delete.
+    /// Equivalent to `classid MAJ:MIN`.
+    pub fn handle(mut self, maj: u16, min: u16) -> Self {
+        self.message.header.handle = TC_H_MAKE!((maj as u32) << 16, min as u32);
+        self
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        tc::{self, constants::*},
+        NetlinkMessage,
+        RtnlMessage,
+        TcMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        TCM_IFINDEX_MAGIC_BLOCK,
+        TC_H_MAKE,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficFilterDelRequest {
+    handle: Handle,
+    message: TcMessage,
+}
+
+impl TrafficFilterDelRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32) -> Self {
+        Self {
+            handle,
+            message: TcMessage::with_index(ifindex),
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let Self {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelTrafficFilter(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Set interface index.
+    /// Equivalent to `dev STRING`, dev and block are mutually exlusive.
+    pub fn index(mut self, index: i32) -> Self {
+        assert_eq!(self.message.header.index, 0);
+        self.message.header.index = index;
+        self
+    }
+
+    /// Set block index.
+    /// Equivalent to `block BLOCK_INDEX`.
+    pub fn block(mut self, block_index: u32) -> Self {
+        assert_eq!(self.message.header.index, 0);
+        self.message.header.index = TCM_IFINDEX_MAGIC_BLOCK as i32;
+        self.message.header.parent = block_index;
+        self
+    }
+
+    /// Set parent.
+    /// Equivalent to `[ root | ingress | egress | parent CLASSID ]`
+    /// command args. They are mutually exlusive.
+    pub fn parent(mut self, parent: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = parent;
+        self
+    }
+
+    /// Set parent to root.
+    pub fn root(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_ROOT;
+        self
+    }
+
+    /// Set parent to ingress.
+    pub fn ingress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_INGRESS);
+        self
+    }
+
+    /// Set parent to egress.
+    pub fn egress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_EGRESS);
+        self
+    }
+
+    /// Set priority. Without it, all the filters of the parent are deleted.
+    /// Equivalent to `priority PRIO` or `pref PRIO`.
+    pub fn priority(mut self, priority: u16) -> Self {
+        assert_eq!(self.message.header.info & TC_H_MAJ_MASK, 0);
+        self.message.header.info = TC_H_MAKE!((priority as u32) << 16, self.message.header.info);
+        self
+    }
+
+    /// Set protocol.
+    /// Equivalent to `protocol PROT`.
+    pub fn protocol(mut self, protocol: u16) -> Self {
+        assert_eq!(self.message.header.info & TC_H_MIN_MASK, 0);
+        self.message.header.info = TC_H_MAKE!(self.message.header.info, protocol as u32);
+        self
+    }
+
+    /// Set the handle of the filter to delete. Without it, all the filters of
+    /// the priority and protocol are deleted.
+    /// Equivalent to `handle FILTERID`.
+    pub fn handle(mut self, handle: u32) -> Self {
+        self.message.header.handle = handle;
+        self
+    }
+
+    /// Set the kind of the classifier of the filter, e.g. `tc::u32::KIND`.
+    /// The kernel rejects the request when it does not match the filter.
+    /// Equivalent to `tc filter del ... KIND`.
+    pub fn kind(mut self, kind: &str) -> Self {
+        assert!(!self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, tc::Nla::Kind(_))));
+        self.message.nlas.push(tc::Nla::Kind(kind.to_string()));
+        self
+    }
+
+    /// Set the chain of the filter.
+    /// Equivalent to `chain CHAIN_INDEX`.
+    pub fn chain(mut self, chain: u32) -> Self {
+        self.message
+            .nlas
+            .push(tc::Nla::Chain(chain.to_ne_bytes().to_vec()));
+        self
+    }
+}
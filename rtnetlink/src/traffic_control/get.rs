@@ -7,7 +7,18 @@ use futures::{
 };
 
 use crate::{
-    packet::{tc::constants::*, NetlinkMessage, RtnlMessage, TcMessage, NLM_F_DUMP, NLM_F_REQUEST},
+    packet::{
+        tc::constants::*,
+        tc::{ActNla, Action, NlaBitfield32, TcActionMessageNla},
+        NetlinkMessage,
+        RtnlMessage,
+        TcActionMessage,
+        TcMessage,
+        NLM_F_DUMP,
+        NLM_F_REQUEST,
+        TCM_IFINDEX_MAGIC_BLOCK,
+        TC_H_MAKE,
+    },
     try_rtnl,
     Error,
     Handle,
@@ -86,6 +97,14 @@ impl TrafficClassGetRequest {
             Err(e) => Either::Right(future::err::<TcMessage, Error>(e).into_stream()),
         }
     }
+
+    /// Only dump the classes of the qdisc whose handle is `parent`.
+    /// Equivalent to `tc class show dev STRING parent QDISC_ID`.
+    pub fn parent(mut self, parent: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = parent;
+        self
+    }
 }
 
 pub struct TrafficFilterGetRequest {
@@ -118,12 +137,35 @@ impl TrafficFilterGetRequest {
         }
     }
 
+    /// Dump the filters of a shared block instead of those of an interface.
+    /// Equivalent to `tc filter show block BLOCK_INDEX`.
+    pub fn block(mut self, block_index: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.index = TCM_IFINDEX_MAGIC_BLOCK as i32;
+        self.message.header.parent = block_index;
+        self
+    }
+
     /// Set parent to root.
     pub fn root(mut self) -> Self {
         assert_eq!(self.message.header.parent, TC_H_UNSPEC);
         self.message.header.parent = TC_H_ROOT;
         self
     }
+
+    /// Set parent to the ingress hook of a clsact qdisc.
+    pub fn ingress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_INGRESS);
+        self
+    }
+
+    /// Set parent to the egress hook of a clsact qdisc.
+    pub fn egress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_EGRESS);
+        self
+    }
 }
 
 pub struct TrafficChainGetRequest {
@@ -155,4 +197,103 @@ impl TrafficChainGetRequest {
             Err(e) => Either::Right(future::err::<TcMessage, Error>(e).into_stream()),
         }
     }
+
+    /// Dump the chains of a shared block instead of those of an interface.
+    /// Equivalent to `tc chain show block BLOCK_INDEX`.
+    pub fn block(mut self, block_index: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.index = TCM_IFINDEX_MAGIC_BLOCK as i32;
+        self.message.header.parent = block_index;
+        self
+    }
+
+    /// Set parent, the chains of the root qdisc are dumped by default.
+    pub fn parent(mut self, parent: u32) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = parent;
+        self
+    }
+
+    /// Set parent to the ingress hook of a clsact qdisc.
+    pub fn ingress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_INGRESS);
+        self
+    }
+
+    /// Set parent to the egress hook of a clsact qdisc.
+    pub fn egress(mut self) -> Self {
+        assert_eq!(self.message.header.parent, TC_H_UNSPEC);
+        self.message.header.parent = TC_H_MAKE!(TC_H_CLSACT, TC_H_MIN_EGRESS);
+        self
+    }
+}
+
+pub struct TrafficActionGetRequest {
+    handle: Handle,
+    message: TcActionMessage,
+    dump: bool,
+}
+
+impl TrafficActionGetRequest {
+    pub(crate) fn new(handle: Handle, kind: &str) -> Self {
+        let mut message = TcActionMessage::default();
+        message.nlas.push(TcActionMessageNla::Actions(vec![Action {
+            tab: 1,
+            nlas: vec![ActNla::Kind(kind.to_string())],
+        }]));
+        message.nlas.push(TcActionMessageNla::Flags(NlaBitfield32 {
+            value: TCA_ACT_FLAG_LARGE_DUMP_ON,
+            selector: TCA_ACT_FLAG_LARGE_DUMP_ON,
+        }));
+        TrafficActionGetRequest {
+            handle,
+            message,
+            dump: true,
+        }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = TcActionMessage, Error = Error> {
+        let TrafficActionGetRequest {
+            mut handle,
+            message,
+            dump,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetTrafficAction(message));
+        req.header.flags = if dump {
+            NLM_F_REQUEST | NLM_F_DUMP
+        } else {
+            NLM_F_REQUEST
+        };
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::GetTrafficAction))),
+            ),
+            Err(e) => Either::Right(future::err::<TcActionMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Only retrieve the action with the given index, instead of dumping all
+    /// the actions of the kind.
+    /// Equivalent to `tc actions get action KIND index INDEX`.
+    pub fn index(mut self, index: u32) -> Self {
+        self.dump = false;
+        self.message
+            .nlas
+            .retain(|nla| matches!(nla, TcActionMessageNla::Actions(_)));
+        if let Some(TcActionMessageNla::Actions(actions)) = self.message.nlas.first_mut() {
+            actions[0].nlas.push(ActNla::Index(index));
+        }
+        self
+    }
+
+    /// Only dump the actions used in the last `msecs` milliseconds.
+    /// Equivalent to `tc actions list action KIND since MSECS`.
+    pub fn since(mut self, msecs: u32) -> Self {
+        self.message.nlas.push(TcActionMessageNla::TimeDelta(msecs));
+        self
+    }
 }
@@ -4,8 +4,16 @@ use super::{
     QDiscDelRequest,
     QDiscGetRequest,
     QDiscNewRequest,
+    TrafficActionDelRequest,
+    TrafficActionGetRequest,
+    TrafficActionNewRequest,
+    TrafficChainDelRequest,
     TrafficChainGetRequest,
+    TrafficChainNewRequest,
+    TrafficClassDelRequest,
     TrafficClassGetRequest,
+    TrafficClassNewRequest,
+    TrafficFilterDelRequest,
     TrafficFilterGetRequest,
     TrafficFilterNewRequest,
 };
@@ -78,6 +86,29 @@ impl TrafficClassHandle {
     pub fn get(&mut self) -> TrafficClassGetRequest {
         TrafficClassGetRequest::new(self.handle.clone(), self.ifindex)
     }
+
+    /// Create a new traffic class, don't replace if the object already exists.
+    /// ( equivalent to `tc class add dev STRING`)
+    pub fn add(&mut self) -> TrafficClassNewRequest {
+        TrafficClassNewRequest::new(self.handle.clone(), self.ifindex, NLM_F_EXCL | NLM_F_CREATE)
+    }
+
+    /// Change the traffic class, the handle cannot be changed and neither can the parent.
+    /// ( equivalent to `tc class change dev STRING`)
+    pub fn change(&mut self) -> TrafficClassNewRequest {
+        TrafficClassNewRequest::new(self.handle.clone(), self.ifindex, 0)
+    }
+
+    /// Replace existing matching traffic class, create it if it doesn't already exist.
+    /// ( equivalent to `tc class replace dev STRING`)
+    pub fn replace(&mut self) -> TrafficClassNewRequest {
+        TrafficClassNewRequest::new(self.handle.clone(), self.ifindex, NLM_F_CREATE)
+    }
+
+    /// Delete the traffic class ( equivalent to `tc class del dev STRING`)
+    pub fn del(&mut self) -> TrafficClassDelRequest {
+        TrafficClassDelRequest::new(self.handle.clone(), self.ifindex)
+    }
 }
 
 pub struct TrafficFilterHandle {
@@ -114,6 +145,11 @@ impl TrafficFilterHandle {
     pub fn replace(&mut self) -> TrafficFilterNewRequest {
         TrafficFilterNewRequest::new(self.handle.clone(), self.ifindex, NLM_F_CREATE)
     }
+
+    /// Delete the filter ( equivalent to `tc filter del dev STRING`)
+    pub fn del(&mut self) -> TrafficFilterDelRequest {
+        TrafficFilterDelRequest::new(self.handle.clone(), self.ifindex)
+    }
 }
 
 pub struct TrafficChainHandle {
@@ -131,4 +167,52 @@ impl TrafficChainHandle {
     pub fn get(&mut self) -> TrafficChainGetRequest {
         TrafficChainGetRequest::new(self.handle.clone(), self.ifindex)
     }
+
+    /// Create a new chain, don't replace if the object already exists.
+    /// ( equivalent to `tc chain add dev STRING`)
+    pub fn add(&mut self) -> TrafficChainNewRequest {
+        TrafficChainNewRequest::new(self.handle.clone(), self.ifindex, NLM_F_EXCL | NLM_F_CREATE)
+    }
+
+    /// Delete the chain and its filters ( equivalent to `tc chain del dev STRING`)
+    pub fn del(&mut self) -> TrafficChainDelRequest {
+        TrafficChainDelRequest::new(self.handle.clone(), self.ifindex)
+    }
+}
+
+pub struct TrafficActionHandle(Handle);
+
+impl TrafficActionHandle {
+    pub fn new(handle: Handle) -> Self {
+        TrafficActionHandle(handle)
+    }
+
+    /// Retrieve the list of actions of a kind (equivalent to
+    /// `tc actions list action KIND`)
+    pub fn get(&mut self, kind: &str) -> TrafficActionGetRequest {
+        TrafficActionGetRequest::new(self.0.clone(), kind)
+    }
+
+    /// Create new actions, don't replace if an object already exists.
+    /// ( equivalent to `tc actions add`)
+    pub fn add(&mut self) -> TrafficActionNewRequest {
+        TrafficActionNewRequest::new(self.0.clone(), NLM_F_EXCL | NLM_F_CREATE)
+    }
+
+    /// Replace existing matching actions, create them if they don't already exist.
+    /// ( equivalent to `tc actions replace`)
+    pub fn replace(&mut self) -> TrafficActionNewRequest {
+        TrafficActionNewRequest::new(self.0.clone(), NLM_F_CREATE | NLM_F_REPLACE)
+    }
+
+    /// Delete an action ( equivalent to `tc actions del action KIND index INDEX`)
+    pub fn del(&mut self, kind: &str, index: u32) -> TrafficActionDelRequest {
+        TrafficActionDelRequest::new(self.0.clone(), kind, Some(index))
+    }
+
+    /// Delete all the actions of a kind that are not bound to a filter
+    /// ( equivalent to `tc actions flush action KIND`)
+    pub fn flush(&mut self, kind: &str) -> TrafficActionDelRequest {
+        TrafficActionDelRequest::new(self.0.clone(), kind, None)
+    }
 }
@@ -15,5 +15,26 @@ pub use self::del_qdisc::*;
 mod add_filter;
 pub use self::add_filter::*;
 
+mod del_filter;
+pub use self::del_filter::*;
+
+mod add_chain;
+pub use self::add_chain::*;
+
+mod del_chain;
+pub use self::del_chain::*;
+
+mod add_action;
+pub use self::add_action::*;
+
+mod del_action;
+pub use self::del_action::*;
+
+mod add_class;
+pub use self::add_class::*;
+
+mod del_class;
+pub use self::del_class::*;
+
 #[cfg(test)]
 mod test;
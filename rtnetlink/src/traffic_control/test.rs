@@ -240,7 +240,7 @@ async fn _get_chains(ifindex: i32) -> Vec<TcMessage> {
             Ok(None) => {
                 break;
             }
-            Err(NetlinkError(ErrorMessage { code, header: _ })) => {
+            Err(NetlinkError(ErrorMessage { code, .. })) => {
                 assert_eq!(code, -95);
                 eprintln!(
                     "The chain in traffic control is not supported, \
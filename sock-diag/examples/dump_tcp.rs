@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use sock_diag::{
+    new_connection,
+    packet::{
+        constants::*,
+        inet::{ExtensionFlags, StateFlags},
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    for family in [AF_INET, AF_INET6] {
+        let mut sockets = handle
+            .inet()
+            .dump(family, IPPROTO_TCP)
+            .states(StateFlags::ESTABLISHED | StateFlags::LISTEN)
+            .extensions(ExtensionFlags::INFO)
+            .execute();
+        while let Some(socket) = sockets.try_next().await.map_err(|e| format!("{}", e))? {
+            let id = &socket.header.socket_id;
+            println!(
+                "{}:{} -> {}:{} (state {}, inode {})",
+                id.source_address,
+                id.source_port,
+                id.destination_address,
+                id.destination_port,
+                socket.header.state,
+                socket.header.inode,
+            );
+        }
+    }
+    Ok(())
+}
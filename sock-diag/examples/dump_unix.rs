@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use sock_diag::new_connection;
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut sockets = handle.unix().dump().execute();
+    while let Some(socket) = sockets.try_next().await.map_err(|e| format!("{}", e))? {
+        println!(
+            "inode {} (state {}): name {:?}, peer {:?}",
+            socket.header.inode,
+            socket.header.state,
+            socket.name(),
+            socket.peer(),
+        );
+    }
+    Ok(())
+}
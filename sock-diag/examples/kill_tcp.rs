@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use std::env;
+
+use sock_diag::{
+    new_connection,
+    packet::{
+        constants::*,
+        inet::{InetFilter, StateFlags},
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        usage();
+        return Ok(());
+    }
+    let port: u16 = args[1].parse().unwrap_or_else(|_| {
+        eprintln!("invalid port");
+        std::process::exit(1);
+    });
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    for family in [AF_INET, AF_INET6] {
+        let killed = handle
+            .inet()
+            .dump(family, IPPROTO_TCP)
+            .states(StateFlags::ESTABLISHED)
+            .filter(InetFilter::DestinationPortEq(port))
+            .kill()
+            .await
+            .map_err(|e| format!("{}", e))?;
+        for socket in killed {
+            let id = &socket.header.socket_id;
+            println!(
+                "killed {}:{} -> {}:{}",
+                id.source_address, id.source_port, id.destination_address, id.destination_port,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn usage() {
+    eprintln!(
+        "usage:
+    cargo run --example kill_tcp -- <port>
+
+Note that you need to run this program as root. Instead of running cargo as root,
+build the example normally:
+
+    cd sock-diag ; cargo build --example kill_tcp
+
+Then find the binary in the target directory:
+
+    cd ../target/debug/example ; sudo ./kill_tcp <port>"
+    );
+}
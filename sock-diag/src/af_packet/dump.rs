@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        constants::*,
+        packet::{PacketRequest, PacketResponse, ShowFlags},
+        NetlinkMessage,
+        SockDiagMessage,
+    },
+    try_sock_diag,
+    Error,
+    Handle,
+};
+
+/// A request to dump the packet (`AF_PACKET`) sockets
+pub struct PacketDumpRequest {
+    handle: Handle,
+    message: PacketRequest,
+}
+
+impl PacketDumpRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let message = PacketRequest {
+            inode: 0,
+            show_flags: ShowFlags::INFO
+                | ShowFlags::MCLIST
+                | ShowFlags::RING_CFG
+                | ShowFlags::FANOUT
+                | ShowFlags::FILTER,
+            cookie: [0xff; 8],
+        };
+        PacketDumpRequest { handle, message }
+    }
+
+    /// Set the information returned as attributes of the responses. By
+    /// default, all the information but the memory usage of the sockets
+    /// is returned.
+    pub fn show_flags(mut self, show_flags: ShowFlags) -> Self {
+        self.message.show_flags = show_flags;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut PacketRequest {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = PacketResponse, Error = Error> {
+        let PacketDumpRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(SockDiagMessage::PacketRequest(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(*try_sock_diag!(msg, SockDiagMessage::PacketResponse))),
+            ),
+            Err(e) => Either::Right(future::err::<PacketResponse, Error>(e).into_stream()),
+        }
+    }
+}
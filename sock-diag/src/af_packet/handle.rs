@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, PacketDumpRequest};
+
+pub struct PacketHandle(Handle);
+
+impl PacketHandle {
+    pub fn new(handle: Handle) -> Self {
+        PacketHandle(handle)
+    }
+
+    /// List the packet (`AF_PACKET`) sockets (equivalent to `ss --packet --all`)
+    pub fn dump(&self) -> PacketDumpRequest {
+        PacketDumpRequest::new(self.0.clone())
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use std::io;
+
+use futures::channel::mpsc::UnboundedReceiver;
+
+use crate::{
+    packet::{NetlinkMessage, SockDiagMessage},
+    proto::Connection,
+    sys::{protocols::NETLINK_SOCK_DIAG, AsyncSocket, SocketAddr},
+    Handle,
+};
+
+#[cfg(feature = "tokio_socket")]
+#[allow(clippy::type_complexity)]
+pub fn new_connection() -> io::Result<(
+    Connection<SockDiagMessage>,
+    Handle,
+    UnboundedReceiver<(NetlinkMessage<SockDiagMessage>, SocketAddr)>,
+)> {
+    new_connection_with_socket()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn new_connection_with_socket<S>() -> io::Result<(
+    Connection<SockDiagMessage, S>,
+    Handle,
+    UnboundedReceiver<(NetlinkMessage<SockDiagMessage>, SocketAddr)>,
+)>
+where
+    S: AsyncSocket,
+{
+    let (mut conn, handle, messages) =
+        netlink_proto::new_connection_with_socket::<SockDiagMessage, S>(NETLINK_SOCK_DIAG)?;
+    conn.enable_ext_ack();
+    Ok((conn, Handle::new(handle), messages))
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use thiserror::Error;
+
+use crate::packet::{ErrorMessage, NetlinkMessage, SockDiagMessage};
+
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+pub enum Error {
+    #[error("Received an unexpected message {0:?}")]
+    UnexpectedMessage(NetlinkMessage<SockDiagMessage>),
+
+    #[error("Received a netlink error message {0}")]
+    NetlinkError(ErrorMessage),
+
+    #[error("A netlink request failed")]
+    RequestFailed,
+
+    #[error("Invalid socket filter: {0}")]
+    InvalidFilter(String),
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use futures::Stream;
+
+use crate::{
+    packet::{NetlinkMessage, SockDiagMessage},
+    proto::ConnectionHandle,
+    sys::SocketAddr,
+    Error,
+    InetHandle,
+    NetlinkHandle,
+    PacketHandle,
+    UnixHandle,
+    XdpHandle,
+};
+
+#[derive(Clone, Debug)]
+pub struct Handle(ConnectionHandle<SockDiagMessage>);
+
+impl Handle {
+    pub(crate) fn new(conn: ConnectionHandle<SockDiagMessage>) -> Self {
+        Handle(conn)
+    }
+
+    pub fn request(
+        &mut self,
+        message: NetlinkMessage<SockDiagMessage>,
+    ) -> Result<impl Stream<Item = NetlinkMessage<SockDiagMessage>>, Error> {
+        self.0
+            .request(message, SocketAddr::new(0, 0))
+            .map_err(|_| Error::RequestFailed)
+    }
+
+    /// Create a new handle, specifically for IPv4 and IPv6 socket requests
+    /// (equivalent to `ss --tcp`, `ss --udp`... commands)
+    pub fn inet(&self) -> InetHandle {
+        InetHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for UNIX domain socket requests
+    /// (equivalent to `ss --unix` commands)
+    pub fn unix(&self) -> UnixHandle {
+        UnixHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for netlink socket requests
+    /// (equivalent to `ss --netlink` commands)
+    pub fn netlink(&self) -> NetlinkHandle {
+        NetlinkHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for packet socket requests
+    /// (equivalent to `ss --packet` commands)
+    pub fn packet(&self) -> PacketHandle {
+        PacketHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for XDP socket requests
+    /// (equivalent to `ss --xdp` commands)
+    pub fn xdp(&self) -> XdpHandle {
+        XdpHandle::new(self.clone())
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        constants::*,
+        inet::{ExtensionFlags, InetRequest, SocketId, StateFlags},
+        NetlinkMessage,
+        SockDiagMessage,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+/// A request to close an IPv4 or IPv6 socket
+pub struct InetDestroyRequest {
+    handle: Handle,
+    message: InetRequest,
+}
+
+impl InetDestroyRequest {
+    pub(crate) fn new(handle: Handle, protocol: u8, socket_id: SocketId) -> Self {
+        let family = if socket_id.source_address.is_ipv6() {
+            AF_INET6
+        } else {
+            AF_INET
+        };
+        let message = InetRequest {
+            family,
+            protocol,
+            extensions: ExtensionFlags::empty(),
+            states: StateFlags::all(),
+            socket_id,
+            nlas: vec![],
+        };
+        InetDestroyRequest { handle, message }
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut InetRequest {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let InetDestroyRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(SockDiagMessage::InetDestroy(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(msg) = response.next().await {
+            try_nl!(msg);
+        }
+        Ok(())
+    }
+}
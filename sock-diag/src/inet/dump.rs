@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        constants::*,
+        inet::{
+            nlas::RequestNla,
+            ExtensionFlags,
+            InetFilter,
+            InetRequest,
+            InetResponse,
+            SocketId,
+            StateFlags,
+        },
+        NetlinkMessage,
+        SockDiagMessage,
+    },
+    try_sock_diag,
+    Error,
+    Handle,
+    InetDestroyRequest,
+};
+
+/// A request to dump the IPv4 or IPv6 sockets of a given protocol
+pub struct InetDumpRequest {
+    handle: Handle,
+    message: InetRequest,
+    // The error of a filter that could not be compiled, returned by
+    // `execute()`
+    error: Option<Error>,
+}
+
+impl InetDumpRequest {
+    pub(crate) fn new(handle: Handle, family: u8, protocol: u8) -> Self {
+        let socket_id = if family == AF_INET6 {
+            SocketId::new_v6()
+        } else {
+            SocketId::new_v4()
+        };
+        let message = InetRequest {
+            family,
+            protocol,
+            extensions: ExtensionFlags::empty(),
+            states: StateFlags::all(),
+            socket_id,
+            nlas: vec![],
+        };
+        InetDumpRequest {
+            handle,
+            message,
+            error: None,
+        }
+    }
+
+    /// Return only the sockets in the given states. By default, the
+    /// sockets in all the states are returned.
+    pub fn states(mut self, states: StateFlags) -> Self {
+        self.message.states = states;
+        self
+    }
+
+    /// Request the given extended information, such as the memory usage or
+    /// the `tcp_info` of the sockets, which is returned as attributes of
+    /// the responses.
+    pub fn extensions(mut self, extensions: ExtensionFlags) -> Self {
+        self.message.extensions |= extensions;
+        self
+    }
+
+    /// Return only the sockets matched by the given filter, which is run by
+    /// the kernel (equivalent to the filter of `ss`, such as `ss 'sport =
+    /// :443'`). The request fails with [`Error::InvalidFilter`] if the
+    /// filter is too large to be compiled.
+    pub fn filter(mut self, filter: InetFilter) -> Self {
+        match filter.to_bytecode() {
+            Ok(bytecode) => self.message.nlas.push(RequestNla::Bytecode(bytecode)),
+            Err(e) => self.error = Some(Error::InvalidFilter(e.to_string())),
+        }
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut InetRequest {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = InetResponse, Error = Error> {
+        let InetDumpRequest {
+            mut handle,
+            message,
+            error,
+        } = self;
+        if let Some(e) = error {
+            return Either::Right(future::err::<InetResponse, Error>(e).into_stream());
+        }
+
+        let mut req = NetlinkMessage::from(SockDiagMessage::InetRequest(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(*try_sock_diag!(msg, SockDiagMessage::InetResponse))),
+            ),
+            Err(e) => Either::Right(future::err::<InetResponse, Error>(e).into_stream()),
+        }
+    }
+
+    /// Close all the sockets matching the request (equivalent to `ss
+    /// --kill`), and return the sockets that were closed. The sockets that
+    /// are already closed or that cannot be closed, such as listening UDP
+    /// sockets, are skipped. See [`InetHandle::destroy`] for the
+    /// requirements.
+    ///
+    /// [`InetHandle::destroy`]: crate::InetHandle::destroy
+    pub async fn kill(self) -> Result<Vec<InetResponse>, Error> {
+        let handle = self.handle.clone();
+        let protocol = self.message.protocol;
+        let sockets: Vec<InetResponse> = self.execute().try_collect().await?;
+
+        let mut killed = Vec::with_capacity(sockets.len());
+        for socket in sockets {
+            let socket_id = socket.header.socket_id.clone();
+            match InetDestroyRequest::new(handle.clone(), protocol, socket_id)
+                .execute()
+                .await
+            {
+                Ok(()) => killed.push(socket),
+                Err(Error::NetlinkError(ref err))
+                    if err.code == -libc::ENOENT || err.code == -libc::EOPNOTSUPP => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(killed)
+    }
+}
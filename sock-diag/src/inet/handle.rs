@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{packet::inet::SocketId, Handle, InetDestroyRequest, InetDumpRequest};
+
+pub struct InetHandle(Handle);
+
+impl InetHandle {
+    pub fn new(handle: Handle) -> Self {
+        InetHandle(handle)
+    }
+
+    /// List the sockets of the given family (`AF_INET` or `AF_INET6`) and
+    /// IP protocol (one of the `IPPROTO_*` constants), such as the TCP
+    /// sockets with `dump(AF_INET, IPPROTO_TCP)` (equivalent to `ss -4
+    /// --tcp --all`)
+    pub fn dump(&self, family: u8, protocol: u8) -> InetDumpRequest {
+        InetDumpRequest::new(self.0.clone(), family, protocol)
+    }
+
+    /// Close the socket of the given IP protocol identified by `socket_id`
+    /// (equivalent to `ss --kill`). This requires `CAP_NET_ADMIN` and a
+    /// kernel built with `CONFIG_INET_DIAG_DESTROY`. The cookie of the
+    /// socket ID must either be the one returned by a dump, or be set to
+    /// `[0xff; 8]` to match any socket.
+    pub fn destroy(&self, protocol: u8, socket_id: SocketId) -> InetDestroyRequest {
+        InetDestroyRequest::new(self.0.clone(), protocol, socket_id)
+    }
+}
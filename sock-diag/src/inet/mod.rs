@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod dump;
+pub use self::dump::*;
+
+mod destroy;
+pub use self::destroy::*;
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+//! This crate provides methods to list the sockets of the system, along with
+//! their state and statistics, via the netlink sock_diag protocol (equivalent
+//! to the `ss` command).
+
+mod handle;
+pub use crate::handle::*;
+
+mod errors;
+pub use crate::errors::*;
+
+mod connection;
+pub use crate::connection::*;
+
+mod inet;
+pub use crate::inet::*;
+
+mod unix;
+pub use crate::unix::*;
+
+mod netlink;
+pub use crate::netlink::*;
+
+mod af_packet;
+pub use crate::af_packet::*;
+
+mod xdp;
+pub use crate::xdp::*;
+
+mod macros;
+
+pub use netlink_packet_sock_diag as packet;
+pub mod proto {
+    pub use netlink_proto::{
+        packet::{NetlinkMessage, NetlinkPayload},
+        Connection,
+        ConnectionHandle,
+        Error,
+    };
+}
+pub use netlink_proto::sys;
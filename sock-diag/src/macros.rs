@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+#[macro_export]
+macro_rules! try_sock_diag {
+    ($msg: expr, $message_type:path) => {{
+        use $crate::{
+            packet::{NetlinkMessage, NetlinkPayload, SockDiagMessage},
+            Error,
+        };
+
+        let (header, payload) = $msg.into_parts();
+        match payload {
+            NetlinkPayload::InnerMessage($message_type(msg)) => msg,
+            NetlinkPayload::Error(err) => return Err(Error::NetlinkError(err)),
+            _ => {
+                return Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                    header, payload,
+                )))
+            }
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! try_nl {
+    ($msg: expr) => {{
+        use $crate::{
+            packet::{NetlinkMessage, NetlinkPayload},
+            Error,
+        };
+        match $msg.payload {
+            NetlinkPayload::Error(err) => return Err(Error::NetlinkError(err)),
+            // The socket overran and the acknowledgement may have been
+            // lost: we can't tell whether the request succeeded.
+            NetlinkPayload::Overrun(data) => {
+                return Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                    $msg.header,
+                    NetlinkPayload::Overrun(data),
+                )))
+            }
+            _ => {}
+        }
+    }};
+}
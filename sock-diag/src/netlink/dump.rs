@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        constants::*,
+        netlink::{NetlinkRequest, NetlinkResponse, ShowFlags},
+        NetlinkMessage,
+        SockDiagMessage,
+    },
+    try_sock_diag,
+    Error,
+    Handle,
+};
+
+/// A request to dump the netlink sockets
+pub struct NetlinkDumpRequest {
+    handle: Handle,
+    message: NetlinkRequest,
+}
+
+impl NetlinkDumpRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let message = NetlinkRequest {
+            protocol: NDIAG_PROTO_ALL,
+            inode: 0,
+            show_flags: ShowFlags::GROUPS,
+            cookie: [0xff; 8],
+        };
+        NetlinkDumpRequest { handle, message }
+    }
+
+    /// Return only the sockets of the given netlink protocol, such as
+    /// `NETLINK_ROUTE`. By default, the sockets of all the protocols are
+    /// returned.
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.protocol = protocol;
+        self
+    }
+
+    /// Set the information returned as attributes of the responses. By
+    /// default, the multicast groups of the sockets are returned.
+    pub fn show_flags(mut self, show_flags: ShowFlags) -> Self {
+        self.message.show_flags = show_flags;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NetlinkRequest {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = NetlinkResponse, Error = Error> {
+        let NetlinkDumpRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(SockDiagMessage::NetlinkRequest(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(*try_sock_diag!(msg, SockDiagMessage::NetlinkResponse))),
+            ),
+            Err(e) => Either::Right(future::err::<NetlinkResponse, Error>(e).into_stream()),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, NetlinkDumpRequest};
+
+pub struct NetlinkHandle(Handle);
+
+impl NetlinkHandle {
+    pub fn new(handle: Handle) -> Self {
+        NetlinkHandle(handle)
+    }
+
+    /// List the netlink sockets (equivalent to `ss --netlink --all`)
+    pub fn dump(&self) -> NetlinkDumpRequest {
+        NetlinkDumpRequest::new(self.0.clone())
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        constants::*,
+        unix::{ShowFlags, StateFlags, UnixRequest, UnixResponse},
+        NetlinkMessage,
+        SockDiagMessage,
+    },
+    try_sock_diag,
+    Error,
+    Handle,
+};
+
+/// A request to dump the UNIX domain sockets
+pub struct UnixDumpRequest {
+    handle: Handle,
+    message: UnixRequest,
+}
+
+impl UnixDumpRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let message = UnixRequest {
+            state_flags: StateFlags::all(),
+            inode: 0,
+            show_flags: ShowFlags::NAME | ShowFlags::PEER | ShowFlags::RQLEN,
+            cookie: [0xff; 8],
+        };
+        UnixDumpRequest { handle, message }
+    }
+
+    /// Return only the sockets in the given states. By default, the
+    /// sockets in all the states are returned.
+    pub fn state_flags(mut self, state_flags: StateFlags) -> Self {
+        self.message.state_flags = state_flags;
+        self
+    }
+
+    /// Set the information returned as attributes of the responses. By
+    /// default, the name, the peer and the queue lengths of the sockets
+    /// are returned, as `ss` does.
+    pub fn show_flags(mut self, show_flags: ShowFlags) -> Self {
+        self.message.show_flags = show_flags;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut UnixRequest {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = UnixResponse, Error = Error> {
+        let UnixDumpRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(SockDiagMessage::UnixRequest(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(*try_sock_diag!(msg, SockDiagMessage::UnixResponse))),
+            ),
+            Err(e) => Either::Right(future::err::<UnixResponse, Error>(e).into_stream()),
+        }
+    }
+}
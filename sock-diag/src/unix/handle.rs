@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, UnixDumpRequest};
+
+pub struct UnixHandle(Handle);
+
+impl UnixHandle {
+    pub fn new(handle: Handle) -> Self {
+        UnixHandle(handle)
+    }
+
+    /// List the UNIX domain sockets (equivalent to `ss --unix --all`)
+    pub fn dump(&self) -> UnixDumpRequest {
+        UnixDumpRequest::new(self.0.clone())
+    }
+}
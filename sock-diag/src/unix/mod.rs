@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod dump;
+pub use self::dump::*;
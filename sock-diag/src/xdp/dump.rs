@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        constants::*,
+        xdp::{ShowFlags, XdpRequest, XdpResponse},
+        NetlinkMessage,
+        SockDiagMessage,
+    },
+    try_sock_diag,
+    Error,
+    Handle,
+};
+
+/// A request to dump the XDP (`AF_XDP`) sockets
+pub struct XdpDumpRequest {
+    handle: Handle,
+    message: XdpRequest,
+}
+
+impl XdpDumpRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let message = XdpRequest {
+            inode: 0,
+            show_flags: ShowFlags::all(),
+            cookie: [0xff; 8],
+        };
+        XdpDumpRequest { handle, message }
+    }
+
+    /// Set the information returned as attributes of the responses. By
+    /// default, all the information about the sockets is returned.
+    pub fn show_flags(mut self, show_flags: ShowFlags) -> Self {
+        self.message.show_flags = show_flags;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut XdpRequest {
+        &mut self.message
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = XdpResponse, Error = Error> {
+        let XdpDumpRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(SockDiagMessage::XdpRequest(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(*try_sock_diag!(msg, SockDiagMessage::XdpResponse))),
+            ),
+            Err(e) => Either::Right(future::err::<XdpResponse, Error>(e).into_stream()),
+        }
+    }
+}
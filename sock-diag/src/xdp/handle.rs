@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, XdpDumpRequest};
+
+pub struct XdpHandle(Handle);
+
+impl XdpHandle {
+    pub fn new(handle: Handle) -> Self {
+        XdpHandle(handle)
+    }
+
+    /// List the XDP (`AF_XDP`) sockets (equivalent to `ss --xdp --all`)
+    pub fn dump(&self) -> XdpDumpRequest {
+        XdpDumpRequest::new(self.0.clone())
+    }
+}
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io::Read,
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    os::unix::{fs::MetadataExt, io::FromRawFd, net::UnixListener},
+};
+
+use futures::stream::TryStreamExt;
+use sock_diag::{
+    new_connection,
+    packet::{
+        constants::*,
+        inet::{InetFilter, StateFlags},
+    },
+    sys::{protocols::NETLINK_ROUTE, AsyncSocket, TokioSocket},
+};
+
+#[tokio::test]
+async fn test_dump_tcp_listener() {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let sockets: Vec<_> = handle
+        .inet()
+        .dump(AF_INET, IPPROTO_TCP)
+        .states(StateFlags::LISTEN)
+        .execute()
+        .try_collect()
+        .await
+        .unwrap();
+    let socket = sockets
+        .iter()
+        .find(|socket| socket.header.socket_id.source_port == port)
+        .expect("listening socket not found");
+    assert_eq!(socket.header.family, AF_INET);
+    assert_eq!(socket.header.state, TCP_LISTEN);
+    assert_eq!(socket.header.socket_id.source_address, Ipv4Addr::LOCALHOST);
+}
+
+#[tokio::test]
+async fn test_dump_tcp_with_filter() {
+    let listeners: Vec<_> = (0..3)
+        .map(|_| TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap())
+        .collect();
+    let ports: Vec<_> = listeners
+        .iter()
+        .map(|listener| listener.local_addr().unwrap().port())
+        .collect();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let filter = InetFilter::SourcePortEq(ports[0])
+        .or(InetFilter::SourcePortEq(ports[1]))
+        .and(!InetFilter::SourcePortEq(ports[1]));
+    let sockets: Vec<_> = handle
+        .inet()
+        .dump(AF_INET, IPPROTO_TCP)
+        .filter(filter)
+        .execute()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(sockets.len(), 1);
+    assert_eq!(sockets[0].header.socket_id.source_port, ports[0]);
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+async fn test_kill_tcp_connection() {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let mut client = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+    let (_server, _) = listener.accept().unwrap();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let killed = handle
+        .inet()
+        .dump(AF_INET, IPPROTO_TCP)
+        .states(StateFlags::ESTABLISHED)
+        .filter(InetFilter::DestinationPortEq(port))
+        .kill()
+        .await
+        .unwrap();
+    assert_eq!(killed.len(), 1);
+    assert_eq!(killed[0].header.socket_id.destination_port, port);
+
+    let err = client.read(&mut [0; 1]).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(libc::ECONNABORTED));
+}
+
+#[tokio::test]
+async fn test_dump_unix_listener() {
+    let path = std::env::temp_dir().join(format!("sock-diag-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let _listener = UnixListener::bind(&path).unwrap();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let sockets: Vec<_> = handle.unix().dump().execute().try_collect().await.unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(sockets
+        .iter()
+        .any(|socket| socket.name().map(String::as_str) == path.to_str()));
+}
+
+#[tokio::test]
+async fn test_dump_netlink_groups() {
+    let mut socket = TokioSocket::new(NETLINK_ROUTE).unwrap();
+    let port_id = socket.socket_mut().bind_auto().unwrap().port_number();
+    socket.socket_mut().add_membership(3).unwrap();
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let sockets: Vec<_> = handle
+        .netlink()
+        .dump()
+        .protocol(NETLINK_ROUTE as u8)
+        .execute()
+        .try_collect()
+        .await
+        .unwrap();
+    let socket = sockets
+        .iter()
+        .find(|socket| socket.header.port_id == port_id)
+        .expect("netlink socket not found");
+    assert_eq!(socket.groups(), Some(&[3][..]));
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+async fn test_dump_packet_socket() {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_PACKET,
+            libc::SOCK_RAW,
+            (libc::ETH_P_ALL as u16).to_be() as i32,
+        )
+    };
+    assert!(fd >= 0);
+    // Take ownership of the socket so that it gets closed
+    let file = unsafe { std::fs::File::from_raw_fd(fd) };
+    let inode = file.metadata().unwrap().ino() as u32;
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let sockets: Vec<_> = handle
+        .packet()
+        .dump()
+        .execute()
+        .try_collect()
+        .await
+        .unwrap();
+    let socket = sockets
+        .iter()
+        .find(|socket| socket.header.inode == inode)
+        .expect("packet socket not found");
+    assert_eq!(socket.header.kind, SOCK_RAW);
+    assert_eq!(socket.header.protocol, libc::ETH_P_ALL as u16);
+    assert!(socket.info().is_some());
+}